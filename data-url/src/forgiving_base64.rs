@@ -1,7 +1,8 @@
 //! <https://infra.spec.whatwg.org/#forgiving-base64-decode>
+//! and <https://infra.spec.whatwg.org/#forgiving-base64-encode>
 
-use alloc::vec::Vec;
-use core::fmt;
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str};
 
 #[derive(Debug)]
 pub struct InvalidBase64(InvalidBase64Details);
@@ -87,9 +88,7 @@ where
     F: FnMut(&[u8]) -> Result<(), E>,
 {
     write_bytes: F,
-    bit_buffer: u32,
-    buffer_bit_length: u8,
-    padding_symbols: u8,
+    state: DecoderState,
 }
 
 impl<F, E> Decoder<F, E>
@@ -99,14 +98,38 @@ where
     pub fn new(write_bytes: F) -> Self {
         Self {
             write_bytes,
-            bit_buffer: 0,
-            buffer_bit_length: 0,
-            padding_symbols: 0,
+            state: DecoderState::default(),
         }
     }
 
     /// Feed to the decoder partial input in an ASCII-compatible encoding
     pub fn feed(&mut self, input: &[u8]) -> Result<(), DecodeError<E>> {
+        self.state.feed(input, &mut self.write_bytes)
+    }
+
+    /// Call this to signal the end of the input
+    pub fn finish(mut self) -> Result<(), DecodeError<E>> {
+        self.state.finish(&mut self.write_bytes)
+    }
+}
+
+/// The state of a [`Decoder`] between calls, separate from where output bytes are written
+#[derive(Default)]
+pub(crate) struct DecoderState {
+    bit_buffer: u32,
+    buffer_bit_length: u8,
+    padding_symbols: u8,
+}
+
+impl DecoderState {
+    pub(crate) fn feed<F, E>(
+        &mut self,
+        input: &[u8],
+        write_bytes: &mut F,
+    ) -> Result<(), DecodeError<E>>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
         for &byte in input.iter() {
             let value = BASE64_DECODE_TABLE[byte as usize];
             if value < 0 {
@@ -139,7 +162,7 @@ where
                     (self.bit_buffer >> 8) as u8,
                     self.bit_buffer as u8,
                 ];
-                (self.write_bytes)(&byte_buffer).map_err(DecodeError::WriteError)?;
+                write_bytes(&byte_buffer).map_err(DecodeError::WriteError)?;
                 self.buffer_bit_length = 0;
                 // No need to reset bit_buffer,
                 // since next time we’re only gonna read relevant bits.
@@ -148,8 +171,10 @@ where
         Ok(())
     }
 
-    /// Call this to signal the end of the input
-    pub fn finish(mut self) -> Result<(), DecodeError<E>> {
+    pub(crate) fn finish<F, E>(&mut self, write_bytes: &mut F) -> Result<(), DecodeError<E>>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
         match (self.buffer_bit_length, self.padding_symbols) {
             (0, 0) => {
                 // A multiple of four of alphabet symbols, and nothing else.
@@ -158,13 +183,13 @@ where
                 // A multiple of four of alphabet symbols, followed by two more symbols,
                 // optionally followed by two padding characters (which make a total multiple of four).
                 let byte_buffer = [(self.bit_buffer >> 4) as u8];
-                (self.write_bytes)(&byte_buffer).map_err(DecodeError::WriteError)?;
+                write_bytes(&byte_buffer).map_err(DecodeError::WriteError)?;
             }
             (18, 1) | (18, 0) => {
                 // A multiple of four of alphabet symbols, followed by three more symbols,
                 // optionally followed by one padding character (which make a total multiple of four).
                 let byte_buffer = [(self.bit_buffer >> 10) as u8, (self.bit_buffer >> 2) as u8];
-                (self.write_bytes)(&byte_buffer).map_err(DecodeError::WriteError)?;
+                write_bytes(&byte_buffer).map_err(DecodeError::WriteError)?;
             }
             (6, _) => return Err(InvalidBase64Details::LoneAlphabetSymbol.into()),
             _ => return Err(InvalidBase64Details::Padding.into()),
@@ -173,6 +198,139 @@ where
     }
 }
 
+/// <https://infra.spec.whatwg.org/#forgiving-base64-encode>
+pub fn encode_to_string(input: &[u8]) -> String {
+    let mut string = String::with_capacity(encoded_len(input.len()));
+    {
+        let mut encoder = Encoder::new(|symbols| {
+            string.push_str(symbols);
+            Ok::<_, Impossible>(())
+        });
+        match encoder.feed(input).and_then(|()| encoder.finish()) {
+            Ok(()) => {}
+            Err(e) => match e {},
+        }
+    }
+    string
+}
+
+/// The number of bytes decoded from `alphabet_symbols` symbols of valid base64.
+pub(crate) fn decoded_len(alphabet_symbols: usize) -> usize {
+    alphabet_symbols / 4 * 3 + [0, 0, 1, 2][alphabet_symbols % 4]
+}
+
+pub(crate) fn is_alphabet_symbol(byte: u8) -> bool {
+    BASE64_DECODE_TABLE[byte as usize] >= 0
+}
+
+/// The length of the base64 encoding of `input_len` bytes, including padding.
+pub(crate) fn encoded_len(input_len: usize) -> usize {
+    (input_len + 2) / 3 * 4
+}
+
+/// <https://infra.spec.whatwg.org/#forgiving-base64-encode>
+///
+/// This uses the standard alphabet with padding, as specified in
+/// <https://tools.ietf.org/html/rfc4648#section-4>.
+pub struct Encoder<F, E>
+where
+    F: FnMut(&str) -> Result<(), E>,
+{
+    write_symbols: F,
+    byte_buffer: [u8; 3],
+    buffer_length: u8,
+}
+
+impl<F, E> Encoder<F, E>
+where
+    F: FnMut(&str) -> Result<(), E>,
+{
+    pub fn new(write_symbols: F) -> Self {
+        Self {
+            write_symbols,
+            byte_buffer: [0; 3],
+            buffer_length: 0,
+        }
+    }
+
+    /// Feed to the encoder partial input
+    pub fn feed(&mut self, mut input: &[u8]) -> Result<(), E> {
+        if self.buffer_length > 0 {
+            while self.buffer_length < 3 {
+                match input.split_first() {
+                    Some((&byte, rest)) => {
+                        self.byte_buffer[self.buffer_length as usize] = byte;
+                        self.buffer_length += 1;
+                        input = rest;
+                    }
+                    None => return Ok(()),
+                }
+            }
+            let mut symbols = [0; 4];
+            encode_three_bytes(self.byte_buffer, &mut symbols);
+            self.buffer_length = 0;
+            self.write(&symbols)?;
+        }
+
+        // Encode into a stack buffer, in order to make fewer write_symbols() calls.
+        let mut symbols = [0; 256];
+        let mut symbols_length = 0;
+        let mut chunks = input.chunks_exact(3);
+        for chunk in &mut chunks {
+            let bytes = [chunk[0], chunk[1], chunk[2]];
+            encode_three_bytes(bytes, &mut symbols[symbols_length..symbols_length + 4]);
+            symbols_length += 4;
+            if symbols_length == symbols.len() {
+                self.write(&symbols)?;
+                symbols_length = 0;
+            }
+        }
+        if symbols_length > 0 {
+            self.write(&symbols[..symbols_length])?;
+        }
+
+        let remainder = chunks.remainder();
+        self.byte_buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_length = remainder.len() as u8;
+        Ok(())
+    }
+
+    /// Call this to signal the end of the input
+    pub fn finish(mut self) -> Result<(), E> {
+        let length = self.buffer_length as usize;
+        if length == 0 {
+            return Ok(());
+        }
+        // One or two remaining bytes are followed by zero bits to make two or three symbols,
+        // then by padding characters to make a total multiple of four.
+        self.byte_buffer[length..].fill(0);
+        let mut symbols = [0; 4];
+        encode_three_bytes(self.byte_buffer, &mut symbols);
+        symbols[length + 1..].fill(b'=');
+        self.write(&symbols)
+    }
+
+    fn write(&mut self, symbols: &[u8]) -> Result<(), E> {
+        // SAFETY: symbols are from BASE64_ENCODE_TABLE or padding, which are ASCII.
+        (self.write_symbols)(unsafe { str::from_utf8_unchecked(symbols) })
+    }
+}
+
+fn encode_three_bytes(bytes: [u8; 3], symbols: &mut [u8]) {
+    // Three times 8 bits equals four times 6 bits.
+    let bit_buffer = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+    symbols[0] = BASE64_ENCODE_TABLE[(bit_buffer >> 18) as usize & 0x3F];
+    symbols[1] = BASE64_ENCODE_TABLE[(bit_buffer >> 12) as usize & 0x3F];
+    symbols[2] = BASE64_ENCODE_TABLE[(bit_buffer >> 6) as usize & 0x3F];
+    symbols[3] = BASE64_ENCODE_TABLE[bit_buffer as usize & 0x3F];
+}
+
+/// "Table 1: The Base 64 Alphabet" at <https://tools.ietf.org/html/rfc4648#section-4>
+///
+/// Array indices are positions in the base64 alphabet, array values are symbols.
+const BASE64_ENCODE_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
 /// Generated by `make_base64_decode_table.py` based on "Table 1: The Base 64 Alphabet"
 /// at <https://tools.ietf.org/html/rfc4648#section-4>
 ///
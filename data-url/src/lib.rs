@@ -14,6 +14,22 @@
 //! assert_eq!(body, b"Hello World!");
 //! assert!(fragment.is_none());
 //! ```
+//!
+//! Data URLs can also be built from a MIME type and a body:
+//!
+//! ```rust
+//! use data_url::{DataUrl, mime::Mime};
+//!
+//! let mime_type: Mime = "image/svg+xml".parse().unwrap();
+//! let url = DataUrl::builder(&mime_type).build(b"<svg/>").unwrap();
+//! assert_eq!(url, "data:image/svg+xml,%3Csvg/%3E");
+//! ```
+//!
+//! With the `url` feature, data URLs can also be processed from an already-parsed `url::Url`
+//! with `DataUrl::from_url` or `DataUrlExt::data_url`.
+//!
+//! With the `encoding_rs` feature, `DataUrl::decode_to_string` supports the legacy
+//! encodings of the Encoding Standard in addition to UTF-8, UTF-16 and windows-1252.
 #![no_std]
 
 // For forwards compatibility
@@ -26,7 +42,11 @@ extern crate alloc;
 #[cfg(not(feature = "alloc"))]
 compile_error!("the `alloc` feature must be enabled");
 
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt;
 
 macro_rules! require {
@@ -39,11 +59,14 @@ macro_rules! require {
 
 pub mod forgiving_base64;
 pub mod mime;
+mod text;
 
 pub struct DataUrl<'a> {
     mime_type: mime::Mime,
     base64: bool,
     encoded_body_plus_fragment: &'a str,
+    /// Set by [`DataUrl::with_max_body_len`]
+    max_body_len: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -67,6 +90,61 @@ impl fmt::Display for DataUrlError {
 #[cfg(feature = "std")]
 impl std::error::Error for DataUrlError {}
 
+/// The decoded body of a data URL is longer than [`DataUrl::with_max_body_len`] allows.
+#[derive(Debug)]
+pub struct BodyTooLong(());
+
+impl fmt::Display for BodyTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data url body is longer than the maximum length")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BodyTooLong {}
+
+/// The MIME type given to [`DataUrlBuilder`] would not be the result of processing the data URL.
+#[derive(Debug)]
+pub struct UnsupportedMimeType(());
+
+impl fmt::Display for UnsupportedMimeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mime type cannot be represented in a data url")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedMimeType {}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DecodeToStringError {
+    InvalidBase64(forgiving_base64::InvalidBase64),
+    /// The value of the MIME type’s `charset` parameter
+    UnsupportedCharset(String),
+    /// The decoded body is longer than [`DataUrl::with_max_body_len`] allows
+    BodyTooLong(BodyTooLong),
+}
+
+impl fmt::Display for DecodeToStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBase64(inner) => write!(f, "base64 not valid: {}", inner),
+            Self::UnsupportedCharset(charset) => write!(f, "unsupported charset: {}", charset),
+            Self::BodyTooLong(inner) => inner.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeToStringError {}
+
+impl From<forgiving_base64::InvalidBase64> for DecodeToStringError {
+    fn from(e: forgiving_base64::InvalidBase64) -> Self {
+        Self::InvalidBase64(e)
+    }
+}
+
 impl<'a> DataUrl<'a> {
     /// <https://fetch.spec.whatwg.org/#data-url-processor>
     /// but starting from a string rather than a parsed `Url`, to avoid extra string copies.
@@ -74,6 +152,26 @@ impl<'a> DataUrl<'a> {
         use crate::DataUrlError::*;
 
         let after_colon = pretend_parse_data_url(input).ok_or(NotADataUrl)?;
+        Self::process_after_colon(after_colon)
+    }
+
+    /// <https://fetch.spec.whatwg.org/#data-url-processor>
+    ///
+    /// This reuses the components of the parsed URL instead of serializing and parsing it again.
+    /// The result is the same as for [`DataUrl::process`] with the URL’s serialization.
+    #[cfg(feature = "url")]
+    pub fn from_url(url: &'a url::Url) -> Result<Self, DataUrlError> {
+        if url.scheme() != "data" {
+            return Err(DataUrlError::NotADataUrl);
+        }
+        // The URL’s serialization with the `data:` prefix removed.
+        // This includes the fragment, which `decode` returns separately.
+        let after_colon = &url.as_str()[url.scheme().len() + 1..];
+        Self::process_after_colon(after_colon)
+    }
+
+    fn process_after_colon(after_colon: &'a str) -> Result<Self, DataUrlError> {
+        use crate::DataUrlError::*;
 
         let (from_colon_to_comma, encoded_body_plus_fragment) =
             find_comma_before_fragment(after_colon).ok_or(NoComma)?;
@@ -84,15 +182,27 @@ impl<'a> DataUrl<'a> {
             mime_type,
             base64,
             encoded_body_plus_fragment,
+            max_body_len: None,
         })
     }
 
+    /// Return a builder for a data URL with the given MIME type.
+    pub fn builder(mime_type: &mime::Mime) -> DataUrlBuilder<'_> {
+        DataUrlBuilder {
+            mime_type,
+            body_encoding: None,
+        }
+    }
+
     pub fn mime_type(&self) -> &mime::Mime {
         &self.mime_type
     }
 
     /// Streaming-decode the data URL’s body to `write_body_bytes`,
     /// and return the URL’s fragment identifier if it has one.
+    ///
+    /// This ignores [`DataUrl::with_max_body_len`]: check [`DataUrl::decoded_body_len`]
+    /// first, or count the bytes in `write_body_bytes`.
     pub fn decode<F, E>(
         &self,
         write_body_bytes: F,
@@ -109,6 +219,9 @@ impl<'a> DataUrl<'a> {
     }
 
     /// Return the decoded body, and the URL’s fragment identifier if it has one.
+    ///
+    /// This ignores [`DataUrl::with_max_body_len`]: check [`DataUrl::decoded_body_len`]
+    /// first, or read the body with [`DataUrl::reader`].
     pub fn decode_to_vec(
         &self,
     ) -> Result<(Vec<u8>, Option<FragmentIdentifier<'a>>), forgiving_base64::InvalidBase64> {
@@ -119,6 +232,309 @@ impl<'a> DataUrl<'a> {
         })?;
         Ok((body, fragment))
     }
+
+    /// Return the body decoded as text according to the MIME type’s `charset` parameter,
+    /// and the URL’s fragment identifier if it has one.
+    ///
+    /// This follows <https://encoding.spec.whatwg.org/#decode>, including byte order mark sniffing
+    /// and replacement of malformed sequences, with UTF-8 when there is no `charset` parameter.
+    /// Supported encodings are UTF-8, UTF-16BE, UTF-16LE, and windows-1252
+    /// (whose labels include `US-ASCII` and `ISO-8859-1`),
+    /// and with the `encoding_rs` feature all the other encodings of the Encoding Standard.
+    ///
+    /// A body longer than [`DataUrl::with_max_body_len`] allows is an error,
+    /// found before decoding.
+    pub fn decode_to_string(
+        &self,
+    ) -> Result<(String, Option<FragmentIdentifier<'a>>), DecodeToStringError> {
+        let encoding = match self.mime_type.get_parameter("charset") {
+            Some(charset) => text::Encoding::for_label(charset)
+                .ok_or_else(|| DecodeToStringError::UnsupportedCharset(charset.to_owned()))?,
+            None => text::Encoding::Utf8,
+        };
+        if let Some(max_body_len) = self.max_body_len {
+            // Exact unless the body is invalid base64, which `decode_to_vec` reports
+            if self.decoded_body_len() > max_body_len {
+                return Err(DecodeToStringError::BodyTooLong(BodyTooLong(())));
+            }
+        }
+        let (body, fragment) = self.decode_to_vec()?;
+        Ok((text::decode(&body, encoding), fragment))
+    }
+
+    /// Return a reader of the decoded body, which is decoded incrementally as it is read.
+    ///
+    /// Invalid base64 and a body longer than [`DataUrl::with_max_body_len`] allows make
+    /// reading fail with [`std::io::ErrorKind::InvalidData`], with [`BodyTooLong`] as the
+    /// inner error in the latter case. Bytes up to the limit may have been read.
+    /// The fragment identifier is not included.
+    #[cfg(feature = "std")]
+    pub fn reader(&self) -> BodyReader<'a> {
+        let bytes = self.encoded_body_plus_fragment.as_bytes();
+        let body_len = bytes.iter().position(|&byte| byte == b'#');
+        BodyReader {
+            remaining: &bytes[..body_len.unwrap_or(bytes.len())],
+            base64: if self.base64 {
+                Some(forgiving_base64::DecoderState::default())
+            } else {
+                None
+            },
+            decoded: Vec::new(),
+            position: 0,
+            remaining_body_len: self.max_body_len.unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Return the length of the decoded body, without decoding it.
+    ///
+    /// This is exact unless the body is invalid base64, in which case decoding fails.
+    /// It does not allocate, so comparing it to a maximum length rejects oversized
+    /// bodies much more cheaply than decoding them.
+    pub fn decoded_body_len(&self) -> usize {
+        let mut len = 0;
+        let bytes = self.encoded_body_plus_fragment.as_bytes();
+        if self.base64 {
+            decode_until_fragment_infallible(bytes, |bytes| {
+                len += bytes
+                    .iter()
+                    .filter(|&&byte| forgiving_base64::is_alphabet_symbol(byte))
+                    .count()
+            });
+            forgiving_base64::decoded_len(len)
+        } else {
+            decode_until_fragment_infallible(bytes, |bytes| len += bytes.len());
+            len
+        }
+    }
+
+    /// Limit the decoded body to `max_body_len` bytes in [`DataUrl::reader`]
+    /// and [`DataUrl::decode_to_string`], which fail with [`BodyTooLong`] beyond that.
+    ///
+    /// [`DataUrl::decode`] and [`DataUrl::decode_to_vec`] ignore the limit.
+    /// To reject oversized bodies upfront, compare [`DataUrl::decoded_body_len`]
+    /// to the limit.
+    ///
+    /// ```rust
+    /// use data_url::DataUrl;
+    ///
+    /// let url = DataUrl::process("data:;base64,SGVsbG8gV29ybGQh").unwrap();
+    /// assert_eq!(url.decoded_body_len(), 12);
+    /// let url = url.with_max_body_len(8);
+    /// assert!(url.decode_to_string().is_err());
+    /// ```
+    pub fn with_max_body_len(self, max_body_len: usize) -> Self {
+        DataUrl {
+            max_body_len: Some(max_body_len),
+            ..self
+        }
+    }
+}
+
+/// The decoded body of a data URL, see [`DataUrl::reader`].
+#[cfg(feature = "std")]
+pub struct BodyReader<'a> {
+    /// The encoded body not decoded yet, without the fragment
+    remaining: &'a [u8],
+    /// `None` if not base64 or once finished
+    base64: Option<forgiving_base64::DecoderState>,
+    decoded: Vec<u8>,
+    position: usize,
+    /// How many more bytes can be decoded within [`DataUrl::with_max_body_len`]
+    remaining_body_len: usize,
+}
+
+#[cfg(feature = "std")]
+impl BodyReader<'_> {
+    fn decode_chunk(&mut self) -> Result<(), DecodeChunkError> {
+        const CHUNK_LEN: usize = 4096;
+
+        let mut chunk_len = self.remaining.len();
+        if chunk_len > CHUNK_LEN {
+            chunk_len = CHUNK_LEN;
+            // Do not split a percent-encoded byte between chunks
+            if let Some(i) = self.remaining[chunk_len - 2..chunk_len]
+                .iter()
+                .rposition(|&byte| byte == b'%')
+            {
+                chunk_len -= 2 - i;
+            }
+        }
+        let (chunk, remaining) = self.remaining.split_at(chunk_len);
+        self.remaining = remaining;
+        self.decoded.clear();
+        self.position = 0;
+
+        let decoded = &mut self.decoded;
+        let mut write_bytes = |bytes: &[u8]| {
+            decoded.extend_from_slice(bytes);
+            Ok::<_, forgiving_base64::Impossible>(())
+        };
+        if let Some(state) = &mut self.base64 {
+            let result = decode_until_fragment(chunk, |bytes| state.feed(bytes, &mut write_bytes))
+                .and_then(|_| {
+                    if remaining.is_empty() {
+                        state.finish(&mut write_bytes)
+                    } else {
+                        Ok(())
+                    }
+                });
+            if let Err(e) = result {
+                self.remaining = &[];
+                self.base64 = None;
+                return Err(DecodeChunkError::InvalidBase64(e.into()));
+            }
+            if remaining.is_empty() {
+                self.base64 = None;
+            }
+        } else {
+            decode_until_fragment_infallible(chunk, |bytes| decoded.extend_from_slice(bytes));
+        }
+        if self.decoded.len() > self.remaining_body_len {
+            self.remaining = &[];
+            self.base64 = None;
+            self.decoded.clear();
+            return Err(DecodeChunkError::BodyTooLong);
+        }
+        self.remaining_body_len -= self.decoded.len();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+enum DecodeChunkError {
+    InvalidBase64(forgiving_base64::InvalidBase64),
+    BodyTooLong,
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for BodyReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position == self.decoded.len() {
+            if self.remaining.is_empty() && self.base64.is_none() {
+                return Ok(0);
+            }
+            self.decode_chunk().map_err(|e| match e {
+                DecodeChunkError::InvalidBase64(e) => {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+                }
+                DecodeChunkError::BodyTooLong => {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, BodyTooLong(()))
+                }
+            })?;
+        }
+        let decoded = &self.decoded[self.position..];
+        let len = decoded.len().min(buf.len());
+        buf[..len].copy_from_slice(&decoded[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// Adds data URL processing to [`url::Url`].
+#[cfg(feature = "url")]
+pub trait DataUrlExt {
+    /// Process this URL as a data URL, see [`DataUrl::from_url`].
+    fn data_url(&self) -> Result<DataUrl<'_>, DataUrlError>;
+}
+
+#[cfg(feature = "url")]
+impl DataUrlExt for url::Url {
+    fn data_url(&self) -> Result<DataUrl<'_>, DataUrlError> {
+        DataUrl::from_url(self)
+    }
+}
+
+/// How the body of a data URL is encoded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BodyEncoding {
+    /// Bytes are percent-encoded if they are not printable ASCII
+    /// or if they have a special meaning in a URL.
+    Percent,
+    /// The body is base64-encoded and the MIME type is followed by `;base64`.
+    Base64,
+}
+
+/// Options for building a data URL, returned by [`DataUrl::builder`].
+#[derive(Copy, Clone)]
+pub struct DataUrlBuilder<'a> {
+    mime_type: &'a mime::Mime,
+    body_encoding: Option<BodyEncoding>,
+}
+
+impl<'a> DataUrlBuilder<'a> {
+    /// Use the given encoding for the body.
+    ///
+    /// By default, the encoding which gives the shortest URL is used.
+    pub fn body_encoding(mut self, new: BodyEncoding) -> Self {
+        self.body_encoding = Some(new);
+        self
+    }
+
+    /// Return a data URL which [`DataUrl::process`] turns back into this MIME type and `body`.
+    ///
+    /// This returns an error if the MIME type is not the result of parsing its own serialization,
+    /// or if its serialization contains `,`, `#`, `?`, or characters other than printable ASCII,
+    /// all of which would be interpreted differently when processing the URL.
+    pub fn build(self, body: &[u8]) -> Result<String, UnsupportedMimeType> {
+        let mut url = String::from("data:");
+        if *self.mime_type != default_mime_type() {
+            let header = self.mime_type.to_string();
+            let representable = header
+                .bytes()
+                .all(|byte| matches!(byte, b' '..=b'~') && !matches!(byte, b',' | b'#' | b'?'));
+            require_mime_type(representable)?;
+            require_mime_type(header.parse::<mime::Mime>().ok().as_ref() == Some(self.mime_type))?;
+            url.push_str(&header);
+        }
+
+        let base64 = match self.body_encoding {
+            Some(body_encoding) => body_encoding == BodyEncoding::Base64,
+            None => {
+                let percent_encoded_len: usize = body
+                    .iter()
+                    .map(|&byte| {
+                        if body_needs_percent_encoding(byte) {
+                            3
+                        } else {
+                            1
+                        }
+                    })
+                    .sum();
+                ";base64".len() + forgiving_base64::encoded_len(body.len()) < percent_encoded_len
+            }
+        };
+        if base64 {
+            url.push_str(";base64,");
+            url.push_str(&forgiving_base64::encode_to_string(body));
+        } else {
+            url.push(',');
+            for &byte in body {
+                if body_needs_percent_encoding(byte) {
+                    percent_encode(byte, &mut url)
+                } else {
+                    url.push(byte as char)
+                }
+            }
+        }
+        Ok(url)
+    }
+}
+
+fn require_mime_type(condition: bool) -> Result<(), UnsupportedMimeType> {
+    if condition {
+        Ok(())
+    } else {
+        Err(UnsupportedMimeType(()))
+    }
+}
+
+fn body_needs_percent_encoding(byte: u8) -> bool {
+    // Bytes that would be ignored, trimmed, or decoded when processing the URL,
+    // `#` which would start the fragment, and bytes that the URL parser would percent-encode.
+    matches!(
+        byte,
+        b'\0'..=b' ' | b'"' | b'#' | b'%' | b'<' | b'>' | b'`' | b'\x7F'..=b'\xFF'
+    )
 }
 
 /// The URL’s fragment identifier (after `#`)
@@ -232,13 +648,18 @@ fn parse_header(from_colon_to_comma: &str) -> (mime::Mime, bool) {
 
     // FIXME: does Mime::from_str match the MIME Sniffing Standard’s parsing algorithm?
     // <https://mimesniff.spec.whatwg.org/#parse-a-mime-type>
-    let mime_type = string.parse().unwrap_or_else(|_| mime::Mime {
+    let mime_type = string.parse().unwrap_or_else(|_| default_mime_type());
+
+    (mime_type, base64)
+}
+
+/// `text/plain;charset=US-ASCII`, used when the header is empty or fails to parse
+fn default_mime_type() -> mime::Mime {
+    mime::Mime {
         type_: String::from("text"),
         subtype: String::from("plain"),
         parameters: vec![(String::from("charset"), String::from("US-ASCII"))],
-    });
-
-    (mime_type, base64)
+    }
 }
 
 /// None: no base64 suffix
@@ -282,12 +703,21 @@ fn percent_encode(byte: u8, string: &mut String) {
 /// We skip that round-trip and pass it through unchanged.
 fn decode_without_base64<F, E>(
     encoded_body_plus_fragment: &str,
-    mut write_bytes: F,
+    write_bytes: F,
 ) -> Result<Option<FragmentIdentifier<'_>>, E>
 where
     F: FnMut(&[u8]) -> Result<(), E>,
 {
-    let bytes = encoded_body_plus_fragment.as_bytes();
+    let fragment_start = decode_until_fragment(encoded_body_plus_fragment.as_bytes(), write_bytes)?;
+    Ok(fragment_start.map(|start| FragmentIdentifier(&encoded_body_plus_fragment[start..])))
+}
+
+/// Like `decode_without_base64()` but on bytes,
+/// returning the start of the fragment (after '#') if there is one.
+fn decode_until_fragment<F, E>(bytes: &[u8], mut write_bytes: F) -> Result<Option<usize>, E>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
     let mut slice_start = 0;
     for (i, &byte) in bytes.iter().enumerate() {
         // We only need to look for 5 different "special" byte values.
@@ -316,11 +746,7 @@ where
                     }
                 }
 
-                b'#' => {
-                    let fragment_start = i + 1;
-                    let fragment = &encoded_body_plus_fragment[fragment_start..];
-                    return Ok(Some(FragmentIdentifier(fragment)));
-                }
+                b'#' => return Ok(Some(i + 1)),
 
                 // Ignore over '\t' | '\n' | '\r'
                 _ => slice_start = i + 1,
@@ -331,6 +757,20 @@ where
     Ok(None)
 }
 
+fn decode_until_fragment_infallible<F>(bytes: &[u8], mut write_bytes: F)
+where
+    F: FnMut(&[u8]),
+{
+    let result = decode_until_fragment(bytes, |bytes| {
+        write_bytes(bytes);
+        Ok::<_, forgiving_base64::Impossible>(())
+    });
+    match result {
+        Ok(_) => {}
+        Err(e) => match e {},
+    }
+}
+
 /// `decode_without_base64()` composed with
 /// <https://infra.spec.whatwg.org/#isomorphic-decode> composed with
 /// <https://infra.spec.whatwg.org/#forgiving-base64-decode>.
@@ -2,8 +2,10 @@ use alloc::{borrow::ToOwned, string::String, vec::Vec};
 use core::fmt::{self, Write};
 use core::str::FromStr;
 
+pub mod sniff;
+
 /// <https://mimesniff.spec.whatwg.org/#mime-type-representation>
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Mime {
     pub type_: String,
     pub subtype: String,
@@ -21,6 +23,133 @@ impl Mime {
             .find(|&(n, _)| name == &**n)
             .map(|(_, v)| &**v)
     }
+
+    /// Set the value of the parameter with the given name, or append a new parameter.
+    ///
+    /// Like in parsing, the name is ASCII-lowercased,
+    /// and an error is returned if it is empty or if either contains code points
+    /// that are not HTTP token code points or HTTP quoted-string token code points respectively.
+    pub fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), InvalidParameter> {
+        if name.is_empty() || !only_http_token_code_points(name) || !valid_value(value) {
+            return Err(InvalidParameter(()));
+        }
+        match self
+            .parameters
+            .iter_mut()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            Some((_, v)) => *v = value.to_owned(),
+            None => self
+                .parameters
+                .push((name.to_ascii_lowercase(), value.to_owned())),
+        }
+        Ok(())
+    }
+
+    /// Remove the parameter with the given name (ASCII case-insensitively) and return its value.
+    pub fn remove_parameter(&mut self, name: &str) -> Option<String> {
+        let index = self
+            .parameters
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))?;
+        Some(self.parameters.remove(index).1)
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#mime-type-essence>
+    pub fn essence(&self) -> String {
+        format!("{}/{}", self.type_, self.subtype)
+    }
+
+    fn is_essence(&self, type_: &str, subtype: &str) -> bool {
+        self.type_ == type_ && self.subtype == subtype
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#image-mime-type>
+    pub fn is_image(&self) -> bool {
+        self.type_ == "image"
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#audio-or-video-mime-type>
+    pub fn is_audio_or_video(&self) -> bool {
+        self.type_ == "audio" || self.type_ == "video" || self.is_essence("application", "ogg")
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#font-mime-type>
+    pub fn is_font(&self) -> bool {
+        self.type_ == "font"
+            || (self.type_ == "application"
+                && matches!(
+                    &*self.subtype,
+                    "font-cff"
+                        | "font-off"
+                        | "font-sfnt"
+                        | "font-ttf"
+                        | "font-woff"
+                        | "vnd.ms-fontobject"
+                        | "vnd.ms-opentype"
+                ))
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#zip-based-mime-type>
+    pub fn is_zip_based(&self) -> bool {
+        self.subtype.ends_with("+zip") || self.is_essence("application", "zip")
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#archive-mime-type>
+    pub fn is_archive(&self) -> bool {
+        self.type_ == "application"
+            && matches!(&*self.subtype, "x-rar-compressed" | "zip" | "x-gzip")
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#xml-mime-type>
+    pub fn is_xml(&self) -> bool {
+        self.subtype.ends_with("+xml")
+            || self.is_essence("text", "xml")
+            || self.is_essence("application", "xml")
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#html-mime-type>
+    pub fn is_html(&self) -> bool {
+        self.is_essence("text", "html")
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#scriptable-mime-type>
+    pub fn is_scriptable(&self) -> bool {
+        self.is_xml() || self.is_html() || self.is_essence("application", "pdf")
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#javascript-mime-type>
+    pub fn is_javascript(&self) -> bool {
+        match &*self.type_ {
+            "application" => matches!(
+                &*self.subtype,
+                "ecmascript" | "javascript" | "x-ecmascript" | "x-javascript"
+            ),
+            "text" => matches!(
+                &*self.subtype,
+                "ecmascript"
+                    | "javascript"
+                    | "javascript1.0"
+                    | "javascript1.1"
+                    | "javascript1.2"
+                    | "javascript1.3"
+                    | "javascript1.4"
+                    | "javascript1.5"
+                    | "jscript"
+                    | "livescript"
+                    | "x-ecmascript"
+                    | "x-javascript"
+            ),
+            _ => false,
+        }
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#json-mime-type>
+    pub fn is_json(&self) -> bool {
+        self.subtype.ends_with("+json")
+            || self.is_essence("application", "json")
+            || self.is_essence("text", "json")
+    }
 }
 
 #[derive(Debug)]
@@ -35,6 +164,123 @@ impl fmt::Display for MimeParsingError {
 #[cfg(feature = "std")]
 impl std::error::Error for MimeParsingError {}
 
+/// Returned by [`Mime::set_parameter`] for an invalid name or value.
+#[derive(Debug)]
+pub struct InvalidParameter(());
+
+impl fmt::Display for InvalidParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid mime type parameter")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidParameter {}
+
+/// Return the MIME type of a response from its `Content-Type` header values (in order),
+/// or `None` if there is no valid one.
+///
+/// <https://fetch.spec.whatwg.org/#concept-header-extract-mime-type>
+///
+/// ```rust
+/// use data_url::mime::extract_mime_type;
+///
+/// let mime = extract_mime_type([&b"text/html;charset=gbk"[..], b"text/html"]).unwrap();
+/// assert_eq!(mime.to_string(), "text/html;charset=gbk");
+/// ```
+pub fn extract_mime_type<'a, I>(content_type: I) -> Option<Mime>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let mut charset = None;
+    let mut essence: Option<(String, String)> = None;
+    let mut mime_type: Option<Mime> = None;
+    for value in get_decode_and_split(content_type) {
+        let mut temporary_mime_type = match parse(&value) {
+            Some(parsed) if !parsed.is_essence("*", "*") => parsed,
+            _ => continue,
+        };
+        let same_essence = essence.as_ref().map_or(false, |(type_, subtype)| {
+            temporary_mime_type.is_essence(type_, subtype)
+        });
+        if !same_essence {
+            charset = temporary_mime_type
+                .get_parameter("charset")
+                .map(str::to_owned);
+            essence = Some((
+                temporary_mime_type.type_.clone(),
+                temporary_mime_type.subtype.clone(),
+            ));
+        } else if temporary_mime_type.get_parameter("charset").is_none() {
+            if let Some(charset) = &charset {
+                temporary_mime_type
+                    .parameters
+                    .push((String::from("charset"), charset.clone()))
+            }
+        }
+        mime_type = Some(temporary_mime_type);
+    }
+    mime_type
+}
+
+/// <https://fetch.spec.whatwg.org/#concept-header-list-get-decode-split>
+/// given the values of all headers with a given name, in order.
+pub(crate) fn get_decode_and_split<'a, I>(header_values: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let mut header_values = header_values.into_iter().peekable();
+    if header_values.peek().is_none() {
+        return Vec::new();
+    }
+    // Get: combine the values with `, `, and isomorphic decode
+    let mut input = String::new();
+    for (i, value) in header_values.enumerate() {
+        if i > 0 {
+            input.push_str(", ")
+        }
+        input.extend(value.iter().map(|&byte| char::from(byte)))
+    }
+
+    // Split on commas outside of quoted strings
+    let mut values = Vec::new();
+    let mut temporary_value = String::new();
+    let mut chars = input.chars();
+    loop {
+        let mut ended = true;
+        while let Some(c) = chars.next() {
+            match c {
+                ',' => {
+                    ended = false;
+                    break;
+                }
+                '"' => {
+                    // Collect an HTTP quoted string, keeping quotes and backslashes
+                    temporary_value.push(c);
+                    while let Some(c) = chars.next() {
+                        temporary_value.push(c);
+                        match c {
+                            '"' => break,
+                            '\\' => temporary_value.extend(chars.next()),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => temporary_value.push(c),
+            }
+        }
+        values.push(
+            temporary_value
+                .trim_matches(|c| matches!(c, '\t' | ' '))
+                .to_owned(),
+        );
+        temporary_value.clear();
+        if ended {
+            return values;
+        }
+    }
+}
+
 /// <https://mimesniff.spec.whatwg.org/#parsing-a-mime-type>
 impl FromStr for Mime {
     type Err = MimeParsingError;
@@ -0,0 +1,637 @@
+//! MIME type sniffing according to the MIME Sniffing Standard:
+//! <https://mimesniff.spec.whatwg.org/#determining-the-computed-mime-type-of-a-resource>
+//!
+//! ```rust
+//! use data_url::mime::{sniff::Resource, Mime};
+//!
+//! let supplied: Mime = "text/plain".parse().unwrap();
+//! let header = b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR";
+//! let computed = Resource::new(Some(&supplied), header)
+//!     .check_for_apache_bug(true)
+//!     .computed_mime_type();
+//! assert_eq!(computed.to_string(), "application/octet-stream");
+//!
+//! let unknown = Resource::new(None, header).computed_mime_type();
+//! assert_eq!(unknown.to_string(), "image/png");
+//! ```
+
+use super::{get_decode_and_split, Mime};
+use alloc::{string::String, vec::Vec};
+
+/// The number of bytes of a resource that are looked at for sniffing.
+///
+/// <https://mimesniff.spec.whatwg.org/#reading-the-resource-header>
+pub const RESOURCE_HEADER_MAX_LEN: usize = 1445;
+
+/// A resource whose MIME type is to be determined.
+///
+/// <https://mimesniff.spec.whatwg.org/#resource>
+#[derive(Copy, Clone, Debug)]
+pub struct Resource<'a> {
+    supplied_mime_type: Option<&'a Mime>,
+    header: &'a [u8],
+    no_sniff: bool,
+    check_for_apache_bug: bool,
+}
+
+/// The contexts in which a resource can be sniffed.
+///
+/// <https://mimesniff.spec.whatwg.org/#context-specific-sniffing>
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Context {
+    Browsing,
+    Image,
+    AudioOrVideo,
+    Plugin,
+    Style,
+    Script,
+    Font,
+    TextTrack,
+    CacheManifest,
+}
+
+impl<'a> Resource<'a> {
+    /// `supplied_mime_type` is typically the parsed `Content-Type` header, if any.
+    /// `header` is the first bytes of the resource: only the first
+    /// [`RESOURCE_HEADER_MAX_LEN`] bytes are used, fewer are fine if that is all there is.
+    pub fn new(supplied_mime_type: Option<&'a Mime>, header: &'a [u8]) -> Self {
+        Resource {
+            supplied_mime_type,
+            header: &header[..header.len().min(RESOURCE_HEADER_MAX_LEN)],
+            no_sniff: false,
+            check_for_apache_bug: false,
+        }
+    }
+
+    /// Set the no-sniff flag, typically from [`determine_nosniff`].
+    pub fn no_sniff(mut self, new: bool) -> Self {
+        self.no_sniff = new;
+        self
+    }
+
+    /// Set the check-for-apache-bug flag, typically from [`has_apache_bug_content_type`].
+    pub fn check_for_apache_bug(mut self, new: bool) -> Self {
+        self.check_for_apache_bug = new;
+        self
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#mime-type-sniffing-algorithm>
+    ///
+    /// All image, audio, and video MIME types are considered to be supported.
+    pub fn computed_mime_type(&self) -> Mime {
+        let supplied = match self.supplied_mime_type {
+            Some(supplied)
+                if !matches!(
+                    (&*supplied.type_, &*supplied.subtype),
+                    ("unknown", "unknown") | ("application", "unknown") | ("*", "*")
+                ) =>
+            {
+                supplied
+            }
+            _ => return identify_unknown_mime_type(self.header, !self.no_sniff),
+        };
+        if self.no_sniff {
+            return supplied.clone();
+        }
+        if self.check_for_apache_bug {
+            return distinguish_text_or_binary(supplied, self.header);
+        }
+        if supplied.is_xml() {
+            return supplied.clone();
+        }
+        if supplied.is_html() {
+            return distinguish_feed_or_html(supplied, self.header);
+        }
+        if supplied.is_image() {
+            if let Some(matched) = match_image_type_pattern(self.header) {
+                return matched;
+            }
+        }
+        if supplied.is_audio_or_video() {
+            if let Some(matched) = match_audio_or_video_type_pattern(self.header) {
+                return matched;
+            }
+        }
+        supplied.clone()
+    }
+
+    /// <https://mimesniff.spec.whatwg.org/#context-specific-sniffing>
+    ///
+    /// Return `None` when the computed MIME type is the supplied MIME type and that is undefined.
+    pub fn sniff_in_context(&self, context: Context) -> Option<Mime> {
+        let supplied = self.supplied_mime_type.cloned();
+        let matched = match context {
+            Context::Browsing => return Some(self.computed_mime_type()),
+            Context::Plugin => {
+                return Some(supplied.unwrap_or_else(|| static_mime("application", "octet-stream")))
+            }
+            Context::Style | Context::Script => return supplied,
+            Context::TextTrack => return Some(static_mime("text", "vtt")),
+            Context::CacheManifest => return Some(static_mime("text", "cache-manifest")),
+            _ if supplied.as_ref().map_or(false, Mime::is_xml) => return supplied,
+            Context::Image => match_image_type_pattern(self.header),
+            Context::AudioOrVideo => match_audio_or_video_type_pattern(self.header),
+            Context::Font => match_font_type_pattern(self.header),
+        };
+        matched.or(supplied)
+    }
+}
+
+/// Return whether the `X-Content-Type-Options` header values (in order)
+/// ask for MIME type sniffing to be disabled.
+///
+/// <https://fetch.spec.whatwg.org/#determine-nosniff>
+pub fn determine_nosniff<'a, I>(x_content_type_options: I) -> bool
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    match get_decode_and_split(x_content_type_options).first() {
+        Some(first) => first.eq_ignore_ascii_case("nosniff"),
+        None => false,
+    }
+}
+
+/// Return whether a `Content-Type` header value is one of those that old versions of Apache
+/// send for binary resources, which should set [`Resource::check_for_apache_bug`].
+///
+/// <https://mimesniff.spec.whatwg.org/#supplied-mime-type-detection-algorithm>
+pub fn has_apache_bug_content_type(content_type: &[u8]) -> bool {
+    matches!(
+        content_type,
+        b"text/plain"
+            | b"text/plain; charset=ISO-8859-1"
+            | b"text/plain; charset=iso-8859-1"
+            | b"text/plain; charset=UTF-8"
+    )
+}
+
+/// <https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type>
+pub fn identify_unknown_mime_type(header: &[u8], sniff_scriptable: bool) -> Mime {
+    if sniff_scriptable {
+        if let Some(matched) = match_table(SCRIPTABLE_PATTERNS, header) {
+            return matched;
+        }
+    }
+    if let Some(matched) = match_table(NON_SCRIPTABLE_PATTERNS, header) {
+        return matched;
+    }
+    if let Some(matched) = match_image_type_pattern(header) {
+        return matched;
+    }
+    if let Some(matched) = match_audio_or_video_type_pattern(header) {
+        return matched;
+    }
+    if let Some(matched) = match_archive_type_pattern(header) {
+        return matched;
+    }
+    if !header.iter().copied().any(is_binary_data_byte) {
+        return static_mime("text", "plain");
+    }
+    static_mime("application", "octet-stream")
+}
+
+/// <https://mimesniff.spec.whatwg.org/#matching-an-image-type-pattern>
+pub fn match_image_type_pattern(header: &[u8]) -> Option<Mime> {
+    match_table(IMAGE_PATTERNS, header)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#matching-an-audio-or-video-type-pattern>
+pub fn match_audio_or_video_type_pattern(header: &[u8]) -> Option<Mime> {
+    if let Some(matched) = match_table(AUDIO_OR_VIDEO_PATTERNS, header) {
+        return Some(matched);
+    }
+    if matches_mp4_signature(header) {
+        return Some(static_mime("video", "mp4"));
+    }
+    if matches_webm_signature(header) {
+        return Some(static_mime("video", "webm"));
+    }
+    if matches_mp3_without_id3_signature(header) {
+        return Some(static_mime("audio", "mpeg"));
+    }
+    None
+}
+
+/// <https://mimesniff.spec.whatwg.org/#matching-a-font-type-pattern>
+pub fn match_font_type_pattern(header: &[u8]) -> Option<Mime> {
+    match_table(FONT_PATTERNS, header)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#matching-an-archive-type-pattern>
+pub fn match_archive_type_pattern(header: &[u8]) -> Option<Mime> {
+    match_table(ARCHIVE_PATTERNS, header)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#rules-for-text-or-binary>
+fn distinguish_text_or_binary(supplied: &Mime, header: &[u8]) -> Mime {
+    if header.starts_with(b"\xFE\xFF")
+        || header.starts_with(b"\xFF\xFE")
+        || header.starts_with(b"\xEF\xBB\xBF")
+    {
+        return supplied.clone();
+    }
+    if !header.iter().copied().any(is_binary_data_byte) {
+        return static_mime("text", "plain");
+    }
+    static_mime("application", "octet-stream")
+}
+
+/// <https://mimesniff.spec.whatwg.org/#rules-for-distinguishing-if-a-resource-is-a-feed-or-html>
+fn distinguish_feed_or_html(supplied: &Mime, header: &[u8]) -> Mime {
+    match sniff_feed(header) {
+        Some(subtype) => static_mime("application", subtype),
+        None => supplied.clone(),
+    }
+}
+
+/// Return the subtype of a feed, or `None` for the supplied MIME type.
+fn sniff_feed(sequence: &[u8]) -> Option<&'static str> {
+    const RSS_1_0: &[u8] = b"http://purl.org/rss/1.0/";
+    const RDF: &[u8] = b"http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+    let mut s = 0;
+    if sequence.starts_with(b"\xEF\xBB\xBF") {
+        s = 3;
+    }
+    let at = |s: usize, pattern: &[u8]| sequence[s.min(sequence.len())..].starts_with(pattern);
+    while s < sequence.len() {
+        // Skip whitespace until a `<`
+        loop {
+            let byte = *sequence.get(s)?;
+            s += 1;
+            if byte == b'<' {
+                break;
+            }
+            if !is_whitespace_byte(byte) {
+                return None;
+            }
+        }
+
+        if at(s, b"!--") {
+            s += 3;
+            while !at(s, b"-->") {
+                sequence.get(s)?;
+                s += 1;
+            }
+            s += 3;
+        } else if at(s, b"!") {
+            s += 1;
+            while !at(s, b">") {
+                sequence.get(s)?;
+                s += 1;
+            }
+            s += 1;
+        } else if at(s, b"?") {
+            s += 1;
+            while !at(s, b"?>") {
+                sequence.get(s)?;
+                s += 1;
+            }
+            s += 2;
+        } else if at(s, b"rss") {
+            return Some("rss+xml");
+        } else if at(s, b"feed") {
+            return Some("atom+xml");
+        } else if at(s, b"rdf:RDF") {
+            s += 7;
+            loop {
+                sequence.get(s)?;
+                for &(first, second) in &[(RSS_1_0, RDF), (RDF, RSS_1_0)] {
+                    if at(s, first) {
+                        s += first.len();
+                        loop {
+                            sequence.get(s)?;
+                            if at(s, second) {
+                                return Some("rss+xml");
+                            }
+                            s += 1;
+                        }
+                    }
+                }
+                s += 1;
+            }
+        } else {
+            return None;
+        }
+    }
+    None
+}
+
+/// <https://mimesniff.spec.whatwg.org/#signature-for-mp4>
+fn matches_mp4_signature(sequence: &[u8]) -> bool {
+    let length = sequence.len();
+    if length < 12 {
+        return false;
+    }
+    let box_size = u32::from_be_bytes([sequence[0], sequence[1], sequence[2], sequence[3]]);
+    let box_size = box_size as usize;
+    if length < box_size || box_size % 4 != 0 {
+        return false;
+    }
+    if &sequence[4..8] != b"ftyp" {
+        return false;
+    }
+    if &sequence[8..11] == b"mp4" {
+        return true;
+    }
+    // Skip the minor version
+    let mut bytes_read = 16;
+    while bytes_read < box_size {
+        if sequence[bytes_read..].starts_with(b"mp4") {
+            return true;
+        }
+        bytes_read += 4;
+    }
+    false
+}
+
+/// <https://mimesniff.spec.whatwg.org/#signature-for-webm>
+fn matches_webm_signature(sequence: &[u8]) -> bool {
+    let length = sequence.len();
+    if !sequence.starts_with(b"\x1A\x45\xDF\xA3") {
+        return false;
+    }
+    let mut iter = 4;
+    while iter < length && iter < 38 {
+        if sequence[iter..].starts_with(b"\x42\x82") {
+            iter += 2;
+            if iter >= length {
+                break;
+            }
+            iter += vint_size(sequence, iter);
+            if iter + 4 >= length {
+                break;
+            }
+            if matches_padded_sequence(b"webm", &sequence[iter..]) {
+                return true;
+            }
+        }
+        iter += 1;
+    }
+    false
+}
+
+/// The number of bytes in the variable size integer starting at `sequence[iter]`.
+///
+/// <https://mimesniff.spec.whatwg.org/#parse-a-vint>
+fn vint_size(sequence: &[u8], iter: usize) -> usize {
+    const MAX_VINT_LENGTH: usize = 8;
+    let mut mask = 0x80;
+    let mut number_size = 1;
+    while number_size < MAX_VINT_LENGTH && number_size < sequence.len() {
+        if sequence[iter] & mask != 0 {
+            break;
+        }
+        mask >>= 1;
+        number_size += 1;
+    }
+    number_size
+}
+
+/// <https://mimesniff.spec.whatwg.org/#matching-a-padded-sequence>
+fn matches_padded_sequence(pattern: &[u8], sequence: &[u8]) -> bool {
+    let padding = sequence.iter().take_while(|&&byte| byte == 0).count();
+    sequence[padding..].starts_with(pattern)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#signature-for-mp3-without-id3>
+fn matches_mp3_without_id3_signature(sequence: &[u8]) -> bool {
+    let frame = match parse_mp3_frame(sequence) {
+        Some(frame) => frame,
+        None => return false,
+    };
+    let skipped_bytes = frame.size();
+    if skipped_bytes < 4 || skipped_bytes > sequence.len() {
+        return false;
+    }
+    parse_mp3_frame(&sequence[skipped_bytes..]).is_some()
+}
+
+struct Mp3Frame {
+    version: u8,
+    bit_rate: u32,
+    sample_rate: u32,
+    padding: bool,
+}
+
+impl Mp3Frame {
+    /// <https://mimesniff.spec.whatwg.org/#compute-an-mp3-frame-size>
+    fn size(&self) -> usize {
+        let scale = if self.version == 1 { 72 } else { 144 };
+        let size = self.bit_rate * scale / self.sample_rate;
+        size as usize + usize::from(self.padding)
+    }
+}
+
+/// <https://mimesniff.spec.whatwg.org/#match-an-mp3-header>
+/// followed by <https://mimesniff.spec.whatwg.org/#parse-an-mp3-frame>
+fn parse_mp3_frame(sequence: &[u8]) -> Option<Mp3Frame> {
+    const MP3_RATES: [u32; 16] = [
+        0, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000, 160000, 192000, 224000,
+        256000, 320000, 0,
+    ];
+    const MP2_5_RATES: [u32; 16] = [
+        0, 8000, 16000, 24000, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000,
+        144000, 160000, 0,
+    ];
+    const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+    if sequence.len() < 4 || sequence[0] != 0xFF || sequence[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+    let layer = (sequence[1] & 0x06) >> 1;
+    if layer == 0 {
+        return None;
+    }
+    let bit_rate_index = (sequence[2] & 0xF0) >> 4;
+    if bit_rate_index == 15 {
+        return None;
+    }
+    let sample_rate_index = (sequence[2] & 0x0C) >> 2;
+    let sample_rate = *SAMPLE_RATES.get(usize::from(sample_rate_index))?;
+    let version = (sequence[1] & 0x18) >> 3;
+    let bit_rate = if version & 0x01 != 0 {
+        MP2_5_RATES[usize::from(bit_rate_index)]
+    } else {
+        MP3_RATES[usize::from(bit_rate_index)]
+    };
+    Some(Mp3Frame {
+        version,
+        bit_rate,
+        sample_rate,
+        padding: sequence[2] & 0x02 != 0,
+    })
+}
+
+/// A row of the byte pattern tables.
+struct Pattern {
+    pattern: &'static [u8],
+    /// Same length as `pattern`, or empty for all 0xFF
+    mask: &'static [u8],
+    /// Whether whitespace bytes at the start of the input are ignored
+    ignore_leading_whitespace: bool,
+    type_: &'static str,
+    subtype: &'static str,
+}
+
+macro_rules! patterns {
+    ($(
+        $pattern: expr, $mask: expr, $ignore_leading_whitespace: expr => $type_: expr, $subtype: expr;
+    )+) => {
+        &[$(
+            Pattern {
+                pattern: $pattern,
+                mask: $mask,
+                ignore_leading_whitespace: $ignore_leading_whitespace,
+                type_: $type_,
+                subtype: $subtype,
+            },
+        )+]
+    };
+}
+
+/// <https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type>
+///
+/// HTML tags are followed by a tag-terminating byte, space or `>`, listed as two rows each.
+/// Their mask makes ASCII letters match case-insensitively.
+static SCRIPTABLE_PATTERNS: &[Pattern] = patterns! {
+    b"<!DOCTYPE HTML ", b"\xFF\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xDF\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<!DOCTYPE HTML>", b"\xFF\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xDF\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<HTML ", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<HTML>", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<HEAD ", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<HEAD>", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<SCRIPT ", b"\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<SCRIPT>", b"\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<IFRAME ", b"\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<IFRAME>", b"\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<H1 ", b"\xFF\xDF\xFF\xFF", true => "text", "html";
+    b"<H1>", b"\xFF\xDF\xFF\xFF", true => "text", "html";
+    b"<DIV ", b"\xFF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<DIV>", b"\xFF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<FONT ", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<FONT>", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<TABLE ", b"\xFF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<TABLE>", b"\xFF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<A ", b"\xFF\xDF\xFF", true => "text", "html";
+    b"<A>", b"\xFF\xDF\xFF", true => "text", "html";
+    b"<STYLE ", b"\xFF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<STYLE>", b"\xFF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<TITLE ", b"\xFF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<TITLE>", b"\xFF\xDF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<B ", b"\xFF\xDF\xFF", true => "text", "html";
+    b"<B>", b"\xFF\xDF\xFF", true => "text", "html";
+    b"<BODY ", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<BODY>", b"\xFF\xDF\xDF\xDF\xDF\xFF", true => "text", "html";
+    b"<BR ", b"\xFF\xDF\xDF\xFF", true => "text", "html";
+    b"<BR>", b"\xFF\xDF\xDF\xFF", true => "text", "html";
+    b"<P ", b"\xFF\xDF\xFF", true => "text", "html";
+    b"<P>", b"\xFF\xDF\xFF", true => "text", "html";
+    b"<!-- ", b"", true => "text", "html";
+    b"<!-->", b"", true => "text", "html";
+    b"<?xml", b"", true => "text", "xml";
+    b"%PDF-", b"", false => "application", "pdf";
+};
+
+/// <https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type>
+static NON_SCRIPTABLE_PATTERNS: &[Pattern] = patterns! {
+    b"%!PS-Adobe-", b"", false => "application", "postscript";
+    // UTF-16BE, UTF-16LE, and UTF-8 byte order marks
+    b"\xFE\xFF\x00\x00", b"\xFF\xFF\x00\x00", false => "text", "plain";
+    b"\xFF\xFE\x00\x00", b"\xFF\xFF\x00\x00", false => "text", "plain";
+    b"\xEF\xBB\xBF\x00", b"\xFF\xFF\xFF\x00", false => "text", "plain";
+};
+
+/// <https://mimesniff.spec.whatwg.org/#image-type-pattern-matching-algorithm>
+static IMAGE_PATTERNS: &[Pattern] = patterns! {
+    // Windows Icon and Windows Cursor
+    b"\x00\x00\x01\x00", b"", false => "image", "x-icon";
+    b"\x00\x00\x02\x00", b"", false => "image", "x-icon";
+    b"BM", b"", false => "image", "bmp";
+    b"GIF87a", b"", false => "image", "gif";
+    b"GIF89a", b"", false => "image", "gif";
+    b"RIFF\x00\x00\x00\x00WEBPVP", b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF\xFF\xFF", false => "image", "webp";
+    b"\x89PNG\r\n\x1A\n", b"", false => "image", "png";
+    b"\xFF\xD8\xFF", b"", false => "image", "jpeg";
+};
+
+/// <https://mimesniff.spec.whatwg.org/#audio-or-video-type-pattern-matching-algorithm>
+static AUDIO_OR_VIDEO_PATTERNS: &[Pattern] = patterns! {
+    b"FORM\x00\x00\x00\x00AIFF", b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF", false => "audio", "aiff";
+    b"ID3", b"", false => "audio", "mpeg";
+    b"OggS\x00", b"", false => "application", "ogg";
+    b"MThd\x00\x00\x00\x06", b"", false => "audio", "midi";
+    b"RIFF\x00\x00\x00\x00AVI ", b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF", false => "video", "avi";
+    b"RIFF\x00\x00\x00\x00WAVE", b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF", false => "audio", "wave";
+};
+
+/// <https://mimesniff.spec.whatwg.org/#matching-a-font-type-pattern>
+static FONT_PATTERNS: &[Pattern] = patterns! {
+    // 34 bytes followed by "LP": Embedded OpenType
+    b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00LP",
+    b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xFF\xFF",
+    false => "application", "vnd.ms-fontobject";
+    b"\x00\x01\x00\x00", b"", false => "font", "ttf";
+    b"OTTO", b"", false => "font", "otf";
+    b"ttcf", b"", false => "font", "collection";
+    b"wOFF", b"", false => "font", "woff";
+    b"wOF2", b"", false => "font", "woff2";
+};
+
+/// <https://mimesniff.spec.whatwg.org/#archive-type-pattern-matching-algorithm>
+static ARCHIVE_PATTERNS: &[Pattern] = patterns! {
+    b"\x1F\x8B\x08", b"", false => "application", "x-gzip";
+    b"PK\x03\x04", b"", false => "application", "zip";
+    b"Rar \x1A\x07\x00", b"", false => "application", "x-rar-compressed";
+};
+
+fn match_table(table: &[Pattern], input: &[u8]) -> Option<Mime> {
+    table
+        .iter()
+        .find(|row| row.matches(input))
+        .map(|row| static_mime(row.type_, row.subtype))
+}
+
+impl Pattern {
+    /// <https://mimesniff.spec.whatwg.org/#pattern-matching-algorithm>
+    fn matches(&self, input: &[u8]) -> bool {
+        debug_assert!(self.mask.is_empty() || self.mask.len() == self.pattern.len());
+        let mut input = input;
+        if self.ignore_leading_whitespace {
+            let whitespace = input
+                .iter()
+                .take_while(|&&byte| is_whitespace_byte(byte))
+                .count();
+            input = &input[whitespace..];
+        }
+        if input.len() < self.pattern.len() {
+            return false;
+        }
+        if self.mask.is_empty() {
+            return input.starts_with(self.pattern);
+        }
+        self.pattern
+            .iter()
+            .zip(self.mask)
+            .zip(input)
+            .all(|((&pattern, &mask), &byte)| byte & mask == pattern)
+    }
+}
+
+fn static_mime(type_: &str, subtype: &str) -> Mime {
+    Mime {
+        type_: String::from(type_),
+        subtype: String::from(subtype),
+        parameters: Vec::new(),
+    }
+}
+
+/// <https://mimesniff.spec.whatwg.org/#binary-data-byte>
+fn is_binary_data_byte(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#whitespace-byte>
+fn is_whitespace_byte(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
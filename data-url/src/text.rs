@@ -0,0 +1,89 @@
+//! <https://encoding.spec.whatwg.org/#decode>
+//! for the encodings that do not need large tables,
+//! and for the other encodings with the `encoding_rs` feature.
+
+use alloc::string::String;
+use core::char;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Encoding {
+    Utf8,
+    Utf16Be,
+    Utf16Le,
+    Windows1252,
+    #[cfg(feature = "encoding_rs")]
+    Legacy(&'static encoding_rs::Encoding),
+}
+
+impl Encoding {
+    /// <https://encoding.spec.whatwg.org/#concept-encoding-get>
+    pub(crate) fn for_label(label: &str) -> Option<Self> {
+        let label = label
+            .trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '))
+            .to_ascii_lowercase();
+        Some(match &*label {
+            "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+            | "x-unicode20utf8" => Self::Utf8,
+            "unicodefffe" | "utf-16be" => Self::Utf16Be,
+            "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+            | "utf-16le" => Self::Utf16Le,
+            "ansi_x3.4-1968" | "ascii" | "cp1252" | "cp819" | "csisolatin1" | "ibm819"
+            | "iso-8859-1" | "iso-ir-100" | "iso8859-1" | "iso88591" | "iso_8859-1"
+            | "iso_8859-1:1987" | "l1" | "latin1" | "us-ascii" | "windows-1252" | "x-cp1252" => {
+                Self::Windows1252
+            }
+            #[cfg(feature = "encoding_rs")]
+            _ => Self::Legacy(encoding_rs::Encoding::for_label(label.as_bytes())?),
+            #[cfg(not(feature = "encoding_rs"))]
+            _ => return None,
+        })
+    }
+}
+
+/// <https://encoding.spec.whatwg.org/#decode>
+pub(crate) fn decode(bytes: &[u8], fallback_encoding: Encoding) -> String {
+    // BOM sniff
+    let (encoding, bytes) = match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => (Encoding::Utf8, rest),
+        [0xFE, 0xFF, rest @ ..] => (Encoding::Utf16Be, rest),
+        [0xFF, 0xFE, rest @ ..] => (Encoding::Utf16Le, rest),
+        _ => (fallback_encoding, bytes),
+    };
+    match encoding {
+        Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+        Encoding::Utf16Be => decode_utf16(bytes, u16::from_be_bytes),
+        Encoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+        Encoding::Windows1252 => bytes.iter().map(|&byte| windows_1252(byte)).collect(),
+        #[cfg(feature = "encoding_rs")]
+        Encoding::Legacy(encoding) => encoding.decode_without_bom_handling(bytes).0.into_owned(),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], from_bytes: fn([u8; 2]) -> u16) -> String {
+    let code_units = bytes
+        .chunks_exact(2)
+        .map(|pair| from_bytes([pair[0], pair[1]]));
+    let mut string: String = char::decode_utf16(code_units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    // A lone trailing byte
+    if bytes.len() % 2 != 0 {
+        string.push(char::REPLACEMENT_CHARACTER)
+    }
+    string
+}
+
+/// <https://encoding.spec.whatwg.org/index-windows-1252.txt>
+fn windows_1252(byte: u8) -> char {
+    const C1: [char; 32] = [
+        '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}',
+        '\u{8F}', '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}',
+        '\u{2014}', '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}',
+        '\u{178}',
+    ];
+    match byte {
+        0x80..=0x9F => C1[(byte - 0x80) as usize],
+        _ => char::from(byte),
+    }
+}
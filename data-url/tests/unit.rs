@@ -0,0 +1,427 @@
+use data_url::mime::sniff::{self, Context, Resource};
+use data_url::mime::{self, Mime};
+use data_url::{DataUrl, DecodeToStringError};
+
+fn mime(s: &str) -> Mime {
+    s.parse().unwrap()
+}
+
+fn computed(supplied: Option<&str>, header: &[u8]) -> String {
+    let supplied = supplied.map(mime);
+    Resource::new(supplied.as_ref(), header)
+        .computed_mime_type()
+        .to_string()
+}
+
+#[test]
+fn sniff_unknown_mime_type() {
+    assert_eq!(computed(None, b" \n<html><body>"), "text/html");
+    assert_eq!(computed(None, b"<!doctype html>"), "text/html");
+    assert_eq!(computed(None, b"<!DocType HTML "), "text/html");
+    assert_eq!(computed(Some("*/*"), b"<p>hello"), "text/html");
+    assert_eq!(computed(Some("unknown/unknown"), b"<?xml "), "text/xml");
+    assert_eq!(
+        computed(Some("application/unknown"), b"%PDF-1.7"),
+        "application/pdf"
+    );
+    assert_eq!(computed(None, b"<htmlfoo>"), "text/plain");
+    assert_eq!(computed(None, b"\xFE\xFF\x00\x41"), "text/plain");
+    assert_eq!(computed(None, b"GIF89a\x01\x00"), "image/gif");
+    assert_eq!(
+        computed(None, b"RIFF\x10\x00\x00\x00WEBPVP8 "),
+        "image/webp"
+    );
+    assert_eq!(
+        computed(None, b"RIFF\x10\x00\x00\x00WAVEfmt "),
+        "audio/wave"
+    );
+    assert_eq!(computed(None, b"PK\x03\x04\x14\x00"), "application/zip");
+    assert_eq!(computed(None, b"plain text\r\n"), "text/plain");
+    assert_eq!(
+        computed(None, b"\x00\x01binary"),
+        "application/octet-stream"
+    );
+}
+
+#[test]
+fn sniff_scriptable_only_without_no_sniff() {
+    let resource = Resource::new(None, b"<script>alert(1)</script>");
+    assert_eq!(resource.computed_mime_type().to_string(), "text/html");
+    let resource = resource.no_sniff(true);
+    assert_eq!(resource.computed_mime_type().to_string(), "text/plain");
+}
+
+#[test]
+fn sniff_supplied_mime_type() {
+    let png = b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR";
+    assert_eq!(computed(Some("image/gif"), png), "image/png");
+    assert_eq!(computed(Some("text/plain"), png), "text/plain");
+    assert_eq!(computed(Some("image/svg+xml"), png), "image/svg+xml");
+    assert_eq!(
+        computed(Some("audio/mpeg"), b"OggS\x00\x02"),
+        "application/ogg"
+    );
+
+    let supplied = mime("image/gif");
+    let resource = Resource::new(Some(&supplied), png).no_sniff(true);
+    assert_eq!(resource.computed_mime_type(), supplied);
+}
+
+#[test]
+fn sniff_apache_bug() {
+    assert!(sniff::has_apache_bug_content_type(
+        b"text/plain; charset=ISO-8859-1"
+    ));
+    assert!(!sniff::has_apache_bug_content_type(
+        b"text/plain;charset=ISO-8859-1"
+    ));
+
+    let supplied = mime("text/plain;charset=UTF-8");
+    let check = |header: &[u8]| {
+        Resource::new(Some(&supplied), header)
+            .check_for_apache_bug(true)
+            .computed_mime_type()
+            .to_string()
+    };
+    assert_eq!(check(b"\xEF\xBB\xBF\x00text"), "text/plain;charset=UTF-8");
+    assert_eq!(check(b"Hello\tworld"), "text/plain");
+    assert_eq!(check(b"\x7FELF\x02\x01"), "application/octet-stream");
+}
+
+#[test]
+fn sniff_determine_nosniff() {
+    assert!(sniff::determine_nosniff([&b"nosniff"[..]]));
+    assert!(sniff::determine_nosniff([&b" NoSniff , foo"[..]]));
+    assert!(!sniff::determine_nosniff([&b"foo, nosniff"[..]]));
+    assert!(!sniff::determine_nosniff([&b""[..], &b"nosniff"[..]]));
+    assert!(!sniff::determine_nosniff(Vec::<&[u8]>::new()));
+}
+
+#[test]
+fn sniff_feed_or_html() {
+    let html = Some("text/html");
+    assert_eq!(
+        computed(
+            html,
+            b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\n<rss version=\"2.0\">"
+        ),
+        "application/rss+xml"
+    );
+    assert_eq!(
+        computed(html, b"<!-- <html> --><!DOCTYPE feed><feed xmlns="),
+        "application/atom+xml"
+    );
+    assert_eq!(
+        computed(
+            html,
+            b"<rdf:RDF xmlns=\"http://purl.org/rss/1.0/\" \
+              xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">"
+        ),
+        "application/rss+xml"
+    );
+    assert_eq!(
+        computed(html, b"<rdf:RDF xmlns=\"http://purl.org/rss/1.0/\">"),
+        "text/html"
+    );
+    assert_eq!(computed(html, b"<html><rss>"), "text/html");
+    assert_eq!(computed(html, b"<!-- unterminated"), "text/html");
+}
+
+#[test]
+fn sniff_audio_and_video_signatures() {
+    let mp4 = b"\x00\x00\x00\x18ftypisom\x00\x00\x00\x00mp41avc1";
+    assert_eq!(computed(Some("video/webm"), mp4), "video/mp4");
+    assert_eq!(computed(Some("video/mp4"), &mp4[..20]), "video/mp4");
+
+    let webm = b"\x1A\x45\xDF\xA3\x42\x82\x84webm\x42";
+    assert_eq!(computed(Some("video/mp4"), webm), "video/webm");
+    assert_eq!(computed(Some("video/mp4"), &webm[..11]), "video/mp4");
+    // A DocType element that doesn't match doesn't end the search
+    let webm = b"\x1A\x45\xDF\xA3\x42\x82\x88matroska\x42\x82\x84webm\x42";
+    assert_eq!(computed(Some("video/mp4"), webm), "video/webm");
+    let matroska = b"\x1A\x45\xDF\xA3\x42\x82\x88matroska\x00\x00";
+    assert_eq!(computed(Some("video/mp4"), matroska), "video/mp4");
+
+    // MPEG frames of 261 bytes without an ID3 tag
+    let mut mp3 = vec![0; 300];
+    mp3[..4].copy_from_slice(b"\xFF\xFB\x90\x64");
+    mp3[261..265].copy_from_slice(b"\xFF\xFB\x90\x64");
+    assert_eq!(computed(None, &mp3), "audio/mpeg");
+    mp3[261] = 0;
+    assert_eq!(computed(None, &mp3), "application/octet-stream");
+}
+
+#[test]
+fn sniff_in_context() {
+    let woff = b"wOFF\x00\x01\x00\x00";
+    let supplied = mime("application/octet-stream");
+    let resource = Resource::new(Some(&supplied), woff);
+    let sniffed = |context| resource.sniff_in_context(context).map(|m| m.to_string());
+    assert_eq!(sniffed(Context::Font).as_deref(), Some("font/woff"));
+    assert_eq!(
+        sniffed(Context::Image).as_deref(),
+        Some("application/octet-stream")
+    );
+    assert_eq!(sniffed(Context::TextTrack).as_deref(), Some("text/vtt"));
+
+    let resource = Resource::new(None, b"\xFF\xD8\xFF\xE0");
+    assert_eq!(
+        resource.sniff_in_context(Context::Image),
+        Some(mime("image/jpeg"))
+    );
+    assert_eq!(resource.sniff_in_context(Context::Script), None);
+    assert_eq!(
+        resource.sniff_in_context(Context::Plugin),
+        Some(mime("application/octet-stream"))
+    );
+
+    let svg = mime("image/svg+xml");
+    let resource = Resource::new(Some(&svg), b"\xFF\xD8\xFF\xE0");
+    assert_eq!(resource.sniff_in_context(Context::Image), Some(svg.clone()));
+}
+
+#[test]
+fn mime_essence_and_groups() {
+    let html = mime("Text/HTML;Charset=\"utf-8\"");
+    assert_eq!(html.essence(), "text/html");
+    assert!(html.is_html() && html.is_scriptable() && !html.is_xml());
+    assert!(mime("image/svg+xml").is_xml());
+    assert!(mime("image/svg+xml").is_image());
+    assert!(mime("application/xml").is_xml());
+    assert!(mime("text/javascript1.5").is_javascript());
+    assert!(mime("application/x-ecmascript").is_javascript());
+    assert!(!mime("application/json").is_javascript());
+    assert!(mime("application/ld+json").is_json());
+    assert!(mime("text/json").is_json());
+    assert!(mime("font/woff2").is_font());
+    assert!(mime("application/vnd.ms-fontobject").is_font());
+    assert!(mime("application/epub+zip").is_zip_based());
+    assert!(mime("application/zip").is_zip_based() && mime("application/zip").is_archive());
+    assert!(mime("application/ogg").is_audio_or_video());
+}
+
+#[test]
+fn mime_parameter_editing() {
+    let mut mime_type = mime("text/plain;charset=utf-8;format=flowed");
+    mime_type.set_parameter("Charset", "gbk").unwrap();
+    mime_type.set_parameter("Delsp", "").unwrap();
+    assert_eq!(
+        mime_type.to_string(),
+        "text/plain;charset=gbk;format=flowed;delsp=\"\""
+    );
+    assert_eq!(
+        mime_type.remove_parameter("FORMAT").as_deref(),
+        Some("flowed")
+    );
+    assert_eq!(mime_type.remove_parameter("format"), None);
+    assert_eq!(mime_type.to_string(), "text/plain;charset=gbk;delsp=\"\"");
+
+    assert!(mime_type.set_parameter("", "x").is_err());
+    assert!(mime_type.set_parameter("a b", "x").is_err());
+    assert!(mime_type.set_parameter("a", "\n").is_err());
+    assert!(mime_type.set_parameter("a", "caf\u{e9} \"\\").is_ok());
+    assert_eq!(
+        mime_type.to_string(),
+        "text/plain;charset=gbk;delsp=\"\";a=\"caf\u{e9} \\\"\\\\\""
+    );
+}
+
+#[test]
+fn mime_extract_from_content_type() {
+    let extract = |values: &[&[u8]]| mime::extract_mime_type(values.iter().copied());
+    let extracted = |values: &[&[u8]]| extract(values).unwrap().to_string();
+    assert!(extract(&[]).is_none());
+    assert!(extract(&[b""]).is_none());
+    assert!(extract(&[b"*/*", b"bogus"]).is_none());
+    assert_eq!(
+        extracted(&[b"text/plain;charset=gbk, text/html"]),
+        "text/html"
+    );
+    assert_eq!(
+        extracted(&[b"text/html;charset=gbk", b"text/html"]),
+        "text/html;charset=gbk"
+    );
+    assert_eq!(
+        extracted(&[b"text/html;charset=gbk;a=b", b"text/html;x=y"]),
+        "text/html;x=y;charset=gbk"
+    );
+    assert_eq!(
+        extracted(&[b"text/html;charset=gbk", b"text/html;charset=windows-1252"]),
+        "text/html;charset=windows-1252"
+    );
+    assert_eq!(
+        extracted(&[b"text/html;charset=gbk", b"*/*"]),
+        "text/html;charset=gbk"
+    );
+    assert_eq!(
+        extracted(&[b"text/html;charset=\"gbk,utf-8\""]),
+        "text/html;charset=\"gbk,utf-8\""
+    );
+    assert_eq!(
+        extracted(&[b"text/plain;charset=\xE9"]),
+        "text/plain;charset=\"\u{e9}\""
+    );
+}
+
+#[cfg(feature = "std")]
+fn read_to_end(url: &DataUrl<'_>) -> std::io::Result<Vec<u8>> {
+    use std::io::Read;
+
+    let mut body = Vec::new();
+    url.reader().read_to_end(&mut body)?;
+    Ok(body)
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn body_reader() {
+    use std::io::Read;
+
+    // Long enough to be decoded in several chunks, with percent-encoded bytes across boundaries
+    for offset in 0..4 {
+        let body = format!("{}{}", "a".repeat(offset), "%41b\n%".repeat(2000));
+        for input in [
+            format!("data:,{}#frag", body),
+            format!(
+                "data:;base64,{}#frag",
+                data_url::forgiving_base64::encode_to_string(body.as_bytes()).replace('A', "%41")
+            ),
+        ] {
+            let url = DataUrl::process(&input).unwrap();
+            let (expected, _) = url.decode_to_vec().unwrap();
+            assert_eq!(read_to_end(&url).unwrap(), expected);
+            assert_eq!(url.decoded_body_len(), expected.len());
+        }
+    }
+
+    let url = DataUrl::process("data:;base64,SGVsbG8h").unwrap();
+    let mut reader = url.reader();
+    let mut buffer = [0; 4];
+    assert_eq!(reader.read(&mut buffer).unwrap(), 4);
+    assert_eq!(&buffer, b"Hell");
+    assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+    assert_eq!(&buffer[..2], b"o!");
+    assert_eq!(reader.read(&mut buffer).unwrap(), 0);
+
+    let url = DataUrl::process("data:;base64,SGVsbG8").unwrap();
+    assert_eq!(read_to_end(&url).unwrap(), b"Hello");
+    let url = DataUrl::process("data:;base64,SGVsbG8h=").unwrap();
+    let error = read_to_end(&url).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn max_body_len() {
+    let url = DataUrl::process("data:,%41%42%43").unwrap();
+    assert_eq!(url.decoded_body_len(), 3);
+    let url = DataUrl::process("data:;base64,%20QUJD%0aRA==#fragment").unwrap();
+    assert_eq!(url.decoded_body_len(), 4);
+
+    // The decoders work up to the limit
+    for input in ["data:,%41%42%43#fragment", "data:;base64,QUJD"] {
+        let url = DataUrl::process(input).unwrap().with_max_body_len(3);
+        assert_eq!(url.decode_to_vec().unwrap().0, b"ABC");
+        assert_eq!(url.decode_to_string().unwrap().0, "ABC");
+        #[cfg(feature = "std")]
+        assert_eq!(read_to_end(&url).unwrap(), b"ABC");
+    }
+
+    // and fail with their own error beyond it, for base64 and percent-encoded bodies
+    for input in ["data:,%41%42%43#fragment", "data:;base64,QUJD"] {
+        let url = DataUrl::process(input).unwrap().with_max_body_len(2);
+        assert!(matches!(
+            url.decode_to_string(),
+            Err(DecodeToStringError::BodyTooLong(_))
+        ));
+        #[cfg(feature = "std")]
+        {
+            let error = read_to_end(&url).unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+            assert!(error.into_inner().unwrap().is::<data_url::BodyTooLong>());
+        }
+        // These ignore the limit
+        assert_eq!(url.decode_to_vec().unwrap().0, b"ABC");
+    }
+
+    // Invalid base64 is not reported as a body that is too long
+    let url = DataUrl::process("data:;base64,QUJDR")
+        .unwrap()
+        .with_max_body_len(3);
+    assert!(matches!(
+        url.decode_to_string(),
+        Err(DecodeToStringError::InvalidBase64(_))
+    ));
+}
+
+#[test]
+fn decode_to_string() {
+    let decode = |input: &str| {
+        let url = DataUrl::process(input).unwrap();
+        url.decode_to_string().map(|(text, _)| text)
+    };
+    assert_eq!(decode("data:text/plain,caf%C3%A9").unwrap(), "caf\u{e9}");
+    // The default charset is US-ASCII, which is windows-1252
+    assert_eq!(
+        decode("data:,caf%C3%A9%80").unwrap(),
+        "caf\u{c3}\u{a9}\u{20ac}"
+    );
+    assert_eq!(
+        decode("data:text/plain;charset=latin1,caf%E9").unwrap(),
+        "caf\u{e9}"
+    );
+    assert_eq!(
+        decode("data:application/json,%7B%FF%7D").unwrap(),
+        "{\u{fffd}}"
+    );
+    assert_eq!(
+        decode("data:text/plain;charset=UTF-16,h%00i%00%00").unwrap(),
+        "hi\u{fffd}"
+    );
+    assert_eq!(
+        decode("data:text/plain;charset=utf-16be,%00h%00i").unwrap(),
+        "hi"
+    );
+    // The byte order mark overrides the charset
+    assert_eq!(
+        decode("data:text/plain;charset=us-ascii,%EF%BB%BFcaf%C3%A9").unwrap(),
+        "caf\u{e9}"
+    );
+    #[cfg(not(feature = "encoding_rs"))]
+    assert!(matches!(
+        decode("data:text/plain;charset=shift_jis,x"),
+        Err(DecodeToStringError::UnsupportedCharset(charset)) if charset == "shift_jis"
+    ));
+    #[cfg(feature = "encoding_rs")]
+    {
+        assert_eq!(
+            decode("data:text/plain;charset=shift_jis,%82%A0x").unwrap(),
+            "\u{3042}x"
+        );
+        assert_eq!(
+            decode("data:text/plain;charset=GBK,%C4%E3%BA%C3").unwrap(),
+            "\u{4f60}\u{597d}"
+        );
+        assert_eq!(
+            decode("data:text/plain;charset=iso-8859-2,%B1").unwrap(),
+            "\u{105}"
+        );
+        // The byte order mark still overrides the charset
+        assert_eq!(
+            decode("data:text/plain;charset=shift_jis,%EF%BB%BFcaf%C3%A9").unwrap(),
+            "caf\u{e9}"
+        );
+        // https://encoding.spec.whatwg.org/#replacement
+        assert_eq!(
+            decode("data:text/plain;charset=iso-2022-kr,abc").unwrap(),
+            "\u{fffd}"
+        );
+    }
+    assert!(matches!(
+        decode("data:text/plain;charset=x-unknown,x"),
+        Err(DecodeToStringError::UnsupportedCharset(charset)) if charset == "x-unknown"
+    ));
+    assert!(matches!(
+        decode("data:;base64,A"),
+        Err(DecodeToStringError::InvalidBase64(_))
+    ));
+}
@@ -15,6 +15,8 @@ fn run_data_url(
             priorhook(p);
         }
     }));
+    #[cfg(feature = "url")]
+    run_data_url_from_url(&input, expected_mime.as_deref(), expected_body.as_deref());
     let url = data_url::DataUrl::process(&input);
     if let Some(expected_mime) = expected_mime {
         let url = url.unwrap();
@@ -27,11 +29,70 @@ fn run_data_url(
         if let Some(expected_body) = expected_body {
             assert_eq!(body, expected_body)
         }
+        check_build_round_trip(url.mime_type(), &body)
     } else if let Ok(url) = url {
         assert!(url.decode_to_vec().is_err(), "{:?}", url.mime_type())
     }
 }
 
+/// Same as `run_data_url`, but starting from a parsed `url::Url`
+#[cfg(feature = "url")]
+fn run_data_url_from_url(input: &str, expected_mime: Option<&str>, expected_body: Option<&[u8]>) {
+    use data_url::DataUrlExt;
+
+    let url = match url::Url::parse(input) {
+        Ok(url) => url,
+        Err(_) => {
+            assert!(expected_mime.is_none());
+            return;
+        }
+    };
+    let data_url = url.data_url();
+    if let Some(expected_mime) = expected_mime {
+        let data_url = data_url.unwrap();
+        let (body, _) = data_url.decode_to_vec().unwrap();
+        if expected_mime.is_empty() {
+            assert_eq!(
+                data_url.mime_type().to_string(),
+                "text/plain;charset=US-ASCII"
+            )
+        } else {
+            assert_eq!(data_url.mime_type().to_string(), expected_mime)
+        }
+        if let Some(expected_body) = expected_body {
+            assert_eq!(body, expected_body)
+        }
+    } else if let Ok(data_url) = data_url {
+        assert!(
+            data_url.decode_to_vec().is_err(),
+            "{:?}",
+            data_url.mime_type()
+        )
+    }
+}
+
+fn check_build_round_trip(mime_type: &data_url::mime::Mime, body: &[u8]) {
+    use data_url::{BodyEncoding, DataUrl};
+
+    let builder = DataUrl::builder(mime_type);
+    for builder in [
+        builder,
+        builder.body_encoding(BodyEncoding::Percent),
+        builder.body_encoding(BodyEncoding::Base64),
+    ] {
+        let built = match builder.build(body) {
+            Ok(built) => built,
+            // Some MIME types in the test data have a `,` or `#` in a quoted parameter value
+            Err(_) => continue,
+        };
+        let url = DataUrl::process(&built).unwrap();
+        let (built_body, fragment) = url.decode_to_vec().unwrap();
+        assert_eq!(url.mime_type(), mime_type, "{}", built);
+        assert_eq!(built_body, body, "{}", built);
+        assert!(fragment.is_none());
+    }
+}
+
 fn collect_data_url<F>(add_test: &mut F)
 where
     F: FnMut(String, bool, test::TestFn),
@@ -65,7 +126,12 @@ where
 fn run_base64(input: String, expected: Option<Vec<u8>>) {
     let result = data_url::forgiving_base64::decode_to_vec(input.as_bytes());
     match (result, expected) {
-        (Ok(bytes), Some(expected)) => assert_eq!(bytes, expected),
+        (Ok(bytes), Some(expected)) => {
+            assert_eq!(bytes, expected);
+            let encoded = data_url::forgiving_base64::encode_to_string(&bytes);
+            let decoded = data_url::forgiving_base64::decode_to_vec(encoded.as_bytes()).unwrap();
+            assert_eq!(decoded, bytes)
+        }
         (Ok(bytes), None) => panic!("Expected error, got {:?}", bytes),
         (Err(e), Some(expected)) => panic!("Expected {:?}, got error {:?}", expected, e),
         (Err(_), None) => {}
@@ -0,0 +1,289 @@
+// Copyright 2013-2016 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::collections::vec_deque::{Drain, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{fmt, mem};
+
+/// An incremental parser for the `application/x-www-form-urlencoded` syntax,
+/// for input that arrives in chunks.
+///
+/// This yields the same pairs as [`parse`](crate::parse) on the concatenated input,
+/// each as soon as the `&` after it is fed.
+/// Percent-encoded bytes can be split between chunks.
+///
+/// ```
+/// use form_urlencoded::FormParser;
+///
+/// let mut parser = FormParser::new().max_pairs(10).max_value_len(100);
+/// let mut pairs = Vec::new();
+/// for chunk in [&b"name=Jo"[..], b"hn+Doe&city=Z%", b"C3%BCrich&empty"] {
+///     pairs.extend(parser.feed(chunk).unwrap());
+/// }
+/// pairs.extend(parser.finish().unwrap());
+/// assert_eq!(pairs, [
+///     ("name".to_owned(), "John Doe".to_owned()),
+///     ("city".to_owned(), "Zürich".to_owned()),
+///     ("empty".to_owned(), "".to_owned()),
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FormParser {
+    max_pairs: usize,
+    max_name_len: usize,
+    max_value_len: usize,
+    pairs: VecDeque<(String, String)>,
+    pair_count: usize,
+    /// Decoded bytes of the current pair
+    name: Vec<u8>,
+    value: Vec<u8>,
+    in_value: bool,
+    /// Whether the current sequence between `&` separators has any byte
+    in_sequence: bool,
+    /// A `%` sign, optionally followed by a hexadecimal digit, at the end of the input so far
+    escape: [u8; 2],
+    escape_len: u8,
+    error: Option<LimitExceeded>,
+}
+
+/// The error returned by [`FormParser`] when the input exceeds one of its limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitExceeded {
+    /// More pairs than [`FormParser::max_pairs`]
+    PairCount,
+    /// A name longer than [`FormParser::max_name_len`] once decoded
+    NameLength,
+    /// A value longer than [`FormParser::max_value_len`] once decoded
+    ValueLength,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PairCount => write!(f, "too many name-value pairs"),
+            Self::NameLength => write!(f, "name too long"),
+            Self::ValueLength => write!(f, "value too long"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitExceeded {}
+
+impl Default for FormParser {
+    fn default() -> Self {
+        FormParser {
+            max_pairs: usize::MAX,
+            max_name_len: usize::MAX,
+            max_value_len: usize::MAX,
+            pairs: VecDeque::new(),
+            pair_count: 0,
+            name: Vec::new(),
+            value: Vec::new(),
+            in_value: false,
+            in_sequence: false,
+            escape: [0; 2],
+            escape_len: 0,
+            error: None,
+        }
+    }
+}
+
+impl FormParser {
+    /// Return a new parser without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of pairs.
+    pub fn max_pairs(mut self, new: usize) -> Self {
+        self.max_pairs = new;
+        self
+    }
+
+    /// Limit the length in bytes of percent-decoded names.
+    pub fn max_name_len(mut self, new: usize) -> Self {
+        self.max_name_len = new;
+        self
+    }
+
+    /// Limit the length in bytes of percent-decoded values.
+    pub fn max_value_len(mut self, new: usize) -> Self {
+        self.max_value_len = new;
+        self
+    }
+
+    /// Parse the next chunk of input, and return the pairs that it completes.
+    ///
+    /// After an error, the parser returns the same error for any further input.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Drain<'_, (String, String)>, LimitExceeded> {
+        self.feed_without_draining(chunk)?;
+        Ok(self.pairs.drain(..))
+    }
+
+    /// Signal the end of the input, and return the last pair if there is one.
+    pub fn finish(mut self) -> Result<Option<(String, String)>, LimitExceeded> {
+        self.end()?;
+        Ok(self.pairs.pop_front())
+    }
+
+    /// Parse the input read from `reader`.
+    ///
+    /// Exceeding a limit is reported as an [`std::io::ErrorKind::InvalidData`] error.
+    #[cfg(feature = "std")]
+    pub fn parse_reader<R: std::io::Read>(self, reader: R) -> ReadPairs<R> {
+        ReadPairs {
+            parser: self,
+            reader,
+            finished: false,
+            error: None,
+        }
+    }
+
+    fn feed_without_draining(&mut self, chunk: &[u8]) -> Result<(), LimitExceeded> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        for &byte in chunk {
+            if let Err(error) = self.byte(byte) {
+                self.error = Some(error);
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<(), LimitExceeded> {
+        self.feed_without_draining(b"&")
+    }
+
+    fn byte(&mut self, byte: u8) -> Result<(), LimitExceeded> {
+        if self.escape_len > 0 {
+            if let Some(digit) = hex_digit(byte) {
+                if self.escape_len == 1 {
+                    self.escape = [b'%', byte];
+                    self.escape_len = 2;
+                    return Ok(());
+                }
+                let high = hex_digit(self.escape[1]).unwrap_or(0);
+                self.escape_len = 0;
+                return self.push(high * 0x10 + digit);
+            }
+            // Not a percent-encoded byte after all, keep it literally
+            let escape = self.escape;
+            let escape_len = mem::replace(&mut self.escape_len, 0);
+            for &literal in &escape[..escape_len as usize] {
+                self.push(literal)?
+            }
+        }
+        match byte {
+            b'&' => self.end_sequence(),
+            b'=' if !self.in_value => {
+                self.in_value = true;
+                self.in_sequence = true;
+                Ok(())
+            }
+            b'%' => {
+                self.escape = [b'%', 0];
+                self.escape_len = 1;
+                self.in_sequence = true;
+                Ok(())
+            }
+            b'+' => self.push(b' '),
+            _ => self.push(byte),
+        }
+    }
+
+    fn push(&mut self, byte: u8) -> Result<(), LimitExceeded> {
+        self.in_sequence = true;
+        if self.in_value {
+            if self.value.len() >= self.max_value_len {
+                return Err(LimitExceeded::ValueLength);
+            }
+            self.value.push(byte)
+        } else {
+            if self.name.len() >= self.max_name_len {
+                return Err(LimitExceeded::NameLength);
+            }
+            self.name.push(byte)
+        }
+        Ok(())
+    }
+
+    fn end_sequence(&mut self) -> Result<(), LimitExceeded> {
+        if !mem::replace(&mut self.in_sequence, false) {
+            return Ok(());
+        }
+        if self.pair_count >= self.max_pairs {
+            return Err(LimitExceeded::PairCount);
+        }
+        self.pair_count += 1;
+        self.in_value = false;
+        let name = decode_utf8_lossy(mem::take(&mut self.name));
+        let value = decode_utf8_lossy(mem::take(&mut self.value));
+        self.pairs.push_back((name, value));
+        Ok(())
+    }
+}
+
+fn hex_digit(byte: u8) -> Option<u8> {
+    char::from(byte).to_digit(16).map(|digit| digit as u8)
+}
+
+fn decode_utf8_lossy(bytes: Vec<u8>) -> String {
+    crate::decode_utf8_lossy(Cow::Owned(bytes)).into_owned()
+}
+
+/// The return type of [`FormParser::parse_reader`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ReadPairs<R> {
+    parser: FormParser,
+    reader: R,
+    finished: bool,
+    /// Returned after the pairs completed before it
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Iterator for ReadPairs<R> {
+    type Item = std::io::Result<(String, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buffer = [0; 4096];
+        loop {
+            if let Some(pair) = self.parser.pairs.pop_front() {
+                return Some(Ok(pair));
+            }
+            if let Some(error) = self.error.take() {
+                return Some(Err(error));
+            }
+            if self.finished {
+                return None;
+            }
+            let result = match self.reader.read(&mut buffer) {
+                Ok(0) => {
+                    self.finished = true;
+                    self.parser.end()
+                }
+                Ok(len) => self.parser.feed_without_draining(&buffer[..len]),
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            };
+            if let Err(error) = result {
+                self.finished = true;
+                self.error = Some(std::io::Error::new(std::io::ErrorKind::InvalidData, error));
+            }
+        }
+    }
+}
@@ -12,12 +12,20 @@
 //!
 //! Converts between a string (such as an URL’s query string)
 //! and a sequence of (name, value) pairs.
+//!
+//! The other encodings of HTML form submissions,
+//! [`multipart/form-data`](MultipartSerializer) and [`text/plain`](TextPlainSerializer),
+//! are also supported.
 #![no_std]
 
 // For forwards compatibility
 #[cfg(feature = "std")]
 extern crate std as _;
 
+// For the `std::io` and `std::error::Error` implementations
+#[cfg(feature = "std")]
+extern crate std;
+
 extern crate alloc;
 
 #[cfg(not(feature = "alloc"))]
@@ -28,6 +36,25 @@ use alloc::string::String;
 use core::str;
 use percent_encoding::{percent_decode, percent_encode_byte};
 
+#[cfg(feature = "std")]
+pub use crate::form_parser::ReadPairs;
+pub use crate::form_parser::{FormParser, LimitExceeded};
+pub use crate::multipart::{
+    parse_multipart, InvalidMultipart, MultipartEvent, MultipartParser, MultipartPart,
+    MultipartSerializer, PartHeaders,
+};
+#[cfg(feature = "std")]
+pub use crate::sink::WriteSink;
+pub use crate::sink::{BinarySink, ByteCount, Sink, SinkSerializer};
+pub use crate::strict::{parse_strict, ParseError, ParseErrorKind, ParseStrict};
+pub use crate::text_plain::{parse_text_plain, ParseTextPlain, TextPlainSerializer};
+
+mod form_parser;
+mod multipart;
+mod sink;
+mod strict;
+mod text_plain;
+
 /// Convert a byte string in the `application/x-www-form-urlencoded` syntax
 /// into a iterator of (name, value) pairs.
 ///
@@ -37,12 +64,16 @@ use percent_encoding::{percent_decode, percent_encode_byte};
 /// converted to `[("#first", "%try%")]`.
 #[inline]
 pub fn parse(input: &[u8]) -> Parse<'_> {
-    Parse { input }
+    Parse {
+        input,
+        generic_query: false,
+    }
 }
 /// The return type of `parse()`.
 #[derive(Copy, Clone)]
 pub struct Parse<'a> {
     input: &'a [u8],
+    generic_query: bool,
 }
 
 impl<'a> Iterator for Parse<'a> {
@@ -53,7 +84,8 @@ impl<'a> Iterator for Parse<'a> {
             if self.input.is_empty() {
                 return None;
             }
-            let mut split2 = self.input.splitn(2, |&b| b == b'&');
+            let generic_query = self.generic_query;
+            let mut split2 = self.input.splitn(2, |&b| is_separator(b, generic_query));
             let sequence = split2.next().unwrap();
             self.input = split2.next().unwrap_or(&[][..]);
             if sequence.is_empty() {
@@ -62,13 +94,21 @@ impl<'a> Iterator for Parse<'a> {
             let mut split2 = sequence.splitn(2, |&b| b == b'=');
             let name = split2.next().unwrap();
             let value = split2.next().unwrap_or(&[][..]);
-            return Some((decode(name), decode(value)));
+            return Some((decode(name, generic_query), decode(value, generic_query)));
         }
     }
 }
 
-fn decode(input: &[u8]) -> Cow<'_, str> {
-    let replaced = replace_plus(input);
+fn is_separator(byte: u8, generic_query: bool) -> bool {
+    byte == b'&' || (generic_query && byte == b';')
+}
+
+fn decode(input: &[u8], generic_query: bool) -> Cow<'_, str> {
+    let replaced = if generic_query {
+        Cow::Borrowed(input)
+    } else {
+        replace_plus(input)
+    };
     decode_utf8_lossy(match percent_decode(&replaced).into() {
         Cow::Owned(vec) => Cow::Owned(vec),
         Cow::Borrowed(_) => replaced,
@@ -93,6 +133,26 @@ fn replace_plus(input: &[u8]) -> Cow<'_, [u8]> {
 }
 
 impl<'a> Parse<'a> {
+    /// Parse the input as a generic query string rather than as a form:
+    /// `+` is a literal plus sign instead of a space,
+    /// and `;` separates pairs like `&` does.
+    ///
+    /// ```
+    /// let pairs: Vec<_> = form_urlencoded::parse(b"a=1+1;b=2&c")
+    ///     .generic_query()
+    ///     .into_owned()
+    ///     .collect();
+    /// assert_eq!(pairs, [
+    ///     ("a".to_owned(), "1+1".to_owned()),
+    ///     ("b".to_owned(), "2".to_owned()),
+    ///     ("c".to_owned(), "".to_owned()),
+    /// ]);
+    /// ```
+    pub fn generic_query(mut self) -> Self {
+        self.generic_query = true;
+        self
+    }
+
     /// Return a new iterator that yields pairs of `String` instead of pairs of `Cow<str>`.
     pub fn into_owned(self) -> ParseIntoOwned<'a> {
         ParseIntoOwned { inner: self }
@@ -385,8 +445,10 @@ fn append_key_only(
     append_encoded(name, string, encoding);
 }
 
-fn append_encoded(s: &str, string: &mut String, encoding: EncodingOverride<'_>) {
-    string.extend(byte_serialize(&encode(encoding, s)))
+fn append_encoded<S: Sink>(s: &str, sink: &mut S, encoding: EncodingOverride<'_>) {
+    for piece in byte_serialize(&encode(encoding, s)) {
+        sink.write_str(piece)
+    }
 }
 
 pub(crate) fn encode<'a>(encoding_override: EncodingOverride<'_>, input: &'a str) -> Cow<'a, [u8]> {
@@ -396,6 +458,34 @@ pub(crate) fn encode<'a>(encoding_override: EncodingOverride<'_>, input: &'a str
     input.as_bytes().into()
 }
 
+/// Replace every CR not followed by LF, and every LF not preceded by CR, with CRLF.
+///
+/// <https://html.spec.whatwg.org/multipage/#convert-to-a-list-of-name-value-pairs>
+pub(crate) fn normalize_newlines(input: &str) -> Cow<'_, str> {
+    let bytes = input.as_bytes();
+    let normalized = bytes.iter().enumerate().all(|(i, &b)| match b {
+        b'\r' => bytes.get(i + 1) == Some(&b'\n'),
+        b'\n' => i > 0 && bytes[i - 1] == b'\r',
+        _ => true,
+    });
+    if normalized {
+        return Cow::Borrowed(input);
+    }
+    let mut output = String::with_capacity(input.len() + 2);
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                output.push_str("\r\n")
+            }
+            '\n' => output.push_str("\r\n"),
+            _ => output.push(c),
+        }
+    }
+    Cow::Owned(output)
+}
+
 // std::ptr::addr_eq was stabilized in rust 1.76. Once we upgrade
 // the MSRV we can remove this lint override.
 #[allow(ambiguous_wide_pointer_comparisons)]
@@ -414,7 +504,7 @@ pub(crate) fn decode_utf8_lossy(input: Cow<'_, [u8]>) -> Cow<'_, str> {
 
                     // First we do a debug_assert to confirm our description above.
                     let raw_utf8: *const [u8] = utf8.as_bytes();
-                    debug_assert!(raw_utf8 == &*bytes as *const [u8]);
+                    debug_assert!(core::ptr::eq(raw_utf8, &*bytes));
 
                     // Given we know the original input bytes are valid UTF-8,
                     // and we have ownership of those bytes, we re-use them and
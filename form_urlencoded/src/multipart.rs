@@ -0,0 +1,607 @@
+// Copyright 2013-2016 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `multipart/form-data` encoding:
+//! <https://html.spec.whatwg.org/multipage/#multipart/form-data-encoding-algorithm>
+//! and <https://fetch.spec.whatwg.org/#multipart-form-data-parser>
+
+use crate::normalize_newlines;
+use crate::sink::BinarySink;
+use alloc::borrow::{Borrow, Cow};
+use alloc::collections::vec_deque::{Drain, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The [`multipart/form-data` encoding algorithm](
+/// https://html.spec.whatwg.org/multipage/#multipart/form-data-encoding-algorithm).
+///
+/// The `Content-Type` of the result is given by [`content_type`](Self::content_type).
+///
+/// ```
+/// use form_urlencoded::MultipartSerializer;
+///
+/// let mut serializer = MultipartSerializer::with_boundary(Vec::new(), "XyZ");
+/// let body = serializer
+///     .append_pair("title", "Line 1\nLine 2")
+///     .append_file("upload", "a\"b.txt", "text/plain", b"contents")
+///     .finish();
+/// assert_eq!(serializer.content_type(), "multipart/form-data; boundary=XyZ");
+/// assert_eq!(
+///     body,
+///     &b"--XyZ\r\n\
+///        Content-Disposition: form-data; name=\"title\"\r\n\
+///        \r\n\
+///        Line 1\r\nLine 2\r\n\
+///        --XyZ\r\n\
+///        Content-Disposition: form-data; name=\"upload\"; filename=\"a%22b.txt\"\r\n\
+///        Content-Type: text/plain\r\n\
+///        \r\n\
+///        contents\r\n\
+///        --XyZ--\r\n"[..]
+/// );
+/// ```
+pub struct MultipartSerializer<S: BinarySink> {
+    sink: Option<S>,
+    boundary: String,
+}
+
+impl<S: BinarySink> MultipartSerializer<S> {
+    /// Create a new `multipart/form-data` serializer for the given sink,
+    /// with a random boundary.
+    ///
+    /// The boundary is long enough that it is unlikely to appear in the contents by accident,
+    /// but it is not suitable for cryptographic purposes.
+    #[cfg(feature = "std")]
+    pub fn new(sink: S) -> Self {
+        Self::with_boundary(sink, &generate_boundary())
+    }
+
+    /// Create a new `multipart/form-data` serializer for the given sink,
+    /// with the given boundary.
+    ///
+    /// The boundary must not appear in any of the names, values, or file contents.
+    ///
+    /// Panics if the boundary is not 1 to 70 characters among ASCII letters, digits,
+    /// and `'()+_,-./:=?` (RFC 2046 allows spaces except at the end, but they would need quoting).
+    pub fn with_boundary(sink: S, boundary: &str) -> Self {
+        let valid = (1..=70).contains(&boundary.len())
+            && boundary.bytes().all(|b| {
+                b.is_ascii_alphanumeric()
+                    || matches!(
+                        b,
+                        b'\''
+                            | b'('
+                            | b')'
+                            | b'+'
+                            | b'_'
+                            | b','
+                            | b'-'
+                            | b'.'
+                            | b'/'
+                            | b':'
+                            | b'='
+                            | b'?'
+                    )
+            });
+        if !valid {
+            panic!("invalid multipart boundary {:?}", boundary);
+        }
+        MultipartSerializer {
+            sink: Some(sink),
+            boundary: boundary.into(),
+        }
+    }
+
+    /// Return the boundary between parts.
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Return the value of the `Content-Type` header for the result.
+    pub fn content_type(&self) -> String {
+        let mut content_type = String::from("multipart/form-data; boundary=");
+        content_type.push_str(&self.boundary);
+        content_type
+    }
+
+    /// Serialize and append a part for a name/value pair.
+    ///
+    /// Newlines in the name and value are normalized to CRLF.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn append_pair(&mut self, name: &str, value: &str) -> &mut Self {
+        let sink = self.part(name, None);
+        sink.write_str("\r\n");
+        sink.write_str(&normalize_newlines(value));
+        sink.write_str("\r\n");
+        self
+    }
+
+    /// Serialize and append a part for a file.
+    ///
+    /// `content_type` can be a string or a value like `data_url::mime::Mime`.
+    /// If it is empty, `application/octet-stream` is used.
+    /// CR and LF in it are removed.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn append_file<T: fmt::Display>(
+        &mut self,
+        name: &str,
+        filename: &str,
+        content_type: T,
+        contents: &[u8],
+    ) -> &mut Self {
+        let mut content_type = content_type.to_string();
+        content_type.retain(|c| !matches!(c, '\r' | '\n'));
+        if content_type.is_empty() {
+            content_type.push_str("application/octet-stream")
+        }
+        let sink = self.part(name, Some(filename));
+        sink.write_str("Content-Type: ");
+        sink.write_str(&content_type);
+        sink.write_str("\r\n\r\n");
+        sink.write_bytes(contents);
+        sink.write_str("\r\n");
+        self
+    }
+
+    /// Serialize and append a number of name/value pairs.
+    ///
+    /// This simply calls `append_pair` repeatedly.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn extend_pairs<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for pair in iter {
+            let (k, v) = pair.borrow();
+            self.append_pair(k.as_ref(), v.as_ref());
+        }
+        self
+    }
+
+    /// Append the final boundary,
+    /// then take the sink and return the result of its [`Sink::finish`](crate::Sink::finish) method.
+    ///
+    /// Panics if called more than once.
+    pub fn finish(&mut self) -> S::Finished {
+        let mut sink = self
+            .sink
+            .take()
+            .expect("url::form_urlencoded::MultipartSerializer double finish");
+        sink.write_str("--");
+        sink.write_str(&self.boundary);
+        sink.write_str("--\r\n");
+        sink.finish()
+    }
+
+    /// Write the boundary and `Content-Disposition` header of a new part.
+    fn part(&mut self, name: &str, filename: Option<&str>) -> &mut S {
+        let sink = self
+            .sink
+            .as_mut()
+            .expect("url::form_urlencoded::MultipartSerializer finished");
+        sink.write_str("--");
+        sink.write_str(&self.boundary);
+        sink.write_str("\r\nContent-Disposition: form-data; name=\"");
+        sink.write_str(&escape(&normalize_newlines(name)));
+        sink.write_str("\"");
+        if let Some(filename) = filename {
+            sink.write_str("; filename=\"");
+            sink.write_str(&escape(filename));
+            sink.write_str("\"");
+        }
+        sink.write_str("\r\n");
+        sink
+    }
+}
+
+#[cfg(feature = "std")]
+fn generate_boundary() -> String {
+    use core::fmt::Write;
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut boundary = String::from("----formdata-rust-url-");
+    for _ in 0..2 {
+        let random = RandomState::new().build_hasher().finish();
+        write!(boundary, "{:016x}", random).unwrap();
+    }
+    boundary
+}
+
+/// Escape a name or filename for a `Content-Disposition` header
+fn escape(s: &str) -> Cow<'_, str> {
+    if !s.contains(|c| matches!(c, '\n' | '\r' | '"')) {
+        return Cow::Borrowed(s);
+    }
+    let mut escaped = String::with_capacity(s.len() + 4);
+    for c in s.chars() {
+        match c {
+            '\n' => escaped.push_str("%0A"),
+            '\r' => escaped.push_str("%0D"),
+            '"' => escaped.push_str("%22"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// The headers of a part of a `multipart/form-data` body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartHeaders {
+    pub name: String,
+    /// Present for file parts
+    pub filename: Option<String>,
+    /// The value of the `Content-Type` header, if any
+    pub content_type: Option<String>,
+}
+
+/// A part of a `multipart/form-data` body, returned by [`parse_multipart`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipartPart {
+    pub headers: PartHeaders,
+    pub body: Vec<u8>,
+}
+
+/// What [`MultipartParser::feed`] returns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultipartEvent {
+    /// The start of a new part
+    PartStart(PartHeaders),
+    /// Some of the body of the current part
+    Data(Vec<u8>),
+    /// The end of the current part
+    PartEnd,
+}
+
+/// The error returned when parsing a malformed `multipart/form-data` body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMultipart(());
+
+impl fmt::Display for InvalidMultipart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid multipart/form-data body")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidMultipart {}
+
+/// Parse a complete `multipart/form-data` body with the given boundary,
+/// following the [Fetch Standard](https://fetch.spec.whatwg.org/#multipart-form-data-parser).
+///
+/// ```
+/// let body = b"--XyZ\r\n\
+///     Content-Disposition: form-data; name=\"title\"\r\n\
+///     \r\n\
+///     Hello\r\n\
+///     --XyZ--\r\n";
+/// let parts = form_urlencoded::parse_multipart(body, "XyZ").unwrap();
+/// assert_eq!(parts[0].headers.name, "title");
+/// assert_eq!(parts[0].body, b"Hello");
+/// ```
+pub fn parse_multipart(
+    body: &[u8],
+    boundary: &str,
+) -> Result<Vec<MultipartPart>, InvalidMultipart> {
+    let mut parser = MultipartParser::new(boundary);
+    let mut parts: Vec<MultipartPart> = Vec::new();
+    for event in parser.feed(body)? {
+        match event {
+            MultipartEvent::PartStart(headers) => parts.push(MultipartPart {
+                headers,
+                body: Vec::new(),
+            }),
+            MultipartEvent::Data(data) => {
+                if let Some(part) = parts.last_mut() {
+                    part.body.extend_from_slice(&data)
+                }
+            }
+            MultipartEvent::PartEnd => {}
+        }
+    }
+    parser.finish()?;
+    Ok(parts)
+}
+
+/// An incremental parser for `multipart/form-data` bodies, for input that arrives in chunks.
+///
+/// The body of each part is returned in pieces as it is parsed,
+/// so that large files do not need to be kept in memory.
+/// Bytes that might be the start of the next boundary are held back until more input arrives.
+///
+/// ```
+/// use form_urlencoded::{MultipartEvent, MultipartParser};
+///
+/// let mut parser = MultipartParser::new("XyZ");
+/// let mut events = Vec::new();
+/// events.extend(parser.feed(b"--XyZ\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nb").unwrap());
+/// events.extend(parser.feed(b"c\r\n--XyZ--\r\n").unwrap());
+/// parser.finish().unwrap();
+/// assert!(matches!(&events[0], MultipartEvent::PartStart(headers) if headers.name == "a"));
+/// assert_eq!(events[1..], [
+///     MultipartEvent::Data(b"bc".to_vec()),
+///     MultipartEvent::PartEnd,
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultipartParser {
+    /// `\r\n--` followed by the boundary
+    delimiter: Vec<u8>,
+    max_headers_len: usize,
+    state: State,
+    /// Input not parsed yet
+    buffer: Vec<u8>,
+    /// Length of the start of `buffer` already searched for the end of headers or a delimiter
+    scanned: usize,
+    events: VecDeque<MultipartEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    AfterBoundary,
+    Closing,
+    Headers,
+    Body,
+    End,
+    Failed,
+}
+
+impl MultipartParser {
+    /// Return a new parser for the given boundary,
+    /// typically the `boundary` parameter of the `Content-Type` header.
+    pub fn new(boundary: &str) -> Self {
+        let mut delimiter = b"\r\n--".to_vec();
+        delimiter.extend_from_slice(boundary.as_bytes());
+        MultipartParser {
+            delimiter,
+            max_headers_len: 8 * 1024,
+            state: State::Start,
+            buffer: Vec::new(),
+            scanned: 0,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Limit the length in bytes of the headers of each part. The default is 8 KiB.
+    pub fn max_headers_len(mut self, new: usize) -> Self {
+        self.max_headers_len = new;
+        self
+    }
+
+    /// Parse the next chunk of input, and return the events that it completes.
+    ///
+    /// After an error, the parser returns an error for any further input.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Drain<'_, MultipartEvent>, InvalidMultipart> {
+        if self.state == State::Failed {
+            return Err(InvalidMultipart(()));
+        }
+        self.buffer.extend_from_slice(chunk);
+        if let Err(error) = self.parse() {
+            self.state = State::Failed;
+            self.buffer = Vec::new();
+            self.events.clear();
+            return Err(error);
+        }
+        Ok(self.events.drain(..))
+    }
+
+    /// Signal the end of the input, and check that the body was complete.
+    pub fn finish(self) -> Result<(), InvalidMultipart> {
+        if self.state == State::End {
+            Ok(())
+        } else {
+            Err(InvalidMultipart(()))
+        }
+    }
+
+    fn parse(&mut self) -> Result<(), InvalidMultipart> {
+        let invalid = InvalidMultipart(());
+        loop {
+            let consumed = match self.state {
+                State::Start => {
+                    // `--` followed by the boundary, at the very start
+                    let dash_boundary = &self.delimiter[2..];
+                    if self.buffer.len() < dash_boundary.len() {
+                        return if dash_boundary.starts_with(&self.buffer) {
+                            Ok(())
+                        } else {
+                            Err(invalid)
+                        };
+                    }
+                    if !self.buffer.starts_with(dash_boundary) {
+                        return Err(invalid);
+                    }
+                    self.state = State::AfterBoundary;
+                    dash_boundary.len()
+                }
+                State::AfterBoundary => {
+                    self.state = match self.buffer.get(..2) {
+                        Some(b"--") => State::Closing,
+                        Some(b"\r\n") => State::Headers,
+                        Some(_) => return Err(invalid),
+                        None if matches!(&*self.buffer, b"" | b"-" | b"\r") => return Ok(()),
+                        None => return Err(invalid),
+                    };
+                    2
+                }
+                State::Closing => {
+                    if !b"\r\n".starts_with(&self.buffer[..self.buffer.len().min(2)]) {
+                        return Err(invalid);
+                    }
+                    if self.buffer.len() < 2 {
+                        return Ok(());
+                    }
+                    self.state = State::End;
+                    2
+                }
+                // A part without headers has no name
+                State::Headers if self.buffer.starts_with(b"\r\n") => return Err(invalid),
+                State::Headers => match find_from(&self.buffer, b"\r\n\r\n", &mut self.scanned) {
+                    Some(position) => {
+                        if position > self.max_headers_len {
+                            return Err(invalid);
+                        }
+                        let headers = parse_headers(&self.buffer[..position + 2])?;
+                        self.events.push_back(MultipartEvent::PartStart(headers));
+                        self.state = State::Body;
+                        position + 4
+                    }
+                    None if self.buffer.len() > self.max_headers_len + 3 => return Err(invalid),
+                    None => return Ok(()),
+                },
+                State::Body => match find_from(&self.buffer, &self.delimiter, &mut self.scanned) {
+                    Some(position) => {
+                        if position > 0 {
+                            let data = self.buffer[..position].to_vec();
+                            self.events.push_back(MultipartEvent::Data(data));
+                        }
+                        self.events.push_back(MultipartEvent::PartEnd);
+                        self.state = State::AfterBoundary;
+                        position + self.delimiter.len()
+                    }
+                    None => {
+                        // Keep what could be the start of a delimiter
+                        let keep = self.delimiter.len() - 1;
+                        if self.buffer.len() > keep {
+                            let len = self.buffer.len() - keep;
+                            let data = self.buffer.drain(..len).collect();
+                            self.events.push_back(MultipartEvent::Data(data));
+                            self.scanned -= len;
+                        }
+                        return Ok(());
+                    }
+                },
+                State::End | State::Failed => {
+                    return if self.buffer.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(invalid)
+                    };
+                }
+            };
+            self.buffer.drain(..consumed);
+            self.scanned = 0;
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Like `find`, but skip the first `scanned` bytes of `haystack` where `needle` was not found
+/// by a previous call, and update `scanned` if `needle` is not found.
+fn find_from(haystack: &[u8], needle: &[u8], scanned: &mut usize) -> Option<usize> {
+    let position = find(&haystack[*scanned..], needle);
+    match position {
+        Some(position) => Some(*scanned + position),
+        None => {
+            // `needle` could still start in its last `needle.len() - 1` bytes
+            *scanned = (haystack.len() + 1)
+                .saturating_sub(needle.len())
+                .max(*scanned);
+            None
+        }
+    }
+}
+
+/// Parse header lines, each followed by CRLF
+fn parse_headers(mut input: &[u8]) -> Result<PartHeaders, InvalidMultipart> {
+    let invalid = InvalidMultipart(());
+    let mut name = None;
+    let mut filename = None;
+    let mut content_type = None;
+    while let Some(line_len) = find(input, b"\r\n") {
+        let line = &input[..line_len];
+        input = &input[line_len + 2..];
+        let colon = line.iter().position(|&b| b == b':').ok_or(invalid)?;
+        let (header_name, value) = (trim_http_whitespace(&line[..colon]), &line[colon + 1..]);
+        if header_name.is_empty() || !header_name.iter().all(|&b| is_token(b)) {
+            return Err(invalid);
+        }
+        if value.iter().any(|&b| matches!(b, b'\r' | b'\n')) {
+            return Err(invalid);
+        }
+        let value = trim_http_whitespace(value);
+        if header_name.eq_ignore_ascii_case(b"content-disposition") {
+            let rest = value
+                .strip_prefix(&b"form-data; name=\""[..])
+                .ok_or(invalid)?;
+            let (parsed_name, rest) = parse_quoted_name(rest)?;
+            name = Some(parsed_name);
+            filename = None;
+            if let Some(rest) = rest.strip_prefix(&b"; filename=\""[..]) {
+                filename = Some(parse_quoted_name(rest)?.0);
+            }
+        } else if header_name.eq_ignore_ascii_case(b"content-type") {
+            // Isomorphic decode
+            content_type = Some(value.iter().map(|&b| char::from(b)).collect());
+        }
+    }
+    Ok(PartHeaders {
+        name: name.ok_or(invalid)?,
+        filename,
+        content_type,
+    })
+}
+
+/// <https://fetch.spec.whatwg.org/#parse-a-multipart-form-data-name>
+fn parse_quoted_name(input: &[u8]) -> Result<(String, &[u8]), InvalidMultipart> {
+    let end = input
+        .iter()
+        .position(|&b| b == b'"')
+        .ok_or(InvalidMultipart(()))?;
+    let mut name = Vec::with_capacity(end);
+    let mut escaped = &input[..end];
+    while let Some((&byte, rest)) = escaped.split_first() {
+        let unescaped = match rest {
+            [b'0', b'A', ..] if byte == b'%' => Some(b'\n'),
+            [b'0', b'D', ..] if byte == b'%' => Some(b'\r'),
+            [b'2', b'2', ..] if byte == b'%' => Some(b'"'),
+            _ => None,
+        };
+        match unescaped {
+            Some(unescaped) => {
+                name.push(unescaped);
+                escaped = &rest[2..];
+            }
+            None => {
+                name.push(byte);
+                escaped = rest;
+            }
+        }
+    }
+    let name = String::from_utf8_lossy(&name).into_owned();
+    Ok((name, &input[end + 1..]))
+}
+
+fn is_token(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
+
+fn trim_http_whitespace(value: &[u8]) -> &[u8] {
+    let is_whitespace = |b: &u8| matches!(b, b' ' | b'\t');
+    let start = value.iter().take_while(|b| is_whitespace(b)).count();
+    let end = value.len()
+        - value[start..]
+            .iter()
+            .rev()
+            .take_while(|b| is_whitespace(b))
+            .count();
+    &value[start..end]
+}
@@ -0,0 +1,285 @@
+// Copyright 2013-2016 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{append_encoded, EncodingOverride};
+use alloc::borrow::Borrow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A destination for the output of [`SinkSerializer`].
+///
+/// Unlike with [`Target`](crate::Target), the output does not need to be kept in a `String`.
+/// Implement this trait to write into other buffer types.
+pub trait Sink {
+    /// Append serialized output.
+    fn write_str(&mut self, s: &str);
+    /// Called by [`SinkSerializer::finish`].
+    fn finish(self) -> Self::Finished;
+    type Finished;
+}
+
+/// A [`Sink`] that also accepts bytes that are not UTF-8,
+/// as needed by [`MultipartSerializer`](crate::MultipartSerializer) for file contents.
+pub trait BinarySink: Sink {
+    /// Append raw bytes.
+    fn write_bytes(&mut self, bytes: &[u8]);
+}
+
+impl Sink for String {
+    fn write_str(&mut self, s: &str) {
+        self.push_str(s)
+    }
+    fn finish(self) -> Self {
+        self
+    }
+    type Finished = Self;
+}
+
+impl Sink for &mut String {
+    fn write_str(&mut self, s: &str) {
+        self.push_str(s)
+    }
+    fn finish(self) -> Self {
+        self
+    }
+    type Finished = Self;
+}
+
+impl Sink for Vec<u8> {
+    fn write_str(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes())
+    }
+    fn finish(self) -> Self {
+        self
+    }
+    type Finished = Self;
+}
+
+impl Sink for &mut Vec<u8> {
+    fn write_str(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes())
+    }
+    fn finish(self) -> Self {
+        self
+    }
+    type Finished = Self;
+}
+
+impl BinarySink for Vec<u8> {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes)
+    }
+}
+
+impl BinarySink for &mut Vec<u8> {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes)
+    }
+}
+
+/// A sink that only counts the length of the output in bytes,
+/// for example for a `Content-Length` header.
+///
+/// ```
+/// use form_urlencoded::{ByteCount, SinkSerializer};
+///
+/// let len = SinkSerializer::new(ByteCount::default())
+///     .append_pair("name", "Zoë Doe")
+///     .finish();
+/// assert_eq!(len, "name=Zo%C3%AB+Doe".len());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ByteCount {
+    len: usize,
+}
+
+impl Sink for ByteCount {
+    fn write_str(&mut self, s: &str) {
+        self.len += s.len()
+    }
+    fn finish(self) -> usize {
+        self.len
+    }
+    type Finished = usize;
+}
+
+impl BinarySink for ByteCount {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.len += bytes.len()
+    }
+}
+
+/// A sink for an [`std::io::Write`] writer.
+///
+/// The first I/O error is kept and returned by [`SinkSerializer::finish`],
+/// and nothing else is written after it.
+/// Writes are not buffered: consider wrapping the writer in [`std::io::BufWriter`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct WriteSink<W> {
+    writer: W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> WriteSink<W> {
+    /// Return a new sink that writes into `writer`.
+    pub fn new(writer: W) -> Self {
+        WriteSink {
+            writer,
+            error: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Sink for WriteSink<W> {
+    fn write_str(&mut self, s: &str) {
+        self.write_bytes(s.as_bytes())
+    }
+    fn finish(self) -> std::io::Result<W> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.writer),
+        }
+    }
+    type Finished = std::io::Result<W>;
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> BinarySink for WriteSink<W> {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        if self.error.is_none() {
+            if let Err(error) = self.writer.write_all(bytes) {
+                self.error = Some(error)
+            }
+        }
+    }
+}
+
+/// The [`application/x-www-form-urlencoded` serializer](
+/// https://url.spec.whatwg.org/#concept-urlencoded-serializer)
+/// for any [`Sink`].
+///
+/// This is like [`Serializer`](crate::Serializer),
+/// except that the output is appended to the sink piece by piece and cannot be cleared.
+/// Any existing content of the sink is left as-is,
+/// and the first pair is not preceded by a `&` separator.
+///
+/// ```
+/// use form_urlencoded::SinkSerializer;
+///
+/// let mut body = b"body: ".to_vec();
+/// SinkSerializer::new(&mut body)
+///     .append_pair("foo", "bar & baz")
+///     .append_key_only("flag")
+///     .finish();
+/// assert_eq!(body, b"body: foo=bar+%26+baz&flag");
+/// ```
+pub struct SinkSerializer<'a, S: Sink> {
+    sink: Option<S>,
+    needs_separator: bool,
+    encoding: EncodingOverride<'a>,
+}
+
+impl<'a, S: Sink> SinkSerializer<'a, S> {
+    /// Create a new `application/x-www-form-urlencoded` serializer for the given sink.
+    pub fn new(sink: S) -> Self {
+        SinkSerializer {
+            sink: Some(sink),
+            needs_separator: false,
+            encoding: None,
+        }
+    }
+
+    /// Set the character encoding to be used for names and values before percent-encoding.
+    pub fn encoding_override(&mut self, new: EncodingOverride<'a>) -> &mut Self {
+        self.encoding = new;
+        self
+    }
+
+    /// Serialize and append a name/value pair.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn append_pair(&mut self, name: &str, value: &str) -> &mut Self {
+        let encoding = self.encoding;
+        let sink = self.separated_sink();
+        append_encoded(name, sink, encoding);
+        sink.write_str("=");
+        append_encoded(value, sink, encoding);
+        self
+    }
+
+    /// Serialize and append a name of parameter without any value.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn append_key_only(&mut self, name: &str) -> &mut Self {
+        let encoding = self.encoding;
+        append_encoded(name, self.separated_sink(), encoding);
+        self
+    }
+
+    /// Serialize and append a number of name/value pairs.
+    ///
+    /// This simply calls `append_pair` repeatedly.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn extend_pairs<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for pair in iter {
+            let (k, v) = pair.borrow();
+            self.append_pair(k.as_ref(), v.as_ref());
+        }
+        self
+    }
+
+    /// Serialize and append a number of names without values.
+    ///
+    /// This simply calls `append_key_only` repeatedly.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn extend_keys_only<I, K>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+        K: AsRef<str>,
+    {
+        for key in iter {
+            self.append_key_only(key.borrow().as_ref());
+        }
+        self
+    }
+
+    /// Take the sink and return the result of its [`Sink::finish`] method.
+    ///
+    /// Panics if called more than once.
+    pub fn finish(&mut self) -> S::Finished {
+        self.sink
+            .take()
+            .expect("url::form_urlencoded::SinkSerializer double finish")
+            .finish()
+    }
+
+    /// Return the sink after writing a separator if there was a previous pair.
+    fn separated_sink(&mut self) -> &mut S {
+        let sink = self
+            .sink
+            .as_mut()
+            .expect("url::form_urlencoded::SinkSerializer finished");
+        if self.needs_separator {
+            sink.write_str("&")
+        }
+        self.needs_separator = true;
+        sink
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2013-2016 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::is_separator;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{fmt, str};
+
+/// Like [`parse`](crate::parse), but report malformed input as errors instead of repairing it.
+///
+/// Each item is either a (name, value) pair or an error for one `&`-separated sequence
+/// of the input. Parsing continues with the next sequence after an error.
+///
+/// ```
+/// use form_urlencoded::ParseErrorKind;
+///
+/// let mut pairs = form_urlencoded::parse_strict(b"amount=10%25&%FF=x&=y");
+/// let pair = pairs.next().unwrap().unwrap();
+/// assert_eq!(pair, ("amount".into(), "10%".into()));
+/// let error = pairs.next().unwrap().unwrap_err();
+/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidUtf8, 13));
+/// let error = pairs.next().unwrap().unwrap_err();
+/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::EmptyName, 19));
+/// assert!(pairs.next().is_none());
+/// ```
+#[inline]
+pub fn parse_strict(input: &[u8]) -> ParseStrict<'_> {
+    ParseStrict {
+        input,
+        offset: 0,
+        generic_query: false,
+    }
+}
+
+/// The return type of [`parse_strict`].
+#[derive(Copy, Clone, Debug)]
+pub struct ParseStrict<'a> {
+    input: &'a [u8],
+    /// Of `input` in the original input
+    offset: usize,
+    generic_query: bool,
+}
+
+impl ParseStrict<'_> {
+    /// Parse the input as a generic query string rather than as a form,
+    /// like [`Parse::generic_query`](crate::Parse::generic_query).
+    pub fn generic_query(mut self) -> Self {
+        self.generic_query = true;
+        self
+    }
+}
+
+impl<'a> Iterator for ParseStrict<'a> {
+    type Item = Result<(Cow<'a, str>, Cow<'a, str>), ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.input.is_empty() {
+                return None;
+            }
+            let generic_query = self.generic_query;
+            let sequence_offset = self.offset;
+            let sequence = match self
+                .input
+                .iter()
+                .position(|&b| is_separator(b, generic_query))
+            {
+                Some(position) => {
+                    let sequence = &self.input[..position];
+                    self.input = &self.input[position + 1..];
+                    self.offset += position + 1;
+                    sequence
+                }
+                None => {
+                    let sequence = self.input;
+                    self.input = &[];
+                    self.offset += sequence.len();
+                    sequence
+                }
+            };
+            if sequence.is_empty() {
+                continue;
+            }
+            let mut split2 = sequence.splitn(2, |&b| b == b'=');
+            let name = split2.next().unwrap();
+            let value = split2.next().unwrap_or(&[][..]);
+            if name.is_empty() {
+                return Some(Err(ParseError {
+                    kind: ParseErrorKind::EmptyName,
+                    offset: sequence_offset,
+                }));
+            }
+            let value_offset = sequence_offset + name.len() + 1;
+            return Some(
+                decode(name, sequence_offset, generic_query).and_then(|name| {
+                    let value = decode(value, value_offset, generic_query)?;
+                    Ok((name, value))
+                }),
+            );
+        }
+    }
+}
+
+/// Percent-decode `input`, found at `offset` in the original input, and check for UTF-8.
+fn decode(input: &[u8], offset: usize, generic_query: bool) -> Result<Cow<'_, str>, ParseError> {
+    let error = |kind, index| ParseError {
+        kind,
+        offset: offset + index,
+    };
+    let unchanged = !input
+        .iter()
+        .any(|&b| b == b'%' || (b == b'+' && !generic_query));
+    if unchanged {
+        return str::from_utf8(input)
+            .map(Cow::Borrowed)
+            .map_err(|e| error(ParseErrorKind::InvalidUtf8, e.valid_up_to()));
+    }
+    let mut decoded = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        match input[i] {
+            b'%' => {
+                let hex_digit = |i| input.get(i).and_then(|&b| char::from(b).to_digit(16));
+                match (hex_digit(i + 1), hex_digit(i + 2)) {
+                    (Some(high), Some(low)) => decoded.push((high * 0x10 + low) as u8),
+                    _ => return Err(error(ParseErrorKind::MalformedEscape, i)),
+                }
+                i += 3;
+                continue;
+            }
+            b'+' if !generic_query => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8(decoded).map(Cow::Owned).map_err(|e| {
+        // Map the position in decoded bytes back to the input
+        let mut index = 0;
+        for _ in 0..e.utf8_error().valid_up_to() {
+            index += if input[index] == b'%' { 3 } else { 1 };
+        }
+        error(ParseErrorKind::InvalidUtf8, index)
+    })
+}
+
+/// An error for one malformed sequence in [`parse_strict`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// Return what is wrong with the sequence.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Return the position in bytes of the error in the input.
+    ///
+    /// This is the `%` sign of a malformed escape,
+    /// the first byte that is not valid UTF-8 (or the `%` sign of its escape),
+    /// or the start of a sequence with an empty name.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// The kinds of [`ParseError`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A name or value is not valid UTF-8 once percent-decoded
+    InvalidUtf8,
+    /// A `%` sign is not followed by two hexadecimal digits
+    MalformedEscape,
+    /// A sequence starts with `=`
+    EmptyName,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUtf8 => write!(f, "invalid UTF-8"),
+            Self::MalformedEscape => write!(f, "malformed percent-encoded byte"),
+            Self::EmptyName => write!(f, "empty name"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
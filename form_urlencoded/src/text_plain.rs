@@ -0,0 +1,136 @@
+// Copyright 2013-2016 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! <https://html.spec.whatwg.org/multipage/#text/plain-encoding-algorithm>
+
+use crate::normalize_newlines;
+use crate::sink::Sink;
+use alloc::borrow::Borrow;
+
+/// The [`text/plain` encoding algorithm](
+/// https://html.spec.whatwg.org/multipage/#text/plain-encoding-algorithm).
+///
+/// Each pair is written as `name=value` followed by CRLF, without any escaping.
+/// This format is meant to be human-readable and is ambiguous
+/// when names contain `=` or values contain newlines.
+///
+/// ```
+/// use form_urlencoded::TextPlainSerializer;
+///
+/// let body = TextPlainSerializer::new(String::new())
+///     .append_pair("to", "a@example.com")
+///     .append_file("attachment", "notes.txt")
+///     .finish();
+/// assert_eq!(body, "to=a@example.com\r\nattachment=notes.txt\r\n");
+/// ```
+pub struct TextPlainSerializer<S: Sink> {
+    sink: Option<S>,
+}
+
+impl<S: Sink> TextPlainSerializer<S> {
+    /// Create a new `text/plain` serializer for the given sink.
+    pub fn new(sink: S) -> Self {
+        TextPlainSerializer { sink: Some(sink) }
+    }
+
+    /// Serialize and append a name/value pair.
+    ///
+    /// Newlines in the name and value are normalized to CRLF.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn append_pair(&mut self, name: &str, value: &str) -> &mut Self {
+        let sink = self
+            .sink
+            .as_mut()
+            .expect("url::form_urlencoded::TextPlainSerializer finished");
+        sink.write_str(&normalize_newlines(name));
+        sink.write_str("=");
+        sink.write_str(&normalize_newlines(value));
+        sink.write_str("\r\n");
+        self
+    }
+
+    /// Serialize and append a file entry. Only its name is included.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn append_file(&mut self, name: &str, filename: &str) -> &mut Self {
+        self.append_pair(name, filename)
+    }
+
+    /// Serialize and append a number of name/value pairs.
+    ///
+    /// This simply calls `append_pair` repeatedly.
+    ///
+    /// Panics if called after `.finish()`.
+    pub fn extend_pairs<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for pair in iter {
+            let (k, v) = pair.borrow();
+            self.append_pair(k.as_ref(), v.as_ref());
+        }
+        self
+    }
+
+    /// Take the sink and return the result of its [`Sink::finish`] method.
+    ///
+    /// Panics if called more than once.
+    pub fn finish(&mut self) -> S::Finished {
+        self.sink
+            .take()
+            .expect("url::form_urlencoded::TextPlainSerializer double finish")
+            .finish()
+    }
+}
+
+/// Parse a `text/plain` form body into an iterator of (name, value) pairs.
+///
+/// Each line, ended by CRLF or LF, is split at its first `=`.
+/// Empty lines are skipped, and a line without `=` is a name with an empty value.
+/// Since the format has no escaping, values that contained newlines are split.
+///
+/// ```
+/// let pairs: Vec<_> = form_urlencoded::parse_text_plain("a=b=c\r\nd\r\n").collect();
+/// assert_eq!(pairs, [("a", "b=c"), ("d", "")]);
+/// ```
+pub fn parse_text_plain(input: &str) -> ParseTextPlain<'_> {
+    ParseTextPlain { input }
+}
+
+/// The return type of [`parse_text_plain`].
+#[derive(Copy, Clone, Debug)]
+pub struct ParseTextPlain<'a> {
+    input: &'a str,
+}
+
+impl<'a> Iterator for ParseTextPlain<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.input.is_empty() {
+                return None;
+            }
+            let mut split2 = self.input.splitn(2, '\n');
+            let line = split2.next().unwrap();
+            self.input = split2.next().unwrap_or("");
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line.is_empty() {
+                continue;
+            }
+            let mut split2 = line.splitn(2, '=');
+            let name = split2.next().unwrap();
+            let value = split2.next().unwrap_or("");
+            return Some((name, value));
+        }
+    }
+}
@@ -0,0 +1,274 @@
+// Copyright 2013-2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An equivalent of `idna_adapter::Adapter` and its types, with the same API,
+//! that uses Unicode data loaded at run time from an ICU4X data provider.
+//!
+//! `idna_adapter` only supports data compiled into the binary, and its types cannot
+//! be constructed from outside of it, so this module is used instead of it
+//! for instances of [`Uts46`](crate::uts46::Uts46) created with a provider.
+//! Instances created with [`Uts46::new`](crate::uts46::Uts46::new) keep using `idna_adapter`.
+
+use icu_normalizer::properties::CanonicalCombiningClassMap;
+use icu_normalizer::provider::{
+    CanonicalCompositionsV1Marker, CanonicalDecompositionDataV1Marker,
+    CanonicalDecompositionTablesV1Marker, CompatibilityDecompositionTablesV1Marker,
+    Uts46DecompositionSupplementV1Marker,
+};
+use icu_normalizer::uts46::Uts46Mapper;
+use icu_normalizer::NormalizerError;
+use icu_properties::maps::{self, CodePointMapData};
+use icu_properties::provider::{BidiClassV1Marker, GeneralCategoryV1Marker, JoiningTypeV1Marker};
+use icu_properties::{CanonicalCombiningClass, GeneralCategory, PropertiesError};
+use icu_provider::{DataError, DataProvider};
+
+const fn joining_type_to_mask(jt: icu_properties::JoiningType) -> u32 {
+    1u32 << jt.0
+}
+
+pub const LEFT_OR_DUAL_JOINING_MASK: JoiningTypeMask = JoiningTypeMask(
+    joining_type_to_mask(icu_properties::JoiningType::LeftJoining)
+        | joining_type_to_mask(icu_properties::JoiningType::DualJoining),
+);
+
+pub const RIGHT_OR_DUAL_JOINING_MASK: JoiningTypeMask = JoiningTypeMask(
+    joining_type_to_mask(icu_properties::JoiningType::RightJoining)
+        | joining_type_to_mask(icu_properties::JoiningType::DualJoining),
+);
+
+const fn bidi_class_to_mask(bc: icu_properties::BidiClass) -> u32 {
+    1u32 << bc.0
+}
+
+pub const RTL_MASK: BidiClassMask = BidiClassMask(
+    bidi_class_to_mask(icu_properties::BidiClass::RightToLeft)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicLetter)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicNumber),
+);
+
+pub const FIRST_BC_MASK: BidiClassMask = BidiClassMask(
+    bidi_class_to_mask(icu_properties::BidiClass::LeftToRight)
+        | bidi_class_to_mask(icu_properties::BidiClass::RightToLeft)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicLetter),
+);
+
+pub const LAST_LTR_MASK: BidiClassMask = BidiClassMask(
+    bidi_class_to_mask(icu_properties::BidiClass::LeftToRight)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanNumber),
+);
+
+pub const LAST_RTL_MASK: BidiClassMask = BidiClassMask(
+    bidi_class_to_mask(icu_properties::BidiClass::RightToLeft)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicLetter)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanNumber)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicNumber),
+);
+
+pub const MIDDLE_LTR_MASK: BidiClassMask = BidiClassMask(
+    bidi_class_to_mask(icu_properties::BidiClass::LeftToRight)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanNumber)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanSeparator)
+        | bidi_class_to_mask(icu_properties::BidiClass::CommonSeparator)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanTerminator)
+        | bidi_class_to_mask(icu_properties::BidiClass::OtherNeutral)
+        | bidi_class_to_mask(icu_properties::BidiClass::BoundaryNeutral)
+        | bidi_class_to_mask(icu_properties::BidiClass::NonspacingMark),
+);
+
+pub const MIDDLE_RTL_MASK: BidiClassMask = BidiClassMask(
+    bidi_class_to_mask(icu_properties::BidiClass::RightToLeft)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicLetter)
+        | bidi_class_to_mask(icu_properties::BidiClass::ArabicNumber)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanNumber)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanSeparator)
+        | bidi_class_to_mask(icu_properties::BidiClass::CommonSeparator)
+        | bidi_class_to_mask(icu_properties::BidiClass::EuropeanTerminator)
+        | bidi_class_to_mask(icu_properties::BidiClass::OtherNeutral)
+        | bidi_class_to_mask(icu_properties::BidiClass::BoundaryNeutral)
+        | bidi_class_to_mask(icu_properties::BidiClass::NonspacingMark),
+);
+
+const fn general_category_to_mask(gc: GeneralCategory) -> u32 {
+    1 << (gc as u32)
+}
+
+const MARK_MASK: u32 = general_category_to_mask(GeneralCategory::NonspacingMark)
+    | general_category_to_mask(GeneralCategory::SpacingMark)
+    | general_category_to_mask(GeneralCategory::EnclosingMark);
+
+#[derive(Clone, Copy)]
+pub struct JoiningType(icu_properties::JoiningType);
+
+impl JoiningType {
+    #[inline(always)]
+    pub fn to_mask(self) -> JoiningTypeMask {
+        JoiningTypeMask(joining_type_to_mask(self.0))
+    }
+
+    #[inline(always)]
+    pub fn is_transparent(self) -> bool {
+        self.0 == icu_properties::JoiningType::Transparent
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct JoiningTypeMask(u32);
+
+impl JoiningTypeMask {
+    #[inline(always)]
+    pub fn intersects(self, other: JoiningTypeMask) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BidiClass(icu_properties::BidiClass);
+
+impl BidiClass {
+    #[inline(always)]
+    pub fn to_mask(self) -> BidiClassMask {
+        BidiClassMask(bidi_class_to_mask(self.0))
+    }
+
+    #[inline(always)]
+    pub fn is_ltr(self) -> bool {
+        self.0 == icu_properties::BidiClass::LeftToRight
+    }
+
+    #[inline(always)]
+    pub fn is_nonspacing_mark(self) -> bool {
+        self.0 == icu_properties::BidiClass::NonspacingMark
+    }
+
+    #[inline(always)]
+    pub fn is_european_number(self) -> bool {
+        self.0 == icu_properties::BidiClass::EuropeanNumber
+    }
+
+    #[inline(always)]
+    pub fn is_arabic_number(self) -> bool {
+        self.0 == icu_properties::BidiClass::ArabicNumber
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BidiClassMask(u32);
+
+impl BidiClassMask {
+    #[inline(always)]
+    pub fn intersects(self, other: BidiClassMask) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+/// The data providers that [`Uts46::try_new_unstable`](crate::uts46::Uts46::try_new_unstable)
+/// can load data from: any provider of the normalization and property data that UTS 46 needs.
+pub trait Uts46DataProvider:
+    DataProvider<CanonicalDecompositionDataV1Marker>
+    + DataProvider<Uts46DecompositionSupplementV1Marker>
+    + DataProvider<CanonicalDecompositionTablesV1Marker>
+    + DataProvider<CompatibilityDecompositionTablesV1Marker>
+    + DataProvider<CanonicalCompositionsV1Marker>
+    + DataProvider<GeneralCategoryV1Marker>
+    + DataProvider<BidiClassV1Marker>
+    + DataProvider<JoiningTypeV1Marker>
+{
+}
+
+impl<P> Uts46DataProvider for P where
+    P: DataProvider<CanonicalDecompositionDataV1Marker>
+        + DataProvider<Uts46DecompositionSupplementV1Marker>
+        + DataProvider<CanonicalDecompositionTablesV1Marker>
+        + DataProvider<CompatibilityDecompositionTablesV1Marker>
+        + DataProvider<CanonicalCompositionsV1Marker>
+        + DataProvider<GeneralCategoryV1Marker>
+        + DataProvider<BidiClassV1Marker>
+        + DataProvider<JoiningTypeV1Marker>
+        + ?Sized
+{
+}
+
+pub struct Adapter {
+    mapper: Uts46Mapper,
+    canonical_combining_class: CanonicalCombiningClassMap,
+    general_category: CodePointMapData<GeneralCategory>,
+    bidi_class: CodePointMapData<icu_properties::BidiClass>,
+    joining_type: CodePointMapData<icu_properties::JoiningType>,
+}
+
+impl Adapter {
+    /// Constructor using data loaded from `provider`.
+    pub fn try_new_unstable<P: Uts46DataProvider + ?Sized>(
+        provider: &P,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            mapper: Uts46Mapper::try_new(provider).map_err(from_normalizer_error)?,
+            canonical_combining_class: CanonicalCombiningClassMap::try_new_unstable(provider)
+                .map_err(from_normalizer_error)?,
+            general_category: maps::load_general_category(provider)
+                .map_err(from_properties_error)?,
+            bidi_class: maps::load_bidi_class(provider).map_err(from_properties_error)?,
+            joining_type: maps::load_joining_type(provider).map_err(from_properties_error)?,
+        })
+    }
+
+    /// `true` iff the Canonical_Combining_Class of `c` is Virama.
+    #[inline(always)]
+    pub fn is_virama(&self, c: char) -> bool {
+        self.canonical_combining_class.get(c) == CanonicalCombiningClass::Virama
+    }
+
+    /// `true` iff the General_Category of `c` is Mark, i.e. any of Nonspacing_Mark,
+    /// Spacing_Mark, or Enclosing_Mark.
+    #[inline(always)]
+    pub fn is_mark(&self, c: char) -> bool {
+        (general_category_to_mask(self.general_category.as_borrowed().get(c)) & MARK_MASK) != 0
+    }
+
+    /// Returns the Bidi_Class of `c`.
+    #[inline(always)]
+    pub fn bidi_class(&self, c: char) -> BidiClass {
+        BidiClass(self.bidi_class.as_borrowed().get(c))
+    }
+
+    /// Returns the Joining_Type of `c`.
+    #[inline(always)]
+    pub fn joining_type(&self, c: char) -> JoiningType {
+        JoiningType(self.joining_type.as_borrowed().get(c))
+    }
+
+    #[inline(always)]
+    pub fn map_normalize<'delegate, I: Iterator<Item = char> + 'delegate>(
+        &'delegate self,
+        iter: I,
+    ) -> impl Iterator<Item = char> + 'delegate {
+        self.mapper.map_normalize(iter)
+    }
+
+    #[inline(always)]
+    pub fn normalize_validate<'delegate, I: Iterator<Item = char> + 'delegate>(
+        &'delegate self,
+        iter: I,
+    ) -> impl Iterator<Item = char> + 'delegate {
+        self.mapper.normalize_validate(iter)
+    }
+}
+
+fn from_normalizer_error(error: NormalizerError) -> DataError {
+    match error {
+        NormalizerError::Data(error) => error,
+        _ => DataError::custom("invalid normalizer data"),
+    }
+}
+
+fn from_properties_error(error: PropertiesError) -> DataError {
+    match error {
+        PropertiesError::PropDataLoad(error) => error,
+        _ => DataError::custom("invalid property data"),
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Unicode data that UTS 46 processing needs, abstracted over where it comes from:
+//! `idna_adapter` for data compiled into the binary, or [`crate::adapter`] for data
+//! loaded at run time.
+//!
+//! The processing code is generic over [`Backend`], so that it is monomorphized
+//! for each back end instead of checking which one to use on each lookup.
+//!
+//! The mapping and normalization methods call a closure for each character
+//! instead of returning an iterator, since the iterator types of the back ends
+//! cannot be named.
+
+/// A Bidi_Class value of a back end
+pub(crate) trait BidiClass: Copy {
+    type Mask: Mask;
+    fn to_mask(self) -> Self::Mask;
+    fn is_ltr(self) -> bool;
+    fn is_nonspacing_mark(self) -> bool;
+    fn is_european_number(self) -> bool;
+    fn is_arabic_number(self) -> bool;
+}
+
+/// A Joining_Type value of a back end
+pub(crate) trait JoiningType: Copy {
+    type Mask: Mask;
+    fn to_mask(self) -> Self::Mask;
+    fn is_transparent(self) -> bool;
+}
+
+/// A set of Bidi_Class or Joining_Type values of a back end
+pub(crate) trait Mask: Copy {
+    fn intersects(self, other: Self) -> bool;
+}
+
+/// A source of the Unicode data that UTS 46 processing needs
+pub(crate) trait Backend {
+    type BidiClass: BidiClass<Mask = Self::BidiClassMask>;
+    type BidiClassMask: Mask;
+    type JoiningType: JoiningType<Mask = Self::JoiningTypeMask>;
+    type JoiningTypeMask: Mask;
+
+    const LEFT_OR_DUAL_JOINING_MASK: Self::JoiningTypeMask;
+    const RIGHT_OR_DUAL_JOINING_MASK: Self::JoiningTypeMask;
+    const RTL_MASK: Self::BidiClassMask;
+    const FIRST_BC_MASK: Self::BidiClassMask;
+    const LAST_LTR_MASK: Self::BidiClassMask;
+    const LAST_RTL_MASK: Self::BidiClassMask;
+    const MIDDLE_LTR_MASK: Self::BidiClassMask;
+    const MIDDLE_RTL_MASK: Self::BidiClassMask;
+
+    /// `true` iff the Canonical_Combining_Class of `c` is Virama.
+    fn is_virama(&self, c: char) -> bool;
+
+    /// `true` iff the General_Category of `c` is Mark.
+    fn is_mark(&self, c: char) -> bool;
+
+    fn bidi_class(&self, c: char) -> Self::BidiClass;
+
+    fn joining_type(&self, c: char) -> Self::JoiningType;
+
+    /// Calls `f` with each character of the UTS 46 mapping and normalization of `iter`,
+    /// until `f` returns an error.
+    fn try_map_normalize<I, E, F>(&self, iter: I, f: F) -> Result<(), E>
+    where
+        I: Iterator<Item = char>,
+        F: FnMut(char) -> Result<(), E>;
+
+    /// Calls `f` with each character of the normalization of `iter` with characters
+    /// that are not valid in UTS 46 turned into U+FFFD, until `f` returns an error.
+    fn try_normalize_validate<I, E, F>(&self, iter: I, f: F) -> Result<(), E>
+    where
+        I: Iterator<Item = char>,
+        F: FnMut(char) -> Result<(), E>;
+}
+
+/// Implements the back end traits for the types of `idna_adapter`, or of a module
+/// with the same API
+macro_rules! impl_backend {
+    ($module:path) => {
+        const _: () = {
+            use $module as module;
+
+            impl $crate::backend::BidiClass for module::BidiClass {
+                type Mask = module::BidiClassMask;
+
+                #[inline(always)]
+                fn to_mask(self) -> Self::Mask {
+                    module::BidiClass::to_mask(self)
+                }
+
+                #[inline(always)]
+                fn is_ltr(self) -> bool {
+                    module::BidiClass::is_ltr(self)
+                }
+
+                #[inline(always)]
+                fn is_nonspacing_mark(self) -> bool {
+                    module::BidiClass::is_nonspacing_mark(self)
+                }
+
+                #[inline(always)]
+                fn is_european_number(self) -> bool {
+                    module::BidiClass::is_european_number(self)
+                }
+
+                #[inline(always)]
+                fn is_arabic_number(self) -> bool {
+                    module::BidiClass::is_arabic_number(self)
+                }
+            }
+
+            impl $crate::backend::JoiningType for module::JoiningType {
+                type Mask = module::JoiningTypeMask;
+
+                #[inline(always)]
+                fn to_mask(self) -> Self::Mask {
+                    module::JoiningType::to_mask(self)
+                }
+
+                #[inline(always)]
+                fn is_transparent(self) -> bool {
+                    module::JoiningType::is_transparent(self)
+                }
+            }
+
+            impl $crate::backend::Mask for module::BidiClassMask {
+                #[inline(always)]
+                fn intersects(self, other: Self) -> bool {
+                    module::BidiClassMask::intersects(self, other)
+                }
+            }
+
+            impl $crate::backend::Mask for module::JoiningTypeMask {
+                #[inline(always)]
+                fn intersects(self, other: Self) -> bool {
+                    module::JoiningTypeMask::intersects(self, other)
+                }
+            }
+
+            impl $crate::backend::Backend for module::Adapter {
+                type BidiClass = module::BidiClass;
+                type BidiClassMask = module::BidiClassMask;
+                type JoiningType = module::JoiningType;
+                type JoiningTypeMask = module::JoiningTypeMask;
+
+                const LEFT_OR_DUAL_JOINING_MASK: Self::JoiningTypeMask =
+                    module::LEFT_OR_DUAL_JOINING_MASK;
+                const RIGHT_OR_DUAL_JOINING_MASK: Self::JoiningTypeMask =
+                    module::RIGHT_OR_DUAL_JOINING_MASK;
+                const RTL_MASK: Self::BidiClassMask = module::RTL_MASK;
+                const FIRST_BC_MASK: Self::BidiClassMask = module::FIRST_BC_MASK;
+                const LAST_LTR_MASK: Self::BidiClassMask = module::LAST_LTR_MASK;
+                const LAST_RTL_MASK: Self::BidiClassMask = module::LAST_RTL_MASK;
+                const MIDDLE_LTR_MASK: Self::BidiClassMask = module::MIDDLE_LTR_MASK;
+                const MIDDLE_RTL_MASK: Self::BidiClassMask = module::MIDDLE_RTL_MASK;
+
+                #[inline(always)]
+                fn is_virama(&self, c: char) -> bool {
+                    module::Adapter::is_virama(self, c)
+                }
+
+                #[inline(always)]
+                fn is_mark(&self, c: char) -> bool {
+                    module::Adapter::is_mark(self, c)
+                }
+
+                #[inline(always)]
+                fn bidi_class(&self, c: char) -> Self::BidiClass {
+                    module::Adapter::bidi_class(self, c)
+                }
+
+                #[inline(always)]
+                fn joining_type(&self, c: char) -> Self::JoiningType {
+                    module::Adapter::joining_type(self, c)
+                }
+
+                #[inline(always)]
+                fn try_map_normalize<I, E, F>(&self, iter: I, f: F) -> Result<(), E>
+                where
+                    I: Iterator<Item = char>,
+                    F: FnMut(char) -> Result<(), E>,
+                {
+                    self.map_normalize(iter).try_for_each(f)
+                }
+
+                #[inline(always)]
+                fn try_normalize_validate<I, E, F>(&self, iter: I, f: F) -> Result<(), E>
+                where
+                    I: Iterator<Item = char>,
+                    F: FnMut(char) -> Result<(), E>,
+                {
+                    self.normalize_validate(iter).try_for_each(f)
+                }
+            }
+        };
+    };
+}
+
+#[cfg(feature = "compiled_data")]
+impl_backend!(idna_adapter);
+
+#[cfg(feature = "provider")]
+impl_backend!(crate::adapter);
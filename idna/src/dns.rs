@@ -0,0 +1,213 @@
+// Copyright 2013-2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between domain names and the DNS wire format of
+//! [RFC 1035 section 3.1](https://www.rfc-editor.org/rfc/rfc1035#section-3.1),
+//! where each label is prefixed with its length and the name ends with the
+//! empty root label.
+//!
+//! ```
+//! let ascii = idna::domain_to_ascii("Bücher.example").unwrap();
+//! let wire = idna::dns::to_wire(&ascii).unwrap();
+//! assert_eq!(wire, b"\x0dxn--bcher-kva\x07example\x00");
+//! assert_eq!(idna::dns::from_wire(&wire).unwrap(), "bücher.example");
+//! ```
+
+use crate::uts46::{AsciiDenyList, Hyphens, Uts46};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Errors of [`to_wire`] and [`from_wire`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum WireError {
+    /// A label other than the final root label is empty.
+    EmptyLabel,
+    /// A label is longer than 63 bytes.
+    LabelTooLong,
+    /// The name is longer than 255 bytes in wire format.
+    NameTooLong,
+    /// A `\` is not followed by a character or by three decimal digits up to 255.
+    InvalidEscape,
+    /// The wire format ends before the root label.
+    Truncated,
+    /// A length byte has one of the two high bits set, as in a compression pointer.
+    /// Names in wire format have to be decompressed before conversion.
+    UnsupportedLabelType,
+    /// The wire format continues after the root label.
+    TrailingData,
+}
+
+impl core::fmt::Display for WireError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match *self {
+            WireError::EmptyLabel => "empty label",
+            WireError::LabelTooLong => "label longer than 63 bytes",
+            WireError::NameTooLong => "domain name longer than 255 bytes in wire format",
+            WireError::InvalidEscape => "invalid escape sequence",
+            WireError::Truncated => "truncated domain name in wire format",
+            WireError::UnsupportedLabelType => "compression pointer or unsupported label type",
+            WireError::TrailingData => "data after the end of the domain name",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WireError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for WireError {}
+
+/// Converts a domain name in the presentation format of
+/// [RFC 1035 section 5.1](https://www.rfc-editor.org/rfc/rfc1035#section-5.1)
+/// to wire format.
+///
+/// The domain name is expected to be the output of [`crate::domain_to_ascii`] or similar.
+/// A trailing dot is optional, and `.` alone is the root. `\.` and `\\` stand for a literal
+/// dot or backslash within a label, `\` followed by any other character for that character,
+/// and `\DDD` for the byte with the decimal value `DDD`.
+///
+/// This checks the length limits of [RFC 1035 section 2.3.4](https://www.rfc-editor.org/rfc/rfc1035#section-2.3.4)
+/// on the unescaped labels, which are the limits of [`crate::uts46::verify_dns_length`]
+/// for names without escapes.
+pub fn to_wire(domain_name: &str) -> Result<Vec<u8>, WireError> {
+    let mut wire = Vec::with_capacity(domain_name.len() + 2);
+    wire.push(0);
+    if domain_name == "." {
+        return Ok(wire);
+    }
+    // The index of the length byte of the current label
+    let mut label_start = 0;
+    let mut bytes = domain_name.as_bytes().iter();
+    while let Some(&b) = bytes.next() {
+        match b {
+            b'.' => {
+                finish_label(&mut wire, &mut label_start)?;
+                if bytes.as_slice().is_empty() {
+                    // Trailing dot: the placeholder length is the root label
+                    break;
+                }
+            }
+            b'\\' => match bytes.next() {
+                Some(&d) if d.is_ascii_digit() => {
+                    let mut value = u32::from(d - b'0');
+                    for _ in 0..2 {
+                        match bytes.next() {
+                            Some(&d) if d.is_ascii_digit() => {
+                                value = value * 10 + u32::from(d - b'0');
+                            }
+                            _ => return Err(WireError::InvalidEscape),
+                        }
+                    }
+                    if value > 255 {
+                        return Err(WireError::InvalidEscape);
+                    }
+                    wire.push(value as u8);
+                }
+                Some(&c) => wire.push(c),
+                None => return Err(WireError::InvalidEscape),
+            },
+            _ => wire.push(b),
+        }
+        if wire.len() > 256 {
+            return Err(WireError::NameTooLong);
+        }
+    }
+    if label_start != wire.len() - 1 || domain_name.is_empty() {
+        finish_label(&mut wire, &mut label_start)?;
+    }
+    if wire.len() > 255 {
+        return Err(WireError::NameTooLong);
+    }
+    Ok(wire)
+}
+
+/// Fills in the length of the current label and starts the next one
+fn finish_label(wire: &mut Vec<u8>, label_start: &mut usize) -> Result<(), WireError> {
+    let len = wire.len() - *label_start - 1;
+    if len == 0 {
+        return Err(WireError::EmptyLabel);
+    }
+    if len > 63 {
+        return Err(WireError::LabelTooLong);
+    }
+    wire[*label_start] = len as u8;
+    *label_start = wire.len();
+    wire.push(0);
+    Ok(())
+}
+
+/// Converts a domain name in wire format to presentation format for display.
+///
+/// A-labels are decoded to Unicode, unless they are not valid according to
+/// [`Uts46::to_unicode`]. Other labels are escaped: `.` and `\` as `\.` and `\\`,
+/// and bytes other than printable ASCII as `\DDD`. The root label is not shown as a
+/// trailing dot, except that the root name on its own is `.`.
+///
+/// `wire` must be exactly one uncompressed name.
+pub fn from_wire(wire: &[u8]) -> Result<String, WireError> {
+    let uts46 = Uts46::new();
+    let mut presentation = String::with_capacity(wire.len());
+    let mut rest = wire;
+    let mut first = true;
+    loop {
+        let (&len, tail) = rest.split_first().ok_or(WireError::Truncated)?;
+        if len == 0 {
+            rest = tail;
+            break;
+        }
+        if len > 63 {
+            return Err(WireError::UnsupportedLabelType);
+        }
+        let label = tail.get(..usize::from(len)).ok_or(WireError::Truncated)?;
+        rest = &tail[label.len()..];
+        if wire.len() - rest.len() >= 255 {
+            return Err(WireError::NameTooLong);
+        }
+        if !first {
+            presentation.push('.');
+        }
+        first = false;
+        write_label(&uts46, label, &mut presentation);
+    }
+    if !rest.is_empty() {
+        return Err(WireError::TrailingData);
+    }
+    if first {
+        presentation.push('.');
+    }
+    Ok(presentation)
+}
+
+/// Writes a label of [`from_wire`] output
+fn write_label(uts46: &Uts46, label: &[u8], presentation: &mut String) {
+    if label.len() > 4
+        && label[..4].eq_ignore_ascii_case(b"xn--")
+        && label
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+    {
+        let (unicode, result) = uts46.to_unicode(label, AsciiDenyList::STD3, Hyphens::Allow);
+        if result.is_ok() {
+            presentation.push_str(&unicode);
+            return;
+        }
+    }
+    for &b in label {
+        match b {
+            b'.' | b'\\' => {
+                presentation.push('\\');
+                presentation.push(char::from(b));
+            }
+            0x21..=0x7E => presentation.push(char::from(b)),
+            // Writing to a `String` can't fail
+            _ => write!(presentation, "\\{:03}", b).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright 2013-2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Validation of domain names for registration under IDNA2008
+//! ([RFC 5891](https://www.rfc-editor.org/rfc/rfc5891),
+//! [RFC 5892](https://www.rfc-editor.org/rfc/rfc5892) and
+//! [RFC 5893](https://www.rfc-editor.org/rfc/rfc5893)).
+//!
+//! The UTS 46 processing in [`crate::uts46`] maps its input and is meant for looking
+//! domain names up. A registry instead has to check that a name is already in the form
+//! that gets registered, so nothing is mapped here: input with upper case letters,
+//! compatibility characters or ideographic full stops is in error.
+//!
+//! ```
+//! use idna::idna2008::to_ascii_for_registration;
+//!
+//! assert_eq!(to_ascii_for_registration("bücher.example").unwrap(), "xn--bcher-kva.example");
+//! assert!(to_ascii_for_registration("Bücher.example").is_err());
+//! ```
+//!
+//! The derived property values of RFC 5892 are computed from the same ICU4X Unicode data
+//! that `idna_adapter` uses by default, so they are for the Unicode version of that data.
+
+use crate::punycode;
+use crate::uts46::{dns_length_errors, DnsLength, Hyphens, LabelError, LabelErrorKind, Uts46};
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_normalizer::ComposingNormalizer;
+use icu_properties::maps;
+use icu_properties::sets;
+use icu_properties::{GeneralCategory, HangulSyllableType, Script};
+
+/// The derived property value of a code point, from
+/// [RFC 5892 section 2](https://www.rfc-editor.org/rfc/rfc5892#section-2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivedProperty {
+    /// Allowed in labels.
+    Pvalid,
+    /// Allowed in labels where the ContextJ rules allow it (ZERO WIDTH NON-JOINER and
+    /// ZERO WIDTH JOINER).
+    ContextJ,
+    /// Allowed in labels where the ContextO rules allow it.
+    ContextO,
+    /// Not allowed in labels.
+    Disallowed,
+    /// Not assigned in the Unicode version of the data, so not allowed in labels.
+    Unassigned,
+}
+
+/// Computes the derived property value of a code point with the algorithm of
+/// [RFC 5892 section 3](https://www.rfc-editor.org/rfc/rfc5892#section-3).
+pub fn derived_property(c: char) -> DerivedProperty {
+    // Exceptions (F)
+    match c {
+        '\u{00DF}' | '\u{03C2}' | '\u{06FD}' | '\u{06FE}' | '\u{0F0B}' | '\u{3007}' => {
+            return DerivedProperty::Pvalid
+        }
+        '\u{00B7}' | '\u{0375}' | '\u{05F3}' | '\u{05F4}' | '\u{30FB}' => {
+            return DerivedProperty::ContextO
+        }
+        '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}' => return DerivedProperty::ContextO,
+        '\u{0640}'
+        | '\u{07FA}'
+        | '\u{302E}'
+        | '\u{302F}'
+        | '\u{3031}'..='\u{3035}'
+        | '\u{303B}' => return DerivedProperty::Disallowed,
+        _ => {}
+    }
+    // BackwardCompatible (G) is empty
+    let general_category = maps::general_category().get(c);
+    // Unassigned (J)
+    if general_category == GeneralCategory::Unassigned
+        && !sets::noncharacter_code_point().contains(c)
+    {
+        return DerivedProperty::Unassigned;
+    }
+    // LDH (K)
+    if c == '-' || c.is_ascii_digit() || c.is_ascii_lowercase() {
+        return DerivedProperty::Pvalid;
+    }
+    // JoinControl (H)
+    if sets::join_control().contains(c) {
+        return DerivedProperty::ContextJ;
+    }
+    // Unstable (B), IgnorableProperties (C), IgnorableBlocks (D) and OldHangulJamo (I)
+    if is_unstable(c)
+        || sets::default_ignorable_code_point().contains(c)
+        || sets::white_space().contains(c)
+        || sets::noncharacter_code_point().contains(c)
+        || matches!(c, '\u{20D0}'..='\u{20FF}' | '\u{1D100}'..='\u{1D24F}')
+        || matches!(
+            maps::hangul_syllable_type().get(c),
+            HangulSyllableType::LeadingJamo
+                | HangulSyllableType::VowelJamo
+                | HangulSyllableType::TrailingJamo
+        )
+    {
+        return DerivedProperty::Disallowed;
+    }
+    // LetterDigits (A)
+    if matches!(
+        general_category,
+        GeneralCategory::LowercaseLetter
+            | GeneralCategory::UppercaseLetter
+            | GeneralCategory::OtherLetter
+            | GeneralCategory::DecimalNumber
+            | GeneralCategory::ModifierLetter
+            | GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+    ) {
+        DerivedProperty::Pvalid
+    } else {
+        DerivedProperty::Disallowed
+    }
+}
+
+/// Whether NFKC, case folding and NFKC change `c`
+fn is_unstable(c: char) -> bool {
+    sets::changes_when_nfkc_casefolded().contains(c)
+        || ComposingNormalizer::new_nfc()
+            .normalize_iter(core::iter::once(c))
+            .ne(core::iter::once(c))
+}
+
+/// Whether the ContextO rule for `label[i]` from
+/// [RFC 5892 appendix A](https://www.rfc-editor.org/rfc/rfc5892#appendix-A) is satisfied
+fn context_o_allowed(label: &[char], i: usize) -> bool {
+    let before = i.checked_sub(1).map(|j| label[j]);
+    let after = label.get(i + 1).copied();
+    let script = |c: Option<char>| c.map(|c| maps::script().get(c));
+    match label[i] {
+        // MIDDLE DOT
+        '\u{00B7}' => before == Some('l') && after == Some('l'),
+        // GREEK LOWER NUMERAL SIGN (KERAIA)
+        '\u{0375}' => script(after) == Some(Script::Greek),
+        // HEBREW PUNCTUATION GERESH and GERSHAYIM
+        '\u{05F3}' | '\u{05F4}' => script(before) == Some(Script::Hebrew),
+        // KATAKANA MIDDLE DOT
+        '\u{30FB}' => label.iter().any(|&c| {
+            matches!(
+                maps::script().get(c),
+                Script::Hiragana | Script::Katakana | Script::Han
+            )
+        }),
+        // ARABIC-INDIC DIGITS
+        '\u{0660}'..='\u{0669}' => !label.iter().any(|c| ('\u{06F0}'..='\u{06F9}').contains(c)),
+        // EXTENDED ARABIC-INDIC DIGITS
+        '\u{06F0}'..='\u{06F9}' => !label.iter().any(|c| ('\u{0660}'..='\u{0669}').contains(c)),
+        _ => {
+            debug_assert!(false, "not a ContextO code point");
+            true
+        }
+    }
+}
+
+/// Checks that a domain name can be registered under IDNA2008 and returns
+/// its ASCII form, with A-labels for labels that are not ASCII.
+///
+/// Each label must be an A-label, a U-label or an LDH label other than a reserved one
+/// (with hyphens in the third and fourth positions). The checks are those of
+/// [RFC 5891 section 4](https://www.rfc-editor.org/rfc/rfc5891#section-4): the code
+/// points must be PVALID or allowed in context, U-labels must be in NFC, the hyphen and
+/// leading combining mark restrictions apply, the labels must satisfy the bidi rule
+/// when the domain name has a right-to-left label, and the DNS length limits apply.
+/// A-labels must be in lower case and decode to valid U-labels.
+///
+/// In case of error, the returned [`crate::Errors`] lists what is wrong with each label.
+pub fn to_ascii_for_registration(domain_name: &str) -> Result<String, crate::Errors> {
+    let (label_errors, ascii) = check(domain_name);
+    if label_errors.is_empty() {
+        Ok(ascii)
+    } else {
+        Err(crate::Errors { label_errors })
+    }
+}
+
+/// Returns the reasons why [`to_ascii_for_registration`] fails, for each label.
+/// The result is empty if and only if the domain name can be registered.
+pub fn registration_errors(domain_name: &str) -> Vec<LabelError> {
+    check(domain_name).0
+}
+
+fn check(domain_name: &str) -> (Vec<LabelError>, String) {
+    let uts46 = Uts46::new();
+    let mut errors = Vec::new();
+    let mut ascii = String::with_capacity(domain_name.len());
+    // U-labels as checked, `None` if already in error
+    let mut labels = Vec::new();
+    for (index, label) in domain_name.split('.').enumerate() {
+        if index > 0 {
+            ascii.push('.');
+        }
+        let mut push = |kind| errors.push(LabelError::new(index, label.into(), kind));
+        if label.is_empty() {
+            push(LabelErrorKind::EmptyLabel);
+            labels.push(None);
+            continue;
+        }
+        let u_label: Vec<char> = if let Some(punycode) = label.strip_prefix("xn--") {
+            // A-labels can't be longer than this, which also bounds the work of decoding
+            if label.len() > 63 {
+                push(LabelErrorKind::LabelTooLong);
+                labels.push(None);
+                continue;
+            }
+            match punycode::decode(punycode) {
+                Some(decoded)
+                    if !decoded.iter().all(char::is_ascii)
+                        && punycode::encode(&decoded).as_deref() == Some(punycode) =>
+                {
+                    decoded
+                }
+                _ => {
+                    push(LabelErrorKind::InvalidPunycode);
+                    labels.push(None);
+                    continue;
+                }
+            }
+        } else {
+            label.chars().collect()
+        };
+
+        let mut in_error = false;
+        for (i, &c) in u_label.iter().enumerate() {
+            let kind = match derived_property(c) {
+                DerivedProperty::Pvalid | DerivedProperty::ContextJ => continue,
+                DerivedProperty::ContextO if context_o_allowed(&u_label, i) => continue,
+                DerivedProperty::ContextO => LabelErrorKind::ContextO(c),
+                DerivedProperty::Disallowed | DerivedProperty::Unassigned => {
+                    LabelErrorKind::DisallowedCodePoint(c)
+                }
+            };
+            push(kind);
+            in_error = true;
+        }
+        if ComposingNormalizer::new_nfc()
+            .normalize_iter(u_label.iter().copied())
+            .ne(u_label.iter().copied())
+        {
+            push(LabelErrorKind::NotNormalized);
+            in_error = true;
+        }
+        for kind in uts46.label_validity_errors(&u_label, Hyphens::Check) {
+            push(kind);
+            in_error = true;
+        }
+        if in_error {
+            labels.push(None);
+            continue;
+        }
+
+        if label.is_ascii() {
+            ascii.push_str(label);
+        } else {
+            // The length of the label has been checked for Punycode
+            ascii.push_str("xn--");
+            ascii.push_str(&punycode::encode(&u_label).unwrap());
+        }
+        labels.push(Some(u_label));
+    }
+
+    // The bidi rule, for labels without other errors
+    if labels.iter().flatten().any(|label| uts46.is_bidi(label)) {
+        for (index, label) in labels.iter().enumerate() {
+            if let Some(label) = label {
+                for rule in uts46.broken_bidi_rules(label) {
+                    errors.push(LabelError::new(
+                        index,
+                        label.iter().collect(),
+                        LabelErrorKind::Bidi(rule),
+                    ));
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        dns_length_errors(&ascii, DnsLength::Verify, &mut errors);
+    }
+    (errors, ascii)
+}
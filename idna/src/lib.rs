@@ -39,31 +39,69 @@ extern crate std;
 
 extern crate alloc;
 
+// The Unicode data crates and `smallvec` link `alloc` unconditionally, so there is no
+// allocator-free configuration.
 #[cfg(not(feature = "alloc"))]
 compile_error!("the `alloc` feature must be enabled");
 
-// Avoid a breaking change if in the future there's a use case for
-// having a Bring-Your-Own-ICU4X-Data constructor for `Uts46` and
-// not also having compiled data in the binary.
-#[cfg(not(feature = "compiled_data"))]
-compile_error!("the `compiled_data` feature must be enabled");
+// Without compiled data, `Uts46` can only be constructed with data loaded at run time.
+#[cfg(not(any(feature = "compiled_data", feature = "provider")))]
+compile_error!("the `compiled_data` or `provider` feature must be enabled");
 
+#[cfg(feature = "compiled_data")]
 use alloc::borrow::Cow;
+#[cfg(feature = "compiled_data")]
 use alloc::string::String;
 pub use uts46::AsciiDenyList;
+#[cfg(feature = "compiled_data")]
 use uts46::Uts46;
 
+#[cfg(feature = "provider")]
+mod adapter;
+mod backend;
+#[cfg(feature = "compiled_data")]
 mod deprecated;
+#[cfg(feature = "compiled_data")]
+pub mod dns;
+#[cfg(feature = "idna2008")]
+pub mod idna2008;
 pub mod punycode;
+#[cfg(feature = "uts39")]
+pub mod uts39;
 pub mod uts46;
 
+#[cfg(feature = "compiled_data")]
 #[allow(deprecated)]
 pub use crate::deprecated::{Config, Idna};
 
 /// Type indicating that there were errors during UTS #46 processing.
-#[derive(Default, Debug)]
+///
+/// Errors returned by [`Uts46::to_ascii_detailed`](uts46::Uts46::to_ascii_detailed)
+/// also say what is wrong with each label. Other functions return errors without details.
+#[derive(Default, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct Errors {}
+pub struct Errors {
+    label_errors: alloc::vec::Vec<uts46::LabelError>,
+}
+
+impl Errors {
+    /// The errors found in each label, in order, if this error has details.
+    pub fn label_errors(&self) -> &[uts46::LabelError] {
+        &self.label_errors
+    }
+}
+
+impl core::fmt::Debug for Errors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.label_errors.is_empty() {
+            f.write_str("Errors")
+        } else {
+            f.debug_struct("Errors")
+                .field("label_errors", &self.label_errors)
+                .finish()
+        }
+    }
+}
 
 impl From<Errors> for Result<(), Errors> {
     fn from(e: Errors) -> Result<(), Errors> {
@@ -79,7 +117,16 @@ impl core::error::Error for Errors {}
 
 impl core::fmt::Display for Errors {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        core::fmt::Debug::fmt(self, f)
+        if self.label_errors.is_empty() {
+            return f.write_str("Errors");
+        }
+        for (i, error) in self.label_errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            core::fmt::Display::fmt(error, f)?;
+        }
+        Ok(())
     }
 }
 
@@ -102,6 +149,7 @@ impl core::fmt::Display for Errors {
 /// If you have a `&str` instead of `&[u8]`, just call `.as_bytes()` on it before
 /// passing it to this function. It's still preferable to use this function over
 /// the sibling functions that take `&str`.
+#[cfg(feature = "compiled_data")]
 pub fn domain_to_ascii_cow(
     domain: &[u8],
     ascii_deny_list: AsciiDenyList,
@@ -129,6 +177,7 @@ pub fn domain_to_ascii_cow(
 /// and using Punycode as necessary.
 ///
 /// This process may fail.
+#[cfg(feature = "compiled_data")]
 pub fn domain_to_ascii_from_cow(
     domain: Cow<'_, [u8]>,
     ascii_deny_list: AsciiDenyList,
@@ -152,6 +201,7 @@ pub fn domain_to_ascii_from_cow(
 /// and using Punycode as necessary.
 ///
 /// This process may fail.
+#[cfg(feature = "compiled_data")]
 pub fn domain_to_ascii(domain: &str) -> Result<String, Errors> {
     domain_to_ascii_cow(domain.as_bytes(), AsciiDenyList::EMPTY).map(|cow| cow.into_owned())
 }
@@ -163,6 +213,7 @@ pub fn domain_to_ascii(domain: &str) -> Result<String, Errors> {
 /// * YouTube CDN nodes
 /// * Some GitHub user pages
 /// * Pseudo-hosts used by various TXT record-based protocols.
+#[cfg(feature = "compiled_data")]
 pub fn domain_to_ascii_strict(domain: &str) -> Result<String, Errors> {
     Uts46::new()
         .to_ascii(
@@ -188,6 +239,7 @@ pub fn domain_to_ascii_strict(domain: &str) -> Result<String, Errors> {
 /// denotes errors using the REPLACEMENT CHARACTERs in order to be able to illustrate
 /// errors to the user. When the second item of the return tuple signals an error,
 /// the first item of the tuple must not be used in a network protocol.
+#[cfg(feature = "compiled_data")]
 pub fn domain_to_unicode(domain: &str) -> (String, Result<(), Errors>) {
     let (cow, result) = Uts46::new().to_unicode(
         domain.as_bytes(),
@@ -0,0 +1,55 @@
+"""
+Generate src/uts39/confusables.rs from the confusables.txt file of UTS #39:
+https://www.unicode.org/Public/security/latest/confusables.txt
+
+Usage: python make_uts39_confusables_table.py confusables.txt > uts39/confusables.rs
+"""
+
+import sys
+
+entries = []
+with open(sys.argv[1], encoding="utf-8-sig") as f:
+    for line in f:
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        source, target, kind = [field.strip() for field in line.split(";")[:3]]
+        assert kind == "MA", line
+        entries.append((int(source, 16), [int(c, 16) for c in target.split()]))
+
+entries.sort()
+assert len(set(source for source, _ in entries)) == len(entries)
+
+
+def escape(code_points):
+    return "".join("\\u{%X}" % c for c in code_points)
+
+
+print("// Copyright 2013-2024 The rust-url developers.")
+print("//")
+print("// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or")
+print("// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license")
+print("// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your")
+print("// option. This file may not be copied, modified, or distributed")
+print("// except according to those terms.")
+print()
+print("// Generated by make_uts39_confusables_table.py. Do not edit.")
+print()
+print("/// The mappings of `confusables.txt`, from a character to its prototype,")
+print("/// sorted by character")
+print("#[rustfmt::skip]")
+print("pub(super) static CONFUSABLES: &[(char, &str)] = &[")
+for source, target in entries:
+    print('    (\'%s\', "%s"),' % (escape([source]), escape(target)))
+print("];")
+print()
+print("/// The indices of [`CONFUSABLES`] sorted by prototype, then by character")
+print("#[rustfmt::skip]")
+print("pub(super) static BY_PROTOTYPE: &[u16] = &[")
+# Rust compares strings by UTF-8 bytes, which is code point order
+order = sorted(range(len(entries)), key=lambda i: (entries[i][1], entries[i][0]))
+assert len(order) < 1 << 16
+per_line = 12
+for start in range(0, len(order), per_line):
+    print("    " + " ".join("%d," % i for i in order[start:start + per_line]))
+print("];")
@@ -12,6 +12,34 @@
 //! `encode` and `decode` take and return slices and vectors of `char`.
 //! `encode_str` and `decode_to_string` provide convenience wrappers
 //! that convert from and to Rust’s UTF-8 based `str` and `String` types.
+//!
+//! [`encode_into`] and [`Decoder`] are the streaming forms of these, which
+//! write to any [`Write`] and decode lazily without allocating (for input up
+//! to the DNS label length).
+//!
+//! The mixed-case annotation of [RFC 3492 appendix A](https://www.rfc-editor.org/rfc/rfc3492#appendix-A)
+//! is supported by [`encode_with_case_flags_into`] and [`Decode::with_case_flags`]:
+//! a case flag for each code point is carried by the case of basic code points
+//! and of the last digit of the encoding of other code points.
+//!
+//! ```
+//! use idna::punycode::{encode_with_case_flags_into, Decoder};
+//!
+//! let mut encoded = String::new();
+//! let flagged = [('ü', true), ('b', true), ('e', false), ('r', false)];
+//! encode_with_case_flags_into(flagged.iter().copied(), &mut encoded).unwrap();
+//! assert_eq!(encoded, "Ber-goA");
+//!
+//! let mut decoder = Decoder::default();
+//! let decoded: String = decoder.decode(encoded.as_bytes()).unwrap().collect();
+//! assert_eq!(decoded, "üBer");
+//! let decoded: Vec<(char, bool)> = decoder
+//!     .decode(encoded.as_bytes())
+//!     .unwrap()
+//!     .with_case_flags()
+//!     .collect();
+//! assert_eq!(decoded, [('ü', true), ('B', true), ('e', false), ('r', false)]);
+//! ```
 
 use alloc::{string::String, vec::Vec};
 use core::char;
@@ -46,12 +74,7 @@ fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
 /// 63 encoded bytes, the DNS limit on domain name labels.
 #[inline]
 pub fn decode_to_string(input: &str) -> Option<String> {
-    Some(
-        Decoder::default()
-            .decode::<u8, ExternalCaller>(input.as_bytes())
-            .ok()?
-            .collect(),
-    )
+    Some(Decoder::default().decode(input.as_bytes())?.collect())
 }
 
 /// Convert Punycode to Unicode.
@@ -60,12 +83,7 @@ pub fn decode_to_string(input: &str) -> Option<String> {
 /// Overflow can only happen on inputs that take more than
 /// 63 encoded bytes, the DNS limit on domain name labels.
 pub fn decode(input: &str) -> Option<Vec<char>> {
-    Some(
-        Decoder::default()
-            .decode::<u8, ExternalCaller>(input.as_bytes())
-            .ok()?
-            .collect(),
-    )
+    Some(Decoder::default().decode(input.as_bytes())?.collect())
 }
 
 /// Marker for internal vs. external caller to retain old API behavior
@@ -97,14 +115,29 @@ impl PunycodeCaller for ExternalCaller {
     const EXTERNAL_CALLER: bool = true;
 }
 
-pub(crate) trait PunycodeCodeUnit {
-    fn is_delimiter(&self) -> bool;
-    fn is_ascii(&self) -> bool;
-    fn digit(&self) -> Option<u32>;
-    fn char(&self) -> char;
-    fn char_ascii_lower_case(&self) -> char;
+mod private {
+    /// The operations on code units that decoding needs
+    pub trait PunycodeCodeUnit: Copy {
+        fn is_delimiter(&self) -> bool;
+        fn is_ascii(&self) -> bool;
+        fn is_ascii_uppercase(&self) -> bool;
+        fn digit(&self) -> Option<u32>;
+        fn char(&self) -> char;
+        fn char_ascii_lower_case(&self) -> char;
+    }
 }
 
+pub(crate) use private::PunycodeCodeUnit;
+
+/// A code unit of the input of [`Decoder::decode`]: `u8` for bytes or `char`.
+///
+/// This trait is sealed.
+pub trait CodeUnit: PunycodeCodeUnit {}
+
+impl CodeUnit for u8 {}
+
+impl CodeUnit for char {}
+
 impl PunycodeCodeUnit for u8 {
     fn is_delimiter(&self) -> bool {
         *self == b'-'
@@ -112,6 +145,9 @@ impl PunycodeCodeUnit for u8 {
     fn is_ascii(&self) -> bool {
         *self < 0x80
     }
+    fn is_ascii_uppercase(&self) -> bool {
+        u8::is_ascii_uppercase(self)
+    }
     fn digit(&self) -> Option<u32> {
         let byte = *self;
         Some(match byte {
@@ -129,25 +165,57 @@ impl PunycodeCodeUnit for u8 {
     }
 }
 
+/// For processing UTF-16 input in `Uts46::process_utf16` without converting it.
+#[cfg(feature = "utf16")]
+impl PunycodeCodeUnit for u16 {
+    fn is_delimiter(&self) -> bool {
+        *self == u16::from(b'-')
+    }
+    fn is_ascii(&self) -> bool {
+        *self < 0x80
+    }
+    fn is_ascii_uppercase(&self) -> bool {
+        (u16::from(b'A')..=u16::from(b'Z')).contains(self)
+    }
+    fn digit(&self) -> Option<u32> {
+        if self.is_ascii() {
+            (*self as u8).digit()
+        } else {
+            None
+        }
+    }
+    fn char(&self) -> char {
+        char::from_u32(u32::from(*self)).unwrap_or('\u{FFFD}')
+    }
+    fn char_ascii_lower_case(&self) -> char {
+        if self.is_ascii_uppercase() {
+            char::from(*self as u8 + 0x20)
+        } else {
+            self.char()
+        }
+    }
+}
+
 impl PunycodeCodeUnit for char {
     fn is_delimiter(&self) -> bool {
         *self == '-'
     }
     fn is_ascii(&self) -> bool {
-        debug_assert!(false); // Unused
-        true
+        char::is_ascii(self)
+    }
+    fn is_ascii_uppercase(&self) -> bool {
+        char::is_ascii_uppercase(self)
     }
     fn digit(&self) -> Option<u32> {
         let byte = *self;
         Some(match byte {
             byte @ '0'..='9' => u32::from(byte) - u32::from('0') + 26,
-            // byte @ 'A'..='Z' => u32::from(byte) - u32::from('A'), // XXX not needed if no public input
+            byte @ 'A'..='Z' => u32::from(byte) - u32::from('A'),
             byte @ 'a'..='z' => u32::from(byte) - u32::from('a'),
             _ => return None,
         })
     }
     fn char(&self) -> char {
-        debug_assert!(false); // Unused
         *self
     }
     fn char_ascii_lower_case(&self) -> char {
@@ -156,17 +224,34 @@ impl PunycodeCodeUnit for char {
     }
 }
 
+/// A Punycode decoder that can be reused for decoding lazily without allocating.
+///
+/// The decoder holds what it needs to decode the non-basic code points of one input
+/// at a time: on the stack for up to 59 of them, which is as many as a DNS label
+/// can have, and on the heap for more.
 #[derive(Default)]
-pub(crate) struct Decoder {
-    insertions: smallvec::SmallVec<[(usize, char); 59]>,
+pub struct Decoder {
+    /// The position, code point and case flag of each non-basic code point
+    insertions: smallvec::SmallVec<[(usize, char, bool); 59]>,
 }
 
 impl Decoder {
+    /// Decode Punycode, without the "xn--" prefix, as bytes or `char`s.
+    ///
+    /// Return None on malformed input or overflow.
+    /// Upper and lower case digits are equivalent, and basic code points
+    /// are returned as they are in the input.
+    pub fn decode<'a, T: CodeUnit>(&'a mut self, input: &'a [T]) -> Option<Decode<'a, T>> {
+        self.decode_impl::<T, ExternalCaller>(input)
+            .ok()
+            .map(|inner| Decode { inner })
+    }
+
     /// Split the input iterator and return a Vec with insertions of encoded characters
-    pub(crate) fn decode<'a, T: PunycodeCodeUnit + Copy, C: PunycodeCaller>(
+    pub(crate) fn decode_impl<'a, T: PunycodeCodeUnit, C: PunycodeCaller>(
         &'a mut self,
         input: &'a [T],
-    ) -> Result<Decode<'a, T, C>, ()> {
+    ) -> Result<DecodeImpl<'a, T, C>, ()> {
         self.insertions.clear();
         // Handle "basic" (ASCII) code points.
         // They are encoded as-is before the last delimiter, if any.
@@ -220,6 +305,7 @@ impl Decoder {
                     k - bias
                 };
                 if digit < t {
+                    // The last digit carries the case flag
                     break;
                 }
                 weight = weight.checked_mul(BASE - t).ok_or(())?;
@@ -242,18 +328,19 @@ impl Decoder {
             };
 
             // Move earlier insertions farther out in the string
-            for (idx, _) in &mut self.insertions {
+            for (idx, _, _) in &mut self.insertions {
                 if *idx >= i as usize {
                     *idx += 1;
                 }
             }
-            self.insertions.push((i as usize, c));
+            self.insertions
+                .push((i as usize, c, byte.is_ascii_uppercase()));
             length += 1;
             i += 1;
         }
 
-        self.insertions.sort_by_key(|(i, _)| *i);
-        Ok(Decode {
+        self.insertions.sort_by_key(|(i, _, _)| *i);
+        Ok(DecodeImpl {
             base: base.iter(),
             insertions: &self.insertions,
             inserted: 0,
@@ -264,44 +351,52 @@ impl Decoder {
     }
 }
 
-pub(crate) struct Decode<'a, T, C>
+pub(crate) struct DecodeImpl<'a, T, C>
 where
-    T: PunycodeCodeUnit + Copy,
+    T: PunycodeCodeUnit,
     C: PunycodeCaller,
 {
     base: core::slice::Iter<'a, T>,
-    pub(crate) insertions: &'a [(usize, char)],
+    insertions: &'a [(usize, char, bool)],
     inserted: usize,
     position: usize,
     len: usize,
     phantom: PhantomData<C>,
 }
 
-impl<T: PunycodeCodeUnit + Copy, C: PunycodeCaller> Iterator for Decode<'_, T, C> {
-    type Item = char;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl<T: PunycodeCodeUnit, C: PunycodeCaller> DecodeImpl<'_, T, C> {
+    /// The next code point and its case flag
+    fn next_with_case_flag(&mut self) -> Option<(char, bool)> {
         loop {
             match self.insertions.get(self.inserted) {
-                Some((pos, c)) if *pos == self.position => {
+                Some((pos, c, flag)) if *pos == self.position => {
                     self.inserted += 1;
                     self.position += 1;
-                    return Some(*c);
+                    return Some((*c, *flag));
                 }
                 _ => {}
             }
             if let Some(c) = self.base.next() {
                 self.position += 1;
                 return Some(if C::EXTERNAL_CALLER {
-                    c.char()
+                    (c.char(), c.is_ascii_uppercase())
                 } else {
-                    c.char_ascii_lower_case()
+                    (c.char_ascii_lower_case(), false)
                 });
             } else if self.inserted >= self.insertions.len() {
                 return None;
             }
         }
     }
+}
+
+impl<T: PunycodeCodeUnit, C: PunycodeCaller> Iterator for DecodeImpl<'_, T, C> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_case_flag().map(|(c, _)| c)
+    }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let len = self.len - self.position;
@@ -309,12 +404,66 @@ impl<T: PunycodeCodeUnit + Copy, C: PunycodeCaller> Iterator for Decode<'_, T, C
     }
 }
 
-impl<T: PunycodeCodeUnit + Copy, C: PunycodeCaller> ExactSizeIterator for Decode<'_, T, C> {
+impl<T: PunycodeCodeUnit, C: PunycodeCaller> ExactSizeIterator for DecodeImpl<'_, T, C> {
     fn len(&self) -> usize {
         self.len - self.position
     }
 }
 
+/// The decoded code points of [`Decoder::decode`].
+pub struct Decode<'a, T: CodeUnit> {
+    inner: DecodeImpl<'a, T, ExternalCaller>,
+}
+
+impl<'a, T: CodeUnit> Decode<'a, T> {
+    /// Return the decoded code points together with their case flags from the
+    /// mixed-case annotation: `true` if the code point should be upper-cased (if
+    /// possible) for display and `false` if it should be lower-cased.
+    ///
+    /// The flag of a basic code point is whether it is an upper-case letter.
+    /// The flag of another code point is whether the last digit of its encoding
+    /// is an upper-case letter, so it is `false` if that digit is a numeral.
+    pub fn with_case_flags(self) -> DecodeWithCaseFlags<'a, T> {
+        DecodeWithCaseFlags { inner: self.inner }
+    }
+}
+
+impl<T: CodeUnit> Iterator for Decode<'_, T> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: CodeUnit> ExactSizeIterator for Decode<'_, T> {}
+
+/// The decoded code points of [`Decoder::decode`] with their case flags.
+/// See [`Decode::with_case_flags`].
+pub struct DecodeWithCaseFlags<'a, T: CodeUnit> {
+    inner: DecodeImpl<'a, T, ExternalCaller>,
+}
+
+impl<T: CodeUnit> Iterator for DecodeWithCaseFlags<'_, T> {
+    type Item = (char, bool);
+
+    #[inline]
+    fn next(&mut self) -> Option<(char, bool)> {
+        self.inner.next_with_case_flag()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: CodeUnit> ExactSizeIterator for DecodeWithCaseFlags<'_, T> {}
+
 /// Convert an Unicode `str` to Punycode.
 ///
 /// This is a convenience wrapper around `encode`.
@@ -324,9 +473,7 @@ pub fn encode_str(input: &str) -> Option<String> {
         return None;
     }
     let mut buf = String::with_capacity(input.len());
-    encode_into::<_, _, ExternalCaller>(input.chars(), &mut buf)
-        .ok()
-        .map(|()| buf)
+    encode_into(input.chars(), &mut buf).ok().map(|()| buf)
 }
 
 /// Convert Unicode to Punycode.
@@ -338,13 +485,19 @@ pub fn encode(input: &[char]) -> Option<String> {
         return None;
     }
     let mut buf = String::with_capacity(input.len());
-    encode_into::<_, _, ExternalCaller>(input.iter().copied(), &mut buf)
+    encode_into(input.iter().copied(), &mut buf)
         .ok()
         .map(|()| buf)
 }
 
-pub(crate) enum PunycodeEncodeError {
+/// Errors of [`encode_into`] and [`encode_with_case_flags_into`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum PunycodeEncodeError {
+    /// The input would take more than 63 encoded bytes, the DNS limit on
+    /// domain name labels, and is too long to encode.
     Overflow,
+    /// The output sink returned an error.
     Sink,
 }
 
@@ -354,20 +507,78 @@ impl From<core::fmt::Error> for PunycodeEncodeError {
     }
 }
 
-pub(crate) fn encode_into<I, W, C>(input: I, output: &mut W) -> Result<(), PunycodeEncodeError>
+impl core::fmt::Display for PunycodeEncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match *self {
+            PunycodeEncodeError::Overflow => "overflow in Punycode encoding",
+            PunycodeEncodeError::Sink => "error writing Punycode",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PunycodeEncodeError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for PunycodeEncodeError {}
+
+/// Convert Unicode to Punycode, writing to `output`.
+///
+/// In case of error, the partial output must not be used.
+pub fn encode_into<I, W>(input: I, output: &mut W) -> Result<(), PunycodeEncodeError>
 where
     I: Iterator<Item = char> + Clone,
     W: Write + ?Sized,
+{
+    encode_impl::<_, _, ExternalCaller>(input.map(|c| (c, false)), false, output)
+}
+
+/// Convert Unicode to Punycode with the mixed-case annotation, writing to `output`.
+///
+/// Each code point comes with its case flag: `true` if it should be upper-cased
+/// (if possible) for display after decoding and `false` if it should be lower-cased.
+/// Basic code points are written in upper or lower case according to their flags.
+/// For other code points, the last digit of their encoding is, but the flag is lost
+/// if that digit is a numeral.
+///
+/// In case of error, the partial output must not be used.
+pub fn encode_with_case_flags_into<I, W>(
+    input: I,
+    output: &mut W,
+) -> Result<(), PunycodeEncodeError>
+where
+    I: Iterator<Item = (char, bool)> + Clone,
+    W: Write + ?Sized,
+{
+    encode_impl::<_, _, ExternalCaller>(input, true, output)
+}
+
+/// Convert Unicode to Punycode, with the case flags of the mixed-case
+/// annotation if `case_flags` is `true`.
+pub(crate) fn encode_impl<I, W, C>(
+    input: I,
+    case_flags: bool,
+    output: &mut W,
+) -> Result<(), PunycodeEncodeError>
+where
+    I: Iterator<Item = (char, bool)> + Clone,
+    W: Write + ?Sized,
     C: PunycodeCaller,
 {
     // Handle "basic" (ASCII) code points. They are encoded as-is.
     let (mut input_length, mut basic_length) = (0u32, 0);
-    for c in input.clone() {
+    for (c, upper_case) in input.clone() {
         input_length = input_length
             .checked_add(1)
             .ok_or(PunycodeEncodeError::Overflow)?;
         if c.is_ascii() {
-            output.write_char(c)?;
+            output.write_char(if !case_flags {
+                c
+            } else if upper_case {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            })?;
             basic_length += 1;
         }
     }
@@ -397,7 +608,7 @@ where
         // Find the next larger one.
         let min_code_point = input
             .clone()
-            .map(|c| c as u32)
+            .map(|(c, _)| c as u32)
             .filter(|&c| c >= code_point)
             .min()
             .unwrap();
@@ -413,7 +624,7 @@ where
             delta += (min_code_point - code_point) * (processed + 1);
         }
         code_point = min_code_point;
-        for c in input.clone() {
+        for (c, upper_case) in input.clone() {
             let c = c as u32;
             if c < code_point {
                 if C::EXTERNAL_CALLER {
@@ -442,7 +653,12 @@ where
                     q = (q - t) / (BASE - t);
                     k += BASE;
                 }
-                output.write_char(value_to_digit(q))?;
+                let digit = value_to_digit(q);
+                output.write_char(if case_flags && upper_case {
+                    digit.to_ascii_uppercase()
+                } else {
+                    digit
+                })?;
                 bias = adapt(delta, processed + 1, processed == basic_length);
                 delta = 0;
                 processed += 1;
@@ -468,7 +684,7 @@ fn value_to_digit(value: u32) -> char {
 #[cfg(target_pointer_width = "64")]
 fn huge_encode() {
     let mut buf = String::new();
-    assert!(encode_into::<_, _, ExternalCaller>(
+    assert!(encode_into(
         core::iter::repeat('ß').take(u32::MAX as usize + 1),
         &mut buf
     )
@@ -0,0 +1,385 @@
+// Copyright 2013-2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Security checks from [*Unicode Security Mechanisms*
+//! (Unicode Technical Standard #39)](https://www.unicode.org/reports/tr39/)
+//! for deciding whether a domain name is safe to display in Unicode.
+//!
+//! [`Uts46::to_user_interface`](crate::uts46::Uts46::to_user_interface) leaves that
+//! decision to its `output_as_unicode` argument; [`display_policy`] returns a ready-made one:
+//!
+//! ```
+//! use idna::uts39::{display_policy, RestrictionLevel};
+//! use idna::uts46::{AsciiDenyList, Hyphens, Uts46};
+//!
+//! let uts46 = Uts46::new();
+//! let policy = display_policy(RestrictionLevel::ModeratelyRestrictive);
+//! let display = |domain: &str| {
+//!     uts46
+//!         .to_user_interface(domain.as_bytes(), AsciiDenyList::URL, Hyphens::Allow, &policy)
+//!         .0
+//!         .into_owned()
+//! };
+//! assert_eq!(display("xn--bcher-kva.example"), "bücher.example");
+//! // "pаypal" with a Cyrillic "а"
+//! assert_eq!(display("xn--pypal-4ve.com"), "xn--pypal-4ve.com");
+//! // "аррӏе" in Cyrillic
+//! assert_eq!(display("xn--80ak6aa92e.com"), "xn--80ak6aa92e.com");
+//! ```
+//!
+//! The checks are also available separately, on labels or whole strings.
+//!
+//! Confusable detection uses the data of
+//! [`confusables.txt`](https://www.unicode.org/Public/security/latest/confusables.txt),
+//! currently from Unicode 15.0, which is compiled into the binary.
+
+use alloc::string::String;
+use icu_normalizer::DecomposingNormalizer;
+use icu_properties::maps;
+use icu_properties::script;
+use icu_properties::sets;
+use icu_properties::{GeneralCategory, Script};
+use smallvec::SmallVec;
+
+mod confusables;
+
+/// How many scripts a string mixes, per
+/// [UTS 39 section 5.2](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection).
+///
+/// Levels are ordered from the most restrictive to the least restrictive,
+/// so a level can be compared to a maximum allowed level.
+///
+/// Unlike in UTS 39, characters are not checked against the General Security Profile:
+/// for domain names, UTS 46 processing already restricts what characters are valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum RestrictionLevel {
+    /// Only ASCII characters.
+    Ascii,
+    /// Characters from a single script, plus characters shared by all scripts
+    /// like digits and combining marks.
+    SingleScript,
+    /// A single script, or one of the combinations that are common in East Asia:
+    /// Latin + Han + Hiragana + Katakana, Latin + Han + Bopomofo, or Latin + Han + Hangul.
+    HighlyRestrictive,
+    /// Latin plus one other script, except Cyrillic, Greek and Cherokee,
+    /// whose letters are the most confusable with Latin letters.
+    ModeratelyRestrictive,
+    /// Any other combination of scripts.
+    MinimallyRestrictive,
+}
+
+/// Returns the restriction level of `s`.
+///
+/// ```
+/// use idna::uts39::{restriction_level, RestrictionLevel};
+///
+/// assert_eq!(restriction_level("example"), RestrictionLevel::Ascii);
+/// assert_eq!(restriction_level("παράδειγμα"), RestrictionLevel::SingleScript);
+/// assert_eq!(restriction_level("abcひらがな"), RestrictionLevel::HighlyRestrictive);
+/// assert_eq!(restriction_level("abcअबक"), RestrictionLevel::ModeratelyRestrictive);
+/// assert_eq!(restriction_level("abcабв"), RestrictionLevel::MinimallyRestrictive);
+/// ```
+pub fn restriction_level(s: &str) -> RestrictionLevel {
+    if s.is_ascii() {
+        return RestrictionLevel::Ascii;
+    }
+    if !resolved_script_set(s.chars()).is_empty() {
+        return RestrictionLevel::SingleScript;
+    }
+    // The scripts mixed with Latin
+    let without_latin = resolved_script_set(
+        s.chars()
+            .filter(|c| !script_set(*c).contains(Script::Latin.0)),
+    );
+    if [HANB, JPAN, KORE]
+        .iter()
+        .any(|script| without_latin.contains(*script))
+    {
+        return RestrictionLevel::HighlyRestrictive;
+    }
+    if !without_latin.is_empty()
+        && ![Script::Cyrillic, Script::Greek, Script::Cherokee]
+            .iter()
+            .any(|script| without_latin.contains(script.0))
+    {
+        return RestrictionLevel::ModeratelyRestrictive;
+    }
+    RestrictionLevel::MinimallyRestrictive
+}
+
+/// Returns the [skeleton](https://www.unicode.org/reports/tr39/#Confusable_Detection)
+/// of `s`: strings that look the same have the same skeleton.
+///
+/// ```
+/// // "раураl" with Cyrillic "р", "а" and "у", and a Latin "l"
+/// assert_eq!(
+///     idna::uts39::skeleton("\u{440}\u{430}\u{443}\u{440}\u{430}l"),
+///     "paypal"
+/// );
+/// ```
+pub fn skeleton(s: &str) -> String {
+    let nfd = DecomposingNormalizer::new_nfd();
+    let mut mapped = String::with_capacity(s.len());
+    for c in nfd.normalize_iter(s.chars()) {
+        match prototype(c) {
+            Some(prototype) => mapped.push_str(prototype),
+            None => mapped.push(c),
+        }
+    }
+    nfd.normalize(&mapped)
+}
+
+/// Returns whether `a` and `b` are confusable, that is whether they have the same
+/// [`skeleton`].
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    skeleton(a) == skeleton(b)
+}
+
+/// Returns whether `s` mixes scripts but looks like a string in a single script,
+/// like "pаypal" with a Cyrillic "а".
+///
+/// This is the case if, for some script, each character of `s` is either in that script
+/// or confusable with a character in that script.
+pub fn is_mixed_script_confusable(s: &str) -> bool {
+    if !resolved_script_set(s.chars()).is_empty() {
+        return false;
+    }
+    let mut scripts = ScriptSet::ALL;
+    for c in s.chars() {
+        let mut lookalikes = script_set(c);
+        lookalikes.union(&confusable_script_set(c));
+        scripts.intersect(&lookalikes);
+    }
+    !scripts.is_empty()
+}
+
+/// Returns whether all the characters of `s` that are specific to a script are confusable
+/// with characters of another single script, like "аре" in Cyrillic with "ape" in Latin.
+pub fn is_whole_script_confusable(s: &str) -> bool {
+    !whole_script_confusable_scripts(s).is_empty()
+}
+
+/// Returns whether `s` contains default ignorable code points, which are usually
+/// invisible, or the same nonspacing mark twice in a row, which may render like a single one.
+///
+/// See [UTS 39 section 5.4](https://www.unicode.org/reports/tr39/#Optional_Detection).
+pub fn has_invisible_characters(s: &str) -> bool {
+    let default_ignorable = sets::default_ignorable_code_point();
+    let general_category = maps::general_category();
+    let mut previous_mark = None;
+    for c in DecomposingNormalizer::new_nfd().normalize_iter(s.chars()) {
+        if default_ignorable.contains(c) {
+            return true;
+        }
+        if general_category.get(c) == GeneralCategory::NonspacingMark {
+            if previous_mark == Some(c) {
+                return true;
+            }
+            previous_mark = Some(c);
+        } else {
+            previous_mark = None;
+        }
+    }
+    false
+}
+
+/// Returns whether `s` contains decimal digits from more than one numbering system,
+/// like ASCII and Bengali digits.
+///
+/// See [UTS 39 section 5.3](https://www.unicode.org/reports/tr39/#Mixed_Number_Detection).
+pub fn has_mixed_numbers(s: &str) -> bool {
+    let general_category = maps::general_category();
+    let mut zero = None;
+    for c in s.chars() {
+        if general_category.get(c) != GeneralCategory::DecimalNumber {
+            continue;
+        }
+        // Decimal digits are encoded in contiguous runs from 0 to 9,
+        // which may follow each other
+        let mut run_start = u32::from(c);
+        while run_start > 0
+            && general_category.get32(run_start - 1) == GeneralCategory::DecimalNumber
+        {
+            run_start -= 1;
+        }
+        let this_zero = u32::from(c) - (u32::from(c) - run_start) % 10;
+        if *zero.get_or_insert(this_zero) != this_zero {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns a function to pass as the `output_as_unicode` argument of
+/// [`Uts46::to_user_interface`](crate::uts46::Uts46::to_user_interface).
+///
+/// A label is displayed in Unicode unless:
+/// * its restriction level is above `max_level`,
+/// * it has invisible characters or mixed numbers,
+/// * it is mixed-script confusable,
+/// * it is whole-script confusable and the top-level domain is not in the label's script.
+pub fn display_policy(max_level: RestrictionLevel) -> impl Fn(&[char], &[char], bool) -> bool {
+    move |label, tld, _is_bidi| {
+        let label: String = label.iter().collect();
+        if restriction_level(&label) > max_level
+            || has_invisible_characters(&label)
+            || has_mixed_numbers(&label)
+            || is_mixed_script_confusable(&label)
+        {
+            return false;
+        }
+        if is_whole_script_confusable(&label) {
+            // For example, a Cyrillic label under a Cyrillic top-level domain is expected
+            let mut scripts = resolved_script_set(label.chars());
+            scripts.intersect(&resolved_script_set(tld.iter().copied()));
+            if scripts.is_empty() || scripts == ScriptSet::ALL {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Scripts other than those of `s` that the script-specific characters of `s` are all confusable with
+fn whole_script_confusable_scripts(s: &str) -> ScriptSet {
+    let own_scripts = resolved_script_set(s.chars());
+    if own_scripts.is_empty() || own_scripts == ScriptSet::ALL {
+        return ScriptSet::empty();
+    }
+    let mut scripts = ScriptSet::ALL;
+    for c in s.chars() {
+        if script_set(c) != ScriptSet::ALL {
+            scripts.intersect(&confusable_script_set(c));
+        }
+    }
+    scripts.subtract(&own_scripts);
+    scripts
+}
+
+/// The scripts of the characters other than `c` that have the same prototype as `c`
+fn confusable_script_set(c: char) -> ScriptSet {
+    let mut buffer = [0; 4];
+    let prototype = prototype(c).unwrap_or_else(|| c.encode_utf8(&mut buffer));
+    let mut scripts = ScriptSet::empty();
+    let mut add = |other_scripts: ScriptSet| {
+        // Characters common to all scripts do not make `c` confusable with any of them
+        if other_scripts != ScriptSet::ALL {
+            scripts.union(&other_scripts)
+        }
+    };
+    if prototype.chars().ne(core::iter::once(c)) {
+        add(resolved_script_set(prototype.chars()));
+    }
+    let by_prototype = confusables::BY_PROTOTYPE;
+    let start =
+        by_prototype.partition_point(|&i| confusables::CONFUSABLES[usize::from(i)].1 < prototype);
+    for &i in &by_prototype[start..] {
+        let (other, other_prototype) = confusables::CONFUSABLES[usize::from(i)];
+        if other_prototype != prototype {
+            break;
+        }
+        if other != c {
+            add(script_set(other));
+        }
+    }
+    scripts
+}
+
+// Sets of scripts that are written together, from UTS 39 section 5.1,
+// with values that do not collide with ICU4X `Script` values
+const HANB: u16 = 0xFFF0;
+const JPAN: u16 = 0xFFF1;
+const KORE: u16 = 0xFFF2;
+
+/// A set of scripts, which may be the set of all scripts
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScriptSet(Option<SmallVec<[u16; 4]>>);
+
+impl ScriptSet {
+    const ALL: ScriptSet = ScriptSet(None);
+
+    fn empty() -> ScriptSet {
+        ScriptSet(Some(SmallVec::new()))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.as_ref().map_or(false, |scripts| scripts.is_empty())
+    }
+
+    fn contains(&self, script: u16) -> bool {
+        self.0
+            .as_ref()
+            .map_or(true, |scripts| scripts.contains(&script))
+    }
+
+    fn intersect(&mut self, other: &ScriptSet) {
+        match (&mut self.0, &other.0) {
+            (_, None) => {}
+            (None, Some(_)) => *self = other.clone(),
+            (Some(scripts), Some(_)) => scripts.retain(|script| other.contains(*script)),
+        }
+    }
+
+    fn union(&mut self, other: &ScriptSet) {
+        match (&mut self.0, &other.0) {
+            (None, _) => {}
+            (Some(_), None) => *self = ScriptSet::ALL,
+            (Some(scripts), Some(others)) => {
+                for &script in others {
+                    if !scripts.contains(&script) {
+                        scripts.push(script)
+                    }
+                }
+            }
+        }
+    }
+
+    fn subtract(&mut self, other: &ScriptSet) {
+        if let Some(scripts) = &mut self.0 {
+            scripts.retain(|script| !other.contains(*script))
+        }
+    }
+}
+
+/// The augmented Script_Extensions of `c`, per UTS 39 section 5.1
+fn script_set(c: char) -> ScriptSet {
+    let mut scripts = SmallVec::new();
+    for script in script::script_with_extensions()
+        .get_script_extensions_val(u32::from(c))
+        .iter()
+    {
+        let augmented: &[u16] = match script {
+            Script::Common | Script::Inherited => return ScriptSet::ALL,
+            Script::Han => &[HANB, JPAN, KORE],
+            Script::Hiragana | Script::Katakana => &[JPAN],
+            Script::Hangul => &[KORE],
+            Script::Bopomofo => &[HANB],
+            _ => &[],
+        };
+        scripts.push(script.0);
+        scripts.extend_from_slice(augmented);
+    }
+    ScriptSet(Some(scripts))
+}
+
+/// The intersection of the augmented Script_Extensions of the characters
+fn resolved_script_set(chars: impl Iterator<Item = char>) -> ScriptSet {
+    let mut scripts = ScriptSet::ALL;
+    for c in chars {
+        scripts.intersect(&script_set(c));
+    }
+    scripts
+}
+
+fn prototype(c: char) -> Option<&'static str> {
+    confusables::CONFUSABLES
+        .binary_search_by_key(&c, |&(source, _)| source)
+        .ok()
+        .map(|index| confusables::CONFUSABLES[index].1)
+}
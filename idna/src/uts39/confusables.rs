@@ -0,0 +1,5705 @@
+// Copyright 2013-2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Generated by make_uts39_confusables_table.py. Do not edit.
+
+/// The mappings of `confusables.txt`, from a character to its prototype,
+/// sorted by character
+#[rustfmt::skip]
+pub(super) static CONFUSABLES: &[(char, &str)] = &[
+    ('\u{22}', "\u{27}\u{27}"),
+    ('\u{25}', "\u{BA}\u{2F}\u{2080}"),
+    ('\u{30}', "\u{4F}"),
+    ('\u{31}', "\u{6C}"),
+    ('\u{49}', "\u{6C}"),
+    ('\u{60}', "\u{27}"),
+    ('\u{6D}', "\u{72}\u{6E}"),
+    ('\u{7C}', "\u{6C}"),
+    ('\u{A0}', "\u{20}"),
+    ('\u{A2}', "\u{63}\u{338}"),
+    ('\u{A5}', "\u{59}\u{335}"),
+    ('\u{AF}', "\u{2C9}"),
+    ('\u{B4}', "\u{27}"),
+    ('\u{B5}', "\u{3BC}"),
+    ('\u{B8}', "\u{2C}"),
+    ('\u{C6}', "\u{41}\u{45}"),
+    ('\u{D0}', "\u{44}\u{335}"),
+    ('\u{D7}', "\u{78}"),
+    ('\u{D8}', "\u{4F}\u{338}"),
+    ('\u{E6}', "\u{61}\u{65}"),
+    ('\u{F0}', "\u{2202}\u{335}"),
+    ('\u{F8}', "\u{6F}\u{338}"),
+    ('\u{110}', "\u{44}\u{335}"),
+    ('\u{111}', "\u{64}\u{335}"),
+    ('\u{126}', "\u{48}\u{335}"),
+    ('\u{127}', "\u{68}\u{335}"),
+    ('\u{131}', "\u{69}"),
+    ('\u{132}', "\u{6C}\u{4A}"),
+    ('\u{133}', "\u{69}\u{6A}"),
+    ('\u{13F}', "\u{6C}\u{B7}"),
+    ('\u{140}', "\u{6C}\u{B7}"),
+    ('\u{141}', "\u{4C}\u{338}"),
+    ('\u{142}', "\u{6C}\u{338}"),
+    ('\u{149}', "\u{27}\u{6E}"),
+    ('\u{152}', "\u{4F}\u{45}"),
+    ('\u{153}', "\u{6F}\u{65}"),
+    ('\u{166}', "\u{54}\u{335}"),
+    ('\u{167}', "\u{74}\u{335}"),
+    ('\u{17F}', "\u{66}"),
+    ('\u{180}', "\u{62}\u{335}"),
+    ('\u{181}', "\u{27}\u{42}"),
+    ('\u{182}', "\u{62}\u{304}"),
+    ('\u{183}', "\u{62}\u{304}"),
+    ('\u{184}', "\u{62}"),
+    ('\u{187}', "\u{43}\u{27}"),
+    ('\u{189}', "\u{44}\u{335}"),
+    ('\u{18A}', "\u{27}\u{44}"),
+    ('\u{18C}', "\u{64}\u{304}"),
+    ('\u{18D}', "\u{67}"),
+    ('\u{191}', "\u{46}\u{326}"),
+    ('\u{192}', "\u{66}\u{326}"),
+    ('\u{193}', "\u{47}\u{27}"),
+    ('\u{196}', "\u{6C}"),
+    ('\u{197}', "\u{6C}\u{335}"),
+    ('\u{198}', "\u{4B}\u{27}"),
+    ('\u{199}', "\u{6B}\u{314}"),
+    ('\u{19A}', "\u{6C}\u{335}"),
+    ('\u{19D}', "\u{4E}\u{326}"),
+    ('\u{19E}', "\u{6E}\u{329}"),
+    ('\u{19F}', "\u{4F}\u{335}"),
+    ('\u{1A4}', "\u{27}\u{50}"),
+    ('\u{1A5}', "\u{70}\u{314}"),
+    ('\u{1A6}', "\u{52}"),
+    ('\u{1A7}', "\u{32}"),
+    ('\u{1AC}', "\u{27}\u{54}"),
+    ('\u{1AD}', "\u{74}\u{314}"),
+    ('\u{1AE}', "\u{54}\u{328}"),
+    ('\u{1B3}', "\u{27}\u{59}"),
+    ('\u{1B4}', "\u{79}\u{314}"),
+    ('\u{1B5}', "\u{5A}\u{335}"),
+    ('\u{1B6}', "\u{7A}\u{335}"),
+    ('\u{1B7}', "\u{33}"),
+    ('\u{1BB}', "\u{32}\u{335}"),
+    ('\u{1BC}', "\u{35}"),
+    ('\u{1BD}', "\u{73}"),
+    ('\u{1BF}', "\u{FE}"),
+    ('\u{1C0}', "\u{6C}"),
+    ('\u{1C1}', "\u{6C}\u{6C}"),
+    ('\u{1C3}', "\u{21}"),
+    ('\u{1C4}', "\u{44}\u{5A}\u{30C}"),
+    ('\u{1C5}', "\u{44}\u{7A}\u{30C}"),
+    ('\u{1C6}', "\u{64}\u{7A}\u{30C}"),
+    ('\u{1C7}', "\u{4C}\u{4A}"),
+    ('\u{1C8}', "\u{4C}\u{6A}"),
+    ('\u{1C9}', "\u{6C}\u{6A}"),
+    ('\u{1CA}', "\u{4E}\u{4A}"),
+    ('\u{1CB}', "\u{4E}\u{6A}"),
+    ('\u{1CC}', "\u{6E}\u{6A}"),
+    ('\u{1E4}', "\u{47}\u{335}"),
+    ('\u{1E5}', "\u{67}\u{335}"),
+    ('\u{1F1}', "\u{44}\u{5A}"),
+    ('\u{1F2}', "\u{44}\u{7A}"),
+    ('\u{1F3}', "\u{64}\u{7A}"),
+    ('\u{21C}', "\u{33}"),
+    ('\u{222}', "\u{38}"),
+    ('\u{223}', "\u{38}"),
+    ('\u{224}', "\u{5A}\u{326}"),
+    ('\u{225}', "\u{7A}\u{326}"),
+    ('\u{23C}', "\u{63}\u{338}"),
+    ('\u{23E}', "\u{54}\u{338}"),
+    ('\u{241}', "\u{3F}"),
+    ('\u{244}', "\u{55}\u{335}"),
+    ('\u{246}', "\u{45}\u{338}"),
+    ('\u{247}', "\u{65}\u{338}"),
+    ('\u{248}', "\u{4A}\u{335}"),
+    ('\u{249}', "\u{6A}\u{335}"),
+    ('\u{24D}', "\u{72}\u{335}"),
+    ('\u{24E}', "\u{59}\u{335}"),
+    ('\u{24F}', "\u{79}\u{335}"),
+    ('\u{251}', "\u{61}"),
+    ('\u{253}', "\u{62}\u{314}"),
+    ('\u{256}', "\u{64}\u{328}"),
+    ('\u{257}', "\u{64}\u{314}"),
+    ('\u{259}', "\u{1DD}"),
+    ('\u{25A}', "\u{1DD}\u{2DE}"),
+    ('\u{25B}', "\u{A793}"),
+    ('\u{260}', "\u{67}\u{314}"),
+    ('\u{261}', "\u{67}"),
+    ('\u{263}', "\u{79}"),
+    ('\u{266}', "\u{68}\u{314}"),
+    ('\u{268}', "\u{69}\u{335}"),
+    ('\u{269}', "\u{69}"),
+    ('\u{26A}', "\u{69}"),
+    ('\u{26B}', "\u{6C}\u{334}"),
+    ('\u{26D}', "\u{6C}\u{328}"),
+    ('\u{26E}', "\u{6C}\u{21D}"),
+    ('\u{26F}', "\u{77}"),
+    ('\u{271}', "\u{72}\u{6E}\u{326}"),
+    ('\u{273}', "\u{6E}\u{328}"),
+    ('\u{275}', "\u{6F}\u{335}"),
+    ('\u{276}', "\u{6F}\u{1D07}"),
+    ('\u{27C}', "\u{72}\u{329}"),
+    ('\u{27D}', "\u{72}\u{328}"),
+    ('\u{282}', "\u{73}\u{328}"),
+    ('\u{28B}', "\u{75}"),
+    ('\u{28F}', "\u{79}"),
+    ('\u{290}', "\u{7A}\u{328}"),
+    ('\u{292}', "\u{21D}"),
+    ('\u{294}', "\u{3F}"),
+    ('\u{2A0}', "\u{71}\u{314}"),
+    ('\u{2A3}', "\u{64}\u{7A}"),
+    ('\u{2A4}', "\u{64}\u{21D}"),
+    ('\u{2A5}', "\u{64}\u{291}"),
+    ('\u{2A6}', "\u{74}\u{73}"),
+    ('\u{2A7}', "\u{74}\u{283}"),
+    ('\u{2A8}', "\u{74}\u{255}"),
+    ('\u{2A9}', "\u{66}\u{14B}"),
+    ('\u{2AA}', "\u{6C}\u{73}"),
+    ('\u{2AB}', "\u{6C}\u{7A}"),
+    ('\u{2B3}', "\u{18F4}"),
+    ('\u{2B9}', "\u{27}"),
+    ('\u{2BA}', "\u{27}\u{27}"),
+    ('\u{2BB}', "\u{27}"),
+    ('\u{2BC}', "\u{27}"),
+    ('\u{2BD}', "\u{27}"),
+    ('\u{2BE}', "\u{27}"),
+    ('\u{2BF}', "\u{559}"),
+    ('\u{2C2}', "\u{3C}"),
+    ('\u{2C3}', "\u{3E}"),
+    ('\u{2C4}', "\u{5E}"),
+    ('\u{2C6}', "\u{5E}"),
+    ('\u{2C8}', "\u{27}"),
+    ('\u{2CA}', "\u{27}"),
+    ('\u{2CB}', "\u{27}"),
+    ('\u{2D0}', "\u{3A}"),
+    ('\u{2D3}', "\u{559}"),
+    ('\u{2D7}', "\u{2D}"),
+    ('\u{2D8}', "\u{2C7}"),
+    ('\u{2D9}', "\u{971}"),
+    ('\u{2DA}', "\u{B0}"),
+    ('\u{2DB}', "\u{69}"),
+    ('\u{2DC}', "\u{7E}"),
+    ('\u{2DD}', "\u{27}\u{27}"),
+    ('\u{2E1}', "\u{18F3}"),
+    ('\u{2E2}', "\u{18F5}"),
+    ('\u{2E4}', "\u{2C1}"),
+    ('\u{2EE}', "\u{27}\u{27}"),
+    ('\u{2F4}', "\u{27}"),
+    ('\u{2F6}', "\u{27}\u{27}"),
+    ('\u{2F8}', "\u{3A}"),
+    ('\u{2FB}', "\u{2EA}"),
+    ('\u{305}', "\u{304}"),
+    ('\u{30C}', "\u{306}"),
+    ('\u{30D}', "\u{670}"),
+    ('\u{310}', "\u{306}\u{307}"),
+    ('\u{311}', "\u{302}"),
+    ('\u{315}', "\u{313}"),
+    ('\u{317}', "\u{650}"),
+    ('\u{320}', "\u{331}"),
+    ('\u{321}', "\u{326}"),
+    ('\u{322}', "\u{328}"),
+    ('\u{327}', "\u{326}"),
+    ('\u{336}', "\u{335}"),
+    ('\u{337}', "\u{338}"),
+    ('\u{339}', "\u{326}"),
+    ('\u{342}', "\u{303}"),
+    ('\u{345}', "\u{328}"),
+    ('\u{347}', "\u{333}"),
+    ('\u{357}', "\u{350}"),
+    ('\u{358}', "\u{307}"),
+    ('\u{366}', "\u{30A}"),
+    ('\u{36E}', "\u{306}"),
+    ('\u{370}', "\u{2C75}"),
+    ('\u{375}', "\u{2CF}"),
+    ('\u{376}', "\u{418}"),
+    ('\u{377}', "\u{1D0E}"),
+    ('\u{37A}', "\u{69}"),
+    ('\u{37B}', "\u{254}"),
+    ('\u{37D}', "\u{A73F}"),
+    ('\u{37F}', "\u{4A}"),
+    ('\u{384}', "\u{27}"),
+    ('\u{391}', "\u{41}"),
+    ('\u{392}', "\u{42}"),
+    ('\u{395}', "\u{45}"),
+    ('\u{396}', "\u{5A}"),
+    ('\u{397}', "\u{48}"),
+    ('\u{398}', "\u{4F}\u{335}"),
+    ('\u{399}', "\u{6C}"),
+    ('\u{39A}', "\u{4B}"),
+    ('\u{39B}', "\u{245}"),
+    ('\u{39C}', "\u{4D}"),
+    ('\u{39D}', "\u{4E}"),
+    ('\u{39F}', "\u{4F}"),
+    ('\u{3A1}', "\u{50}"),
+    ('\u{3A3}', "\u{1A9}"),
+    ('\u{3A4}', "\u{54}"),
+    ('\u{3A5}', "\u{59}"),
+    ('\u{3A7}', "\u{58}"),
+    ('\u{3B1}', "\u{61}"),
+    ('\u{3B2}', "\u{DF}"),
+    ('\u{3B3}', "\u{79}"),
+    ('\u{3B4}', "\u{1E9F}"),
+    ('\u{3B5}', "\u{A793}"),
+    ('\u{3B7}', "\u{6E}\u{329}"),
+    ('\u{3B8}', "\u{4F}\u{335}"),
+    ('\u{3B9}', "\u{69}"),
+    ('\u{3BA}', "\u{138}"),
+    ('\u{3BD}', "\u{76}"),
+    ('\u{3BF}', "\u{6F}"),
+    ('\u{3C1}', "\u{70}"),
+    ('\u{3C3}', "\u{6F}"),
+    ('\u{3C4}', "\u{1D1B}"),
+    ('\u{3C5}', "\u{75}"),
+    ('\u{3C6}', "\u{278}"),
+    ('\u{3D0}', "\u{DF}"),
+    ('\u{3D1}', "\u{4F}\u{335}"),
+    ('\u{3D2}', "\u{59}"),
+    ('\u{3D5}', "\u{278}"),
+    ('\u{3D6}', "\u{3C0}"),
+    ('\u{3DB}', "\u{3C2}"),
+    ('\u{3DC}', "\u{46}"),
+    ('\u{3E8}', "\u{32}"),
+    ('\u{3E9}', "\u{1A8}"),
+    ('\u{3F0}', "\u{138}"),
+    ('\u{3F1}', "\u{70}"),
+    ('\u{3F2}', "\u{63}"),
+    ('\u{3F3}', "\u{6A}"),
+    ('\u{3F4}', "\u{4F}\u{335}"),
+    ('\u{3F5}', "\u{A793}"),
+    ('\u{3F7}', "\u{DE}"),
+    ('\u{3F8}', "\u{FE}"),
+    ('\u{3F9}', "\u{43}"),
+    ('\u{3FA}', "\u{4D}"),
+    ('\u{3FD}', "\u{186}"),
+    ('\u{3FF}', "\u{A73E}"),
+    ('\u{404}', "\u{A792}"),
+    ('\u{405}', "\u{53}"),
+    ('\u{406}', "\u{6C}"),
+    ('\u{408}', "\u{4A}"),
+    ('\u{410}', "\u{41}"),
+    ('\u{411}', "\u{62}\u{304}"),
+    ('\u{412}', "\u{42}"),
+    ('\u{413}', "\u{393}"),
+    ('\u{415}', "\u{45}"),
+    ('\u{417}', "\u{33}"),
+    ('\u{41A}', "\u{4B}"),
+    ('\u{41B}', "\u{245}"),
+    ('\u{41C}', "\u{4D}"),
+    ('\u{41D}', "\u{48}"),
+    ('\u{41E}', "\u{4F}"),
+    ('\u{41F}', "\u{3A0}"),
+    ('\u{420}', "\u{50}"),
+    ('\u{421}', "\u{43}"),
+    ('\u{422}', "\u{54}"),
+    ('\u{423}', "\u{59}"),
+    ('\u{424}', "\u{3A6}"),
+    ('\u{425}', "\u{58}"),
+    ('\u{42B}', "\u{62}\u{6C}"),
+    ('\u{42C}', "\u{62}"),
+    ('\u{42E}', "\u{6C}\u{4F}"),
+    ('\u{430}', "\u{61}"),
+    ('\u{431}', "\u{36}"),
+    ('\u{432}', "\u{299}"),
+    ('\u{433}', "\u{72}"),
+    ('\u{435}', "\u{65}"),
+    ('\u{437}', "\u{25C}"),
+    ('\u{438}', "\u{1D0E}"),
+    ('\u{43A}', "\u{138}"),
+    ('\u{43C}', "\u{28D}"),
+    ('\u{43D}', "\u{29C}"),
+    ('\u{43E}', "\u{6F}"),
+    ('\u{43F}', "\u{3C0}"),
+    ('\u{440}', "\u{70}"),
+    ('\u{441}', "\u{63}"),
+    ('\u{442}', "\u{1D1B}"),
+    ('\u{443}', "\u{79}"),
+    ('\u{444}', "\u{278}"),
+    ('\u{445}', "\u{78}"),
+    ('\u{44A}', "\u{2C9}\u{62}"),
+    ('\u{44B}', "\u{185}\u{69}"),
+    ('\u{44C}', "\u{185}"),
+    ('\u{44F}', "\u{1D19}"),
+    ('\u{454}', "\u{A793}"),
+    ('\u{455}', "\u{73}"),
+    ('\u{456}', "\u{69}"),
+    ('\u{458}', "\u{6A}"),
+    ('\u{45B}', "\u{68}\u{335}"),
+    ('\u{461}', "\u{77}"),
+    ('\u{462}', "\u{62}\u{335}"),
+    ('\u{463}', "\u{62}\u{335}"),
+    ('\u{470}', "\u{3A8}"),
+    ('\u{471}', "\u{3C8}"),
+    ('\u{472}', "\u{4F}\u{335}"),
+    ('\u{473}', "\u{6F}\u{335}"),
+    ('\u{474}', "\u{56}"),
+    ('\u{475}', "\u{76}"),
+    ('\u{47C}', "\u{460}\u{486}\u{487}"),
+    ('\u{47D}', "\u{77}\u{486}\u{487}"),
+    ('\u{48A}', "\u{418}\u{326}\u{300}"),
+    ('\u{48B}', "\u{438}\u{326}\u{306}"),
+    ('\u{48C}', "\u{62}\u{335}"),
+    ('\u{48D}', "\u{62}\u{335}"),
+    ('\u{490}', "\u{393}\u{27}"),
+    ('\u{491}', "\u{72}\u{27}"),
+    ('\u{492}', "\u{393}\u{335}"),
+    ('\u{493}', "\u{72}\u{335}"),
+    ('\u{496}', "\u{416}\u{329}"),
+    ('\u{497}', "\u{436}\u{329}"),
+    ('\u{498}', "\u{33}\u{326}"),
+    ('\u{499}', "\u{25C}\u{326}"),
+    ('\u{49A}', "\u{4B}\u{329}"),
+    ('\u{49B}', "\u{138}\u{329}"),
+    ('\u{49E}', "\u{4B}\u{335}"),
+    ('\u{49F}', "\u{138}\u{335}"),
+    ('\u{4A2}', "\u{48}\u{329}"),
+    ('\u{4A3}', "\u{29C}\u{329}"),
+    ('\u{4AA}', "\u{43}\u{326}"),
+    ('\u{4AB}', "\u{63}\u{326}"),
+    ('\u{4AC}', "\u{54}\u{329}"),
+    ('\u{4AD}', "\u{1D1B}\u{329}"),
+    ('\u{4AE}', "\u{59}"),
+    ('\u{4AF}', "\u{79}"),
+    ('\u{4B0}', "\u{59}\u{335}"),
+    ('\u{4B1}', "\u{79}\u{335}"),
+    ('\u{4B2}', "\u{58}\u{329}"),
+    ('\u{4BB}', "\u{68}"),
+    ('\u{4BD}', "\u{65}"),
+    ('\u{4BE}', "\u{4BC}\u{328}"),
+    ('\u{4BF}', "\u{65}\u{328}"),
+    ('\u{4C0}', "\u{6C}"),
+    ('\u{4C5}', "\u{245}\u{326}"),
+    ('\u{4C6}', "\u{43B}\u{326}"),
+    ('\u{4C7}', "\u{48}\u{326}"),
+    ('\u{4C8}', "\u{29C}\u{326}"),
+    ('\u{4C9}', "\u{48}\u{326}"),
+    ('\u{4CA}', "\u{29C}\u{326}"),
+    ('\u{4CB}', "\u{4B6}"),
+    ('\u{4CC}', "\u{4B7}"),
+    ('\u{4CD}', "\u{4D}\u{326}"),
+    ('\u{4CE}', "\u{28D}\u{326}"),
+    ('\u{4CF}', "\u{69}"),
+    ('\u{4D4}', "\u{41}\u{45}"),
+    ('\u{4D5}', "\u{61}\u{65}"),
+    ('\u{4D8}', "\u{18F}"),
+    ('\u{4D9}', "\u{1DD}"),
+    ('\u{4E0}', "\u{33}"),
+    ('\u{4E1}', "\u{21D}"),
+    ('\u{4E8}', "\u{4F}\u{335}"),
+    ('\u{4E9}', "\u{6F}\u{335}"),
+    ('\u{501}', "\u{64}"),
+    ('\u{50A}', "\u{1F6}"),
+    ('\u{50C}', "\u{47}"),
+    ('\u{50D}', "\u{262}"),
+    ('\u{510}', "\u{190}"),
+    ('\u{511}', "\u{A793}"),
+    ('\u{51B}', "\u{71}"),
+    ('\u{51C}', "\u{57}"),
+    ('\u{51D}', "\u{77}"),
+    ('\u{53B}', "\u{12AE}"),
+    ('\u{544}', "\u{1206}"),
+    ('\u{54A}', "\u{1323}"),
+    ('\u{54C}', "\u{1261}"),
+    ('\u{54D}', "\u{55}"),
+    ('\u{54F}', "\u{53}"),
+    ('\u{553}', "\u{3A6}"),
+    ('\u{555}', "\u{4F}"),
+    ('\u{55A}', "\u{27}"),
+    ('\u{55D}', "\u{27}"),
+    ('\u{561}', "\u{77}"),
+    ('\u{563}', "\u{71}"),
+    ('\u{566}', "\u{71}"),
+    ('\u{56E}', "\u{1E9F}"),
+    ('\u{570}', "\u{68}"),
+    ('\u{575}', "\u{237}"),
+    ('\u{578}', "\u{6E}"),
+    ('\u{57A}', "\u{270}"),
+    ('\u{57C}', "\u{6E}"),
+    ('\u{57D}', "\u{75}"),
+    ('\u{581}', "\u{67}"),
+    ('\u{584}', "\u{66}"),
+    ('\u{585}', "\u{6F}"),
+    ('\u{587}', "\u{565}\u{582}"),
+    ('\u{589}', "\u{3A}"),
+    ('\u{59C}', "\u{301}"),
+    ('\u{59D}', "\u{301}"),
+    ('\u{5A4}', "\u{59A}"),
+    ('\u{5A8}', "\u{599}"),
+    ('\u{5AD}', "\u{596}"),
+    ('\u{5AE}', "\u{598}"),
+    ('\u{5AF}', "\u{30A}"),
+    ('\u{5B4}', "\u{323}"),
+    ('\u{5B9}', "\u{307}"),
+    ('\u{5BA}', "\u{307}"),
+    ('\u{5C0}', "\u{6C}"),
+    ('\u{5C1}', "\u{307}"),
+    ('\u{5C2}', "\u{307}"),
+    ('\u{5C3}', "\u{3A}"),
+    ('\u{5C4}', "\u{307}"),
+    ('\u{5C5}', "\u{323}"),
+    ('\u{5D5}', "\u{6C}"),
+    ('\u{5D8}', "\u{76}"),
+    ('\u{5D9}', "\u{27}"),
+    ('\u{5DF}', "\u{6C}"),
+    ('\u{5E1}', "\u{6F}"),
+    ('\u{5F0}', "\u{6C}\u{6C}"),
+    ('\u{5F1}', "\u{6C}\u{27}"),
+    ('\u{5F2}', "\u{27}\u{27}"),
+    ('\u{5F3}', "\u{27}"),
+    ('\u{5F4}', "\u{27}\u{27}"),
+    ('\u{609}', "\u{BA}\u{2F}\u{2080}\u{2080}"),
+    ('\u{60A}', "\u{BA}\u{2F}\u{2080}\u{2080}\u{2080}"),
+    ('\u{60D}', "\u{2C}"),
+    ('\u{60F}', "\u{639}"),
+    ('\u{618}', "\u{301}"),
+    ('\u{619}', "\u{313}"),
+    ('\u{61A}', "\u{650}"),
+    ('\u{627}', "\u{6C}"),
+    ('\u{62B}', "\u{649}\u{6DB}"),
+    ('\u{634}', "\u{633}\u{6DB}"),
+    ('\u{63D}', "\u{649}\u{302}"),
+    ('\u{63F}', "\u{649}\u{6DB}"),
+    ('\u{647}', "\u{6F}"),
+    ('\u{64A}', "\u{649}"),
+    ('\u{64B}', "\u{30B}"),
+    ('\u{64E}', "\u{301}"),
+    ('\u{64F}', "\u{313}"),
+    ('\u{652}', "\u{30A}"),
+    ('\u{653}', "\u{303}"),
+    ('\u{656}', "\u{329}"),
+    ('\u{657}', "\u{312}"),
+    ('\u{658}', "\u{306}"),
+    ('\u{659}', "\u{304}"),
+    ('\u{65A}', "\u{306}"),
+    ('\u{65B}', "\u{302}"),
+    ('\u{65C}', "\u{323}"),
+    ('\u{65D}', "\u{314}"),
+    ('\u{65F}', "\u{655}"),
+    ('\u{660}', "\u{2E}"),
+    ('\u{661}', "\u{6C}"),
+    ('\u{665}', "\u{6F}"),
+    ('\u{667}', "\u{56}"),
+    ('\u{668}', "\u{245}"),
+    ('\u{66A}', "\u{BA}\u{2F}\u{2080}"),
+    ('\u{66B}', "\u{2C}"),
+    ('\u{66C}', "\u{60C}"),
+    ('\u{66D}', "\u{2A}"),
+    ('\u{66E}', "\u{649}"),
+    ('\u{66F}', "\u{6A1}"),
+    ('\u{672}', "\u{6C}\u{674}"),
+    ('\u{673}', "\u{6C}\u{655}"),
+    ('\u{675}', "\u{6C}\u{674}"),
+    ('\u{676}', "\u{648}\u{674}"),
+    ('\u{677}', "\u{648}\u{313}\u{674}"),
+    ('\u{678}', "\u{649}\u{674}"),
+    ('\u{679}', "\u{649}\u{615}"),
+    ('\u{67E}', "\u{649}\u{6DB}"),
+    ('\u{681}', "\u{62D}\u{654}"),
+    ('\u{685}', "\u{62D}\u{6DB}"),
+    ('\u{688}', "\u{62F}\u{615}"),
+    ('\u{68B}', "\u{68A}\u{615}"),
+    ('\u{68E}', "\u{62F}\u{6DB}"),
+    ('\u{691}', "\u{631}\u{615}"),
+    ('\u{692}', "\u{631}\u{306}"),
+    ('\u{698}', "\u{631}\u{6DB}"),
+    ('\u{69E}', "\u{635}\u{6DB}"),
+    ('\u{69F}', "\u{637}\u{6DB}"),
+    ('\u{6A4}', "\u{6A1}\u{6DB}"),
+    ('\u{6A7}', "\u{641}"),
+    ('\u{6A8}', "\u{6A1}\u{6DB}"),
+    ('\u{6A9}', "\u{643}"),
+    ('\u{6AA}', "\u{643}"),
+    ('\u{6AD}', "\u{643}\u{6DB}"),
+    ('\u{6B4}', "\u{6AF}\u{6DB}"),
+    ('\u{6B5}', "\u{644}\u{306}"),
+    ('\u{6B7}', "\u{644}\u{6DB}"),
+    ('\u{6BA}', "\u{649}"),
+    ('\u{6BB}', "\u{649}\u{615}"),
+    ('\u{6BD}', "\u{649}\u{6DB}"),
+    ('\u{6BE}', "\u{6F}"),
+    ('\u{6C1}', "\u{6F}"),
+    ('\u{6C3}', "\u{629}"),
+    ('\u{6C6}', "\u{648}\u{306}"),
+    ('\u{6C7}', "\u{648}\u{313}"),
+    ('\u{6C8}', "\u{648}\u{670}"),
+    ('\u{6C9}', "\u{648}\u{302}"),
+    ('\u{6CB}', "\u{648}\u{6DB}"),
+    ('\u{6CC}', "\u{649}"),
+    ('\u{6CE}', "\u{649}\u{306}"),
+    ('\u{6D0}', "\u{67B}"),
+    ('\u{6D1}', "\u{649}\u{6DB}"),
+    ('\u{6D2}', "\u{649}"),
+    ('\u{6D4}', "\u{2D}"),
+    ('\u{6D5}', "\u{6F}"),
+    ('\u{6DF}', "\u{30A}"),
+    ('\u{6E8}', "\u{306}\u{307}"),
+    ('\u{6EC}', "\u{307}"),
+    ('\u{6EE}', "\u{62F}\u{302}"),
+    ('\u{6EF}', "\u{631}\u{302}"),
+    ('\u{6F0}', "\u{2E}"),
+    ('\u{6F1}', "\u{6C}"),
+    ('\u{6F2}', "\u{662}"),
+    ('\u{6F3}', "\u{663}"),
+    ('\u{6F4}', "\u{664}"),
+    ('\u{6F5}', "\u{6F}"),
+    ('\u{6F6}', "\u{666}"),
+    ('\u{6F7}', "\u{56}"),
+    ('\u{6F8}', "\u{245}"),
+    ('\u{6F9}', "\u{669}"),
+    ('\u{6FD}', "\u{621}\u{348}"),
+    ('\u{6FE}', "\u{645}\u{348}"),
+    ('\u{6FF}', "\u{6F}\u{302}"),
+    ('\u{701}', "\u{2E}"),
+    ('\u{702}', "\u{2E}"),
+    ('\u{703}', "\u{3A}"),
+    ('\u{704}', "\u{3A}"),
+    ('\u{740}', "\u{307}"),
+    ('\u{741}', "\u{307}"),
+    ('\u{742}', "\u{73C}"),
+    ('\u{747}', "\u{301}"),
+    ('\u{751}', "\u{628}\u{6DB}"),
+    ('\u{756}', "\u{649}\u{306}"),
+    ('\u{762}', "\u{6AC}"),
+    ('\u{763}', "\u{643}\u{6DB}"),
+    ('\u{767}', "\u{754}"),
+    ('\u{768}', "\u{646}\u{615}"),
+    ('\u{769}', "\u{646}\u{306}"),
+    ('\u{76C}', "\u{631}\u{654}"),
+    ('\u{771}', "\u{697}\u{615}"),
+    ('\u{772}', "\u{62D}\u{654}"),
+    ('\u{77E}', "\u{633}\u{302}"),
+    ('\u{7C0}', "\u{4F}"),
+    ('\u{7CA}', "\u{6C}"),
+    ('\u{7EB}', "\u{304}"),
+    ('\u{7ED}', "\u{307}"),
+    ('\u{7EE}', "\u{302}"),
+    ('\u{7F3}', "\u{308}"),
+    ('\u{7F4}', "\u{27}"),
+    ('\u{7F5}', "\u{27}"),
+    ('\u{7FA}', "\u{5F}"),
+    ('\u{8A1}', "\u{628}\u{654}"),
+    ('\u{8A4}', "\u{6A2}\u{6DB}"),
+    ('\u{8A7}', "\u{645}\u{6DB}"),
+    ('\u{8A8}', "\u{649}\u{654}"),
+    ('\u{8A9}', "\u{754}"),
+    ('\u{8AE}', "\u{62F}\u{324}\u{323}"),
+    ('\u{8AF}', "\u{635}\u{324}\u{323}"),
+    ('\u{8B0}', "\u{6AF}"),
+    ('\u{8B1}', "\u{648}"),
+    ('\u{8B2}', "\u{632}\u{302}"),
+    ('\u{8B6}', "\u{628}\u{6E2}"),
+    ('\u{8B7}', "\u{649}\u{6DB}\u{6E2}"),
+    ('\u{8B9}', "\u{631}\u{306}\u{307}"),
+    ('\u{8BA}', "\u{649}\u{306}\u{307}"),
+    ('\u{8BB}', "\u{6A1}"),
+    ('\u{8BC}', "\u{6A1}"),
+    ('\u{8BD}', "\u{649}"),
+    ('\u{8E5}', "\u{64C}"),
+    ('\u{8E8}', "\u{64C}"),
+    ('\u{8EA}', "\u{307}"),
+    ('\u{8EB}', "\u{308}"),
+    ('\u{8ED}', "\u{323}"),
+    ('\u{8EE}', "\u{324}"),
+    ('\u{8F0}', "\u{30B}"),
+    ('\u{8F1}', "\u{64C}"),
+    ('\u{8F2}', "\u{64D}"),
+    ('\u{8F3}', "\u{313}"),
+    ('\u{8F8}', "\u{350}"),
+    ('\u{8F9}', "\u{354}"),
+    ('\u{8FA}', "\u{355}"),
+    ('\u{8FF}', "\u{350}"),
+    ('\u{900}', "\u{352}"),
+    ('\u{901}', "\u{306}\u{307}"),
+    ('\u{902}', "\u{307}"),
+    ('\u{903}', "\u{3A}"),
+    ('\u{904}', "\u{905}\u{946}"),
+    ('\u{906}', "\u{905}\u{93E}"),
+    ('\u{908}', "\u{930}\u{94D}\u{907}"),
+    ('\u{90D}', "\u{90F}\u{945}"),
+    ('\u{90E}', "\u{90F}\u{946}"),
+    ('\u{910}', "\u{90F}\u{947}"),
+    ('\u{911}', "\u{905}\u{949}"),
+    ('\u{912}', "\u{905}\u{93E}\u{946}"),
+    ('\u{913}', "\u{905}\u{93E}\u{947}"),
+    ('\u{914}', "\u{905}\u{93E}\u{948}"),
+    ('\u{93C}', "\u{323}"),
+    ('\u{952}', "\u{331}"),
+    ('\u{953}', "\u{300}"),
+    ('\u{954}', "\u{301}"),
+    ('\u{965}', "\u{964}\u{964}"),
+    ('\u{966}', "\u{6F}"),
+    ('\u{967}', "\u{669}"),
+    ('\u{97D}', "\u{3F}"),
+    ('\u{981}', "\u{306}\u{307}"),
+    ('\u{986}', "\u{985}\u{9BE}"),
+    ('\u{9BC}', "\u{323}"),
+    ('\u{9E0}', "\u{98B}\u{9C3}"),
+    ('\u{9E1}', "\u{98B}\u{9C3}"),
+    ('\u{9E6}', "\u{4F}"),
+    ('\u{9EA}', "\u{38}"),
+    ('\u{9ED}', "\u{39}"),
+    ('\u{A02}', "\u{307}"),
+    ('\u{A03}', "\u{983}"),
+    ('\u{A06}', "\u{A05}\u{A3E}"),
+    ('\u{A07}', "\u{A72}\u{A3F}"),
+    ('\u{A08}', "\u{A72}\u{A40}"),
+    ('\u{A09}', "\u{A73}\u{A41}"),
+    ('\u{A0A}', "\u{A73}\u{A42}"),
+    ('\u{A0F}', "\u{A72}\u{A47}"),
+    ('\u{A10}', "\u{A05}\u{A48}"),
+    ('\u{A14}', "\u{A05}\u{A4C}"),
+    ('\u{A3C}', "\u{323}"),
+    ('\u{A4B}', "\u{946}"),
+    ('\u{A4D}', "\u{94D}"),
+    ('\u{A66}', "\u{6F}"),
+    ('\u{A67}', "\u{39}"),
+    ('\u{A6A}', "\u{38}"),
+    ('\u{A81}', "\u{306}\u{307}"),
+    ('\u{A82}', "\u{307}"),
+    ('\u{A83}', "\u{3A}"),
+    ('\u{A86}', "\u{A85}\u{ABE}"),
+    ('\u{A8D}', "\u{A85}\u{AC5}"),
+    ('\u{A8F}', "\u{A85}\u{AC7}"),
+    ('\u{A90}', "\u{A85}\u{AC8}"),
+    ('\u{A91}', "\u{A85}\u{ABE}\u{AC5}"),
+    ('\u{A93}', "\u{A85}\u{ABE}\u{AC7}"),
+    ('\u{A94}', "\u{A85}\u{ABE}\u{AC8}"),
+    ('\u{ABC}', "\u{323}"),
+    ('\u{ABD}', "\u{93D}"),
+    ('\u{AC1}', "\u{941}"),
+    ('\u{AC2}', "\u{942}"),
+    ('\u{ACD}', "\u{94D}"),
+    ('\u{AE6}', "\u{6F}"),
+    ('\u{AE8}', "\u{968}"),
+    ('\u{AE9}', "\u{969}"),
+    ('\u{AEA}', "\u{96A}"),
+    ('\u{AEE}', "\u{96E}"),
+    ('\u{AF0}', "\u{970}"),
+    ('\u{B01}', "\u{306}\u{307}"),
+    ('\u{B03}', "\u{38}"),
+    ('\u{B06}', "\u{B05}\u{B3E}"),
+    ('\u{B20}', "\u{4F}"),
+    ('\u{B3C}', "\u{323}"),
+    ('\u{B66}', "\u{4F}"),
+    ('\u{B68}', "\u{39}"),
+    ('\u{B82}', "\u{30A}"),
+    ('\u{B8A}', "\u{B89}\u{BB3}"),
+    ('\u{B9C}', "\u{B90}"),
+    ('\u{BB0}', "\u{B88}"),
+    ('\u{BBE}', "\u{B88}"),
+    ('\u{BC8}', "\u{BA9}"),
+    ('\u{BCD}', "\u{307}"),
+    ('\u{BD7}', "\u{BB3}"),
+    ('\u{BE6}', "\u{6F}"),
+    ('\u{BE7}', "\u{B95}"),
+    ('\u{BE8}', "\u{B89}"),
+    ('\u{BEA}', "\u{B9A}"),
+    ('\u{BEB}', "\u{B88}\u{BC1}"),
+    ('\u{BEC}', "\u{B9A}\u{BC1}"),
+    ('\u{BED}', "\u{B8E}"),
+    ('\u{BEE}', "\u{B85}"),
+    ('\u{BF0}', "\u{BAF}"),
+    ('\u{BF2}', "\u{B9A}\u{BC2}"),
+    ('\u{BF4}', "\u{BAE}\u{BC0}"),
+    ('\u{BF5}', "\u{BF3}"),
+    ('\u{BF7}', "\u{B8E}\u{BB5}"),
+    ('\u{BF8}', "\u{BB7}"),
+    ('\u{BFA}', "\u{BA8}\u{BC0}"),
+    ('\u{C00}', "\u{306}\u{307}"),
+    ('\u{C02}', "\u{6F}"),
+    ('\u{C03}', "\u{983}"),
+    ('\u{C13}', "\u{C12}\u{C55}"),
+    ('\u{C14}', "\u{C12}\u{C4C}"),
+    ('\u{C20}', "\u{C30}\u{5BC}"),
+    ('\u{C22}', "\u{C21}\u{323}"),
+    ('\u{C25}', "\u{C27}\u{5BC}"),
+    ('\u{C2D}', "\u{C2C}\u{323}"),
+    ('\u{C2E}', "\u{C35}\u{C41}"),
+    ('\u{C37}', "\u{C35}\u{323}"),
+    ('\u{C39}', "\u{C35}\u{C3E}"),
+    ('\u{C42}', "\u{C41}\u{C3E}"),
+    ('\u{C44}', "\u{C43}\u{C3E}"),
+    ('\u{C60}', "\u{C0B}\u{C3E}"),
+    ('\u{C61}', "\u{C0C}\u{C3E}"),
+    ('\u{C66}', "\u{6F}"),
+    ('\u{C81}', "\u{306}\u{307}"),
+    ('\u{C82}', "\u{6F}"),
+    ('\u{C83}', "\u{983}"),
+    ('\u{C85}', "\u{C05}"),
+    ('\u{C86}', "\u{C06}"),
+    ('\u{C87}', "\u{C07}"),
+    ('\u{C92}', "\u{C12}"),
+    ('\u{C93}', "\u{C12}\u{C55}"),
+    ('\u{C94}', "\u{C12}\u{C4C}"),
+    ('\u{C9C}', "\u{C1C}"),
+    ('\u{C9E}', "\u{C1E}"),
+    ('\u{CA3}', "\u{C23}"),
+    ('\u{CAF}', "\u{C2F}"),
+    ('\u{CB1}', "\u{C31}"),
+    ('\u{CB2}', "\u{C32}"),
+    ('\u{CE1}', "\u{C8C}\u{CBE}"),
+    ('\u{CE6}', "\u{6F}"),
+    ('\u{CE7}', "\u{C67}"),
+    ('\u{CE8}', "\u{C68}"),
+    ('\u{CEF}', "\u{C6F}"),
+    ('\u{D01}', "\u{306}\u{307}"),
+    ('\u{D02}', "\u{6F}"),
+    ('\u{D03}', "\u{983}"),
+    ('\u{D08}', "\u{D07}\u{D57}"),
+    ('\u{D09}', "\u{B89}"),
+    ('\u{D0A}', "\u{B89}\u{D57}"),
+    ('\u{D0C}', "\u{D28}\u{D41}"),
+    ('\u{D10}', "\u{D0E}\u{D46}"),
+    ('\u{D13}', "\u{D12}\u{D3E}"),
+    ('\u{D14}', "\u{D12}\u{D57}"),
+    ('\u{D19}', "\u{D28}\u{D41}"),
+    ('\u{D1C}', "\u{B90}"),
+    ('\u{D20}', "\u{6F}"),
+    ('\u{D23}', "\u{BA3}"),
+    ('\u{D31}', "\u{D30}"),
+    ('\u{D34}', "\u{BB4}"),
+    ('\u{D36}', "\u{BB6}"),
+    ('\u{D3A}', "\u{B9F}\u{BBF}"),
+    ('\u{D3F}', "\u{BBF}"),
+    ('\u{D40}', "\u{BBF}"),
+    ('\u{D42}', "\u{D41}"),
+    ('\u{D43}', "\u{D41}"),
+    ('\u{D48}', "\u{D46}\u{D46}"),
+    ('\u{D4E}', "\u{971}"),
+    ('\u{D5A}', "\u{D28}\u{D4D}\u{D2E}"),
+    ('\u{D5F}', "\u{6F}\u{D30}\u{6F}"),
+    ('\u{D61}', "\u{D1E}"),
+    ('\u{D66}', "\u{6F}"),
+    ('\u{D6A}', "\u{D30}\u{D4D}"),
+    ('\u{D6B}', "\u{D26}\u{D4D}\u{D30}"),
+    ('\u{D6C}', "\u{D28}\u{D4D}\u{D28}"),
+    ('\u{D6D}', "\u{39}"),
+    ('\u{D6E}', "\u{D35}\u{D4D}\u{D30}"),
+    ('\u{D6F}', "\u{D28}\u{D4D}"),
+    ('\u{D76}', "\u{D39}\u{D4D}\u{D2E}"),
+    ('\u{D79}', "\u{D28}\u{D41}"),
+    ('\u{D7B}', "\u{D28}\u{D4D}"),
+    ('\u{D7C}', "\u{D30}\u{D4D}"),
+    ('\u{D82}', "\u{6F}"),
+    ('\u{D83}', "\u{983}"),
+    ('\u{DE9}', "\u{DE8}\u{DCF}"),
+    ('\u{DEA}', "\u{DA2}"),
+    ('\u{DEB}', "\u{DAF}"),
+    ('\u{DEF}', "\u{DE8}\u{DD3}"),
+    ('\u{E03}', "\u{E02}"),
+    ('\u{E0B}', "\u{E0A}"),
+    ('\u{E0F}', "\u{E0E}"),
+    ('\u{E14}', "\u{E04}"),
+    ('\u{E15}', "\u{E04}"),
+    ('\u{E17}', "\u{E11}"),
+    ('\u{E21}', "\u{E06}"),
+    ('\u{E26}', "\u{E20}"),
+    ('\u{E33}', "\u{30A}\u{E32}"),
+    ('\u{E41}', "\u{E40}\u{E40}"),
+    ('\u{E45}', "\u{E32}"),
+    ('\u{E4D}', "\u{30A}"),
+    ('\u{E50}', "\u{6F}"),
+    ('\u{E88}', "\u{E08}"),
+    ('\u{E8D}', "\u{E22}"),
+    ('\u{E9A}', "\u{E1A}"),
+    ('\u{E9B}', "\u{E1B}"),
+    ('\u{E9D}', "\u{E1D}"),
+    ('\u{E9E}', "\u{E1E}"),
+    ('\u{E9F}', "\u{E1F}"),
+    ('\u{EB3}', "\u{30A}\u{EB2}"),
+    ('\u{EB8}', "\u{E38}"),
+    ('\u{EB9}', "\u{E39}"),
+    ('\u{EC8}', "\u{E48}"),
+    ('\u{EC9}', "\u{E49}"),
+    ('\u{ECA}', "\u{E4A}"),
+    ('\u{ECB}', "\u{E4B}"),
+    ('\u{ECD}', "\u{30A}"),
+    ('\u{ED0}', "\u{6F}"),
+    ('\u{EDC}', "\u{EAB}\u{E99}"),
+    ('\u{EDD}', "\u{EAB}\u{EA1}"),
+    ('\u{F00}', "\u{F68}\u{F7C}\u{F7E}"),
+    ('\u{F02}', "\u{F60}\u{F74}\u{F82}\u{F7F}"),
+    ('\u{F03}', "\u{F60}\u{F74}\u{F82}\u{F14}"),
+    ('\u{F0C}', "\u{F0B}"),
+    ('\u{F0E}', "\u{F0D}\u{F0D}"),
+    ('\u{F1B}', "\u{F1A}\u{F1A}"),
+    ('\u{F1E}', "\u{F1D}\u{F1D}"),
+    ('\u{F1F}', "\u{F1A}\u{F1D}"),
+    ('\u{F37}', "\u{325}"),
+    ('\u{F6A}', "\u{F62}"),
+    ('\u{F77}', "\u{FB2}\u{F71}\u{F80}"),
+    ('\u{F79}', "\u{FB3}\u{F71}\u{F80}"),
+    ('\u{FCE}', "\u{F1D}\u{F1A}"),
+    ('\u{FD5}', "\u{5350}"),
+    ('\u{FD6}', "\u{534D}"),
+    ('\u{1000}', "\u{1002}\u{102C}"),
+    ('\u{1010}', "\u{6F}\u{102C}"),
+    ('\u{101D}', "\u{6F}"),
+    ('\u{101F}', "\u{1015}\u{102C}"),
+    ('\u{1029}', "\u{101E}\u{103C}"),
+    ('\u{102A}', "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"),
+    ('\u{1036}', "\u{30A}"),
+    ('\u{1038}', "\u{983}"),
+    ('\u{1040}', "\u{6F}"),
+    ('\u{104B}', "\u{104A}\u{104A}"),
+    ('\u{1065}', "\u{1041}"),
+    ('\u{1066}', "\u{1015}\u{103E}"),
+    ('\u{106F}', "\u{1015}\u{102C}\u{103E}"),
+    ('\u{1070}', "\u{1003}\u{103E}"),
+    ('\u{107E}', "\u{107D}\u{103E}"),
+    ('\u{1081}', "\u{1002}\u{103E}"),
+    ('\u{109E}', "\u{1083}\u{30A}"),
+    ('\u{10A0}', "\u{A786}"),
+    ('\u{10E7}', "\u{79}"),
+    ('\u{10F3}', "\u{21D}"),
+    ('\u{10FF}', "\u{6F}"),
+    ('\u{1101}', "\u{1100}\u{1100}"),
+    ('\u{1104}', "\u{1103}\u{1103}"),
+    ('\u{1108}', "\u{1107}\u{1107}"),
+    ('\u{110A}', "\u{1109}\u{1109}"),
+    ('\u{110D}', "\u{110C}\u{110C}"),
+    ('\u{1113}', "\u{1102}\u{1100}"),
+    ('\u{1114}', "\u{1102}\u{1102}"),
+    ('\u{1115}', "\u{1102}\u{1103}"),
+    ('\u{1116}', "\u{1102}\u{1107}"),
+    ('\u{1117}', "\u{1103}\u{1100}"),
+    ('\u{1118}', "\u{1105}\u{1102}"),
+    ('\u{1119}', "\u{1105}\u{1105}"),
+    ('\u{111A}', "\u{1105}\u{1112}"),
+    ('\u{111B}', "\u{1105}\u{110B}"),
+    ('\u{111C}', "\u{1106}\u{1107}"),
+    ('\u{111D}', "\u{1106}\u{110B}"),
+    ('\u{111E}', "\u{1107}\u{1100}"),
+    ('\u{111F}', "\u{1107}\u{1102}"),
+    ('\u{1120}', "\u{1107}\u{1103}"),
+    ('\u{1121}', "\u{1107}\u{1109}"),
+    ('\u{1122}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{1123}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{1124}', "\u{1107}\u{1109}\u{1107}"),
+    ('\u{1125}', "\u{1107}\u{1109}\u{1109}"),
+    ('\u{1126}', "\u{1107}\u{1109}\u{110C}"),
+    ('\u{1127}', "\u{1107}\u{110C}"),
+    ('\u{1128}', "\u{1107}\u{110E}"),
+    ('\u{1129}', "\u{1107}\u{1110}"),
+    ('\u{112A}', "\u{1107}\u{1111}"),
+    ('\u{112B}', "\u{1107}\u{110B}"),
+    ('\u{112C}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{112D}', "\u{1109}\u{1100}"),
+    ('\u{112E}', "\u{1109}\u{1102}"),
+    ('\u{112F}', "\u{1109}\u{1103}"),
+    ('\u{1130}', "\u{1109}\u{1105}"),
+    ('\u{1131}', "\u{1109}\u{1106}"),
+    ('\u{1132}', "\u{1109}\u{1107}"),
+    ('\u{1133}', "\u{1109}\u{1107}\u{1100}"),
+    ('\u{1134}', "\u{1109}\u{1109}\u{1109}"),
+    ('\u{1135}', "\u{1109}\u{110B}"),
+    ('\u{1136}', "\u{1109}\u{110C}"),
+    ('\u{1137}', "\u{1109}\u{110E}"),
+    ('\u{1138}', "\u{1109}\u{110F}"),
+    ('\u{1139}', "\u{1109}\u{1110}"),
+    ('\u{113A}', "\u{1109}\u{1111}"),
+    ('\u{113B}', "\u{1105}\u{1112}"),
+    ('\u{113D}', "\u{113C}\u{113C}"),
+    ('\u{113F}', "\u{113E}\u{113E}"),
+    ('\u{1141}', "\u{110B}\u{1100}"),
+    ('\u{1142}', "\u{110B}\u{1103}"),
+    ('\u{1143}', "\u{110B}\u{1106}"),
+    ('\u{1144}', "\u{110B}\u{1107}"),
+    ('\u{1145}', "\u{110B}\u{1109}"),
+    ('\u{1146}', "\u{110B}\u{1140}"),
+    ('\u{1147}', "\u{110B}\u{110B}"),
+    ('\u{1148}', "\u{110B}\u{110C}"),
+    ('\u{1149}', "\u{110B}\u{110E}"),
+    ('\u{114A}', "\u{110B}\u{1110}"),
+    ('\u{114B}', "\u{110B}\u{1111}"),
+    ('\u{114D}', "\u{110C}\u{110B}"),
+    ('\u{114F}', "\u{114E}\u{114E}"),
+    ('\u{1151}', "\u{1150}\u{1150}"),
+    ('\u{1152}', "\u{110E}\u{110F}"),
+    ('\u{1153}', "\u{110E}\u{1112}"),
+    ('\u{1156}', "\u{1111}\u{1107}"),
+    ('\u{1157}', "\u{1111}\u{110B}"),
+    ('\u{1158}', "\u{1112}\u{1112}"),
+    ('\u{115A}', "\u{1100}\u{1103}"),
+    ('\u{115B}', "\u{1102}\u{1109}"),
+    ('\u{115C}', "\u{1102}\u{110C}"),
+    ('\u{115D}', "\u{1102}\u{1112}"),
+    ('\u{115E}', "\u{1103}\u{1105}"),
+    ('\u{1162}', "\u{1161}\u{4E28}"),
+    ('\u{1164}', "\u{1163}\u{4E28}"),
+    ('\u{1166}', "\u{1165}\u{4E28}"),
+    ('\u{1168}', "\u{1167}\u{4E28}"),
+    ('\u{116A}', "\u{1169}\u{1161}"),
+    ('\u{116B}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{116C}', "\u{1169}\u{4E28}"),
+    ('\u{116F}', "\u{116E}\u{1165}"),
+    ('\u{1170}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{1171}', "\u{116E}\u{4E28}"),
+    ('\u{1173}', "\u{30FC}"),
+    ('\u{1174}', "\u{30FC}\u{4E28}"),
+    ('\u{1175}', "\u{4E28}"),
+    ('\u{1176}', "\u{1161}\u{1169}"),
+    ('\u{1177}', "\u{1161}\u{116E}"),
+    ('\u{1178}', "\u{1163}\u{1169}"),
+    ('\u{1179}', "\u{1163}\u{116D}"),
+    ('\u{117A}', "\u{1165}\u{1169}"),
+    ('\u{117B}', "\u{1165}\u{116E}"),
+    ('\u{117C}', "\u{1165}\u{30FC}"),
+    ('\u{117D}', "\u{1167}\u{1169}"),
+    ('\u{117E}', "\u{1167}\u{116E}"),
+    ('\u{117F}', "\u{1169}\u{1165}"),
+    ('\u{1180}', "\u{1169}\u{1165}\u{4E28}"),
+    ('\u{1181}', "\u{1169}\u{1167}\u{4E28}"),
+    ('\u{1182}', "\u{1169}\u{1169}"),
+    ('\u{1183}', "\u{1169}\u{116E}"),
+    ('\u{1184}', "\u{116D}\u{1163}"),
+    ('\u{1185}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{1186}', "\u{116D}\u{1163}"),
+    ('\u{1187}', "\u{116D}\u{1169}"),
+    ('\u{1188}', "\u{116D}\u{4E28}"),
+    ('\u{1189}', "\u{116E}\u{1161}"),
+    ('\u{118A}', "\u{116E}\u{1161}\u{4E28}"),
+    ('\u{118B}', "\u{116E}\u{1165}\u{30FC}"),
+    ('\u{118C}', "\u{116E}\u{1167}\u{4E28}"),
+    ('\u{118D}', "\u{116E}\u{116E}"),
+    ('\u{118E}', "\u{1172}\u{1161}"),
+    ('\u{118F}', "\u{1172}\u{1165}"),
+    ('\u{1190}', "\u{1172}\u{1165}\u{4E28}"),
+    ('\u{1191}', "\u{1172}\u{1167}"),
+    ('\u{1192}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{1193}', "\u{1172}\u{116E}"),
+    ('\u{1194}', "\u{1172}\u{4E28}"),
+    ('\u{1195}', "\u{30FC}\u{116E}"),
+    ('\u{1196}', "\u{30FC}\u{30FC}"),
+    ('\u{1197}', "\u{30FC}\u{4E28}\u{116E}"),
+    ('\u{1198}', "\u{4E28}\u{1161}"),
+    ('\u{1199}', "\u{4E28}\u{1163}"),
+    ('\u{119A}', "\u{4E28}\u{1169}"),
+    ('\u{119B}', "\u{4E28}\u{116E}"),
+    ('\u{119C}', "\u{4E28}\u{30FC}"),
+    ('\u{119D}', "\u{4E28}\u{119E}"),
+    ('\u{119F}', "\u{119E}\u{1165}"),
+    ('\u{11A0}', "\u{119E}\u{116E}"),
+    ('\u{11A1}', "\u{119E}\u{4E28}"),
+    ('\u{11A2}', "\u{119E}\u{119E}"),
+    ('\u{11A3}', "\u{1161}\u{30FC}"),
+    ('\u{11A4}', "\u{1163}\u{116E}"),
+    ('\u{11A5}', "\u{1167}\u{1163}"),
+    ('\u{11A6}', "\u{1169}\u{1163}"),
+    ('\u{11A7}', "\u{1169}\u{1163}\u{4E28}"),
+    ('\u{11A8}', "\u{1100}"),
+    ('\u{11A9}', "\u{1100}\u{1100}"),
+    ('\u{11AA}', "\u{1100}\u{1109}"),
+    ('\u{11AB}', "\u{1102}"),
+    ('\u{11AC}', "\u{1102}\u{110C}"),
+    ('\u{11AD}', "\u{1102}\u{1112}"),
+    ('\u{11AE}', "\u{1103}"),
+    ('\u{11AF}', "\u{1105}"),
+    ('\u{11B0}', "\u{1105}\u{1100}"),
+    ('\u{11B1}', "\u{1105}\u{1106}"),
+    ('\u{11B2}', "\u{1105}\u{1107}"),
+    ('\u{11B3}', "\u{1105}\u{1109}"),
+    ('\u{11B4}', "\u{1105}\u{1110}"),
+    ('\u{11B5}', "\u{1105}\u{1111}"),
+    ('\u{11B6}', "\u{1105}\u{1112}"),
+    ('\u{11B7}', "\u{1106}"),
+    ('\u{11B8}', "\u{1107}"),
+    ('\u{11B9}', "\u{1107}\u{1109}"),
+    ('\u{11BA}', "\u{1109}"),
+    ('\u{11BB}', "\u{1109}\u{1109}"),
+    ('\u{11BC}', "\u{110B}"),
+    ('\u{11BD}', "\u{110C}"),
+    ('\u{11BE}', "\u{110E}"),
+    ('\u{11BF}', "\u{110F}"),
+    ('\u{11C0}', "\u{1110}"),
+    ('\u{11C1}', "\u{1111}"),
+    ('\u{11C2}', "\u{1112}"),
+    ('\u{11C3}', "\u{1100}\u{1105}"),
+    ('\u{11C4}', "\u{1100}\u{1109}\u{1100}"),
+    ('\u{11C5}', "\u{1102}\u{1100}"),
+    ('\u{11C6}', "\u{1102}\u{1103}"),
+    ('\u{11C7}', "\u{1102}\u{1109}"),
+    ('\u{11C8}', "\u{1102}\u{1140}"),
+    ('\u{11C9}', "\u{1102}\u{1110}"),
+    ('\u{11CA}', "\u{1103}\u{1100}"),
+    ('\u{11CB}', "\u{1103}\u{1105}"),
+    ('\u{11CC}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{11CD}', "\u{1105}\u{1102}"),
+    ('\u{11CE}', "\u{1105}\u{1103}"),
+    ('\u{11CF}', "\u{1105}\u{1103}\u{1112}"),
+    ('\u{11D0}', "\u{1105}\u{1105}"),
+    ('\u{11D1}', "\u{1105}\u{1106}\u{1100}"),
+    ('\u{11D2}', "\u{1105}\u{1106}\u{1109}"),
+    ('\u{11D3}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{11D4}', "\u{1105}\u{1107}\u{1112}"),
+    ('\u{11D5}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{11D6}', "\u{1105}\u{1109}\u{1109}"),
+    ('\u{11D7}', "\u{1105}\u{1140}"),
+    ('\u{11D8}', "\u{1105}\u{110F}"),
+    ('\u{11D9}', "\u{1105}\u{1159}"),
+    ('\u{11DA}', "\u{1106}\u{1100}"),
+    ('\u{11DB}', "\u{1106}\u{1105}"),
+    ('\u{11DC}', "\u{1106}\u{1107}"),
+    ('\u{11DD}', "\u{1106}\u{1109}"),
+    ('\u{11DE}', "\u{1106}\u{1109}\u{1109}"),
+    ('\u{11DF}', "\u{1106}\u{1140}"),
+    ('\u{11E0}', "\u{1106}\u{110E}"),
+    ('\u{11E1}', "\u{1106}\u{1112}"),
+    ('\u{11E2}', "\u{1106}\u{110B}"),
+    ('\u{11E3}', "\u{1107}\u{1105}"),
+    ('\u{11E4}', "\u{1107}\u{1111}"),
+    ('\u{11E5}', "\u{1107}\u{1112}"),
+    ('\u{11E6}', "\u{1107}\u{110B}"),
+    ('\u{11E7}', "\u{1109}\u{1100}"),
+    ('\u{11E8}', "\u{1109}\u{1103}"),
+    ('\u{11E9}', "\u{1109}\u{1105}"),
+    ('\u{11EA}', "\u{1109}\u{1107}"),
+    ('\u{11EB}', "\u{1140}"),
+    ('\u{11EC}', "\u{110B}\u{1100}"),
+    ('\u{11ED}', "\u{110B}\u{1100}\u{1100}"),
+    ('\u{11EE}', "\u{110B}\u{110B}"),
+    ('\u{11EF}', "\u{110B}\u{110F}"),
+    ('\u{11F0}', "\u{114C}"),
+    ('\u{11F1}', "\u{110B}\u{1109}"),
+    ('\u{11F2}', "\u{110B}\u{1140}"),
+    ('\u{11F3}', "\u{1111}\u{1107}"),
+    ('\u{11F4}', "\u{1111}\u{110B}"),
+    ('\u{11F5}', "\u{1112}\u{1102}"),
+    ('\u{11F6}', "\u{1112}\u{1105}"),
+    ('\u{11F7}', "\u{1112}\u{1106}"),
+    ('\u{11F8}', "\u{1112}\u{1107}"),
+    ('\u{11F9}', "\u{1159}"),
+    ('\u{11FA}', "\u{1100}\u{1102}"),
+    ('\u{11FB}', "\u{1100}\u{1107}"),
+    ('\u{11FC}', "\u{1100}\u{110E}"),
+    ('\u{11FD}', "\u{1100}\u{110F}"),
+    ('\u{11FE}', "\u{1100}\u{1112}"),
+    ('\u{11FF}', "\u{1102}\u{1102}"),
+    ('\u{1200}', "\u{55}"),
+    ('\u{1223}', "\u{270}"),
+    ('\u{1240}', "\u{3A6}"),
+    ('\u{1260}', "\u{548}"),
+    ('\u{1294}', "\u{571}"),
+    ('\u{12D0}', "\u{4F}"),
+    ('\u{13A0}', "\u{44}"),
+    ('\u{13A1}', "\u{52}"),
+    ('\u{13A2}', "\u{54}"),
+    ('\u{13A4}', "\u{4F}\u{27}"),
+    ('\u{13A5}', "\u{69}"),
+    ('\u{13A8}', "\u{2C75}"),
+    ('\u{13A9}', "\u{59}"),
+    ('\u{13AA}', "\u{41}"),
+    ('\u{13AB}', "\u{4A}"),
+    ('\u{13AC}', "\u{45}"),
+    ('\u{13AE}', "\u{3F}"),
+    ('\u{13B0}', "\u{2C75}"),
+    ('\u{13B1}', "\u{393}"),
+    ('\u{13B3}', "\u{57}"),
+    ('\u{13B7}', "\u{4D}"),
+    ('\u{13BB}', "\u{48}"),
+    ('\u{13BD}', "\u{59}"),
+    ('\u{13BE}', "\u{4F}\u{335}"),
+    ('\u{13BF}', "\u{1AB}"),
+    ('\u{13C0}', "\u{47}"),
+    ('\u{13C2}', "\u{68}"),
+    ('\u{13C3}', "\u{5A}"),
+    ('\u{13C7}', "\u{460}"),
+    ('\u{13CB}', "\u{190}"),
+    ('\u{13CC}', "\u{55}\u{335}"),
+    ('\u{13CE}', "\u{34}"),
+    ('\u{13CF}', "\u{62}"),
+    ('\u{13D2}', "\u{52}"),
+    ('\u{13D4}', "\u{57}"),
+    ('\u{13D5}', "\u{53}"),
+    ('\u{13D9}', "\u{56}"),
+    ('\u{13DA}', "\u{53}"),
+    ('\u{13DE}', "\u{4C}"),
+    ('\u{13DF}', "\u{43}"),
+    ('\u{13E2}', "\u{50}"),
+    ('\u{13E6}', "\u{4B}"),
+    ('\u{13E7}', "\u{64}"),
+    ('\u{13EB}', "\u{4F}\u{335}"),
+    ('\u{13EE}', "\u{36}"),
+    ('\u{13F0}', "\u{DF}"),
+    ('\u{13F2}', "\u{68}\u{314}"),
+    ('\u{13F3}', "\u{47}"),
+    ('\u{13F4}', "\u{42}"),
+    ('\u{13FB}', "\u{262}"),
+    ('\u{13FC}', "\u{299}"),
+    ('\u{1400}', "\u{3D}"),
+    ('\u{1403}', "\u{394}"),
+    ('\u{140C}', "\u{B7}\u{1401}"),
+    ('\u{140D}', "\u{1401}\u{B7}"),
+    ('\u{140E}', "\u{B7}\u{394}"),
+    ('\u{140F}', "\u{394}\u{B7}"),
+    ('\u{1410}', "\u{B7}\u{1404}"),
+    ('\u{1411}', "\u{1404}\u{B7}"),
+    ('\u{1412}', "\u{B7}\u{1405}"),
+    ('\u{1413}', "\u{1405}\u{B7}"),
+    ('\u{1414}', "\u{B7}\u{1406}"),
+    ('\u{1415}', "\u{1406}\u{B7}"),
+    ('\u{1417}', "\u{B7}\u{140A}"),
+    ('\u{1418}', "\u{140A}\u{B7}"),
+    ('\u{1419}', "\u{B7}\u{140B}"),
+    ('\u{141A}', "\u{140B}\u{B7}"),
+    ('\u{1427}', "\u{B7}"),
+    ('\u{142B}', "\u{1401}\u{1420}"),
+    ('\u{142C}', "\u{394}\u{1420}"),
+    ('\u{142D}', "\u{1405}\u{1420}"),
+    ('\u{142E}', "\u{140A}\u{1420}"),
+    ('\u{142F}', "\u{56}"),
+    ('\u{1431}', "\u{245}"),
+    ('\u{1433}', "\u{3E}"),
+    ('\u{1437}', "\u{B7}\u{3E}"),
+    ('\u{1438}', "\u{3C}"),
+    ('\u{143A}', "\u{B7}\u{56}"),
+    ('\u{143B}', "\u{56}\u{B7}"),
+    ('\u{143C}', "\u{B7}\u{245}"),
+    ('\u{143D}', "\u{245}\u{B7}"),
+    ('\u{143E}', "\u{B7}\u{1432}"),
+    ('\u{143F}', "\u{1432}\u{B7}"),
+    ('\u{1440}', "\u{B7}\u{3E}"),
+    ('\u{1441}', "\u{3E}\u{B7}"),
+    ('\u{1442}', "\u{B7}\u{1434}"),
+    ('\u{1443}', "\u{1434}\u{B7}"),
+    ('\u{1444}', "\u{B7}\u{3C}"),
+    ('\u{1445}', "\u{3C}\u{B7}"),
+    ('\u{1446}', "\u{B7}\u{1439}"),
+    ('\u{1447}', "\u{1439}\u{B7}"),
+    ('\u{144A}', "\u{27}"),
+    ('\u{144C}', "\u{55}"),
+    ('\u{144E}', "\u{548}"),
+    ('\u{1454}', "\u{B7}\u{1450}"),
+    ('\u{1457}', "\u{B7}\u{55}"),
+    ('\u{1458}', "\u{55}\u{B7}"),
+    ('\u{1459}', "\u{B7}\u{548}"),
+    ('\u{145A}', "\u{548}\u{B7}"),
+    ('\u{145B}', "\u{B7}\u{144F}"),
+    ('\u{145C}', "\u{144F}\u{B7}"),
+    ('\u{145D}', "\u{B7}\u{1450}"),
+    ('\u{145E}', "\u{1450}\u{B7}"),
+    ('\u{145F}', "\u{B7}\u{1451}"),
+    ('\u{1460}', "\u{1451}\u{B7}"),
+    ('\u{1461}', "\u{B7}\u{1455}"),
+    ('\u{1462}', "\u{1455}\u{B7}"),
+    ('\u{1463}', "\u{B7}\u{1456}"),
+    ('\u{1464}', "\u{1456}\u{B7}"),
+    ('\u{1467}', "\u{55}\u{27}"),
+    ('\u{1468}', "\u{548}\u{27}"),
+    ('\u{1469}', "\u{1450}\u{27}"),
+    ('\u{146A}', "\u{1455}\u{27}"),
+    ('\u{146D}', "\u{50}"),
+    ('\u{146F}', "\u{64}"),
+    ('\u{1472}', "\u{62}"),
+    ('\u{1473}', "\u{62}\u{307}"),
+    ('\u{1474}', "\u{B7}\u{146B}"),
+    ('\u{1475}', "\u{146B}\u{B7}"),
+    ('\u{1476}', "\u{B7}\u{50}"),
+    ('\u{1477}', "\u{70}\u{B7}"),
+    ('\u{1478}', "\u{B7}\u{146E}"),
+    ('\u{1479}', "\u{146E}\u{B7}"),
+    ('\u{147A}', "\u{B7}\u{64}"),
+    ('\u{147B}', "\u{64}\u{B7}"),
+    ('\u{147C}', "\u{B7}\u{1470}"),
+    ('\u{147D}', "\u{1470}\u{B7}"),
+    ('\u{147E}', "\u{B7}\u{62}"),
+    ('\u{147F}', "\u{62}\u{B7}"),
+    ('\u{1480}', "\u{B7}\u{62}\u{307}"),
+    ('\u{1481}', "\u{62}\u{307}\u{B7}"),
+    ('\u{1485}', "\u{146B}\u{27}"),
+    ('\u{1486}', "\u{50}\u{27}"),
+    ('\u{1487}', "\u{64}\u{27}"),
+    ('\u{1488}', "\u{62}\u{27}"),
+    ('\u{148D}', "\u{4A}"),
+    ('\u{1492}', "\u{B7}\u{1489}"),
+    ('\u{1493}', "\u{1489}\u{B7}"),
+    ('\u{1494}', "\u{B7}\u{148B}"),
+    ('\u{1495}', "\u{148B}\u{B7}"),
+    ('\u{1496}', "\u{B7}\u{148C}"),
+    ('\u{1497}', "\u{148C}\u{B7}"),
+    ('\u{1498}', "\u{B7}\u{4A}"),
+    ('\u{1499}', "\u{4A}\u{B7}"),
+    ('\u{149A}', "\u{B7}\u{148E}"),
+    ('\u{149B}', "\u{148E}\u{B7}"),
+    ('\u{149C}', "\u{B7}\u{1490}"),
+    ('\u{149D}', "\u{1490}\u{B7}"),
+    ('\u{149E}', "\u{B7}\u{1491}"),
+    ('\u{149F}', "\u{1491}\u{B7}"),
+    ('\u{14A5}', "\u{393}"),
+    ('\u{14AA}', "\u{4C}"),
+    ('\u{14AC}', "\u{B7}\u{14A3}"),
+    ('\u{14AD}', "\u{14A3}\u{B7}"),
+    ('\u{14AE}', "\u{B7}\u{393}"),
+    ('\u{14AF}', "\u{393}\u{B7}"),
+    ('\u{14B0}', "\u{B7}\u{14A6}"),
+    ('\u{14B1}', "\u{14A6}\u{B7}"),
+    ('\u{14B2}', "\u{B7}\u{14A7}"),
+    ('\u{14B3}', "\u{14A7}\u{B7}"),
+    ('\u{14B4}', "\u{B7}\u{14A8}"),
+    ('\u{14B5}', "\u{14A8}\u{B7}"),
+    ('\u{14B6}', "\u{B7}\u{4C}"),
+    ('\u{14B7}', "\u{6C}\u{B7}"),
+    ('\u{14B8}', "\u{B7}\u{14AB}"),
+    ('\u{14B9}', "\u{14AB}\u{B7}"),
+    ('\u{14BF}', "\u{32}"),
+    ('\u{14C9}', "\u{B7}\u{14C0}"),
+    ('\u{14CA}', "\u{14C0}\u{B7}"),
+    ('\u{14CB}', "\u{B7}\u{14C7}"),
+    ('\u{14CC}', "\u{14C7}\u{B7}"),
+    ('\u{14CD}', "\u{B7}\u{14C8}"),
+    ('\u{14CE}', "\u{14C8}\u{B7}"),
+    ('\u{14D1}', "\u{1421}"),
+    ('\u{14DC}', "\u{B7}\u{14D3}"),
+    ('\u{14DD}', "\u{14D3}\u{B7}"),
+    ('\u{14DE}', "\u{B7}\u{14D5}"),
+    ('\u{14DF}', "\u{14D5}\u{B7}"),
+    ('\u{14E0}', "\u{B7}\u{14D6}"),
+    ('\u{14E1}', "\u{14D6}\u{B7}"),
+    ('\u{14E2}', "\u{B7}\u{14D7}"),
+    ('\u{14E3}', "\u{14D7}\u{B7}"),
+    ('\u{14E4}', "\u{B7}\u{14D8}"),
+    ('\u{14E5}', "\u{14D8}\u{B7}"),
+    ('\u{14E6}', "\u{B7}\u{14DA}"),
+    ('\u{14E7}', "\u{14DA}\u{B7}"),
+    ('\u{14E8}', "\u{B7}\u{14DB}"),
+    ('\u{14E9}', "\u{14DB}\u{B7}"),
+    ('\u{14F6}', "\u{B7}\u{14ED}"),
+    ('\u{14F7}', "\u{14ED}\u{B7}"),
+    ('\u{14F8}', "\u{B7}\u{14EF}"),
+    ('\u{14F9}', "\u{14EF}\u{B7}"),
+    ('\u{14FA}', "\u{B7}\u{14F0}"),
+    ('\u{14FB}', "\u{14F0}\u{B7}"),
+    ('\u{14FC}', "\u{B7}\u{14F1}"),
+    ('\u{14FD}', "\u{14F1}\u{B7}"),
+    ('\u{14FE}', "\u{B7}\u{14F2}"),
+    ('\u{14FF}', "\u{14F2}\u{B7}"),
+    ('\u{1500}', "\u{B7}\u{14F4}"),
+    ('\u{1501}', "\u{14F4}\u{B7}"),
+    ('\u{1502}', "\u{B7}\u{14F5}"),
+    ('\u{1503}', "\u{14F5}\u{B7}"),
+    ('\u{150C}', "\u{150B}\u{3C}"),
+    ('\u{150D}', "\u{150B}\u{1455}"),
+    ('\u{150E}', "\u{150B}\u{62}"),
+    ('\u{150F}', "\u{150B}\u{1490}"),
+    ('\u{1517}', "\u{B7}\u{1510}"),
+    ('\u{1518}', "\u{1510}\u{B7}"),
+    ('\u{1519}', "\u{B7}\u{1511}"),
+    ('\u{151A}', "\u{1511}\u{B7}"),
+    ('\u{151B}', "\u{B7}\u{1512}"),
+    ('\u{151C}', "\u{1512}\u{B7}"),
+    ('\u{151D}', "\u{B7}\u{1513}"),
+    ('\u{151E}', "\u{1513}\u{B7}"),
+    ('\u{151F}', "\u{B7}\u{1514}"),
+    ('\u{1520}', "\u{1514}\u{B7}"),
+    ('\u{1521}', "\u{B7}\u{1515}"),
+    ('\u{1522}', "\u{1515}\u{B7}"),
+    ('\u{1523}', "\u{B7}\u{1516}"),
+    ('\u{1524}', "\u{1516}\u{B7}"),
+    ('\u{152F}', "\u{B7}\u{34}"),
+    ('\u{1530}', "\u{34}\u{B7}"),
+    ('\u{1531}', "\u{B7}\u{1528}"),
+    ('\u{1532}', "\u{1528}\u{B7}"),
+    ('\u{1533}', "\u{B7}\u{1529}"),
+    ('\u{1534}', "\u{1529}\u{B7}"),
+    ('\u{1535}', "\u{B7}\u{152A}"),
+    ('\u{1536}', "\u{152A}\u{B7}"),
+    ('\u{1537}', "\u{B7}\u{152B}"),
+    ('\u{1538}', "\u{152B}\u{B7}"),
+    ('\u{1539}', "\u{B7}\u{152D}"),
+    ('\u{153A}', "\u{152D}\u{B7}"),
+    ('\u{153B}', "\u{B7}\u{152E}"),
+    ('\u{153C}', "\u{152E}\u{B7}"),
+    ('\u{1540}', "\u{1429}"),
+    ('\u{1541}', "\u{78}"),
+    ('\u{154E}', "\u{B7}\u{154C}"),
+    ('\u{154F}', "\u{154C}\u{B7}"),
+    ('\u{155B}', "\u{B7}\u{155A}"),
+    ('\u{155C}', "\u{155A}\u{B7}"),
+    ('\u{1568}', "\u{B7}\u{1567}"),
+    ('\u{1569}', "\u{1567}\u{B7}"),
+    ('\u{1577}', "\u{1E9F}"),
+    ('\u{157C}', "\u{48}"),
+    ('\u{157D}', "\u{78}"),
+    ('\u{157E}', "\u{1550}\u{146C}"),
+    ('\u{157F}', "\u{1550}\u{50}"),
+    ('\u{1580}', "\u{1550}\u{146E}"),
+    ('\u{1581}', "\u{1550}\u{64}"),
+    ('\u{1582}', "\u{1550}\u{1470}"),
+    ('\u{1583}', "\u{1550}\u{62}"),
+    ('\u{1584}', "\u{1550}\u{62}\u{307}"),
+    ('\u{1585}', "\u{1550}\u{1483}"),
+    ('\u{1587}', "\u{52}"),
+    ('\u{158E}', "\u{1595}\u{148A}"),
+    ('\u{158F}', "\u{1595}\u{148B}"),
+    ('\u{1590}', "\u{1595}\u{148C}"),
+    ('\u{1591}', "\u{1595}\u{4A}"),
+    ('\u{1592}', "\u{1595}\u{148E}"),
+    ('\u{1593}', "\u{1595}\u{1490}"),
+    ('\u{1594}', "\u{1595}\u{1491}"),
+    ('\u{15AF}', "\u{62}"),
+    ('\u{15B4}', "\u{46}"),
+    ('\u{15B5}', "\u{2132}"),
+    ('\u{15B7}', "\u{A7FB}"),
+    ('\u{15C4}', "\u{2C6F}"),
+    ('\u{15C5}', "\u{41}"),
+    ('\u{15DE}', "\u{44}"),
+    ('\u{15EA}', "\u{44}"),
+    ('\u{15EF}', "\u{460}"),
+    ('\u{15F0}', "\u{4D}"),
+    ('\u{15F7}', "\u{42}"),
+    ('\u{1602}', "\u{1490}"),
+    ('\u{1603}', "\u{1489}"),
+    ('\u{1604}', "\u{14D3}"),
+    ('\u{1607}', "\u{14DA}"),
+    ('\u{1622}', "\u{1543}"),
+    ('\u{1623}', "\u{1546}"),
+    ('\u{1624}', "\u{154A}"),
+    ('\u{162E}', "\u{1B1}"),
+    ('\u{162F}', "\u{3A9}"),
+    ('\u{1634}', "\u{1B1}"),
+    ('\u{1635}', "\u{3A9}"),
+    ('\u{166D}', "\u{58}"),
+    ('\u{166E}', "\u{78}"),
+    ('\u{166F}', "\u{1550}\u{146B}"),
+    ('\u{1670}', "\u{1595}\u{1489}"),
+    ('\u{1671}', "\u{1596}\u{148B}"),
+    ('\u{1672}', "\u{1596}\u{148C}"),
+    ('\u{1673}', "\u{1596}\u{4A}"),
+    ('\u{1674}', "\u{1596}\u{148E}"),
+    ('\u{1675}', "\u{1596}\u{1490}"),
+    ('\u{1676}', "\u{1596}\u{1491}"),
+    ('\u{1677}', "\u{15A7}\u{B7}"),
+    ('\u{1678}', "\u{15A8}\u{B7}"),
+    ('\u{1679}', "\u{15A9}\u{B7}"),
+    ('\u{167A}', "\u{15AA}\u{B7}"),
+    ('\u{167B}', "\u{15AB}\u{B7}"),
+    ('\u{167C}', "\u{15AC}\u{B7}"),
+    ('\u{167D}', "\u{15AD}\u{B7}"),
+    ('\u{1680}', "\u{20}"),
+    ('\u{16B2}', "\u{3C}"),
+    ('\u{16B7}', "\u{58}"),
+    ('\u{16C1}', "\u{6C}"),
+    ('\u{16C2}', "\u{16BD}"),
+    ('\u{16CC}', "\u{27}"),
+    ('\u{16D5}', "\u{4B}"),
+    ('\u{16D6}', "\u{4D}"),
+    ('\u{16D8}', "\u{3A8}"),
+    ('\u{16E1}', "\u{16BC}"),
+    ('\u{16EB}', "\u{B7}"),
+    ('\u{16EC}', "\u{3A}"),
+    ('\u{16ED}', "\u{2B}"),
+    ('\u{16F0}', "\u{3A6}"),
+    ('\u{1735}', "\u{2F}"),
+    ('\u{17A3}', "\u{17A2}"),
+    ('\u{17B7}', "\u{E34}"),
+    ('\u{17B8}', "\u{E35}"),
+    ('\u{17B9}', "\u{E36}"),
+    ('\u{17BA}', "\u{E37}"),
+    ('\u{17C6}', "\u{30A}"),
+    ('\u{17CB}', "\u{E48}"),
+    ('\u{17D3}', "\u{30A}"),
+    ('\u{17D4}', "\u{E2F}"),
+    ('\u{17D5}', "\u{E5A}"),
+    ('\u{17D9}', "\u{E4F}"),
+    ('\u{17DA}', "\u{E5B}"),
+    ('\u{1803}', "\u{3A}"),
+    ('\u{1809}', "\u{3A}"),
+    ('\u{1855}', "\u{1835}"),
+    ('\u{1896}', "\u{185C}"),
+    ('\u{18B3}', "\u{B7}\u{18B1}"),
+    ('\u{18B6}', "\u{B7}\u{18B4}"),
+    ('\u{18B9}', "\u{B7}\u{18B8}"),
+    ('\u{18C2}', "\u{B7}\u{18C0}"),
+    ('\u{18C6}', "\u{B7}\u{14C2}"),
+    ('\u{18C7}', "\u{14C2}\u{B7}"),
+    ('\u{18C8}', "\u{B7}\u{14C3}"),
+    ('\u{18C9}', "\u{14C3}\u{B7}"),
+    ('\u{18CA}', "\u{B7}\u{14C4}"),
+    ('\u{18CB}', "\u{14C4}\u{B7}"),
+    ('\u{18CC}', "\u{B7}\u{14C5}"),
+    ('\u{18CD}', "\u{14C5}\u{B7}"),
+    ('\u{18CE}', "\u{B7}\u{1543}"),
+    ('\u{18CF}', "\u{B7}\u{1546}"),
+    ('\u{18D0}', "\u{B7}\u{1547}"),
+    ('\u{18D1}', "\u{B7}\u{1548}"),
+    ('\u{18D2}', "\u{B7}\u{1549}"),
+    ('\u{18D3}', "\u{B7}\u{154B}"),
+    ('\u{18DB}', "\u{18F5}"),
+    ('\u{18DC}', "\u{18DF}\u{141E}"),
+    ('\u{18DD}', "\u{141E}\u{18DF}"),
+    ('\u{18E0}', "\u{1543}\u{B7}"),
+    ('\u{18E3}', "\u{155E}\u{B7}"),
+    ('\u{18E4}', "\u{1566}\u{B7}"),
+    ('\u{18E5}', "\u{156B}\u{B7}"),
+    ('\u{18E8}', "\u{1586}\u{B7}"),
+    ('\u{18EA}', "\u{1597}\u{B7}"),
+    ('\u{18ED}', "\u{460}\u{B7}"),
+    ('\u{18F0}', "\u{15F4}\u{B7}"),
+    ('\u{18F2}', "\u{161B}\u{B7}"),
+    ('\u{19D0}', "\u{199E}"),
+    ('\u{19D1}', "\u{19B1}"),
+    ('\u{1A80}', "\u{1A45}"),
+    ('\u{1A90}', "\u{1A45}"),
+    ('\u{1AA9}', "\u{1AA8}\u{1AA8}"),
+    ('\u{1AAB}', "\u{1AAA}\u{1AA8}"),
+    ('\u{1AB4}', "\u{6DB}"),
+    ('\u{1AB7}', "\u{328}"),
+    ('\u{1B52}', "\u{1B0D}"),
+    ('\u{1B53}', "\u{1B11}"),
+    ('\u{1B58}', "\u{1B28}"),
+    ('\u{1B5C}', "\u{1B50}"),
+    ('\u{1B5F}', "\u{1B5E}\u{1B5E}"),
+    ('\u{1C3C}', "\u{1C3B}\u{1C3B}"),
+    ('\u{1C7F}', "\u{1C7E}\u{1C7E}"),
+    ('\u{1CD0}', "\u{302}"),
+    ('\u{1CD2}', "\u{304}"),
+    ('\u{1CD3}', "\u{27}\u{27}"),
+    ('\u{1CD5}', "\u{32B}"),
+    ('\u{1CD8}', "\u{32E}"),
+    ('\u{1CD9}', "\u{32D}"),
+    ('\u{1CDA}', "\u{30E}"),
+    ('\u{1CDC}', "\u{329}"),
+    ('\u{1CDD}', "\u{323}"),
+    ('\u{1CDE}', "\u{324}"),
+    ('\u{1CED}', "\u{316}"),
+    ('\u{1D04}', "\u{63}"),
+    ('\u{1D08}', "\u{25C}"),
+    ('\u{1D0B}', "\u{138}"),
+    ('\u{1D0D}', "\u{28D}"),
+    ('\u{1D0F}', "\u{6F}"),
+    ('\u{1D10}', "\u{254}"),
+    ('\u{1D11}', "\u{6F}"),
+    ('\u{1D14}', "\u{1DD}\u{6F}"),
+    ('\u{1D1C}', "\u{75}"),
+    ('\u{1D20}', "\u{76}"),
+    ('\u{1D21}', "\u{77}"),
+    ('\u{1D22}', "\u{7A}"),
+    ('\u{1D24}', "\u{1A8}"),
+    ('\u{1D26}', "\u{72}"),
+    ('\u{1D27}', "\u{28C}"),
+    ('\u{1D28}', "\u{3C0}"),
+    ('\u{1D29}', "\u{1D18}"),
+    ('\u{1D2B}', "\u{43B}"),
+    ('\u{1D3E}', "\u{18D6}"),
+    ('\u{1D52}', "\u{BA}"),
+    ('\u{1D6B}', "\u{75}\u{65}"),
+    ('\u{1D6E}', "\u{66}\u{334}"),
+    ('\u{1D6F}', "\u{72}\u{6E}\u{334}"),
+    ('\u{1D70}', "\u{6E}\u{334}"),
+    ('\u{1D72}', "\u{72}\u{334}"),
+    ('\u{1D73}', "\u{27E}\u{334}"),
+    ('\u{1D74}', "\u{73}\u{334}"),
+    ('\u{1D75}', "\u{74}\u{334}"),
+    ('\u{1D76}', "\u{7A}\u{334}"),
+    ('\u{1D78}', "\u{1D34}"),
+    ('\u{1D7B}', "\u{69}\u{335}"),
+    ('\u{1D7C}', "\u{69}\u{335}"),
+    ('\u{1D7D}', "\u{70}\u{335}"),
+    ('\u{1D7E}', "\u{75}\u{335}"),
+    ('\u{1D7F}', "\u{28A}\u{335}"),
+    ('\u{1D83}', "\u{67}"),
+    ('\u{1D8C}', "\u{79}"),
+    ('\u{1D90}', "\u{24B}"),
+    ('\u{1D9F}', "\u{1D4B}"),
+    ('\u{1DA2}', "\u{1D4D}"),
+    ('\u{1DBA}', "\u{18D4}"),
+    ('\u{1DBB}', "\u{1646}"),
+    ('\u{1DEE}', "\u{2DEC}"),
+    ('\u{1E9A}', "\u{61}\u{309}"),
+    ('\u{1E9D}', "\u{66}"),
+    ('\u{1EFF}', "\u{79}"),
+    ('\u{1FBD}', "\u{27}"),
+    ('\u{1FBF}', "\u{27}"),
+    ('\u{1FC0}', "\u{7E}"),
+    ('\u{1FFE}', "\u{27}"),
+    ('\u{2002}', "\u{20}"),
+    ('\u{2003}', "\u{20}"),
+    ('\u{2004}', "\u{20}"),
+    ('\u{2005}', "\u{20}"),
+    ('\u{2006}', "\u{20}"),
+    ('\u{2007}', "\u{20}"),
+    ('\u{2008}', "\u{20}"),
+    ('\u{2009}', "\u{20}"),
+    ('\u{200A}', "\u{20}"),
+    ('\u{2010}', "\u{2D}"),
+    ('\u{2011}', "\u{2D}"),
+    ('\u{2012}', "\u{2D}"),
+    ('\u{2013}', "\u{2D}"),
+    ('\u{2014}', "\u{30FC}"),
+    ('\u{2015}', "\u{30FC}"),
+    ('\u{2016}', "\u{6C}\u{6C}"),
+    ('\u{2018}', "\u{27}"),
+    ('\u{2019}', "\u{27}"),
+    ('\u{201A}', "\u{2C}"),
+    ('\u{201B}', "\u{27}"),
+    ('\u{201C}', "\u{27}\u{27}"),
+    ('\u{201D}', "\u{27}\u{27}"),
+    ('\u{201F}', "\u{27}\u{27}"),
+    ('\u{2022}', "\u{B7}"),
+    ('\u{2024}', "\u{2E}"),
+    ('\u{2025}', "\u{2E}\u{2E}"),
+    ('\u{2026}', "\u{2E}\u{2E}\u{2E}"),
+    ('\u{2027}', "\u{B7}"),
+    ('\u{2028}', "\u{20}"),
+    ('\u{2029}', "\u{20}"),
+    ('\u{202F}', "\u{20}"),
+    ('\u{2030}', "\u{BA}\u{2F}\u{2080}\u{2080}"),
+    ('\u{2031}', "\u{BA}\u{2F}\u{2080}\u{2080}\u{2080}"),
+    ('\u{2032}', "\u{27}"),
+    ('\u{2033}', "\u{27}\u{27}"),
+    ('\u{2034}', "\u{27}\u{27}\u{27}"),
+    ('\u{2035}', "\u{27}"),
+    ('\u{2036}', "\u{27}\u{27}"),
+    ('\u{2037}', "\u{27}\u{27}\u{27}"),
+    ('\u{2039}', "\u{3C}"),
+    ('\u{203A}', "\u{3E}"),
+    ('\u{203C}', "\u{21}\u{21}"),
+    ('\u{203E}', "\u{2C9}"),
+    ('\u{2041}', "\u{2F}"),
+    ('\u{2043}', "\u{2D}"),
+    ('\u{2044}', "\u{2F}"),
+    ('\u{2047}', "\u{3F}\u{3F}"),
+    ('\u{2048}', "\u{3F}\u{21}"),
+    ('\u{2049}', "\u{21}\u{3F}"),
+    ('\u{204E}', "\u{2A}"),
+    ('\u{2052}', "\u{BA}\u{2F}\u{2080}"),
+    ('\u{2053}', "\u{7E}"),
+    ('\u{2057}', "\u{27}\u{27}\u{27}\u{27}"),
+    ('\u{205A}', "\u{3A}"),
+    ('\u{205D}', "\u{2D57}"),
+    ('\u{205E}', "\u{2D42}"),
+    ('\u{205F}', "\u{20}"),
+    ('\u{2070}', "\u{BA}"),
+    ('\u{2079}', "\u{A770}"),
+    ('\u{20A1}', "\u{43}\u{20EB}"),
+    ('\u{20A4}', "\u{A3}"),
+    ('\u{20A5}', "\u{72}\u{6E}\u{338}"),
+    ('\u{20A8}', "\u{52}\u{73}"),
+    ('\u{20A9}', "\u{57}\u{335}"),
+    ('\u{20AB}', "\u{64}\u{335}\u{331}"),
+    ('\u{20AC}', "\u{A792}"),
+    ('\u{20AD}', "\u{4B}\u{335}"),
+    ('\u{20AE}', "\u{54}\u{20EB}"),
+    ('\u{20B6}', "\u{6C}\u{74}"),
+    ('\u{20BD}', "\u{554}"),
+    ('\u{20DB}', "\u{6DB}"),
+    ('\u{2100}', "\u{61}\u{2F}\u{63}"),
+    ('\u{2101}', "\u{61}\u{2F}\u{73}"),
+    ('\u{2102}', "\u{43}"),
+    ('\u{2103}', "\u{B0}\u{43}"),
+    ('\u{2105}', "\u{63}\u{2F}\u{6F}"),
+    ('\u{2106}', "\u{63}\u{2F}\u{75}"),
+    ('\u{2107}', "\u{190}"),
+    ('\u{2108}', "\u{42D}"),
+    ('\u{2109}', "\u{B0}\u{46}"),
+    ('\u{210A}', "\u{67}"),
+    ('\u{210B}', "\u{48}"),
+    ('\u{210C}', "\u{48}"),
+    ('\u{210D}', "\u{48}"),
+    ('\u{210E}', "\u{68}"),
+    ('\u{210F}', "\u{68}\u{335}"),
+    ('\u{2110}', "\u{6C}"),
+    ('\u{2111}', "\u{6C}"),
+    ('\u{2112}', "\u{4C}"),
+    ('\u{2113}', "\u{6C}"),
+    ('\u{2115}', "\u{4E}"),
+    ('\u{2116}', "\u{4E}\u{6F}"),
+    ('\u{2119}', "\u{50}"),
+    ('\u{211A}', "\u{51}"),
+    ('\u{211B}', "\u{52}"),
+    ('\u{211C}', "\u{52}"),
+    ('\u{211D}', "\u{52}"),
+    ('\u{2121}', "\u{54}\u{45}\u{4C}"),
+    ('\u{2124}', "\u{5A}"),
+    ('\u{2127}', "\u{1B1}"),
+    ('\u{2128}', "\u{5A}"),
+    ('\u{2129}', "\u{27F}"),
+    ('\u{212C}', "\u{42}"),
+    ('\u{212D}', "\u{43}"),
+    ('\u{212E}', "\u{65}"),
+    ('\u{212F}', "\u{65}"),
+    ('\u{2130}', "\u{45}"),
+    ('\u{2131}', "\u{46}"),
+    ('\u{2133}', "\u{4D}"),
+    ('\u{2134}', "\u{6F}"),
+    ('\u{2135}', "\u{5D0}"),
+    ('\u{2136}', "\u{5D1}"),
+    ('\u{2137}', "\u{5D2}"),
+    ('\u{2138}', "\u{5D3}"),
+    ('\u{2139}', "\u{69}"),
+    ('\u{213B}', "\u{46}\u{41}\u{58}"),
+    ('\u{213C}', "\u{3C0}"),
+    ('\u{213D}', "\u{79}"),
+    ('\u{213E}', "\u{393}"),
+    ('\u{213F}', "\u{3A0}"),
+    ('\u{2140}', "\u{1A9}"),
+    ('\u{2141}', "\u{A4E8}"),
+    ('\u{2142}', "\u{A4F6}"),
+    ('\u{2143}', "\u{16F00}"),
+    ('\u{2145}', "\u{44}"),
+    ('\u{2146}', "\u{64}"),
+    ('\u{2147}', "\u{65}"),
+    ('\u{2148}', "\u{69}"),
+    ('\u{2149}', "\u{6A}"),
+    ('\u{2160}', "\u{6C}"),
+    ('\u{2161}', "\u{6C}\u{6C}"),
+    ('\u{2162}', "\u{6C}\u{6C}\u{6C}"),
+    ('\u{2163}', "\u{6C}\u{56}"),
+    ('\u{2164}', "\u{56}"),
+    ('\u{2165}', "\u{56}\u{6C}"),
+    ('\u{2166}', "\u{56}\u{6C}\u{6C}"),
+    ('\u{2167}', "\u{56}\u{6C}\u{6C}\u{6C}"),
+    ('\u{2168}', "\u{6C}\u{58}"),
+    ('\u{2169}', "\u{58}"),
+    ('\u{216A}', "\u{58}\u{6C}"),
+    ('\u{216B}', "\u{58}\u{6C}\u{6C}"),
+    ('\u{216C}', "\u{4C}"),
+    ('\u{216D}', "\u{43}"),
+    ('\u{216E}', "\u{44}"),
+    ('\u{216F}', "\u{4D}"),
+    ('\u{2170}', "\u{69}"),
+    ('\u{2171}', "\u{69}\u{69}"),
+    ('\u{2172}', "\u{69}\u{69}\u{69}"),
+    ('\u{2173}', "\u{69}\u{76}"),
+    ('\u{2174}', "\u{76}"),
+    ('\u{2175}', "\u{76}\u{69}"),
+    ('\u{2176}', "\u{76}\u{69}\u{69}"),
+    ('\u{2177}', "\u{76}\u{69}\u{69}\u{69}"),
+    ('\u{2178}', "\u{69}\u{78}"),
+    ('\u{2179}', "\u{78}"),
+    ('\u{217A}', "\u{78}\u{69}"),
+    ('\u{217B}', "\u{78}\u{69}\u{69}"),
+    ('\u{217C}', "\u{6C}"),
+    ('\u{217D}', "\u{63}"),
+    ('\u{217E}', "\u{64}"),
+    ('\u{217F}', "\u{72}\u{6E}"),
+    ('\u{2183}', "\u{186}"),
+    ('\u{2184}', "\u{254}"),
+    ('\u{2191}', "\u{16CF}"),
+    ('\u{2195}', "\u{16E8}"),
+    ('\u{21B5}', "\u{21B2}"),
+    ('\u{21BA}', "\u{1F10E}"),
+    ('\u{21BE}', "\u{16DA}"),
+    ('\u{21BF}', "\u{16D0}"),
+    ('\u{2200}', "\u{2C6F}"),
+    ('\u{2203}', "\u{18E}"),
+    ('\u{2206}', "\u{394}"),
+    ('\u{220F}', "\u{3A0}"),
+    ('\u{2211}', "\u{1A9}"),
+    ('\u{2212}', "\u{2D}"),
+    ('\u{2214}', "\u{2B}\u{307}"),
+    ('\u{2215}', "\u{2F}"),
+    ('\u{2216}', "\u{5C}"),
+    ('\u{2217}', "\u{2A}"),
+    ('\u{2218}', "\u{B0}"),
+    ('\u{2219}', "\u{B7}"),
+    ('\u{221E}', "\u{6F}\u{6F}"),
+    ('\u{2223}', "\u{6C}"),
+    ('\u{2225}', "\u{6C}\u{6C}"),
+    ('\u{2228}', "\u{76}"),
+    ('\u{2229}', "\u{548}"),
+    ('\u{222A}', "\u{55}"),
+    ('\u{222B}', "\u{283}"),
+    ('\u{222C}', "\u{283}\u{283}"),
+    ('\u{222D}', "\u{283}\u{283}\u{283}"),
+    ('\u{222F}', "\u{222E}\u{222E}"),
+    ('\u{2230}', "\u{222E}\u{222E}\u{222E}"),
+    ('\u{2236}', "\u{3A}"),
+    ('\u{2238}', "\u{2D}\u{307}"),
+    ('\u{223C}', "\u{7E}"),
+    ('\u{2250}', "\u{3D}\u{307}"),
+    ('\u{2251}', "\u{3D}\u{323}\u{307}"),
+    ('\u{2257}', "\u{3D}\u{30A}"),
+    ('\u{2259}', "\u{3D}\u{302}"),
+    ('\u{225A}', "\u{3D}\u{306}"),
+    ('\u{225E}', "\u{3D}\u{36B}"),
+    ('\u{2263}', "\u{2261}"),
+    ('\u{226A}', "\u{3C}\u{3C}"),
+    ('\u{226B}', "\u{3E}\u{3E}"),
+    ('\u{2282}', "\u{1455}"),
+    ('\u{2283}', "\u{1450}"),
+    ('\u{2295}', "\u{102A8}"),
+    ('\u{2296}', "\u{4F}\u{335}"),
+    ('\u{2299}', "\u{298}"),
+    ('\u{229D}', "\u{4F}\u{335}"),
+    ('\u{22A4}', "\u{54}"),
+    ('\u{22A5}', "\u{A4D5}"),
+    ('\u{22C0}', "\u{2227}"),
+    ('\u{22C1}', "\u{76}"),
+    ('\u{22C2}', "\u{548}"),
+    ('\u{22C3}', "\u{55}"),
+    ('\u{22C4}', "\u{16DC}"),
+    ('\u{22C5}', "\u{B7}"),
+    ('\u{22C8}', "\u{16DE}"),
+    ('\u{22D6}', "\u{3C}\u{B7}"),
+    ('\u{22D7}', "\u{B7}\u{3E}"),
+    ('\u{22D8}', "\u{3C}\u{3C}\u{3C}"),
+    ('\u{22D9}', "\u{3E}\u{3E}\u{3E}"),
+    ('\u{22EE}', "\u{2D57}"),
+    ('\u{22EF}', "\u{B7}\u{B7}\u{B7}"),
+    ('\u{22F4}', "\u{A793}"),
+    ('\u{22FF}', "\u{45}"),
+    ('\u{2300}', "\u{2205}"),
+    ('\u{2325}', "\u{2324}"),
+    ('\u{2341}', "\u{303C}"),
+    ('\u{2359}', "\u{394}\u{332}"),
+    ('\u{235A}', "\u{16DC}\u{332}"),
+    ('\u{235C}', "\u{B0}\u{332}"),
+    ('\u{235F}', "\u{229B}"),
+    ('\u{2361}', "\u{54}\u{308}"),
+    ('\u{2362}', "\u{2207}\u{308}"),
+    ('\u{2363}', "\u{22C6}\u{308}"),
+    ('\u{2364}', "\u{B0}\u{308}"),
+    ('\u{2365}', "\u{629}"),
+    ('\u{2368}', "\u{7E}\u{308}"),
+    ('\u{2369}', "\u{1435}"),
+    ('\u{236B}', "\u{2207}\u{334}"),
+    ('\u{236C}', "\u{4F}\u{335}"),
+    ('\u{2373}', "\u{69}"),
+    ('\u{2374}', "\u{70}"),
+    ('\u{2375}', "\u{3C9}"),
+    ('\u{2376}', "\u{61}\u{332}"),
+    ('\u{2377}', "\u{A793}\u{332}"),
+    ('\u{2378}', "\u{69}\u{332}"),
+    ('\u{2379}', "\u{3C9}\u{332}"),
+    ('\u{237A}', "\u{61}"),
+    ('\u{237F}', "\u{16BD}"),
+    ('\u{239C}', "\u{4E28}"),
+    ('\u{239F}', "\u{4E28}"),
+    ('\u{23A2}', "\u{4E28}"),
+    ('\u{23A5}', "\u{4E28}"),
+    ('\u{23AA}', "\u{4E28}"),
+    ('\u{23AE}', "\u{4E28}"),
+    ('\u{23C1}', "\u{2355}"),
+    ('\u{23C2}', "\u{234E}"),
+    ('\u{23C3}', "\u{234B}"),
+    ('\u{23C6}', "\u{236D}"),
+    ('\u{23E8}', "\u{2081}\u{2080}"),
+    ('\u{23FC}', "\u{23FB}"),
+    ('\u{23FD}', "\u{6C}"),
+    ('\u{23FE}', "\u{263E}"),
+    ('\u{244A}', "\u{5C}\u{5C}"),
+    ('\u{2460}', "\u{2780}"),
+    ('\u{2461}', "\u{2781}"),
+    ('\u{2462}', "\u{2782}"),
+    ('\u{2463}', "\u{2783}"),
+    ('\u{2464}', "\u{2784}"),
+    ('\u{2465}', "\u{2785}"),
+    ('\u{2466}', "\u{2786}"),
+    ('\u{2467}', "\u{2787}"),
+    ('\u{2468}', "\u{2788}"),
+    ('\u{2469}', "\u{2789}"),
+    ('\u{2474}', "\u{28}\u{6C}\u{29}"),
+    ('\u{2475}', "\u{28}\u{32}\u{29}"),
+    ('\u{2476}', "\u{28}\u{33}\u{29}"),
+    ('\u{2477}', "\u{28}\u{34}\u{29}"),
+    ('\u{2478}', "\u{28}\u{35}\u{29}"),
+    ('\u{2479}', "\u{28}\u{36}\u{29}"),
+    ('\u{247A}', "\u{28}\u{37}\u{29}"),
+    ('\u{247B}', "\u{28}\u{38}\u{29}"),
+    ('\u{247C}', "\u{28}\u{39}\u{29}"),
+    ('\u{247D}', "\u{28}\u{6C}\u{4F}\u{29}"),
+    ('\u{247E}', "\u{28}\u{6C}\u{6C}\u{29}"),
+    ('\u{247F}', "\u{28}\u{6C}\u{32}\u{29}"),
+    ('\u{2480}', "\u{28}\u{6C}\u{33}\u{29}"),
+    ('\u{2481}', "\u{28}\u{6C}\u{34}\u{29}"),
+    ('\u{2482}', "\u{28}\u{6C}\u{35}\u{29}"),
+    ('\u{2483}', "\u{28}\u{6C}\u{36}\u{29}"),
+    ('\u{2484}', "\u{28}\u{6C}\u{37}\u{29}"),
+    ('\u{2485}', "\u{28}\u{6C}\u{38}\u{29}"),
+    ('\u{2486}', "\u{28}\u{6C}\u{39}\u{29}"),
+    ('\u{2487}', "\u{28}\u{32}\u{4F}\u{29}"),
+    ('\u{2488}', "\u{6C}\u{2E}"),
+    ('\u{2489}', "\u{32}\u{2E}"),
+    ('\u{248A}', "\u{33}\u{2E}"),
+    ('\u{248B}', "\u{34}\u{2E}"),
+    ('\u{248C}', "\u{35}\u{2E}"),
+    ('\u{248D}', "\u{36}\u{2E}"),
+    ('\u{248E}', "\u{37}\u{2E}"),
+    ('\u{248F}', "\u{38}\u{2E}"),
+    ('\u{2490}', "\u{39}\u{2E}"),
+    ('\u{2491}', "\u{6C}\u{4F}\u{2E}"),
+    ('\u{2492}', "\u{6C}\u{6C}\u{2E}"),
+    ('\u{2493}', "\u{6C}\u{32}\u{2E}"),
+    ('\u{2494}', "\u{6C}\u{33}\u{2E}"),
+    ('\u{2495}', "\u{6C}\u{34}\u{2E}"),
+    ('\u{2496}', "\u{6C}\u{35}\u{2E}"),
+    ('\u{2497}', "\u{6C}\u{36}\u{2E}"),
+    ('\u{2498}', "\u{6C}\u{37}\u{2E}"),
+    ('\u{2499}', "\u{6C}\u{38}\u{2E}"),
+    ('\u{249A}', "\u{6C}\u{39}\u{2E}"),
+    ('\u{249B}', "\u{32}\u{4F}\u{2E}"),
+    ('\u{249C}', "\u{28}\u{61}\u{29}"),
+    ('\u{249D}', "\u{28}\u{62}\u{29}"),
+    ('\u{249E}', "\u{28}\u{63}\u{29}"),
+    ('\u{249F}', "\u{28}\u{64}\u{29}"),
+    ('\u{24A0}', "\u{28}\u{65}\u{29}"),
+    ('\u{24A1}', "\u{28}\u{66}\u{29}"),
+    ('\u{24A2}', "\u{28}\u{67}\u{29}"),
+    ('\u{24A3}', "\u{28}\u{68}\u{29}"),
+    ('\u{24A4}', "\u{28}\u{69}\u{29}"),
+    ('\u{24A5}', "\u{28}\u{6A}\u{29}"),
+    ('\u{24A6}', "\u{28}\u{6B}\u{29}"),
+    ('\u{24A7}', "\u{28}\u{6C}\u{29}"),
+    ('\u{24A8}', "\u{28}\u{72}\u{6E}\u{29}"),
+    ('\u{24A9}', "\u{28}\u{6E}\u{29}"),
+    ('\u{24AA}', "\u{28}\u{6F}\u{29}"),
+    ('\u{24AB}', "\u{28}\u{70}\u{29}"),
+    ('\u{24AC}', "\u{28}\u{71}\u{29}"),
+    ('\u{24AD}', "\u{28}\u{72}\u{29}"),
+    ('\u{24AE}', "\u{28}\u{73}\u{29}"),
+    ('\u{24AF}', "\u{28}\u{74}\u{29}"),
+    ('\u{24B0}', "\u{28}\u{75}\u{29}"),
+    ('\u{24B1}', "\u{28}\u{76}\u{29}"),
+    ('\u{24B2}', "\u{28}\u{77}\u{29}"),
+    ('\u{24B3}', "\u{28}\u{78}\u{29}"),
+    ('\u{24B4}', "\u{28}\u{79}\u{29}"),
+    ('\u{24B5}', "\u{28}\u{7A}\u{29}"),
+    ('\u{24B8}', "\u{A9}"),
+    ('\u{24C5}', "\u{2117}"),
+    ('\u{24C7}', "\u{AE}"),
+    ('\u{24DB}', "\u{24BE}"),
+    ('\u{24EA}', "\u{1F10D}"),
+    ('\u{2500}', "\u{30FC}"),
+    ('\u{2501}', "\u{30FC}"),
+    ('\u{2503}', "\u{2502}"),
+    ('\u{250F}', "\u{250C}"),
+    ('\u{2523}', "\u{251C}"),
+    ('\u{2571}', "\u{2F}"),
+    ('\u{2573}', "\u{58}"),
+    ('\u{2588}', "\u{220E}"),
+    ('\u{2590}', "\u{258C}"),
+    ('\u{2594}', "\u{2C9}"),
+    ('\u{2597}', "\u{2596}"),
+    ('\u{259D}', "\u{2598}"),
+    ('\u{25A0}', "\u{220E}"),
+    ('\u{25B1}', "\u{23E5}"),
+    ('\u{25B3}', "\u{394}"),
+    ('\u{25B7}', "\u{22B3}"),
+    ('\u{25B8}', "\u{25B6}"),
+    ('\u{25BA}', "\u{25B6}"),
+    ('\u{25BD}', "\u{102BC}"),
+    ('\u{25C1}', "\u{22B2}"),
+    ('\u{25C7}', "\u{16DC}"),
+    ('\u{25CA}', "\u{16DC}"),
+    ('\u{25CB}', "\u{B0}"),
+    ('\u{25CE}', "\u{233E}"),
+    ('\u{25E0}', "\u{2312}"),
+    ('\u{25E6}', "\u{B0}"),
+    ('\u{2609}', "\u{298}"),
+    ('\u{2610}', "\u{25A1}"),
+    ('\u{2625}', "\u{1099E}"),
+    ('\u{2630}', "\u{2CB6}"),
+    ('\u{2638}', "\u{2388}"),
+    ('\u{264E}', "\u{224F}"),
+    ('\u{2662}', "\u{16DC}"),
+    ('\u{2669}', "\u{1D158}\u{1D165}"),
+    ('\u{266A}', "\u{1D158}\u{1D165}\u{1D16E}"),
+    ('\u{26AC}', "\u{970}"),
+    ('\u{2768}', "\u{28}"),
+    ('\u{2769}', "\u{29}"),
+    ('\u{276E}', "\u{3C}"),
+    ('\u{276F}', "\u{3E}"),
+    ('\u{2772}', "\u{28}"),
+    ('\u{2773}', "\u{29}"),
+    ('\u{2774}', "\u{7B}"),
+    ('\u{2775}', "\u{7D}"),
+    ('\u{2795}', "\u{2B}"),
+    ('\u{2796}', "\u{2D}"),
+    ('\u{2797}', "\u{F7}"),
+    ('\u{27C2}', "\u{A4D5}"),
+    ('\u{27C8}', "\u{5C}\u{1455}"),
+    ('\u{27C9}', "\u{1450}\u{2F}"),
+    ('\u{27CB}', "\u{2F}"),
+    ('\u{27CD}', "\u{5C}"),
+    ('\u{27D9}', "\u{54}"),
+    ('\u{27E8}', "\u{276C}"),
+    ('\u{27E9}', "\u{276D}"),
+    ('\u{292B}', "\u{78}"),
+    ('\u{292C}', "\u{78}"),
+    ('\u{2963}', "\u{16D0}\u{16DA}"),
+    ('\u{2965}', "\u{21C3}\u{21C2}"),
+    ('\u{296E}', "\u{16D0}\u{21C2}"),
+    ('\u{296F}', "\u{21C3}\u{16DA}"),
+    ('\u{2999}', "\u{2D42}"),
+    ('\u{29B0}', "\u{2349}"),
+    ('\u{29BE}', "\u{233E}"),
+    ('\u{29C4}', "\u{303C}"),
+    ('\u{29C5}', "\u{2342}"),
+    ('\u{29C7}', "\u{233B}"),
+    ('\u{29D6}', "\u{102C0}"),
+    ('\u{29D9}', "\u{299A}"),
+    ('\u{29F4}', "\u{3A}\u{2192}"),
+    ('\u{29F5}', "\u{5C}"),
+    ('\u{29F6}', "\u{2F}\u{304}"),
+    ('\u{29F8}', "\u{2F}"),
+    ('\u{29F9}', "\u{5C}"),
+    ('\u{2A00}', "\u{298}"),
+    ('\u{2A01}', "\u{102A8}"),
+    ('\u{2A02}', "\u{2297}"),
+    ('\u{2A03}', "\u{228D}"),
+    ('\u{2A04}', "\u{228E}"),
+    ('\u{2A05}', "\u{2293}"),
+    ('\u{2A06}', "\u{2294}"),
+    ('\u{2A0C}', "\u{283}\u{283}\u{283}\u{283}"),
+    ('\u{2A1D}', "\u{16DE}"),
+    ('\u{2A20}', "\u{3E}\u{3E}"),
+    ('\u{2A21}', "\u{16DA}"),
+    ('\u{2A22}', "\u{2B}\u{30A}"),
+    ('\u{2A23}', "\u{2B}\u{302}"),
+    ('\u{2A24}', "\u{2B}\u{303}"),
+    ('\u{2A25}', "\u{2B}\u{323}"),
+    ('\u{2A26}', "\u{2B}\u{330}"),
+    ('\u{2A27}', "\u{2B}\u{2082}"),
+    ('\u{2A29}', "\u{2D}\u{313}"),
+    ('\u{2A2A}', "\u{2D}\u{323}"),
+    ('\u{2A2F}', "\u{78}"),
+    ('\u{2A30}', "\u{78}\u{307}"),
+    ('\u{2A3D}', "\u{2319}"),
+    ('\u{2A3E}', "\u{2A1F}"),
+    ('\u{2A3F}', "\u{2210}"),
+    ('\u{2A6A}', "\u{7E}\u{307}"),
+    ('\u{2A6E}', "\u{3D}\u{20F0}"),
+    ('\u{2A74}', "\u{3A}\u{3A}\u{3D}"),
+    ('\u{2A75}', "\u{3D}\u{3D}"),
+    ('\u{2A76}', "\u{3D}\u{3D}\u{3D}"),
+    ('\u{2AA5}', "\u{3E}\u{3C}"),
+    ('\u{2AAA}', "\u{15D5}"),
+    ('\u{2AAB}', "\u{15D2}"),
+    ('\u{2AD7}', "\u{1450}\u{1455}"),
+    ('\u{2AFB}', "\u{2F}\u{2F}\u{2F}"),
+    ('\u{2AFD}', "\u{2F}\u{2F}"),
+    ('\u{2BEC}', "\u{219E}"),
+    ('\u{2BED}', "\u{219F}"),
+    ('\u{2BEE}', "\u{21A0}"),
+    ('\u{2BEF}', "\u{21A1}"),
+    ('\u{2C67}', "\u{48}\u{329}"),
+    ('\u{2C69}', "\u{4B}\u{329}"),
+    ('\u{2C84}', "\u{393}"),
+    ('\u{2C85}', "\u{72}"),
+    ('\u{2C86}', "\u{394}"),
+    ('\u{2C88}', "\u{A792}"),
+    ('\u{2C89}', "\u{A793}"),
+    ('\u{2C8E}', "\u{48}"),
+    ('\u{2C92}', "\u{6C}"),
+    ('\u{2C94}', "\u{4B}"),
+    ('\u{2C95}', "\u{138}"),
+    ('\u{2C96}', "\u{3BB}"),
+    ('\u{2C98}', "\u{4D}"),
+    ('\u{2C9A}', "\u{4E}"),
+    ('\u{2C9E}', "\u{4F}"),
+    ('\u{2C9F}', "\u{6F}"),
+    ('\u{2CA0}', "\u{3A0}"),
+    ('\u{2CA2}', "\u{50}"),
+    ('\u{2CA3}', "\u{70}"),
+    ('\u{2CA4}', "\u{43}"),
+    ('\u{2CA5}', "\u{63}"),
+    ('\u{2CA6}', "\u{54}"),
+    ('\u{2CA8}', "\u{59}"),
+    ('\u{2CAA}', "\u{3A6}"),
+    ('\u{2CAB}', "\u{278}"),
+    ('\u{2CAC}', "\u{58}"),
+    ('\u{2CAD}', "\u{3C7}"),
+    ('\u{2CAE}', "\u{3A8}"),
+    ('\u{2CB1}', "\u{3C9}"),
+    ('\u{2CB4}', "\u{3C}\u{B7}"),
+    ('\u{2CBA}', "\u{2D}"),
+    ('\u{2CBC}', "\u{428}"),
+    ('\u{2CBD}', "\u{448}"),
+    ('\u{2CC6}', "\u{2F}"),
+    ('\u{2CCA}', "\u{39}"),
+    ('\u{2CCC}', "\u{33}"),
+    ('\u{2CCD}', "\u{21D}"),
+    ('\u{2CD0}', "\u{4C}"),
+    ('\u{2CD1}', "\u{29F}"),
+    ('\u{2CD2}', "\u{36}"),
+    ('\u{2CDC}', "\u{3EC}"),
+    ('\u{2CE4}', "\u{3D7}"),
+    ('\u{2CE9}', "\u{2627}"),
+    ('\u{2CF9}', "\u{5C}\u{5C}"),
+    ('\u{2D31}', "\u{4F}\u{335}"),
+    ('\u{2D37}', "\u{245}"),
+    ('\u{2D38}', "\u{56}"),
+    ('\u{2D39}', "\u{45}"),
+    ('\u{2D3A}', "\u{18E}"),
+    ('\u{2D41}', "\u{4F}\u{338}"),
+    ('\u{2D48}', "\u{B7}\u{B7}\u{B7}"),
+    ('\u{2D49}', "\u{1A9}"),
+    ('\u{2D4F}', "\u{6C}"),
+    ('\u{2D51}', "\u{21}"),
+    ('\u{2D54}', "\u{4F}"),
+    ('\u{2D55}', "\u{51}"),
+    ('\u{2D59}', "\u{298}"),
+    ('\u{2D5D}', "\u{58}"),
+    ('\u{2D60}', "\u{394}"),
+    ('\u{2D63}', "\u{16EF}"),
+    ('\u{2DE8}', "\u{1DDF}"),
+    ('\u{2DEA}', "\u{30A}"),
+    ('\u{2DED}', "\u{368}"),
+    ('\u{2DEF}', "\u{36F}"),
+    ('\u{2DF6}', "\u{363}"),
+    ('\u{2DF7}', "\u{364}"),
+    ('\u{2E1A}', "\u{2D}\u{308}"),
+    ('\u{2E1E}', "\u{7E}\u{307}"),
+    ('\u{2E1F}', "\u{7E}\u{323}"),
+    ('\u{2E26}', "\u{1455}"),
+    ('\u{2E27}', "\u{1450}"),
+    ('\u{2E28}', "\u{28}\u{28}"),
+    ('\u{2E29}', "\u{29}\u{29}"),
+    ('\u{2E2A}', "\u{2235}"),
+    ('\u{2E2B}', "\u{2234}"),
+    ('\u{2E2C}', "\u{2237}"),
+    ('\u{2E2E}', "\u{61F}"),
+    ('\u{2E30}', "\u{B0}"),
+    ('\u{2E31}', "\u{B7}"),
+    ('\u{2E32}', "\u{60C}"),
+    ('\u{2E35}', "\u{61B}"),
+    ('\u{2E39}', "\u{1E9F}"),
+    ('\u{2E3D}', "\u{2D42}"),
+    ('\u{2E3F}', "\u{B6}"),
+    ('\u{2E40}', "\u{3D}"),
+    ('\u{2E82}', "\u{4E5B}"),
+    ('\u{2E83}', "\u{4E5A}"),
+    ('\u{2E85}', "\u{4EBB}"),
+    ('\u{2E89}', "\u{5202}"),
+    ('\u{2E8B}', "\u{353E}"),
+    ('\u{2E8E}', "\u{5140}"),
+    ('\u{2E8F}', "\u{5C23}"),
+    ('\u{2E90}', "\u{5C22}"),
+    ('\u{2E92}', "\u{5DF3}"),
+    ('\u{2E93}', "\u{5E7A}"),
+    ('\u{2E94}', "\u{5F51}"),
+    ('\u{2E96}', "\u{5FC4}"),
+    ('\u{2E97}', "\u{38FA}"),
+    ('\u{2E98}', "\u{624C}"),
+    ('\u{2E99}', "\u{6535}"),
+    ('\u{2E9B}', "\u{65E1}"),
+    ('\u{2E9E}', "\u{6B7A}"),
+    ('\u{2E9F}', "\u{6BCD}"),
+    ('\u{2EA0}', "\u{6C11}"),
+    ('\u{2EA1}', "\u{6C35}"),
+    ('\u{2EA2}', "\u{6C3A}"),
+    ('\u{2EA3}', "\u{706C}"),
+    ('\u{2EA4}', "\u{722B}"),
+    ('\u{2EA6}', "\u{4E2C}"),
+    ('\u{2EA8}', "\u{72AD}"),
+    ('\u{2EAB}', "\u{7F52}"),
+    ('\u{2EAD}', "\u{793B}"),
+    ('\u{2EAF}', "\u{7CF9}"),
+    ('\u{2EB1}', "\u{7F53}"),
+    ('\u{2EB2}', "\u{7F52}"),
+    ('\u{2EB9}', "\u{8002}"),
+    ('\u{2EBA}', "\u{8080}"),
+    ('\u{2EBE}', "\u{8279}"),
+    ('\u{2EBF}', "\u{8279}"),
+    ('\u{2EC0}', "\u{8279}"),
+    ('\u{2EC1}', "\u{864E}"),
+    ('\u{2EC2}', "\u{8864}"),
+    ('\u{2EC3}', "\u{8980}"),
+    ('\u{2EC4}', "\u{897F}"),
+    ('\u{2EC5}', "\u{89C1}"),
+    ('\u{2EC8}', "\u{8BA0}"),
+    ('\u{2EC9}', "\u{8D1D}"),
+    ('\u{2ECB}', "\u{8F66}"),
+    ('\u{2ECC}', "\u{8FB6}"),
+    ('\u{2ECD}', "\u{8FB6}"),
+    ('\u{2ECF}', "\u{961D}"),
+    ('\u{2ED0}', "\u{9485}"),
+    ('\u{2ED1}', "\u{9577}"),
+    ('\u{2ED2}', "\u{9578}"),
+    ('\u{2ED3}', "\u{957F}"),
+    ('\u{2ED4}', "\u{95E8}"),
+    ('\u{2ED6}', "\u{961D}"),
+    ('\u{2ED8}', "\u{9752}"),
+    ('\u{2ED9}', "\u{97E6}"),
+    ('\u{2EDA}', "\u{9875}"),
+    ('\u{2EDB}', "\u{98CE}"),
+    ('\u{2EDC}', "\u{98DE}"),
+    ('\u{2EDD}', "\u{98DF}"),
+    ('\u{2EDF}', "\u{98E0}"),
+    ('\u{2EE0}', "\u{9963}"),
+    ('\u{2EE2}', "\u{9A6C}"),
+    ('\u{2EE4}', "\u{9B3C}"),
+    ('\u{2EE5}', "\u{9C7C}"),
+    ('\u{2EE8}', "\u{9EA6}"),
+    ('\u{2EE9}', "\u{9EC4}"),
+    ('\u{2EEB}', "\u{6589}"),
+    ('\u{2EEC}', "\u{9F50}"),
+    ('\u{2EED}', "\u{6B6F}"),
+    ('\u{2EEE}', "\u{9F7F}"),
+    ('\u{2EEF}', "\u{7ADC}"),
+    ('\u{2EF0}', "\u{9F99}"),
+    ('\u{2EF2}', "\u{4E80}"),
+    ('\u{2EF3}', "\u{9F9F}"),
+    ('\u{2F00}', "\u{30FC}"),
+    ('\u{2F01}', "\u{4E28}"),
+    ('\u{2F02}', "\u{5C}"),
+    ('\u{2F03}', "\u{2F}"),
+    ('\u{2F04}', "\u{4E59}"),
+    ('\u{2F05}', "\u{4E85}"),
+    ('\u{2F06}', "\u{4E8C}"),
+    ('\u{2F07}', "\u{4EA0}"),
+    ('\u{2F08}', "\u{4EBA}"),
+    ('\u{2F09}', "\u{513F}"),
+    ('\u{2F0A}', "\u{5165}"),
+    ('\u{2F0B}', "\u{516B}"),
+    ('\u{2F0C}', "\u{5182}"),
+    ('\u{2F0D}', "\u{5196}"),
+    ('\u{2F0E}', "\u{51AB}"),
+    ('\u{2F0F}', "\u{51E0}"),
+    ('\u{2F10}', "\u{51F5}"),
+    ('\u{2F11}', "\u{5200}"),
+    ('\u{2F12}', "\u{529B}"),
+    ('\u{2F13}', "\u{52F9}"),
+    ('\u{2F14}', "\u{5315}"),
+    ('\u{2F15}', "\u{531A}"),
+    ('\u{2F16}', "\u{5338}"),
+    ('\u{2F17}', "\u{5341}"),
+    ('\u{2F18}', "\u{535C}"),
+    ('\u{2F19}', "\u{5369}"),
+    ('\u{2F1A}', "\u{5382}"),
+    ('\u{2F1B}', "\u{53B6}"),
+    ('\u{2F1C}', "\u{53C8}"),
+    ('\u{2F1D}', "\u{53E3}"),
+    ('\u{2F1E}', "\u{53E3}"),
+    ('\u{2F1F}', "\u{571F}"),
+    ('\u{2F20}', "\u{571F}"),
+    ('\u{2F21}', "\u{5902}"),
+    ('\u{2F22}', "\u{590A}"),
+    ('\u{2F23}', "\u{5915}"),
+    ('\u{2F24}', "\u{5927}"),
+    ('\u{2F25}', "\u{5973}"),
+    ('\u{2F26}', "\u{5B50}"),
+    ('\u{2F27}', "\u{5B80}"),
+    ('\u{2F28}', "\u{5BF8}"),
+    ('\u{2F29}', "\u{5C0F}"),
+    ('\u{2F2A}', "\u{5C22}"),
+    ('\u{2F2B}', "\u{5C38}"),
+    ('\u{2F2C}', "\u{5C6E}"),
+    ('\u{2F2D}', "\u{5C71}"),
+    ('\u{2F2E}', "\u{5DDB}"),
+    ('\u{2F2F}', "\u{5DE5}"),
+    ('\u{2F30}', "\u{5DF1}"),
+    ('\u{2F31}', "\u{5DFE}"),
+    ('\u{2F32}', "\u{5E72}"),
+    ('\u{2F33}', "\u{5E7A}"),
+    ('\u{2F34}', "\u{5E7F}"),
+    ('\u{2F35}', "\u{5EF4}"),
+    ('\u{2F36}', "\u{5EFE}"),
+    ('\u{2F37}', "\u{5F0B}"),
+    ('\u{2F38}', "\u{5F13}"),
+    ('\u{2F39}', "\u{5F50}"),
+    ('\u{2F3A}', "\u{5F61}"),
+    ('\u{2F3B}', "\u{5F73}"),
+    ('\u{2F3C}', "\u{5FC3}"),
+    ('\u{2F3D}', "\u{6208}"),
+    ('\u{2F3E}', "\u{6236}"),
+    ('\u{2F3F}', "\u{624B}"),
+    ('\u{2F40}', "\u{652F}"),
+    ('\u{2F41}', "\u{6534}"),
+    ('\u{2F42}', "\u{6587}"),
+    ('\u{2F43}', "\u{6597}"),
+    ('\u{2F44}', "\u{65A4}"),
+    ('\u{2F45}', "\u{65B9}"),
+    ('\u{2F46}', "\u{65E0}"),
+    ('\u{2F47}', "\u{65E5}"),
+    ('\u{2F48}', "\u{66F0}"),
+    ('\u{2F49}', "\u{6708}"),
+    ('\u{2F4A}', "\u{6728}"),
+    ('\u{2F4B}', "\u{6B20}"),
+    ('\u{2F4C}', "\u{6B62}"),
+    ('\u{2F4D}', "\u{6B79}"),
+    ('\u{2F4E}', "\u{6BB3}"),
+    ('\u{2F4F}', "\u{6BCB}"),
+    ('\u{2F50}', "\u{6BD4}"),
+    ('\u{2F51}', "\u{6BDB}"),
+    ('\u{2F52}', "\u{6C0F}"),
+    ('\u{2F53}', "\u{6C14}"),
+    ('\u{2F54}', "\u{6C34}"),
+    ('\u{2F55}', "\u{706B}"),
+    ('\u{2F56}', "\u{722A}"),
+    ('\u{2F57}', "\u{7236}"),
+    ('\u{2F58}', "\u{723B}"),
+    ('\u{2F59}', "\u{723F}"),
+    ('\u{2F5A}', "\u{7247}"),
+    ('\u{2F5B}', "\u{7259}"),
+    ('\u{2F5C}', "\u{725B}"),
+    ('\u{2F5D}', "\u{72AC}"),
+    ('\u{2F5E}', "\u{7384}"),
+    ('\u{2F5F}', "\u{7389}"),
+    ('\u{2F60}', "\u{74DC}"),
+    ('\u{2F61}', "\u{74E6}"),
+    ('\u{2F62}', "\u{7518}"),
+    ('\u{2F63}', "\u{751F}"),
+    ('\u{2F64}', "\u{7528}"),
+    ('\u{2F65}', "\u{7530}"),
+    ('\u{2F66}', "\u{758B}"),
+    ('\u{2F67}', "\u{7592}"),
+    ('\u{2F68}', "\u{7676}"),
+    ('\u{2F69}', "\u{767D}"),
+    ('\u{2F6A}', "\u{76AE}"),
+    ('\u{2F6B}', "\u{76BF}"),
+    ('\u{2F6C}', "\u{76EE}"),
+    ('\u{2F6D}', "\u{77DB}"),
+    ('\u{2F6E}', "\u{77E2}"),
+    ('\u{2F6F}', "\u{77F3}"),
+    ('\u{2F70}', "\u{793A}"),
+    ('\u{2F71}', "\u{79B8}"),
+    ('\u{2F72}', "\u{79BE}"),
+    ('\u{2F73}', "\u{7A74}"),
+    ('\u{2F74}', "\u{7ACB}"),
+    ('\u{2F75}', "\u{7AF9}"),
+    ('\u{2F76}', "\u{7C73}"),
+    ('\u{2F77}', "\u{7CF8}"),
+    ('\u{2F78}', "\u{7F36}"),
+    ('\u{2F79}', "\u{7F51}"),
+    ('\u{2F7A}', "\u{7F8A}"),
+    ('\u{2F7B}', "\u{7FBD}"),
+    ('\u{2F7C}', "\u{8001}"),
+    ('\u{2F7D}', "\u{800C}"),
+    ('\u{2F7E}', "\u{8012}"),
+    ('\u{2F7F}', "\u{8033}"),
+    ('\u{2F80}', "\u{807F}"),
+    ('\u{2F81}', "\u{8089}"),
+    ('\u{2F82}', "\u{81E3}"),
+    ('\u{2F83}', "\u{81EA}"),
+    ('\u{2F84}', "\u{81F3}"),
+    ('\u{2F85}', "\u{81FC}"),
+    ('\u{2F86}', "\u{820C}"),
+    ('\u{2F87}', "\u{821B}"),
+    ('\u{2F88}', "\u{821F}"),
+    ('\u{2F89}', "\u{826E}"),
+    ('\u{2F8A}', "\u{8272}"),
+    ('\u{2F8B}', "\u{8278}"),
+    ('\u{2F8C}', "\u{864D}"),
+    ('\u{2F8D}', "\u{866B}"),
+    ('\u{2F8E}', "\u{8840}"),
+    ('\u{2F8F}', "\u{884C}"),
+    ('\u{2F90}', "\u{8863}"),
+    ('\u{2F91}', "\u{897E}"),
+    ('\u{2F92}', "\u{898B}"),
+    ('\u{2F93}', "\u{89D2}"),
+    ('\u{2F94}', "\u{8A00}"),
+    ('\u{2F95}', "\u{8C37}"),
+    ('\u{2F96}', "\u{8C46}"),
+    ('\u{2F97}', "\u{8C55}"),
+    ('\u{2F98}', "\u{8C78}"),
+    ('\u{2F99}', "\u{8C9D}"),
+    ('\u{2F9A}', "\u{8D64}"),
+    ('\u{2F9B}', "\u{8D70}"),
+    ('\u{2F9C}', "\u{8DB3}"),
+    ('\u{2F9D}', "\u{8EAB}"),
+    ('\u{2F9E}', "\u{8ECA}"),
+    ('\u{2F9F}', "\u{8F9B}"),
+    ('\u{2FA0}', "\u{8FB0}"),
+    ('\u{2FA1}', "\u{8FB5}"),
+    ('\u{2FA2}', "\u{9091}"),
+    ('\u{2FA3}', "\u{9149}"),
+    ('\u{2FA4}', "\u{91C6}"),
+    ('\u{2FA5}', "\u{91CC}"),
+    ('\u{2FA6}', "\u{91D1}"),
+    ('\u{2FA7}', "\u{9577}"),
+    ('\u{2FA8}', "\u{9580}"),
+    ('\u{2FA9}', "\u{961C}"),
+    ('\u{2FAA}', "\u{96B6}"),
+    ('\u{2FAB}', "\u{96B9}"),
+    ('\u{2FAC}', "\u{96E8}"),
+    ('\u{2FAD}', "\u{9751}"),
+    ('\u{2FAE}', "\u{975E}"),
+    ('\u{2FAF}', "\u{9762}"),
+    ('\u{2FB0}', "\u{9769}"),
+    ('\u{2FB1}', "\u{97CB}"),
+    ('\u{2FB2}', "\u{97ED}"),
+    ('\u{2FB3}', "\u{97F3}"),
+    ('\u{2FB4}', "\u{9801}"),
+    ('\u{2FB5}', "\u{98A8}"),
+    ('\u{2FB6}', "\u{98DB}"),
+    ('\u{2FB7}', "\u{98DF}"),
+    ('\u{2FB8}', "\u{9996}"),
+    ('\u{2FB9}', "\u{9999}"),
+    ('\u{2FBA}', "\u{99AC}"),
+    ('\u{2FBB}', "\u{9AA8}"),
+    ('\u{2FBC}', "\u{9AD8}"),
+    ('\u{2FBD}', "\u{9ADF}"),
+    ('\u{2FBE}', "\u{9B25}"),
+    ('\u{2FBF}', "\u{9B2F}"),
+    ('\u{2FC0}', "\u{9B32}"),
+    ('\u{2FC1}', "\u{9B3C}"),
+    ('\u{2FC2}', "\u{9B5A}"),
+    ('\u{2FC3}', "\u{9CE5}"),
+    ('\u{2FC4}', "\u{9E75}"),
+    ('\u{2FC5}', "\u{9E7F}"),
+    ('\u{2FC6}', "\u{9EA5}"),
+    ('\u{2FC7}', "\u{9EBB}"),
+    ('\u{2FC8}', "\u{9EC3}"),
+    ('\u{2FC9}', "\u{9ECD}"),
+    ('\u{2FCA}', "\u{9ED1}"),
+    ('\u{2FCB}', "\u{9EF9}"),
+    ('\u{2FCC}', "\u{9EFD}"),
+    ('\u{2FCD}', "\u{9F0E}"),
+    ('\u{2FCE}', "\u{9F13}"),
+    ('\u{2FCF}', "\u{9F20}"),
+    ('\u{2FD0}', "\u{9F3B}"),
+    ('\u{2FD1}', "\u{9F4A}"),
+    ('\u{2FD2}', "\u{9F52}"),
+    ('\u{2FD3}', "\u{9F8D}"),
+    ('\u{2FD4}', "\u{9F9C}"),
+    ('\u{2FD5}', "\u{9FA0}"),
+    ('\u{3002}', "\u{2F3}"),
+    ('\u{3003}', "\u{27}\u{27}"),
+    ('\u{3007}', "\u{4F}"),
+    ('\u{3008}', "\u{276C}"),
+    ('\u{3009}', "\u{276D}"),
+    ('\u{3012}', "\u{20B8}"),
+    ('\u{3014}', "\u{28}"),
+    ('\u{3015}', "\u{29}"),
+    ('\u{301A}', "\u{27E6}"),
+    ('\u{301B}', "\u{27E7}"),
+    ('\u{302C}', "\u{309}"),
+    ('\u{302D}', "\u{325}"),
+    ('\u{3033}', "\u{2F}"),
+    ('\u{3036}', "\u{20B8}"),
+    ('\u{3038}', "\u{5341}"),
+    ('\u{3039}', "\u{5344}"),
+    ('\u{303A}', "\u{5345}"),
+    ('\u{304F}', "\u{276C}"),
+    ('\u{309A}', "\u{30A}"),
+    ('\u{309B}', "\u{FF9E}"),
+    ('\u{309C}', "\u{FF9F}"),
+    ('\u{30A0}', "\u{3D}"),
+    ('\u{30A4}', "\u{4EBB}"),
+    ('\u{30A8}', "\u{5DE5}"),
+    ('\u{30AB}', "\u{529B}"),
+    ('\u{30BF}', "\u{5915}"),
+    ('\u{30C8}', "\u{535C}"),
+    ('\u{30CB}', "\u{4E8C}"),
+    ('\u{30CE}', "\u{2F}"),
+    ('\u{30CF}', "\u{516B}"),
+    ('\u{30D8}', "\u{3078}"),
+    ('\u{30ED}', "\u{53E3}"),
+    ('\u{30FB}', "\u{B7}"),
+    ('\u{3131}', "\u{1100}"),
+    ('\u{3132}', "\u{1100}\u{1100}"),
+    ('\u{3133}', "\u{1100}\u{1109}"),
+    ('\u{3134}', "\u{1102}"),
+    ('\u{3135}', "\u{1102}\u{110C}"),
+    ('\u{3136}', "\u{1102}\u{1112}"),
+    ('\u{3137}', "\u{1103}"),
+    ('\u{3138}', "\u{1103}\u{1103}"),
+    ('\u{3139}', "\u{1105}"),
+    ('\u{313A}', "\u{1105}\u{1100}"),
+    ('\u{313B}', "\u{1105}\u{1106}"),
+    ('\u{313C}', "\u{1105}\u{1107}"),
+    ('\u{313D}', "\u{1105}\u{1109}"),
+    ('\u{313E}', "\u{1105}\u{1110}"),
+    ('\u{313F}', "\u{1105}\u{1111}"),
+    ('\u{3140}', "\u{1105}\u{1112}"),
+    ('\u{3141}', "\u{1106}"),
+    ('\u{3142}', "\u{1107}"),
+    ('\u{3143}', "\u{1107}\u{1107}"),
+    ('\u{3144}', "\u{1107}\u{1109}"),
+    ('\u{3145}', "\u{1109}"),
+    ('\u{3146}', "\u{1109}\u{1109}"),
+    ('\u{3147}', "\u{110B}"),
+    ('\u{3148}', "\u{110C}"),
+    ('\u{3149}', "\u{110C}\u{110C}"),
+    ('\u{314A}', "\u{110E}"),
+    ('\u{314B}', "\u{110F}"),
+    ('\u{314C}', "\u{1110}"),
+    ('\u{314D}', "\u{1111}"),
+    ('\u{314E}', "\u{1112}"),
+    ('\u{314F}', "\u{1161}"),
+    ('\u{3150}', "\u{1161}\u{4E28}"),
+    ('\u{3151}', "\u{1163}"),
+    ('\u{3152}', "\u{1163}\u{4E28}"),
+    ('\u{3153}', "\u{1165}"),
+    ('\u{3154}', "\u{1165}\u{4E28}"),
+    ('\u{3155}', "\u{1167}"),
+    ('\u{3156}', "\u{1167}\u{4E28}"),
+    ('\u{3157}', "\u{1169}"),
+    ('\u{3158}', "\u{1169}\u{1161}"),
+    ('\u{3159}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{315A}', "\u{1169}\u{4E28}"),
+    ('\u{315B}', "\u{116D}"),
+    ('\u{315C}', "\u{116E}"),
+    ('\u{315D}', "\u{116E}\u{1165}"),
+    ('\u{315E}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{315F}', "\u{116E}\u{4E28}"),
+    ('\u{3160}', "\u{1172}"),
+    ('\u{3161}', "\u{30FC}"),
+    ('\u{3162}', "\u{30FC}\u{4E28}"),
+    ('\u{3163}', "\u{4E28}"),
+    ('\u{3164}', "\u{1160}"),
+    ('\u{3165}', "\u{1102}\u{1102}"),
+    ('\u{3166}', "\u{1102}\u{1103}"),
+    ('\u{3167}', "\u{1102}\u{1109}"),
+    ('\u{3168}', "\u{1102}\u{1140}"),
+    ('\u{3169}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{316A}', "\u{1105}\u{1103}"),
+    ('\u{316B}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{316C}', "\u{1105}\u{1140}"),
+    ('\u{316D}', "\u{1105}\u{1159}"),
+    ('\u{316E}', "\u{1106}\u{1107}"),
+    ('\u{316F}', "\u{1106}\u{1109}"),
+    ('\u{3170}', "\u{1106}\u{1140}"),
+    ('\u{3171}', "\u{1106}\u{110B}"),
+    ('\u{3172}', "\u{1107}\u{1100}"),
+    ('\u{3173}', "\u{1107}\u{1103}"),
+    ('\u{3174}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{3175}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{3176}', "\u{1107}\u{110C}"),
+    ('\u{3177}', "\u{1107}\u{1110}"),
+    ('\u{3178}', "\u{1107}\u{110B}"),
+    ('\u{3179}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{317A}', "\u{1109}\u{1100}"),
+    ('\u{317B}', "\u{1109}\u{1102}"),
+    ('\u{317C}', "\u{1109}\u{1103}"),
+    ('\u{317D}', "\u{1109}\u{1107}"),
+    ('\u{317E}', "\u{1109}\u{110C}"),
+    ('\u{317F}', "\u{1140}"),
+    ('\u{3180}', "\u{110B}\u{110B}"),
+    ('\u{3181}', "\u{114C}"),
+    ('\u{3182}', "\u{110B}\u{1109}"),
+    ('\u{3183}', "\u{110B}\u{1140}"),
+    ('\u{3184}', "\u{1111}\u{110B}"),
+    ('\u{3185}', "\u{1112}\u{1112}"),
+    ('\u{3186}', "\u{1159}"),
+    ('\u{3187}', "\u{116D}\u{1163}"),
+    ('\u{3188}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{3189}', "\u{116D}\u{4E28}"),
+    ('\u{318A}', "\u{1172}\u{1167}"),
+    ('\u{318B}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{318C}', "\u{1172}\u{4E28}"),
+    ('\u{318D}', "\u{119E}"),
+    ('\u{318E}', "\u{119E}\u{4E28}"),
+    ('\u{31D0}', "\u{30FC}"),
+    ('\u{31D1}', "\u{4E28}"),
+    ('\u{31D3}', "\u{2F}"),
+    ('\u{31D4}', "\u{5C}"),
+    ('\u{31D6}', "\u{4E5B}"),
+    ('\u{31DA}', "\u{4E85}"),
+    ('\u{31DB}', "\u{276C}"),
+    ('\u{31DF}', "\u{4E5A}"),
+    ('\u{31E0}', "\u{4E59}"),
+    ('\u{3200}', "\u{28}\u{1100}\u{29}"),
+    ('\u{3201}', "\u{28}\u{1102}\u{29}"),
+    ('\u{3202}', "\u{28}\u{1103}\u{29}"),
+    ('\u{3203}', "\u{28}\u{1105}\u{29}"),
+    ('\u{3204}', "\u{28}\u{1106}\u{29}"),
+    ('\u{3205}', "\u{28}\u{1107}\u{29}"),
+    ('\u{3206}', "\u{28}\u{1109}\u{29}"),
+    ('\u{3207}', "\u{28}\u{110B}\u{29}"),
+    ('\u{3208}', "\u{28}\u{110C}\u{29}"),
+    ('\u{3209}', "\u{28}\u{110E}\u{29}"),
+    ('\u{320A}', "\u{28}\u{110F}\u{29}"),
+    ('\u{320B}', "\u{28}\u{1110}\u{29}"),
+    ('\u{320C}', "\u{28}\u{1111}\u{29}"),
+    ('\u{320D}', "\u{28}\u{1112}\u{29}"),
+    ('\u{320E}', "\u{28}\u{1100}\u{1161}\u{29}"),
+    ('\u{320F}', "\u{28}\u{1102}\u{1161}\u{29}"),
+    ('\u{3210}', "\u{28}\u{1103}\u{1161}\u{29}"),
+    ('\u{3211}', "\u{28}\u{1105}\u{1161}\u{29}"),
+    ('\u{3212}', "\u{28}\u{1106}\u{1161}\u{29}"),
+    ('\u{3213}', "\u{28}\u{1107}\u{1161}\u{29}"),
+    ('\u{3214}', "\u{28}\u{1109}\u{1161}\u{29}"),
+    ('\u{3215}', "\u{28}\u{110B}\u{1161}\u{29}"),
+    ('\u{3216}', "\u{28}\u{110C}\u{1161}\u{29}"),
+    ('\u{3217}', "\u{28}\u{110E}\u{1161}\u{29}"),
+    ('\u{3218}', "\u{28}\u{110F}\u{1161}\u{29}"),
+    ('\u{3219}', "\u{28}\u{1110}\u{1161}\u{29}"),
+    ('\u{321A}', "\u{28}\u{1111}\u{1161}\u{29}"),
+    ('\u{321B}', "\u{28}\u{1112}\u{1161}\u{29}"),
+    ('\u{321C}', "\u{28}\u{110C}\u{116E}\u{29}"),
+    ('\u{321D}', "\u{28}\u{110B}\u{1169}\u{110C}\u{1165}\u{11AB}\u{29}"),
+    ('\u{321E}', "\u{28}\u{110B}\u{1169}\u{1112}\u{116E}\u{29}"),
+    ('\u{3220}', "\u{28}\u{30FC}\u{29}"),
+    ('\u{3221}', "\u{28}\u{4E8C}\u{29}"),
+    ('\u{3222}', "\u{28}\u{4E09}\u{29}"),
+    ('\u{3223}', "\u{28}\u{56DB}\u{29}"),
+    ('\u{3224}', "\u{28}\u{4E94}\u{29}"),
+    ('\u{3225}', "\u{28}\u{516D}\u{29}"),
+    ('\u{3226}', "\u{28}\u{4E03}\u{29}"),
+    ('\u{3227}', "\u{28}\u{516B}\u{29}"),
+    ('\u{3228}', "\u{28}\u{4E5D}\u{29}"),
+    ('\u{3229}', "\u{28}\u{5341}\u{29}"),
+    ('\u{322A}', "\u{28}\u{6708}\u{29}"),
+    ('\u{322B}', "\u{28}\u{706B}\u{29}"),
+    ('\u{322C}', "\u{28}\u{6C34}\u{29}"),
+    ('\u{322D}', "\u{28}\u{6728}\u{29}"),
+    ('\u{322E}', "\u{28}\u{91D1}\u{29}"),
+    ('\u{322F}', "\u{28}\u{571F}\u{29}"),
+    ('\u{3230}', "\u{28}\u{65E5}\u{29}"),
+    ('\u{3231}', "\u{28}\u{682A}\u{29}"),
+    ('\u{3232}', "\u{28}\u{6709}\u{29}"),
+    ('\u{3233}', "\u{28}\u{793E}\u{29}"),
+    ('\u{3234}', "\u{28}\u{540D}\u{29}"),
+    ('\u{3235}', "\u{28}\u{7279}\u{29}"),
+    ('\u{3236}', "\u{28}\u{8CA1}\u{29}"),
+    ('\u{3237}', "\u{28}\u{795D}\u{29}"),
+    ('\u{3238}', "\u{28}\u{52B4}\u{29}"),
+    ('\u{3239}', "\u{28}\u{4EE3}\u{29}"),
+    ('\u{323A}', "\u{28}\u{547C}\u{29}"),
+    ('\u{323B}', "\u{28}\u{5B66}\u{29}"),
+    ('\u{323C}', "\u{28}\u{76E3}\u{29}"),
+    ('\u{323D}', "\u{28}\u{4F01}\u{29}"),
+    ('\u{323E}', "\u{28}\u{8CC7}\u{29}"),
+    ('\u{323F}', "\u{28}\u{5354}\u{29}"),
+    ('\u{3240}', "\u{28}\u{796D}\u{29}"),
+    ('\u{3241}', "\u{28}\u{4F11}\u{29}"),
+    ('\u{3242}', "\u{28}\u{81EA}\u{29}"),
+    ('\u{3243}', "\u{28}\u{81F3}\u{29}"),
+    ('\u{32C0}', "\u{6C}\u{6708}"),
+    ('\u{32C1}', "\u{32}\u{6708}"),
+    ('\u{32C2}', "\u{33}\u{6708}"),
+    ('\u{32C3}', "\u{34}\u{6708}"),
+    ('\u{32C4}', "\u{35}\u{6708}"),
+    ('\u{32C5}', "\u{36}\u{6708}"),
+    ('\u{32C6}', "\u{37}\u{6708}"),
+    ('\u{32C7}', "\u{38}\u{6708}"),
+    ('\u{32C8}', "\u{39}\u{6708}"),
+    ('\u{32C9}', "\u{6C}\u{4F}\u{6708}"),
+    ('\u{32CA}', "\u{6C}\u{6C}\u{6708}"),
+    ('\u{32CB}', "\u{6C}\u{32}\u{6708}"),
+    ('\u{3358}', "\u{4F}\u{70B9}"),
+    ('\u{3359}', "\u{6C}\u{70B9}"),
+    ('\u{335A}', "\u{32}\u{70B9}"),
+    ('\u{335B}', "\u{33}\u{70B9}"),
+    ('\u{335C}', "\u{34}\u{70B9}"),
+    ('\u{335D}', "\u{35}\u{70B9}"),
+    ('\u{335E}', "\u{36}\u{70B9}"),
+    ('\u{335F}', "\u{37}\u{70B9}"),
+    ('\u{3360}', "\u{38}\u{70B9}"),
+    ('\u{3361}', "\u{39}\u{70B9}"),
+    ('\u{3362}', "\u{6C}\u{4F}\u{70B9}"),
+    ('\u{3363}', "\u{6C}\u{6C}\u{70B9}"),
+    ('\u{3364}', "\u{6C}\u{32}\u{70B9}"),
+    ('\u{3365}', "\u{6C}\u{33}\u{70B9}"),
+    ('\u{3366}', "\u{6C}\u{34}\u{70B9}"),
+    ('\u{3367}', "\u{6C}\u{35}\u{70B9}"),
+    ('\u{3368}', "\u{6C}\u{36}\u{70B9}"),
+    ('\u{3369}', "\u{6C}\u{37}\u{70B9}"),
+    ('\u{336A}', "\u{6C}\u{38}\u{70B9}"),
+    ('\u{336B}', "\u{6C}\u{39}\u{70B9}"),
+    ('\u{336C}', "\u{32}\u{4F}\u{70B9}"),
+    ('\u{336D}', "\u{32}\u{6C}\u{70B9}"),
+    ('\u{336E}', "\u{32}\u{32}\u{70B9}"),
+    ('\u{336F}', "\u{32}\u{33}\u{70B9}"),
+    ('\u{3370}', "\u{32}\u{34}\u{70B9}"),
+    ('\u{33E0}', "\u{6C}\u{65E5}"),
+    ('\u{33E1}', "\u{32}\u{65E5}"),
+    ('\u{33E2}', "\u{33}\u{65E5}"),
+    ('\u{33E3}', "\u{34}\u{65E5}"),
+    ('\u{33E4}', "\u{35}\u{65E5}"),
+    ('\u{33E5}', "\u{36}\u{65E5}"),
+    ('\u{33E6}', "\u{37}\u{65E5}"),
+    ('\u{33E7}', "\u{38}\u{65E5}"),
+    ('\u{33E8}', "\u{39}\u{65E5}"),
+    ('\u{33E9}', "\u{6C}\u{4F}\u{65E5}"),
+    ('\u{33EA}', "\u{6C}\u{6C}\u{65E5}"),
+    ('\u{33EB}', "\u{6C}\u{32}\u{65E5}"),
+    ('\u{33EC}', "\u{6C}\u{33}\u{65E5}"),
+    ('\u{33ED}', "\u{6C}\u{34}\u{65E5}"),
+    ('\u{33EE}', "\u{6C}\u{35}\u{65E5}"),
+    ('\u{33EF}', "\u{6C}\u{36}\u{65E5}"),
+    ('\u{33F0}', "\u{6C}\u{37}\u{65E5}"),
+    ('\u{33F1}', "\u{6C}\u{38}\u{65E5}"),
+    ('\u{33F2}', "\u{6C}\u{39}\u{65E5}"),
+    ('\u{33F3}', "\u{32}\u{4F}\u{65E5}"),
+    ('\u{33F4}', "\u{32}\u{6C}\u{65E5}"),
+    ('\u{33F5}', "\u{32}\u{32}\u{65E5}"),
+    ('\u{33F6}', "\u{32}\u{33}\u{65E5}"),
+    ('\u{33F7}', "\u{32}\u{34}\u{65E5}"),
+    ('\u{33F8}', "\u{32}\u{35}\u{65E5}"),
+    ('\u{33F9}', "\u{32}\u{36}\u{65E5}"),
+    ('\u{33FA}', "\u{32}\u{37}\u{65E5}"),
+    ('\u{33FB}', "\u{32}\u{38}\u{65E5}"),
+    ('\u{33FC}', "\u{32}\u{39}\u{65E5}"),
+    ('\u{33FD}', "\u{33}\u{4F}\u{65E5}"),
+    ('\u{33FE}', "\u{33}\u{6C}\u{65E5}"),
+    ('\u{39B3}', "\u{363D}"),
+    ('\u{439B}', "\u{3588}"),
+    ('\u{4420}', "\u{3B3B}"),
+    ('\u{4E00}', "\u{30FC}"),
+    ('\u{4E36}', "\u{5C}"),
+    ('\u{4E3F}', "\u{2F}"),
+    ('\u{5002}', "\u{4F75}"),
+    ('\u{503C}', "\u{5024}"),
+    ('\u{555F}', "\u{5553}"),
+    ('\u{56D7}', "\u{53E3}"),
+    ('\u{586B}', "\u{5861}"),
+    ('\u{58EB}', "\u{571F}"),
+    ('\u{58FF}', "\u{58AB}"),
+    ('\u{5B00}', "\u{5AAF}"),
+    ('\u{5E32}', "\u{5E21}"),
+    ('\u{5E50}', "\u{3B3A}"),
+    ('\u{6238}', "\u{6236}"),
+    ('\u{6409}', "\u{3A41}"),
+    ('\u{6663}', "\u{403F}"),
+    ('\u{6669}', "\u{665A}"),
+    ('\u{66F6}', "\u{3ADA}"),
+    ('\u{6726}', "\u{4443}"),
+    ('\u{67FF}', "\u{676E}"),
+    ('\u{69E9}', "\u{3BA3}"),
+    ('\u{6A27}', "\u{699D}"),
+    ('\u{6F59}', "\u{6E88}"),
+    ('\u{784F}', "\u{7814}"),
+    ('\u{7D76}', "\u{7D55}"),
+    ('\u{80A6}', "\u{670C}"),
+    ('\u{80CA}', "\u{6710}"),
+    ('\u{80D0}', "\u{670F}"),
+    ('\u{80F6}', "\u{3B35}"),
+    ('\u{8101}', "\u{6713}"),
+    ('\u{8127}', "\u{6718}"),
+    ('\u{8141}', "\u{80FC}"),
+    ('\u{81A7}', "\u{6723}"),
+    ('\u{853F}', "\u{848D}"),
+    ('\u{8641}', "\u{8637}"),
+    ('\u{8A1E}', "\u{46B6}"),
+    ('\u{8A7D}', "\u{8A2E}"),
+    ('\u{8B8F}', "\u{8B86}"),
+    ('\u{8C63}', "\u{8C5C}"),
+    ('\u{8D86}', "\u{8D7F}"),
+    ('\u{8DFA}', "\u{8DE5}"),
+    ('\u{8E9B}', "\u{8E97}"),
+    ('\u{8F27}', "\u{8EFF}"),
+    ('\u{90DE}', "\u{90CE}"),
+    ('\u{93AE}', "\u{93AD}"),
+    ('\u{96B8}', "\u{96B7}"),
+    ('\u{9E43}', "\u{9E42}"),
+    ('\u{9ED2}', "\u{9ED1}"),
+    ('\u{9FC3}', "\u{4039}"),
+    ('\u{A494}', "\u{A2CD}"),
+    ('\u{A49C}', "\u{A0C0}"),
+    ('\u{A49E}', "\u{A04A}"),
+    ('\u{A4A7}', "\u{A458}"),
+    ('\u{A4A8}', "\u{A132}"),
+    ('\u{A4AC}', "\u{A050}"),
+    ('\u{A4B0}', "\u{A3C2}"),
+    ('\u{A4BA}', "\u{A3BF}"),
+    ('\u{A4BE}', "\u{A2B1}"),
+    ('\u{A4BF}', "\u{A259}"),
+    ('\u{A4C0}', "\u{A3AB}"),
+    ('\u{A4C2}', "\u{A3B5}"),
+    ('\u{A4D0}', "\u{42}"),
+    ('\u{A4D1}', "\u{50}"),
+    ('\u{A4D2}', "\u{64}"),
+    ('\u{A4D3}', "\u{44}"),
+    ('\u{A4D4}', "\u{54}"),
+    ('\u{A4D6}', "\u{47}"),
+    ('\u{A4D7}', "\u{4B}"),
+    ('\u{A4D9}', "\u{4A}"),
+    ('\u{A4DA}', "\u{43}"),
+    ('\u{A4DB}', "\u{186}"),
+    ('\u{A4DC}', "\u{5A}"),
+    ('\u{A4DD}', "\u{46}"),
+    ('\u{A4DE}', "\u{2132}"),
+    ('\u{A4DF}', "\u{4D}"),
+    ('\u{A4E0}', "\u{4E}"),
+    ('\u{A4E1}', "\u{4C}"),
+    ('\u{A4E2}', "\u{53}"),
+    ('\u{A4E3}', "\u{52}"),
+    ('\u{A4E5}', "\u{245}"),
+    ('\u{A4E6}', "\u{56}"),
+    ('\u{A4E7}', "\u{48}"),
+    ('\u{A4EA}', "\u{57}"),
+    ('\u{A4EB}', "\u{58}"),
+    ('\u{A4EC}', "\u{59}"),
+    ('\u{A4ED}', "\u{1660}"),
+    ('\u{A4EE}', "\u{41}"),
+    ('\u{A4EF}', "\u{2C6F}"),
+    ('\u{A4F0}', "\u{45}"),
+    ('\u{A4F1}', "\u{18E}"),
+    ('\u{A4F2}', "\u{6C}"),
+    ('\u{A4F3}', "\u{4F}"),
+    ('\u{A4F4}', "\u{55}"),
+    ('\u{A4F5}', "\u{548}"),
+    ('\u{A4F7}', "\u{15E1}"),
+    ('\u{A4F8}', "\u{2E}"),
+    ('\u{A4F9}', "\u{2C}"),
+    ('\u{A4FA}', "\u{2E}\u{2E}"),
+    ('\u{A4FB}', "\u{2E}\u{2C}"),
+    ('\u{A4FD}', "\u{3A}"),
+    ('\u{A4FE}', "\u{2D}\u{2E}"),
+    ('\u{A4FF}', "\u{3D}"),
+    ('\u{A60E}', "\u{2E}"),
+    ('\u{A644}', "\u{32}"),
+    ('\u{A645}', "\u{1A8}"),
+    ('\u{A647}', "\u{69}"),
+    ('\u{A64D}', "\u{3C9}"),
+    ('\u{A650}', "\u{42A}\u{6C}"),
+    ('\u{A651}', "\u{2C9}\u{62}\u{69}"),
+    ('\u{A668}', "\u{298}"),
+    ('\u{A66F}', "\u{20E9}"),
+    ('\u{A67C}', "\u{306}"),
+    ('\u{A67E}', "\u{2C7}"),
+    ('\u{A695}', "\u{68}\u{314}"),
+    ('\u{A698}', "\u{4F}\u{4F}"),
+    ('\u{A699}', "\u{6F}\u{6F}"),
+    ('\u{A69A}', "\u{102A8}"),
+    ('\u{A6A1}', "\u{418}"),
+    ('\u{A6B0}', "\u{16B9}"),
+    ('\u{A6B1}', "\u{2C75}"),
+    ('\u{A6CD}', "\u{2A1}"),
+    ('\u{A6CE}', "\u{245}"),
+    ('\u{A6DB}', "\u{3A0}"),
+    ('\u{A6DF}', "\u{56}"),
+    ('\u{A6EB}', "\u{3F}"),
+    ('\u{A6EF}', "\u{32}"),
+    ('\u{A6F0}', "\u{302}"),
+    ('\u{A6F1}', "\u{304}"),
+    ('\u{A6F4}', "\u{A6F3}\u{A6F3}"),
+    ('\u{A714}', "\u{2EB}"),
+    ('\u{A716}', "\u{2EA}"),
+    ('\u{A728}', "\u{54}\u{33}"),
+    ('\u{A729}', "\u{74}\u{21D}"),
+    ('\u{A731}', "\u{73}"),
+    ('\u{A732}', "\u{41}\u{41}"),
+    ('\u{A733}', "\u{61}\u{61}"),
+    ('\u{A734}', "\u{41}\u{4F}"),
+    ('\u{A735}', "\u{61}\u{6F}"),
+    ('\u{A736}', "\u{41}\u{55}"),
+    ('\u{A737}', "\u{61}\u{75}"),
+    ('\u{A738}', "\u{41}\u{56}"),
+    ('\u{A739}', "\u{61}\u{76}"),
+    ('\u{A73A}', "\u{41}\u{56}"),
+    ('\u{A73B}', "\u{61}\u{76}"),
+    ('\u{A73C}', "\u{41}\u{59}"),
+    ('\u{A73D}', "\u{61}\u{79}"),
+    ('\u{A740}', "\u{4B}\u{335}"),
+    ('\u{A74A}', "\u{4F}\u{335}"),
+    ('\u{A74B}', "\u{6F}\u{335}"),
+    ('\u{A74E}', "\u{4F}\u{4F}"),
+    ('\u{A74F}', "\u{6F}\u{6F}"),
+    ('\u{A75A}', "\u{32}"),
+    ('\u{A761}', "\u{77}\u{326}"),
+    ('\u{A76A}', "\u{33}"),
+    ('\u{A76B}', "\u{21D}"),
+    ('\u{A76E}', "\u{39}"),
+    ('\u{A777}', "\u{74}\u{66}"),
+    ('\u{A778}', "\u{26}"),
+    ('\u{A77A}', "\u{A779}"),
+    ('\u{A789}', "\u{3A}"),
+    ('\u{A78C}', "\u{27}"),
+    ('\u{A78F}', "\u{B7}"),
+    ('\u{A795}', "\u{A727}"),
+    ('\u{A798}', "\u{46}"),
+    ('\u{A799}', "\u{66}"),
+    ('\u{A79A}', "\u{10412}"),
+    ('\u{A79B}', "\u{1043A}"),
+    ('\u{A79D}', "\u{29A}"),
+    ('\u{A79E}', "\u{A4E4}"),
+    ('\u{A79F}', "\u{75}"),
+    ('\u{A7AB}', "\u{33}"),
+    ('\u{A7B1}', "\u{A4D5}"),
+    ('\u{A7B2}', "\u{4A}"),
+    ('\u{A7B3}', "\u{58}"),
+    ('\u{A7B4}', "\u{42}"),
+    ('\u{A7B5}', "\u{DF}"),
+    ('\u{A7B6}', "\u{A64C}"),
+    ('\u{A7B7}', "\u{3C9}"),
+    ('\u{A7F7}', "\u{30FC}"),
+    ('\u{A830}', "\u{964}"),
+    ('\u{A960}', "\u{1103}\u{1106}"),
+    ('\u{A961}', "\u{1103}\u{1107}"),
+    ('\u{A962}', "\u{1103}\u{1109}"),
+    ('\u{A963}', "\u{1103}\u{110C}"),
+    ('\u{A964}', "\u{1105}\u{1100}"),
+    ('\u{A965}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{A966}', "\u{1105}\u{1103}"),
+    ('\u{A967}', "\u{1105}\u{1103}\u{1103}"),
+    ('\u{A968}', "\u{1105}\u{1106}"),
+    ('\u{A969}', "\u{1105}\u{1107}"),
+    ('\u{A96A}', "\u{1105}\u{1107}\u{1107}"),
+    ('\u{A96B}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{A96C}', "\u{1105}\u{1109}"),
+    ('\u{A96D}', "\u{1105}\u{110C}"),
+    ('\u{A96E}', "\u{1105}\u{110F}"),
+    ('\u{A96F}', "\u{1106}\u{1100}"),
+    ('\u{A970}', "\u{1106}\u{1103}"),
+    ('\u{A971}', "\u{1106}\u{1109}"),
+    ('\u{A972}', "\u{1107}\u{1109}\u{1110}"),
+    ('\u{A973}', "\u{1107}\u{110F}"),
+    ('\u{A974}', "\u{1107}\u{1112}"),
+    ('\u{A975}', "\u{1109}\u{1109}\u{1107}"),
+    ('\u{A976}', "\u{110B}\u{1105}"),
+    ('\u{A977}', "\u{110B}\u{1112}"),
+    ('\u{A978}', "\u{110C}\u{110C}\u{1112}"),
+    ('\u{A979}', "\u{1110}\u{1110}"),
+    ('\u{A97A}', "\u{1111}\u{1112}"),
+    ('\u{A97B}', "\u{1112}\u{1109}"),
+    ('\u{A97C}', "\u{1159}\u{1159}"),
+    ('\u{A992}', "\u{2C3F}"),
+    ('\u{A9A3}', "\u{A99D}"),
+    ('\u{A9C6}', "\u{A9D0}"),
+    ('\u{A9CF}', "\u{662}"),
+    ('\u{AA53}', "\u{AA01}"),
+    ('\u{AA56}', "\u{AA23}"),
+    ('\u{AB32}', "\u{65}"),
+    ('\u{AB35}', "\u{66}"),
+    ('\u{AB3D}', "\u{6F}"),
+    ('\u{AB3E}', "\u{6F}\u{338}"),
+    ('\u{AB3F}', "\u{254}\u{338}"),
+    ('\u{AB41}', "\u{1DD}\u{6F}\u{338}"),
+    ('\u{AB42}', "\u{1DD}\u{6F}\u{335}"),
+    ('\u{AB47}', "\u{72}"),
+    ('\u{AB48}', "\u{72}"),
+    ('\u{AB4D}', "\u{283}"),
+    ('\u{AB4E}', "\u{75}"),
+    ('\u{AB52}', "\u{75}"),
+    ('\u{AB53}', "\u{3C7}"),
+    ('\u{AB55}', "\u{3C7}"),
+    ('\u{AB5A}', "\u{79}"),
+    ('\u{AB60}', "\u{459}"),
+    ('\u{AB62}', "\u{254}\u{65}"),
+    ('\u{AB63}', "\u{75}\u{6F}"),
+    ('\u{AB70}', "\u{1D05}"),
+    ('\u{AB71}', "\u{280}"),
+    ('\u{AB72}', "\u{1D1B}"),
+    ('\u{AB74}', "\u{6F}\u{31B}"),
+    ('\u{AB75}', "\u{69}"),
+    ('\u{AB7A}', "\u{1D00}"),
+    ('\u{AB7B}', "\u{1D0A}"),
+    ('\u{AB7C}', "\u{1D07}"),
+    ('\u{AB7E}', "\u{242}"),
+    ('\u{AB80}', "\u{2C76}"),
+    ('\u{AB81}', "\u{72}"),
+    ('\u{AB83}', "\u{77}"),
+    ('\u{AB87}', "\u{28D}"),
+    ('\u{AB8B}', "\u{29C}"),
+    ('\u{AB8E}', "\u{6F}\u{335}"),
+    ('\u{AB90}', "\u{262}"),
+    ('\u{AB93}', "\u{7A}"),
+    ('\u{AB9B}', "\u{A793}"),
+    ('\u{AB9C}', "\u{75}\u{335}"),
+    ('\u{AB9F}', "\u{185}"),
+    ('\u{ABA2}', "\u{280}"),
+    ('\u{ABA9}', "\u{76}"),
+    ('\u{ABAA}', "\u{73}"),
+    ('\u{ABAE}', "\u{29F}"),
+    ('\u{ABAF}', "\u{63}"),
+    ('\u{ABB2}', "\u{1D18}"),
+    ('\u{ABB6}', "\u{138}"),
+    ('\u{ABBB}', "\u{6F}\u{335}"),
+    ('\u{D7B0}', "\u{1169}\u{1167}"),
+    ('\u{D7B1}', "\u{1169}\u{1169}\u{4E28}"),
+    ('\u{D7B2}', "\u{116D}\u{1161}"),
+    ('\u{D7B3}', "\u{116D}\u{1161}\u{4E28}"),
+    ('\u{D7B4}', "\u{116D}\u{1165}"),
+    ('\u{D7B5}', "\u{116E}\u{1167}"),
+    ('\u{D7B6}', "\u{116E}\u{4E28}\u{4E28}"),
+    ('\u{D7B7}', "\u{1172}\u{1161}\u{4E28}"),
+    ('\u{D7B8}', "\u{1172}\u{1169}"),
+    ('\u{D7B9}', "\u{30FC}\u{1161}"),
+    ('\u{D7BA}', "\u{30FC}\u{1165}"),
+    ('\u{D7BB}', "\u{30FC}\u{1165}\u{4E28}"),
+    ('\u{D7BC}', "\u{30FC}\u{1169}"),
+    ('\u{D7BD}', "\u{4E28}\u{1163}\u{1169}"),
+    ('\u{D7BE}', "\u{4E28}\u{1163}\u{4E28}"),
+    ('\u{D7BF}', "\u{4E28}\u{1167}"),
+    ('\u{D7C0}', "\u{4E28}\u{1167}\u{4E28}"),
+    ('\u{D7C1}', "\u{4E28}\u{1169}\u{4E28}"),
+    ('\u{D7C2}', "\u{4E28}\u{116D}"),
+    ('\u{D7C3}', "\u{4E28}\u{1172}"),
+    ('\u{D7C4}', "\u{4E28}\u{4E28}"),
+    ('\u{D7C5}', "\u{119E}\u{1161}"),
+    ('\u{D7C6}', "\u{119E}\u{1165}\u{4E28}"),
+    ('\u{D7CB}', "\u{1102}\u{1105}"),
+    ('\u{D7CC}', "\u{1102}\u{110E}"),
+    ('\u{D7CD}', "\u{1103}\u{1103}"),
+    ('\u{D7CE}', "\u{1103}\u{1103}\u{1107}"),
+    ('\u{D7CF}', "\u{1103}\u{1107}"),
+    ('\u{D7D0}', "\u{1103}\u{1109}"),
+    ('\u{D7D1}', "\u{1103}\u{1109}\u{1100}"),
+    ('\u{D7D2}', "\u{1103}\u{110C}"),
+    ('\u{D7D3}', "\u{1103}\u{110E}"),
+    ('\u{D7D4}', "\u{1103}\u{1110}"),
+    ('\u{D7D5}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{D7D6}', "\u{1105}\u{1100}\u{1112}"),
+    ('\u{D7D7}', "\u{1105}\u{1105}\u{110F}"),
+    ('\u{D7D8}', "\u{1105}\u{1106}\u{1112}"),
+    ('\u{D7D9}', "\u{1105}\u{1107}\u{1103}"),
+    ('\u{D7DA}', "\u{1105}\u{1107}\u{1111}"),
+    ('\u{D7DB}', "\u{1105}\u{114C}"),
+    ('\u{D7DC}', "\u{1105}\u{1159}\u{1112}"),
+    ('\u{D7DD}', "\u{1105}\u{110B}"),
+    ('\u{D7DE}', "\u{1106}\u{1102}"),
+    ('\u{D7DF}', "\u{1106}\u{1102}\u{1102}"),
+    ('\u{D7E0}', "\u{1106}\u{1106}"),
+    ('\u{D7E1}', "\u{1106}\u{1107}\u{1109}"),
+    ('\u{D7E2}', "\u{1106}\u{110C}"),
+    ('\u{D7E3}', "\u{1107}\u{1103}"),
+    ('\u{D7E4}', "\u{1107}\u{1105}\u{1111}"),
+    ('\u{D7E5}', "\u{1107}\u{1106}"),
+    ('\u{D7E6}', "\u{1107}\u{1107}"),
+    ('\u{D7E7}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{D7E8}', "\u{1107}\u{110C}"),
+    ('\u{D7E9}', "\u{1107}\u{110E}"),
+    ('\u{D7EA}', "\u{1109}\u{1106}"),
+    ('\u{D7EB}', "\u{1109}\u{1107}\u{110B}"),
+    ('\u{D7EC}', "\u{1109}\u{1109}\u{1100}"),
+    ('\u{D7ED}', "\u{1109}\u{1109}\u{1103}"),
+    ('\u{D7EE}', "\u{1109}\u{1140}"),
+    ('\u{D7EF}', "\u{1109}\u{110C}"),
+    ('\u{D7F0}', "\u{1109}\u{110E}"),
+    ('\u{D7F1}', "\u{1109}\u{1110}"),
+    ('\u{D7F2}', "\u{1105}\u{1112}"),
+    ('\u{D7F3}', "\u{1140}\u{1107}"),
+    ('\u{D7F4}', "\u{1140}\u{1107}\u{110B}"),
+    ('\u{D7F5}', "\u{114C}\u{1106}"),
+    ('\u{D7F6}', "\u{114C}\u{1112}"),
+    ('\u{D7F7}', "\u{110C}\u{1107}"),
+    ('\u{D7F8}', "\u{110C}\u{1107}\u{1107}"),
+    ('\u{D7F9}', "\u{110C}\u{110C}"),
+    ('\u{D7FA}', "\u{1111}\u{1109}"),
+    ('\u{D7FB}', "\u{1111}\u{1110}"),
+    ('\u{FB00}', "\u{66}\u{66}"),
+    ('\u{FB01}', "\u{66}\u{69}"),
+    ('\u{FB02}', "\u{66}\u{6C}"),
+    ('\u{FB03}', "\u{66}\u{66}\u{69}"),
+    ('\u{FB04}', "\u{66}\u{66}\u{6C}"),
+    ('\u{FB06}', "\u{73}\u{74}"),
+    ('\u{FB13}', "\u{574}\u{576}"),
+    ('\u{FB14}', "\u{574}\u{565}"),
+    ('\u{FB15}', "\u{574}\u{56B}"),
+    ('\u{FB16}', "\u{57E}\u{576}"),
+    ('\u{FB17}', "\u{574}\u{56D}"),
+    ('\u{FB20}', "\u{5E2}"),
+    ('\u{FB21}', "\u{5D0}"),
+    ('\u{FB22}', "\u{5D3}"),
+    ('\u{FB23}', "\u{5D4}"),
+    ('\u{FB24}', "\u{5DB}"),
+    ('\u{FB25}', "\u{5DC}"),
+    ('\u{FB26}', "\u{5DD}"),
+    ('\u{FB27}', "\u{5E8}"),
+    ('\u{FB28}', "\u{5EA}"),
+    ('\u{FB29}', "\u{2D}\u{307}"),
+    ('\u{FB4F}', "\u{5D0}\u{5DC}"),
+    ('\u{FB50}', "\u{671}"),
+    ('\u{FB51}', "\u{671}"),
+    ('\u{FB52}', "\u{67B}"),
+    ('\u{FB53}', "\u{67B}"),
+    ('\u{FB54}', "\u{67B}"),
+    ('\u{FB55}', "\u{67B}"),
+    ('\u{FB56}', "\u{649}\u{6DB}"),
+    ('\u{FB57}', "\u{649}\u{6DB}"),
+    ('\u{FB58}', "\u{649}\u{6DB}"),
+    ('\u{FB59}', "\u{649}\u{6DB}"),
+    ('\u{FB5A}', "\u{680}"),
+    ('\u{FB5B}', "\u{680}"),
+    ('\u{FB5C}', "\u{680}"),
+    ('\u{FB5D}', "\u{680}"),
+    ('\u{FB5E}', "\u{67A}"),
+    ('\u{FB5F}', "\u{67A}"),
+    ('\u{FB60}', "\u{67A}"),
+    ('\u{FB61}', "\u{67A}"),
+    ('\u{FB62}', "\u{67F}"),
+    ('\u{FB63}', "\u{67F}"),
+    ('\u{FB64}', "\u{67F}"),
+    ('\u{FB65}', "\u{67F}"),
+    ('\u{FB66}', "\u{649}\u{615}"),
+    ('\u{FB67}', "\u{649}\u{615}"),
+    ('\u{FB68}', "\u{649}\u{615}"),
+    ('\u{FB69}', "\u{649}\u{615}"),
+    ('\u{FB6A}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6B}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6C}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6D}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6E}', "\u{6A6}"),
+    ('\u{FB6F}', "\u{6A6}"),
+    ('\u{FB70}', "\u{6A6}"),
+    ('\u{FB71}', "\u{6A6}"),
+    ('\u{FB72}', "\u{684}"),
+    ('\u{FB73}', "\u{684}"),
+    ('\u{FB74}', "\u{684}"),
+    ('\u{FB75}', "\u{684}"),
+    ('\u{FB76}', "\u{683}"),
+    ('\u{FB77}', "\u{683}"),
+    ('\u{FB78}', "\u{683}"),
+    ('\u{FB79}', "\u{683}"),
+    ('\u{FB7A}', "\u{686}"),
+    ('\u{FB7B}', "\u{686}"),
+    ('\u{FB7C}', "\u{686}"),
+    ('\u{FB7D}', "\u{686}"),
+    ('\u{FB7E}', "\u{687}"),
+    ('\u{FB7F}', "\u{687}"),
+    ('\u{FB80}', "\u{687}"),
+    ('\u{FB81}', "\u{687}"),
+    ('\u{FB82}', "\u{68D}"),
+    ('\u{FB83}', "\u{68D}"),
+    ('\u{FB84}', "\u{68C}"),
+    ('\u{FB85}', "\u{68C}"),
+    ('\u{FB86}', "\u{62F}\u{6DB}"),
+    ('\u{FB87}', "\u{62F}\u{6DB}"),
+    ('\u{FB88}', "\u{62F}\u{615}"),
+    ('\u{FB89}', "\u{62F}\u{615}"),
+    ('\u{FB8A}', "\u{631}\u{6DB}"),
+    ('\u{FB8B}', "\u{631}\u{6DB}"),
+    ('\u{FB8C}', "\u{631}\u{615}"),
+    ('\u{FB8D}', "\u{631}\u{615}"),
+    ('\u{FB8E}', "\u{643}"),
+    ('\u{FB8F}', "\u{643}"),
+    ('\u{FB90}', "\u{643}"),
+    ('\u{FB91}', "\u{643}"),
+    ('\u{FB92}', "\u{6AF}"),
+    ('\u{FB93}', "\u{6AF}"),
+    ('\u{FB94}', "\u{6AF}"),
+    ('\u{FB95}', "\u{6AF}"),
+    ('\u{FB96}', "\u{6B3}"),
+    ('\u{FB97}', "\u{6B3}"),
+    ('\u{FB98}', "\u{6B3}"),
+    ('\u{FB99}', "\u{6B3}"),
+    ('\u{FB9A}', "\u{6B1}"),
+    ('\u{FB9B}', "\u{6B1}"),
+    ('\u{FB9C}', "\u{6B1}"),
+    ('\u{FB9D}', "\u{6B1}"),
+    ('\u{FB9E}', "\u{649}"),
+    ('\u{FB9F}', "\u{649}"),
+    ('\u{FBA0}', "\u{649}\u{615}"),
+    ('\u{FBA1}', "\u{649}\u{615}"),
+    ('\u{FBA2}', "\u{649}\u{615}"),
+    ('\u{FBA3}', "\u{649}\u{615}"),
+    ('\u{FBA4}', "\u{6D5}\u{654}"),
+    ('\u{FBA5}', "\u{6D5}\u{654}"),
+    ('\u{FBA6}', "\u{6F}"),
+    ('\u{FBA7}', "\u{6F}"),
+    ('\u{FBA8}', "\u{6F}"),
+    ('\u{FBA9}', "\u{6F}"),
+    ('\u{FBAA}', "\u{6F}"),
+    ('\u{FBAB}', "\u{6F}"),
+    ('\u{FBAC}', "\u{6F}"),
+    ('\u{FBAD}', "\u{6F}"),
+    ('\u{FBAE}', "\u{649}"),
+    ('\u{FBAF}', "\u{649}"),
+    ('\u{FBB0}', "\u{6D2}\u{654}"),
+    ('\u{FBB1}', "\u{6D2}\u{654}"),
+    ('\u{FBD3}', "\u{643}\u{6DB}"),
+    ('\u{FBD4}', "\u{643}\u{6DB}"),
+    ('\u{FBD5}', "\u{643}\u{6DB}"),
+    ('\u{FBD6}', "\u{643}\u{6DB}"),
+    ('\u{FBD7}', "\u{648}\u{313}"),
+    ('\u{FBD8}', "\u{648}\u{313}"),
+    ('\u{FBD9}', "\u{648}\u{306}"),
+    ('\u{FBDA}', "\u{648}\u{306}"),
+    ('\u{FBDB}', "\u{648}\u{670}"),
+    ('\u{FBDC}', "\u{648}\u{670}"),
+    ('\u{FBDD}', "\u{648}\u{313}\u{674}"),
+    ('\u{FBDE}', "\u{648}\u{6DB}"),
+    ('\u{FBDF}', "\u{648}\u{6DB}"),
+    ('\u{FBE0}', "\u{6C5}"),
+    ('\u{FBE1}', "\u{6C5}"),
+    ('\u{FBE2}', "\u{648}\u{302}"),
+    ('\u{FBE3}', "\u{648}\u{302}"),
+    ('\u{FBE4}', "\u{67B}"),
+    ('\u{FBE5}', "\u{67B}"),
+    ('\u{FBE6}', "\u{67B}"),
+    ('\u{FBE7}', "\u{67B}"),
+    ('\u{FBE8}', "\u{649}"),
+    ('\u{FBE9}', "\u{649}"),
+    ('\u{FBEA}', "\u{649}\u{674}\u{6C}"),
+    ('\u{FBEB}', "\u{649}\u{674}\u{6C}"),
+    ('\u{FBEC}', "\u{649}\u{674}\u{6F}"),
+    ('\u{FBED}', "\u{649}\u{674}\u{6F}"),
+    ('\u{FBEE}', "\u{649}\u{674}\u{648}"),
+    ('\u{FBEF}', "\u{649}\u{674}\u{648}"),
+    ('\u{FBF0}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{FBF1}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{FBF2}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{FBF3}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{FBF4}', "\u{649}\u{674}\u{648}\u{670}"),
+    ('\u{FBF5}', "\u{649}\u{674}\u{648}\u{670}"),
+    ('\u{FBF6}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF7}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF8}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF9}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFA}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFB}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFC}', "\u{649}"),
+    ('\u{FBFD}', "\u{649}"),
+    ('\u{FBFE}', "\u{649}"),
+    ('\u{FBFF}', "\u{649}"),
+    ('\u{FC00}', "\u{649}\u{674}\u{62C}"),
+    ('\u{FC01}', "\u{649}\u{674}\u{62D}"),
+    ('\u{FC02}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC03}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC04}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC05}', "\u{628}\u{62C}"),
+    ('\u{FC06}', "\u{628}\u{62D}"),
+    ('\u{FC07}', "\u{628}\u{62E}"),
+    ('\u{FC08}', "\u{628}\u{645}"),
+    ('\u{FC09}', "\u{628}\u{649}"),
+    ('\u{FC0A}', "\u{628}\u{649}"),
+    ('\u{FC0B}', "\u{62A}\u{62C}"),
+    ('\u{FC0C}', "\u{62A}\u{62D}"),
+    ('\u{FC0D}', "\u{62A}\u{62E}"),
+    ('\u{FC0E}', "\u{62A}\u{645}"),
+    ('\u{FC0F}', "\u{62A}\u{649}"),
+    ('\u{FC10}', "\u{62A}\u{649}"),
+    ('\u{FC11}', "\u{649}\u{6DB}\u{62C}"),
+    ('\u{FC12}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FC13}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC14}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC15}', "\u{62C}\u{62D}"),
+    ('\u{FC16}', "\u{62C}\u{645}"),
+    ('\u{FC17}', "\u{62D}\u{62C}"),
+    ('\u{FC18}', "\u{62D}\u{645}"),
+    ('\u{FC19}', "\u{62E}\u{62C}"),
+    ('\u{FC1A}', "\u{62E}\u{62D}"),
+    ('\u{FC1B}', "\u{62E}\u{645}"),
+    ('\u{FC1C}', "\u{633}\u{62C}"),
+    ('\u{FC1D}', "\u{633}\u{62D}"),
+    ('\u{FC1E}', "\u{633}\u{62E}"),
+    ('\u{FC1F}', "\u{633}\u{645}"),
+    ('\u{FC20}', "\u{635}\u{62D}"),
+    ('\u{FC21}', "\u{635}\u{645}"),
+    ('\u{FC22}', "\u{636}\u{62C}"),
+    ('\u{FC23}', "\u{636}\u{62D}"),
+    ('\u{FC24}', "\u{636}\u{62E}"),
+    ('\u{FC25}', "\u{636}\u{645}"),
+    ('\u{FC26}', "\u{637}\u{62D}"),
+    ('\u{FC27}', "\u{637}\u{645}"),
+    ('\u{FC28}', "\u{638}\u{645}"),
+    ('\u{FC29}', "\u{639}\u{62C}"),
+    ('\u{FC2A}', "\u{639}\u{645}"),
+    ('\u{FC2B}', "\u{63A}\u{62C}"),
+    ('\u{FC2C}', "\u{63A}\u{645}"),
+    ('\u{FC2D}', "\u{641}\u{62C}"),
+    ('\u{FC2E}', "\u{641}\u{62D}"),
+    ('\u{FC2F}', "\u{641}\u{62E}"),
+    ('\u{FC30}', "\u{641}\u{645}"),
+    ('\u{FC31}', "\u{641}\u{649}"),
+    ('\u{FC32}', "\u{641}\u{649}"),
+    ('\u{FC33}', "\u{642}\u{62D}"),
+    ('\u{FC34}', "\u{642}\u{645}"),
+    ('\u{FC35}', "\u{642}\u{649}"),
+    ('\u{FC36}', "\u{642}\u{649}"),
+    ('\u{FC37}', "\u{643}\u{6C}"),
+    ('\u{FC38}', "\u{643}\u{62C}"),
+    ('\u{FC39}', "\u{643}\u{62D}"),
+    ('\u{FC3A}', "\u{643}\u{62E}"),
+    ('\u{FC3B}', "\u{643}\u{644}"),
+    ('\u{FC3C}', "\u{643}\u{645}"),
+    ('\u{FC3D}', "\u{643}\u{649}"),
+    ('\u{FC3E}', "\u{643}\u{649}"),
+    ('\u{FC3F}', "\u{644}\u{62C}"),
+    ('\u{FC40}', "\u{644}\u{62D}"),
+    ('\u{FC41}', "\u{644}\u{62E}"),
+    ('\u{FC42}', "\u{644}\u{645}"),
+    ('\u{FC43}', "\u{644}\u{649}"),
+    ('\u{FC44}', "\u{644}\u{649}"),
+    ('\u{FC45}', "\u{645}\u{62C}"),
+    ('\u{FC46}', "\u{645}\u{62D}"),
+    ('\u{FC47}', "\u{645}\u{62E}"),
+    ('\u{FC48}', "\u{645}\u{645}"),
+    ('\u{FC49}', "\u{645}\u{649}"),
+    ('\u{FC4A}', "\u{645}\u{649}"),
+    ('\u{FC4B}', "\u{628}\u{62E}"),
+    ('\u{FC4C}', "\u{646}\u{62D}"),
+    ('\u{FC4D}', "\u{646}\u{62E}"),
+    ('\u{FC4E}', "\u{646}\u{645}"),
+    ('\u{FC4F}', "\u{646}\u{649}"),
+    ('\u{FC50}', "\u{646}\u{649}"),
+    ('\u{FC51}', "\u{6F}\u{62C}"),
+    ('\u{FC52}', "\u{6F}\u{645}"),
+    ('\u{FC53}', "\u{6F}\u{649}"),
+    ('\u{FC54}', "\u{6F}\u{649}"),
+    ('\u{FC55}', "\u{649}\u{62C}"),
+    ('\u{FC56}', "\u{649}\u{62D}"),
+    ('\u{FC57}', "\u{649}\u{62E}"),
+    ('\u{FC58}', "\u{649}\u{645}"),
+    ('\u{FC59}', "\u{649}\u{649}"),
+    ('\u{FC5A}', "\u{649}\u{649}"),
+    ('\u{FC5B}', "\u{630}\u{670}"),
+    ('\u{FC5C}', "\u{631}\u{670}"),
+    ('\u{FC5D}', "\u{649}\u{670}"),
+    ('\u{FC5E}', "\u{FE72}\u{651}"),
+    ('\u{FC5F}', "\u{FE74}\u{651}"),
+    ('\u{FC60}', "\u{FE76}\u{651}"),
+    ('\u{FC61}', "\u{FE78}\u{651}"),
+    ('\u{FC62}', "\u{FE7A}\u{651}"),
+    ('\u{FC63}', "\u{FE7C}\u{670}"),
+    ('\u{FC64}', "\u{649}\u{674}\u{631}"),
+    ('\u{FC65}', "\u{649}\u{674}\u{632}"),
+    ('\u{FC66}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC67}', "\u{649}\u{674}\u{646}"),
+    ('\u{FC68}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC69}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC6A}', "\u{628}\u{631}"),
+    ('\u{FC6B}', "\u{628}\u{632}"),
+    ('\u{FC6C}', "\u{628}\u{645}"),
+    ('\u{FC6D}', "\u{628}\u{646}"),
+    ('\u{FC6E}', "\u{628}\u{649}"),
+    ('\u{FC6F}', "\u{628}\u{649}"),
+    ('\u{FC70}', "\u{62A}\u{631}"),
+    ('\u{FC71}', "\u{62A}\u{632}"),
+    ('\u{FC72}', "\u{62A}\u{645}"),
+    ('\u{FC73}', "\u{62A}\u{646}"),
+    ('\u{FC74}', "\u{62A}\u{649}"),
+    ('\u{FC75}', "\u{62A}\u{649}"),
+    ('\u{FC76}', "\u{649}\u{6DB}\u{631}"),
+    ('\u{FC77}', "\u{649}\u{6DB}\u{632}"),
+    ('\u{FC78}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FC79}', "\u{649}\u{6DB}\u{646}"),
+    ('\u{FC7A}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC7B}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC7C}', "\u{641}\u{649}"),
+    ('\u{FC7D}', "\u{641}\u{649}"),
+    ('\u{FC7E}', "\u{642}\u{649}"),
+    ('\u{FC7F}', "\u{642}\u{649}"),
+    ('\u{FC80}', "\u{643}\u{6C}"),
+    ('\u{FC81}', "\u{643}\u{644}"),
+    ('\u{FC82}', "\u{643}\u{645}"),
+    ('\u{FC83}', "\u{643}\u{649}"),
+    ('\u{FC84}', "\u{643}\u{649}"),
+    ('\u{FC85}', "\u{644}\u{645}"),
+    ('\u{FC86}', "\u{644}\u{649}"),
+    ('\u{FC87}', "\u{644}\u{649}"),
+    ('\u{FC88}', "\u{645}\u{6C}"),
+    ('\u{FC89}', "\u{645}\u{645}"),
+    ('\u{FC8A}', "\u{646}\u{631}"),
+    ('\u{FC8B}', "\u{646}\u{632}"),
+    ('\u{FC8C}', "\u{646}\u{645}"),
+    ('\u{FC8D}', "\u{646}\u{646}"),
+    ('\u{FC8E}', "\u{646}\u{649}"),
+    ('\u{FC8F}', "\u{646}\u{649}"),
+    ('\u{FC90}', "\u{649}\u{670}"),
+    ('\u{FC91}', "\u{649}\u{631}"),
+    ('\u{FC92}', "\u{649}\u{632}"),
+    ('\u{FC93}', "\u{649}\u{645}"),
+    ('\u{FC94}', "\u{649}\u{646}"),
+    ('\u{FC95}', "\u{649}\u{649}"),
+    ('\u{FC96}', "\u{649}\u{649}"),
+    ('\u{FC97}', "\u{649}\u{674}\u{62C}"),
+    ('\u{FC98}', "\u{649}\u{674}\u{62D}"),
+    ('\u{FC99}', "\u{649}\u{674}\u{62E}"),
+    ('\u{FC9A}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC9B}', "\u{649}\u{674}\u{6F}"),
+    ('\u{FC9C}', "\u{628}\u{62C}"),
+    ('\u{FC9D}', "\u{628}\u{62D}"),
+    ('\u{FC9E}', "\u{628}\u{62E}"),
+    ('\u{FC9F}', "\u{628}\u{645}"),
+    ('\u{FCA0}', "\u{628}\u{6F}"),
+    ('\u{FCA1}', "\u{62A}\u{62C}"),
+    ('\u{FCA2}', "\u{62A}\u{62D}"),
+    ('\u{FCA3}', "\u{62A}\u{62E}"),
+    ('\u{FCA4}', "\u{62A}\u{645}"),
+    ('\u{FCA5}', "\u{62A}\u{6F}"),
+    ('\u{FCA6}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FCA7}', "\u{62C}\u{62D}"),
+    ('\u{FCA8}', "\u{62C}\u{645}"),
+    ('\u{FCA9}', "\u{62D}\u{62C}"),
+    ('\u{FCAA}', "\u{62D}\u{645}"),
+    ('\u{FCAB}', "\u{62E}\u{62C}"),
+    ('\u{FCAC}', "\u{62E}\u{645}"),
+    ('\u{FCAD}', "\u{633}\u{62C}"),
+    ('\u{FCAE}', "\u{633}\u{62D}"),
+    ('\u{FCAF}', "\u{633}\u{62E}"),
+    ('\u{FCB0}', "\u{633}\u{645}"),
+    ('\u{FCB1}', "\u{635}\u{62D}"),
+    ('\u{FCB2}', "\u{635}\u{62E}"),
+    ('\u{FCB3}', "\u{635}\u{645}"),
+    ('\u{FCB4}', "\u{636}\u{62C}"),
+    ('\u{FCB5}', "\u{636}\u{62D}"),
+    ('\u{FCB6}', "\u{636}\u{62E}"),
+    ('\u{FCB7}', "\u{636}\u{645}"),
+    ('\u{FCB8}', "\u{637}\u{62D}"),
+    ('\u{FCB9}', "\u{638}\u{645}"),
+    ('\u{FCBA}', "\u{639}\u{62C}"),
+    ('\u{FCBB}', "\u{639}\u{645}"),
+    ('\u{FCBC}', "\u{63A}\u{62C}"),
+    ('\u{FCBD}', "\u{63A}\u{645}"),
+    ('\u{FCBE}', "\u{641}\u{62C}"),
+    ('\u{FCBF}', "\u{641}\u{62D}"),
+    ('\u{FCC0}', "\u{641}\u{62E}"),
+    ('\u{FCC1}', "\u{641}\u{645}"),
+    ('\u{FCC2}', "\u{642}\u{62D}"),
+    ('\u{FCC3}', "\u{642}\u{645}"),
+    ('\u{FCC4}', "\u{643}\u{62C}"),
+    ('\u{FCC5}', "\u{643}\u{62D}"),
+    ('\u{FCC6}', "\u{643}\u{62E}"),
+    ('\u{FCC7}', "\u{643}\u{644}"),
+    ('\u{FCC8}', "\u{643}\u{645}"),
+    ('\u{FCC9}', "\u{644}\u{62C}"),
+    ('\u{FCCA}', "\u{644}\u{62D}"),
+    ('\u{FCCB}', "\u{644}\u{62E}"),
+    ('\u{FCCC}', "\u{644}\u{645}"),
+    ('\u{FCCD}', "\u{644}\u{6F}"),
+    ('\u{FCCE}', "\u{645}\u{62C}"),
+    ('\u{FCCF}', "\u{645}\u{62D}"),
+    ('\u{FCD0}', "\u{645}\u{62E}"),
+    ('\u{FCD1}', "\u{645}\u{645}"),
+    ('\u{FCD2}', "\u{628}\u{62E}"),
+    ('\u{FCD3}', "\u{646}\u{62D}"),
+    ('\u{FCD4}', "\u{646}\u{62E}"),
+    ('\u{FCD5}', "\u{646}\u{645}"),
+    ('\u{FCD6}', "\u{646}\u{6F}"),
+    ('\u{FCD7}', "\u{6F}\u{62C}"),
+    ('\u{FCD8}', "\u{6F}\u{645}"),
+    ('\u{FCD9}', "\u{6F}\u{670}"),
+    ('\u{FCDA}', "\u{649}\u{62C}"),
+    ('\u{FCDB}', "\u{649}\u{62D}"),
+    ('\u{FCDC}', "\u{649}\u{62E}"),
+    ('\u{FCDD}', "\u{649}\u{645}"),
+    ('\u{FCDE}', "\u{649}\u{6F}"),
+    ('\u{FCDF}', "\u{649}\u{674}\u{645}"),
+    ('\u{FCE0}', "\u{649}\u{674}\u{6F}"),
+    ('\u{FCE1}', "\u{628}\u{645}"),
+    ('\u{FCE2}', "\u{628}\u{6F}"),
+    ('\u{FCE3}', "\u{62A}\u{645}"),
+    ('\u{FCE4}', "\u{62A}\u{6F}"),
+    ('\u{FCE5}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FCE6}', "\u{649}\u{6DB}\u{6F}"),
+    ('\u{FCE7}', "\u{633}\u{645}"),
+    ('\u{FCE8}', "\u{633}\u{6F}"),
+    ('\u{FCE9}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FCEA}', "\u{633}\u{6DB}\u{6F}"),
+    ('\u{FCEB}', "\u{643}\u{644}"),
+    ('\u{FCEC}', "\u{643}\u{645}"),
+    ('\u{FCED}', "\u{644}\u{645}"),
+    ('\u{FCEE}', "\u{646}\u{645}"),
+    ('\u{FCEF}', "\u{646}\u{6F}"),
+    ('\u{FCF0}', "\u{649}\u{645}"),
+    ('\u{FCF1}', "\u{649}\u{6F}"),
+    ('\u{FCF2}', "\u{FE77}\u{651}"),
+    ('\u{FCF3}', "\u{FE79}\u{651}"),
+    ('\u{FCF4}', "\u{FE7B}\u{651}"),
+    ('\u{FCF5}', "\u{637}\u{649}"),
+    ('\u{FCF6}', "\u{637}\u{649}"),
+    ('\u{FCF7}', "\u{639}\u{649}"),
+    ('\u{FCF8}', "\u{639}\u{649}"),
+    ('\u{FCF9}', "\u{63A}\u{649}"),
+    ('\u{FCFA}', "\u{63A}\u{649}"),
+    ('\u{FCFB}', "\u{633}\u{649}"),
+    ('\u{FCFC}', "\u{633}\u{649}"),
+    ('\u{FCFD}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FCFE}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FCFF}', "\u{62D}\u{649}"),
+    ('\u{FD00}', "\u{62D}\u{649}"),
+    ('\u{FD01}', "\u{62C}\u{649}"),
+    ('\u{FD02}', "\u{62C}\u{649}"),
+    ('\u{FD03}', "\u{62E}\u{649}"),
+    ('\u{FD04}', "\u{62E}\u{649}"),
+    ('\u{FD05}', "\u{635}\u{649}"),
+    ('\u{FD06}', "\u{635}\u{649}"),
+    ('\u{FD07}', "\u{636}\u{649}"),
+    ('\u{FD08}', "\u{636}\u{649}"),
+    ('\u{FD09}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD0A}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD0B}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD0C}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD0D}', "\u{633}\u{6DB}\u{631}"),
+    ('\u{FD0E}', "\u{633}\u{631}"),
+    ('\u{FD0F}', "\u{635}\u{631}"),
+    ('\u{FD10}', "\u{636}\u{631}"),
+    ('\u{FD11}', "\u{637}\u{649}"),
+    ('\u{FD12}', "\u{637}\u{649}"),
+    ('\u{FD13}', "\u{639}\u{649}"),
+    ('\u{FD14}', "\u{639}\u{649}"),
+    ('\u{FD15}', "\u{63A}\u{649}"),
+    ('\u{FD16}', "\u{63A}\u{649}"),
+    ('\u{FD17}', "\u{633}\u{649}"),
+    ('\u{FD18}', "\u{633}\u{649}"),
+    ('\u{FD19}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FD1A}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FD1B}', "\u{62D}\u{649}"),
+    ('\u{FD1C}', "\u{62D}\u{649}"),
+    ('\u{FD1D}', "\u{62C}\u{649}"),
+    ('\u{FD1E}', "\u{62C}\u{649}"),
+    ('\u{FD1F}', "\u{62E}\u{649}"),
+    ('\u{FD20}', "\u{62E}\u{649}"),
+    ('\u{FD21}', "\u{635}\u{649}"),
+    ('\u{FD22}', "\u{635}\u{649}"),
+    ('\u{FD23}', "\u{636}\u{649}"),
+    ('\u{FD24}', "\u{636}\u{649}"),
+    ('\u{FD25}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD26}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD27}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD28}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD29}', "\u{633}\u{6DB}\u{631}"),
+    ('\u{FD2A}', "\u{633}\u{631}"),
+    ('\u{FD2B}', "\u{635}\u{631}"),
+    ('\u{FD2C}', "\u{636}\u{631}"),
+    ('\u{FD2D}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD2E}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD2F}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD30}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD31}', "\u{633}\u{6F}"),
+    ('\u{FD32}', "\u{633}\u{6DB}\u{6F}"),
+    ('\u{FD33}', "\u{637}\u{645}"),
+    ('\u{FD34}', "\u{633}\u{62C}"),
+    ('\u{FD35}', "\u{633}\u{62D}"),
+    ('\u{FD36}', "\u{633}\u{62E}"),
+    ('\u{FD37}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD38}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD39}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD3A}', "\u{637}\u{645}"),
+    ('\u{FD3B}', "\u{638}\u{645}"),
+    ('\u{FD3C}', "\u{6C}\u{30B}"),
+    ('\u{FD3D}', "\u{6C}\u{30B}"),
+    ('\u{FD3E}', "\u{28}"),
+    ('\u{FD3F}', "\u{29}"),
+    ('\u{FD50}', "\u{62A}\u{62C}\u{645}"),
+    ('\u{FD51}', "\u{62A}\u{62D}\u{62C}"),
+    ('\u{FD52}', "\u{62A}\u{62D}\u{62C}"),
+    ('\u{FD53}', "\u{62A}\u{62D}\u{645}"),
+    ('\u{FD54}', "\u{62A}\u{62E}\u{645}"),
+    ('\u{FD55}', "\u{62A}\u{645}\u{62C}"),
+    ('\u{FD56}', "\u{62A}\u{645}\u{62D}"),
+    ('\u{FD57}', "\u{62A}\u{645}\u{62E}"),
+    ('\u{FD58}', "\u{62C}\u{645}\u{62D}"),
+    ('\u{FD59}', "\u{62C}\u{645}\u{62D}"),
+    ('\u{FD5A}', "\u{62D}\u{645}\u{649}"),
+    ('\u{FD5B}', "\u{62D}\u{645}\u{649}"),
+    ('\u{FD5C}', "\u{633}\u{62D}\u{62C}"),
+    ('\u{FD5D}', "\u{633}\u{62C}\u{62D}"),
+    ('\u{FD5E}', "\u{633}\u{62C}\u{649}"),
+    ('\u{FD5F}', "\u{633}\u{645}\u{62D}"),
+    ('\u{FD60}', "\u{633}\u{645}\u{62D}"),
+    ('\u{FD61}', "\u{633}\u{645}\u{62C}"),
+    ('\u{FD62}', "\u{633}\u{645}\u{645}"),
+    ('\u{FD63}', "\u{633}\u{645}\u{645}"),
+    ('\u{FD64}', "\u{635}\u{62D}\u{62D}"),
+    ('\u{FD65}', "\u{635}\u{62D}\u{62D}"),
+    ('\u{FD66}', "\u{635}\u{645}\u{645}"),
+    ('\u{FD67}', "\u{633}\u{6DB}\u{62D}\u{645}"),
+    ('\u{FD68}', "\u{633}\u{6DB}\u{62D}\u{645}"),
+    ('\u{FD69}', "\u{633}\u{6DB}\u{62C}\u{649}"),
+    ('\u{FD6A}', "\u{633}\u{6DB}\u{645}\u{62E}"),
+    ('\u{FD6B}', "\u{633}\u{6DB}\u{645}\u{62E}"),
+    ('\u{FD6C}', "\u{633}\u{6DB}\u{645}\u{645}"),
+    ('\u{FD6D}', "\u{633}\u{6DB}\u{645}\u{645}"),
+    ('\u{FD6E}', "\u{636}\u{62D}\u{649}"),
+    ('\u{FD6F}', "\u{636}\u{62E}\u{645}"),
+    ('\u{FD70}', "\u{636}\u{62E}\u{645}"),
+    ('\u{FD71}', "\u{637}\u{645}\u{62D}"),
+    ('\u{FD72}', "\u{637}\u{645}\u{62D}"),
+    ('\u{FD73}', "\u{637}\u{645}\u{645}"),
+    ('\u{FD74}', "\u{637}\u{645}\u{649}"),
+    ('\u{FD75}', "\u{639}\u{62C}\u{645}"),
+    ('\u{FD76}', "\u{639}\u{645}\u{645}"),
+    ('\u{FD77}', "\u{639}\u{645}\u{645}"),
+    ('\u{FD78}', "\u{639}\u{645}\u{649}"),
+    ('\u{FD79}', "\u{63A}\u{645}\u{645}"),
+    ('\u{FD7A}', "\u{63A}\u{645}\u{649}"),
+    ('\u{FD7B}', "\u{63A}\u{645}\u{649}"),
+    ('\u{FD7C}', "\u{641}\u{62E}\u{645}"),
+    ('\u{FD7D}', "\u{641}\u{62E}\u{645}"),
+    ('\u{FD7E}', "\u{642}\u{645}\u{62D}"),
+    ('\u{FD7F}', "\u{642}\u{645}\u{645}"),
+    ('\u{FD80}', "\u{644}\u{62D}\u{645}"),
+    ('\u{FD81}', "\u{644}\u{62D}\u{649}"),
+    ('\u{FD82}', "\u{644}\u{62D}\u{649}"),
+    ('\u{FD83}', "\u{644}\u{62C}\u{62C}"),
+    ('\u{FD84}', "\u{644}\u{62C}\u{62C}"),
+    ('\u{FD85}', "\u{644}\u{62E}\u{645}"),
+    ('\u{FD86}', "\u{644}\u{62E}\u{645}"),
+    ('\u{FD87}', "\u{644}\u{645}\u{62D}"),
+    ('\u{FD88}', "\u{644}\u{645}\u{62D}"),
+    ('\u{FD89}', "\u{645}\u{62D}\u{62C}"),
+    ('\u{FD8A}', "\u{645}\u{62D}\u{645}"),
+    ('\u{FD8B}', "\u{645}\u{62D}\u{649}"),
+    ('\u{FD8C}', "\u{645}\u{62C}\u{62D}"),
+    ('\u{FD8D}', "\u{645}\u{62C}\u{645}"),
+    ('\u{FD8E}', "\u{645}\u{62E}\u{62C}"),
+    ('\u{FD8F}', "\u{645}\u{62E}\u{645}"),
+    ('\u{FD92}', "\u{645}\u{62C}\u{62E}"),
+    ('\u{FD93}', "\u{6F}\u{645}\u{62C}"),
+    ('\u{FD94}', "\u{6F}\u{645}\u{645}"),
+    ('\u{FD95}', "\u{646}\u{62D}\u{645}"),
+    ('\u{FD96}', "\u{646}\u{62D}\u{649}"),
+    ('\u{FD97}', "\u{646}\u{62C}\u{645}"),
+    ('\u{FD98}', "\u{646}\u{62C}\u{645}"),
+    ('\u{FD99}', "\u{646}\u{62C}\u{649}"),
+    ('\u{FD9A}', "\u{646}\u{645}\u{649}"),
+    ('\u{FD9B}', "\u{646}\u{645}\u{649}"),
+    ('\u{FD9C}', "\u{649}\u{645}\u{645}"),
+    ('\u{FD9D}', "\u{649}\u{645}\u{645}"),
+    ('\u{FD9E}', "\u{628}\u{62E}\u{649}"),
+    ('\u{FD9F}', "\u{62A}\u{62C}\u{649}"),
+    ('\u{FDA0}', "\u{62A}\u{62C}\u{649}"),
+    ('\u{FDA1}', "\u{62A}\u{62E}\u{649}"),
+    ('\u{FDA2}', "\u{62A}\u{62E}\u{649}"),
+    ('\u{FDA3}', "\u{62A}\u{645}\u{649}"),
+    ('\u{FDA4}', "\u{62A}\u{645}\u{649}"),
+    ('\u{FDA5}', "\u{62C}\u{645}\u{649}"),
+    ('\u{FDA6}', "\u{62C}\u{62D}\u{649}"),
+    ('\u{FDA7}', "\u{62C}\u{645}\u{649}"),
+    ('\u{FDA8}', "\u{633}\u{62E}\u{649}"),
+    ('\u{FDA9}', "\u{635}\u{62D}\u{649}"),
+    ('\u{FDAA}', "\u{633}\u{6DB}\u{62D}\u{649}"),
+    ('\u{FDAB}', "\u{636}\u{62D}\u{649}"),
+    ('\u{FDAC}', "\u{644}\u{62C}\u{649}"),
+    ('\u{FDAD}', "\u{644}\u{645}\u{649}"),
+    ('\u{FDAE}', "\u{649}\u{62D}\u{649}"),
+    ('\u{FDAF}', "\u{649}\u{62C}\u{649}"),
+    ('\u{FDB0}', "\u{649}\u{645}\u{649}"),
+    ('\u{FDB1}', "\u{645}\u{645}\u{649}"),
+    ('\u{FDB2}', "\u{642}\u{645}\u{649}"),
+    ('\u{FDB3}', "\u{646}\u{62D}\u{649}"),
+    ('\u{FDB4}', "\u{642}\u{645}\u{62D}"),
+    ('\u{FDB5}', "\u{644}\u{62D}\u{645}"),
+    ('\u{FDB6}', "\u{639}\u{645}\u{649}"),
+    ('\u{FDB7}', "\u{643}\u{645}\u{649}"),
+    ('\u{FDB8}', "\u{646}\u{62C}\u{62D}"),
+    ('\u{FDB9}', "\u{645}\u{62E}\u{649}"),
+    ('\u{FDBA}', "\u{644}\u{62C}\u{645}"),
+    ('\u{FDBB}', "\u{643}\u{645}\u{645}"),
+    ('\u{FDBC}', "\u{644}\u{62C}\u{645}"),
+    ('\u{FDBD}', "\u{646}\u{62C}\u{62D}"),
+    ('\u{FDBE}', "\u{62C}\u{62D}\u{649}"),
+    ('\u{FDBF}', "\u{62D}\u{62C}\u{649}"),
+    ('\u{FDC0}', "\u{645}\u{62C}\u{649}"),
+    ('\u{FDC1}', "\u{641}\u{645}\u{649}"),
+    ('\u{FDC2}', "\u{628}\u{62D}\u{649}"),
+    ('\u{FDC3}', "\u{643}\u{645}\u{645}"),
+    ('\u{FDC4}', "\u{639}\u{62C}\u{645}"),
+    ('\u{FDC5}', "\u{635}\u{645}\u{645}"),
+    ('\u{FDC6}', "\u{633}\u{62E}\u{649}"),
+    ('\u{FDC7}', "\u{646}\u{62C}\u{649}"),
+    ('\u{FDF0}', "\u{635}\u{644}\u{649}"),
+    ('\u{FDF1}', "\u{642}\u{644}\u{649}"),
+    ('\u{FDF2}', "\u{6C}\u{644}\u{644}\u{651}\u{670}\u{6F}"),
+    ('\u{FDF3}', "\u{6C}\u{643}\u{628}\u{631}"),
+    ('\u{FDF4}', "\u{645}\u{62D}\u{645}\u{62F}"),
+    ('\u{FDF5}', "\u{635}\u{644}\u{639}\u{645}"),
+    ('\u{FDF6}', "\u{631}\u{633}\u{648}\u{644}"),
+    ('\u{FDF7}', "\u{639}\u{644}\u{649}\u{6F}"),
+    ('\u{FDF8}', "\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{FDF9}', "\u{635}\u{644}\u{649}"),
+    ('\u{FDFA}', "\u{635}\u{644}\u{649}\u{20}\u{6C}\u{644}\u{644}\u{6F}\u{20}\u{639}\u{644}\u{649}\u{6F}\u{20}\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{FDFB}', "\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{6C}\u{644}\u{6F}"),
+    ('\u{FDFC}', "\u{631}\u{649}\u{6C}\u{644}"),
+    ('\u{FE19}', "\u{2D57}"),
+    ('\u{FE30}', "\u{3A}"),
+    ('\u{FE31}', "\u{2502}"),
+    ('\u{FE34}', "\u{2307}"),
+    ('\u{FE35}', "\u{23DC}"),
+    ('\u{FE36}', "\u{23DD}"),
+    ('\u{FE37}', "\u{23DE}"),
+    ('\u{FE38}', "\u{23DF}"),
+    ('\u{FE39}', "\u{23E0}"),
+    ('\u{FE3A}', "\u{23E1}"),
+    ('\u{FE49}', "\u{2C9}"),
+    ('\u{FE4A}', "\u{2C9}"),
+    ('\u{FE4B}', "\u{2C9}"),
+    ('\u{FE4C}', "\u{2C9}"),
+    ('\u{FE4D}', "\u{5F}"),
+    ('\u{FE4E}', "\u{5F}"),
+    ('\u{FE4F}', "\u{5F}"),
+    ('\u{FE58}', "\u{2D}"),
+    ('\u{FE68}', "\u{5C}"),
+    ('\u{FE80}', "\u{621}"),
+    ('\u{FE81}', "\u{627}\u{653}"),
+    ('\u{FE82}', "\u{627}\u{653}"),
+    ('\u{FE83}', "\u{6C}\u{674}"),
+    ('\u{FE84}', "\u{6C}\u{674}"),
+    ('\u{FE85}', "\u{648}\u{674}"),
+    ('\u{FE86}', "\u{648}\u{674}"),
+    ('\u{FE87}', "\u{6C}\u{655}"),
+    ('\u{FE88}', "\u{6C}\u{655}"),
+    ('\u{FE89}', "\u{649}\u{674}"),
+    ('\u{FE8A}', "\u{649}\u{674}"),
+    ('\u{FE8B}', "\u{649}\u{674}"),
+    ('\u{FE8C}', "\u{649}\u{674}"),
+    ('\u{FE8D}', "\u{6C}"),
+    ('\u{FE8E}', "\u{6C}"),
+    ('\u{FE8F}', "\u{628}"),
+    ('\u{FE90}', "\u{628}"),
+    ('\u{FE91}', "\u{628}"),
+    ('\u{FE92}', "\u{628}"),
+    ('\u{FE93}', "\u{629}"),
+    ('\u{FE94}', "\u{629}"),
+    ('\u{FE95}', "\u{62A}"),
+    ('\u{FE96}', "\u{62A}"),
+    ('\u{FE97}', "\u{62A}"),
+    ('\u{FE98}', "\u{62A}"),
+    ('\u{FE99}', "\u{649}\u{6DB}"),
+    ('\u{FE9A}', "\u{649}\u{6DB}"),
+    ('\u{FE9B}', "\u{649}\u{6DB}"),
+    ('\u{FE9C}', "\u{649}\u{6DB}"),
+    ('\u{FE9D}', "\u{62C}"),
+    ('\u{FE9E}', "\u{62C}"),
+    ('\u{FE9F}', "\u{62C}"),
+    ('\u{FEA0}', "\u{62C}"),
+    ('\u{FEA1}', "\u{62D}"),
+    ('\u{FEA2}', "\u{62D}"),
+    ('\u{FEA3}', "\u{62D}"),
+    ('\u{FEA4}', "\u{62D}"),
+    ('\u{FEA5}', "\u{62E}"),
+    ('\u{FEA6}', "\u{62E}"),
+    ('\u{FEA7}', "\u{62E}"),
+    ('\u{FEA8}', "\u{62E}"),
+    ('\u{FEA9}', "\u{62F}"),
+    ('\u{FEAA}', "\u{62F}"),
+    ('\u{FEAB}', "\u{630}"),
+    ('\u{FEAC}', "\u{630}"),
+    ('\u{FEAD}', "\u{631}"),
+    ('\u{FEAE}', "\u{631}"),
+    ('\u{FEAF}', "\u{632}"),
+    ('\u{FEB0}', "\u{632}"),
+    ('\u{FEB1}', "\u{633}"),
+    ('\u{FEB2}', "\u{633}"),
+    ('\u{FEB3}', "\u{633}"),
+    ('\u{FEB4}', "\u{633}"),
+    ('\u{FEB5}', "\u{633}\u{6DB}"),
+    ('\u{FEB6}', "\u{633}\u{6DB}"),
+    ('\u{FEB7}', "\u{633}\u{6DB}"),
+    ('\u{FEB8}', "\u{633}\u{6DB}"),
+    ('\u{FEB9}', "\u{635}"),
+    ('\u{FEBA}', "\u{635}"),
+    ('\u{FEBB}', "\u{635}"),
+    ('\u{FEBC}', "\u{635}"),
+    ('\u{FEBD}', "\u{636}"),
+    ('\u{FEBE}', "\u{636}"),
+    ('\u{FEBF}', "\u{636}"),
+    ('\u{FEC0}', "\u{636}"),
+    ('\u{FEC1}', "\u{637}"),
+    ('\u{FEC2}', "\u{637}"),
+    ('\u{FEC3}', "\u{637}"),
+    ('\u{FEC4}', "\u{637}"),
+    ('\u{FEC5}', "\u{638}"),
+    ('\u{FEC6}', "\u{638}"),
+    ('\u{FEC7}', "\u{638}"),
+    ('\u{FEC8}', "\u{638}"),
+    ('\u{FEC9}', "\u{639}"),
+    ('\u{FECA}', "\u{639}"),
+    ('\u{FECB}', "\u{639}"),
+    ('\u{FECC}', "\u{639}"),
+    ('\u{FECD}', "\u{63A}"),
+    ('\u{FECE}', "\u{63A}"),
+    ('\u{FECF}', "\u{63A}"),
+    ('\u{FED0}', "\u{63A}"),
+    ('\u{FED1}', "\u{641}"),
+    ('\u{FED2}', "\u{641}"),
+    ('\u{FED3}', "\u{641}"),
+    ('\u{FED4}', "\u{641}"),
+    ('\u{FED5}', "\u{642}"),
+    ('\u{FED6}', "\u{642}"),
+    ('\u{FED7}', "\u{642}"),
+    ('\u{FED8}', "\u{642}"),
+    ('\u{FED9}', "\u{643}"),
+    ('\u{FEDA}', "\u{643}"),
+    ('\u{FEDB}', "\u{643}"),
+    ('\u{FEDC}', "\u{643}"),
+    ('\u{FEDD}', "\u{644}"),
+    ('\u{FEDE}', "\u{644}"),
+    ('\u{FEDF}', "\u{644}"),
+    ('\u{FEE0}', "\u{644}"),
+    ('\u{FEE1}', "\u{645}"),
+    ('\u{FEE2}', "\u{645}"),
+    ('\u{FEE3}', "\u{645}"),
+    ('\u{FEE4}', "\u{645}"),
+    ('\u{FEE5}', "\u{646}"),
+    ('\u{FEE6}', "\u{646}"),
+    ('\u{FEE7}', "\u{646}"),
+    ('\u{FEE8}', "\u{646}"),
+    ('\u{FEE9}', "\u{6F}"),
+    ('\u{FEEA}', "\u{6F}"),
+    ('\u{FEEB}', "\u{6F}"),
+    ('\u{FEEC}', "\u{6F}"),
+    ('\u{FEED}', "\u{648}"),
+    ('\u{FEEE}', "\u{648}"),
+    ('\u{FEEF}', "\u{649}"),
+    ('\u{FEF0}', "\u{649}"),
+    ('\u{FEF1}', "\u{649}"),
+    ('\u{FEF2}', "\u{649}"),
+    ('\u{FEF3}', "\u{649}"),
+    ('\u{FEF4}', "\u{649}"),
+    ('\u{FEF5}', "\u{644}\u{627}\u{653}"),
+    ('\u{FEF6}', "\u{644}\u{627}\u{653}"),
+    ('\u{FEF7}', "\u{644}\u{6C}\u{674}"),
+    ('\u{FEF8}', "\u{644}\u{6C}\u{674}"),
+    ('\u{FEF9}', "\u{644}\u{6C}\u{655}"),
+    ('\u{FEFA}', "\u{644}\u{6C}\u{655}"),
+    ('\u{FEFB}', "\u{644}\u{6C}"),
+    ('\u{FEFC}', "\u{644}\u{6C}"),
+    ('\u{FF01}', "\u{21}"),
+    ('\u{FF02}', "\u{27}\u{27}"),
+    ('\u{FF07}', "\u{27}"),
+    ('\u{FF0D}', "\u{30FC}"),
+    ('\u{FF1A}', "\u{3A}"),
+    ('\u{FF21}', "\u{41}"),
+    ('\u{FF22}', "\u{42}"),
+    ('\u{FF23}', "\u{43}"),
+    ('\u{FF25}', "\u{45}"),
+    ('\u{FF28}', "\u{48}"),
+    ('\u{FF29}', "\u{6C}"),
+    ('\u{FF2A}', "\u{4A}"),
+    ('\u{FF2B}', "\u{4B}"),
+    ('\u{FF2D}', "\u{4D}"),
+    ('\u{FF2E}', "\u{4E}"),
+    ('\u{FF2F}', "\u{4F}"),
+    ('\u{FF30}', "\u{50}"),
+    ('\u{FF33}', "\u{53}"),
+    ('\u{FF34}', "\u{54}"),
+    ('\u{FF38}', "\u{58}"),
+    ('\u{FF39}', "\u{59}"),
+    ('\u{FF3A}', "\u{5A}"),
+    ('\u{FF3B}', "\u{28}"),
+    ('\u{FF3C}', "\u{5C}"),
+    ('\u{FF3D}', "\u{29}"),
+    ('\u{FF3E}', "\u{FE3F}"),
+    ('\u{FF40}', "\u{27}"),
+    ('\u{FF41}', "\u{61}"),
+    ('\u{FF43}', "\u{63}"),
+    ('\u{FF45}', "\u{65}"),
+    ('\u{FF47}', "\u{67}"),
+    ('\u{FF48}', "\u{68}"),
+    ('\u{FF49}', "\u{69}"),
+    ('\u{FF4A}', "\u{6A}"),
+    ('\u{FF4C}', "\u{6C}"),
+    ('\u{FF4F}', "\u{6F}"),
+    ('\u{FF50}', "\u{70}"),
+    ('\u{FF53}', "\u{73}"),
+    ('\u{FF56}', "\u{76}"),
+    ('\u{FF58}', "\u{78}"),
+    ('\u{FF59}', "\u{79}"),
+    ('\u{FF5C}', "\u{2502}"),
+    ('\u{FF5E}', "\u{301C}"),
+    ('\u{FF65}', "\u{B7}"),
+    ('\u{FFE3}', "\u{2C9}"),
+    ('\u{FFE8}', "\u{6C}"),
+    ('\u{FFED}', "\u{25AA}"),
+    ('\u{10101}', "\u{B7}"),
+    ('\u{1018E}', "\u{4E}\u{30A}"),
+    ('\u{10196}', "\u{58}\u{335}"),
+    ('\u{10197}', "\u{56}\u{335}"),
+    ('\u{10198}', "\u{6C}\u{335}\u{6C}\u{335}\u{53}\u{335}"),
+    ('\u{10199}', "\u{6C}\u{335}\u{6C}\u{335}"),
+    ('\u{101A0}', "\u{2CE8}"),
+    ('\u{10282}', "\u{42}"),
+    ('\u{10285}', "\u{394}"),
+    ('\u{10286}', "\u{45}"),
+    ('\u{10287}', "\u{46}"),
+    ('\u{1028A}', "\u{6C}"),
+    ('\u{1028D}', "\u{245}"),
+    ('\u{10290}', "\u{58}"),
+    ('\u{10292}', "\u{4F}"),
+    ('\u{10294}', "\u{16DC}"),
+    ('\u{10295}', "\u{50}"),
+    ('\u{10296}', "\u{53}"),
+    ('\u{10297}', "\u{54}"),
+    ('\u{1029B}', "\u{2B}"),
+    ('\u{102A0}', "\u{41}"),
+    ('\u{102A1}', "\u{42}"),
+    ('\u{102A2}', "\u{43}"),
+    ('\u{102A3}', "\u{394}"),
+    ('\u{102A5}', "\u{46}"),
+    ('\u{102AB}', "\u{4F}"),
+    ('\u{102AD}', "\u{3D8}"),
+    ('\u{102B0}', "\u{4D}"),
+    ('\u{102B1}', "\u{54}"),
+    ('\u{102B2}', "\u{59}"),
+    ('\u{102B3}', "\u{3A6}"),
+    ('\u{102B4}', "\u{58}"),
+    ('\u{102B5}', "\u{3A8}"),
+    ('\u{102B6}', "\u{3A9}"),
+    ('\u{102B8}', "\u{2D40}"),
+    ('\u{102CF}', "\u{48}"),
+    ('\u{102E1}', "\u{62F}"),
+    ('\u{102E4}', "\u{648}"),
+    ('\u{102E8}', "\u{637}"),
+    ('\u{102F2}', "\u{635}"),
+    ('\u{102F5}', "\u{5A}"),
+    ('\u{10301}', "\u{42}"),
+    ('\u{10302}', "\u{43}"),
+    ('\u{10309}', "\u{6C}"),
+    ('\u{10311}', "\u{4D}"),
+    ('\u{10312}', "\u{3D8}"),
+    ('\u{10315}', "\u{54}"),
+    ('\u{10317}', "\u{58}"),
+    ('\u{1031A}', "\u{38}"),
+    ('\u{1031F}', "\u{2A}"),
+    ('\u{10320}', "\u{6C}"),
+    ('\u{10322}', "\u{58}"),
+    ('\u{103D1}', "\u{10382}"),
+    ('\u{103D3}', "\u{10393}"),
+    ('\u{10401}', "\u{190}"),
+    ('\u{10404}', "\u{4F}"),
+    ('\u{10411}', "\u{A4F6}"),
+    ('\u{10415}', "\u{43}"),
+    ('\u{1041B}', "\u{4C}"),
+    ('\u{1041F}', "\u{2C70}"),
+    ('\u{10420}', "\u{53}"),
+    ('\u{10423}', "\u{186}"),
+    ('\u{10425}', "\u{418}"),
+    ('\u{10429}', "\u{A793}"),
+    ('\u{1042A}', "\u{29A}"),
+    ('\u{1042C}', "\u{6F}"),
+    ('\u{1043D}', "\u{63}"),
+    ('\u{1043F}', "\u{277}"),
+    ('\u{10442}', "\u{25E}"),
+    ('\u{10443}', "\u{29F}"),
+    ('\u{10448}', "\u{73}"),
+    ('\u{1044B}', "\u{254}"),
+    ('\u{1044D}', "\u{1D0E}"),
+    ('\u{104A0}', "\u{10486}"),
+    ('\u{104B0}', "\u{245}"),
+    ('\u{104B4}', "\u{52}"),
+    ('\u{104BC}', "\u{4C3}"),
+    ('\u{104C2}', "\u{4F}"),
+    ('\u{104C3}', "\u{298}"),
+    ('\u{104C4}', "\u{DE}"),
+    ('\u{104CD}', "\u{40B}"),
+    ('\u{104CE}', "\u{55}"),
+    ('\u{104D0}', "\u{16E6}"),
+    ('\u{104D1}', "\u{3A8}"),
+    ('\u{104D2}', "\u{37}"),
+    ('\u{104D8}', "\u{28C}"),
+    ('\u{104DB}', "\u{3BB}"),
+    ('\u{104EA}', "\u{6F}"),
+    ('\u{104EB}', "\u{A669}"),
+    ('\u{104F6}', "\u{75}"),
+    ('\u{104F9}', "\u{3C8}"),
+    ('\u{10513}', "\u{4E}"),
+    ('\u{10516}', "\u{4F}"),
+    ('\u{10518}', "\u{4B}"),
+    ('\u{1051C}', "\u{43}"),
+    ('\u{1051D}', "\u{56}"),
+    ('\u{10525}', "\u{46}"),
+    ('\u{10526}', "\u{4C}"),
+    ('\u{10527}', "\u{58}"),
+    ('\u{10A3A}', "\u{323}"),
+    ('\u{10A50}', "\u{2E}"),
+    ('\u{10A57}', "\u{10A56}\u{10A56}"),
+    ('\u{10CFA}', "\u{10CA5}"),
+    ('\u{10CFC}', "\u{10C82}"),
+    ('\u{110BB}', "\u{970}"),
+    ('\u{111C7}', "\u{970}"),
+    ('\u{111CA}', "\u{323}"),
+    ('\u{111CB}', "\u{93A}"),
+    ('\u{111DB}', "\u{A8FC}"),
+    ('\u{111DC}', "\u{A8FB}"),
+    ('\u{111DE}', "\u{2248}"),
+    ('\u{11300}', "\u{30A}"),
+    ('\u{11413}', "\u{11434}\u{11442}\u{11412}"),
+    ('\u{11419}', "\u{11434}\u{11442}\u{11418}"),
+    ('\u{11424}', "\u{11434}\u{11442}\u{11423}"),
+    ('\u{1142A}', "\u{11434}\u{11442}\u{11429}"),
+    ('\u{1142D}', "\u{11434}\u{11442}\u{1142C}"),
+    ('\u{1142F}', "\u{11434}\u{11442}\u{1142E}"),
+    ('\u{1144C}', "\u{1144B}\u{1144B}"),
+    ('\u{11492}', "\u{998}"),
+    ('\u{11494}', "\u{99A}"),
+    ('\u{11496}', "\u{99C}"),
+    ('\u{11498}', "\u{99E}"),
+    ('\u{11499}', "\u{99F}"),
+    ('\u{1149B}', "\u{9A1}"),
+    ('\u{1149D}', "\u{9B2}"),
+    ('\u{1149E}', "\u{9A4}"),
+    ('\u{1149F}', "\u{9A5}"),
+    ('\u{114A0}', "\u{9A6}"),
+    ('\u{114A1}', "\u{9A7}"),
+    ('\u{114A2}', "\u{9A8}"),
+    ('\u{114A3}', "\u{9AA}"),
+    ('\u{114A7}', "\u{9AE}"),
+    ('\u{114A8}', "\u{9AF}"),
+    ('\u{114A9}', "\u{9AC}"),
+    ('\u{114AA}', "\u{9A3}"),
+    ('\u{114AB}', "\u{9B0}"),
+    ('\u{114AD}', "\u{9B7}"),
+    ('\u{114AE}', "\u{9B8}"),
+    ('\u{114B0}', "\u{9BE}"),
+    ('\u{114B1}', "\u{9BF}"),
+    ('\u{114B9}', "\u{9C7}"),
+    ('\u{114BD}', "\u{9D7}"),
+    ('\u{114BF}', "\u{306}\u{307}"),
+    ('\u{114C1}', "\u{983}"),
+    ('\u{114C2}', "\u{9CD}"),
+    ('\u{114C3}', "\u{323}"),
+    ('\u{114C4}', "\u{9BD}"),
+    ('\u{114C5}', "\u{77}\u{307}"),
+    ('\u{114D0}', "\u{4F}"),
+    ('\u{114D1}', "\u{9E7}"),
+    ('\u{114D2}', "\u{9E8}"),
+    ('\u{114D6}', "\u{9EC}"),
+    ('\u{115D8}', "\u{11582}"),
+    ('\u{115D9}', "\u{11582}"),
+    ('\u{115DA}', "\u{11583}"),
+    ('\u{115DB}', "\u{11584}"),
+    ('\u{115DC}', "\u{115B2}"),
+    ('\u{115DD}', "\u{115B3}"),
+    ('\u{11642}', "\u{11641}\u{11641}"),
+    ('\u{11700}', "\u{72}\u{6E}"),
+    ('\u{11706}', "\u{76}"),
+    ('\u{1170A}', "\u{77}"),
+    ('\u{1170E}', "\u{77}"),
+    ('\u{1170F}', "\u{77}"),
+    ('\u{118A0}', "\u{56}"),
+    ('\u{118A2}', "\u{46}"),
+    ('\u{118A3}', "\u{4C}"),
+    ('\u{118A4}', "\u{59}"),
+    ('\u{118A6}', "\u{45}"),
+    ('\u{118A8}', "\u{2207}"),
+    ('\u{118A9}', "\u{5A}"),
+    ('\u{118AC}', "\u{39}"),
+    ('\u{118AE}', "\u{45}"),
+    ('\u{118AF}', "\u{34}"),
+    ('\u{118B2}', "\u{4C}"),
+    ('\u{118B5}', "\u{4F}"),
+    ('\u{118B7}', "\u{16DC}"),
+    ('\u{118B8}', "\u{55}"),
+    ('\u{118BB}', "\u{35}"),
+    ('\u{118BC}', "\u{54}"),
+    ('\u{118C0}', "\u{76}"),
+    ('\u{118C1}', "\u{73}"),
+    ('\u{118C2}', "\u{46}"),
+    ('\u{118C3}', "\u{69}"),
+    ('\u{118C4}', "\u{7A}"),
+    ('\u{118C6}', "\u{37}"),
+    ('\u{118C8}', "\u{6F}"),
+    ('\u{118CA}', "\u{33}"),
+    ('\u{118CC}', "\u{39}"),
+    ('\u{118CE}', "\u{A793}"),
+    ('\u{118D5}', "\u{36}"),
+    ('\u{118D6}', "\u{39}"),
+    ('\u{118D7}', "\u{6F}"),
+    ('\u{118D8}', "\u{75}"),
+    ('\u{118DC}', "\u{79}"),
+    ('\u{118E0}', "\u{4F}"),
+    ('\u{118E3}', "\u{72}\u{6E}"),
+    ('\u{118E4}', "\u{669}"),
+    ('\u{118E5}', "\u{5A}"),
+    ('\u{118E6}', "\u{57}"),
+    ('\u{118E9}', "\u{43}"),
+    ('\u{118EC}', "\u{58}"),
+    ('\u{118EF}', "\u{57}"),
+    ('\u{118F2}', "\u{43}"),
+    ('\u{11AE6}', "\u{11AE5}\u{11AEF}"),
+    ('\u{11AE7}', "\u{11AE5}\u{11AF0}"),
+    ('\u{11AE8}', "\u{11AE5}\u{11AE5}"),
+    ('\u{11AE9}', "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    ('\u{11AEA}', "\u{11AE5}\u{11AE5}\u{11AF0}"),
+    ('\u{11AEC}', "\u{11AEB}\u{11AEF}"),
+    ('\u{11AED}', "\u{11AEB}\u{11AEB}"),
+    ('\u{11AEE}', "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    ('\u{11AF4}', "\u{11AF3}\u{11AEF}"),
+    ('\u{11AF5}', "\u{11AF3}\u{11AF0}"),
+    ('\u{11AF6}', "\u{11AF3}\u{11AF3}"),
+    ('\u{11AF7}', "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    ('\u{11AF8}', "\u{11AF3}\u{11AF3}\u{11AF0}"),
+    ('\u{11C42}', "\u{11C41}\u{11C41}"),
+    ('\u{11CB2}', "\u{11CAA}"),
+    ('\u{12038}', "\u{1039A}"),
+    ('\u{132F9}', "\u{1099E}"),
+    ('\u{16F07}', "\u{393}"),
+    ('\u{16F08}', "\u{56}"),
+    ('\u{16F0A}', "\u{54}"),
+    ('\u{16F16}', "\u{4C}"),
+    ('\u{16F1A}', "\u{394}"),
+    ('\u{16F1C}', "\u{A658}"),
+    ('\u{16F26}', "\u{A4F6}"),
+    ('\u{16F28}', "\u{6C}"),
+    ('\u{16F2D}', "\u{190}"),
+    ('\u{16F35}', "\u{52}"),
+    ('\u{16F3A}', "\u{53}"),
+    ('\u{16F3B}', "\u{33}"),
+    ('\u{16F3D}', "\u{245}"),
+    ('\u{16F3F}', "\u{3E}"),
+    ('\u{16F40}', "\u{41}"),
+    ('\u{16F42}', "\u{55}"),
+    ('\u{16F43}', "\u{59}"),
+    ('\u{16F51}', "\u{27}"),
+    ('\u{16F52}', "\u{27}"),
+    ('\u{1D114}', "\u{7B}"),
+    ('\u{1D16D}', "\u{2E}"),
+    ('\u{1D202}', "\u{4FE}"),
+    ('\u{1D206}', "\u{33}"),
+    ('\u{1D20B}', "\u{418}"),
+    ('\u{1D20D}', "\u{56}"),
+    ('\u{1D20F}', "\u{5C}"),
+    ('\u{1D212}', "\u{37}"),
+    ('\u{1D213}', "\u{46}"),
+    ('\u{1D214}', "\u{102BC}"),
+    ('\u{1D215}', "\u{A4F6}"),
+    ('\u{1D216}', "\u{52}"),
+    ('\u{1D217}', "\u{2C6F}"),
+    ('\u{1D21A}', "\u{4F}\u{335}"),
+    ('\u{1D21B}', "\u{2144}"),
+    ('\u{1D21C}', "\u{A4D5}"),
+    ('\u{1D221}', "\u{190}"),
+    ('\u{1D222}', "\u{460}"),
+    ('\u{1D22A}', "\u{4C}"),
+    ('\u{1D22B}', "\u{A4F6}"),
+    ('\u{1D230}', "\u{A7FB}"),
+    ('\u{1D236}', "\u{3C}"),
+    ('\u{1D237}', "\u{3E}"),
+    ('\u{1D238}', "\u{228F}"),
+    ('\u{1D239}', "\u{2290}"),
+    ('\u{1D23A}', "\u{2F}"),
+    ('\u{1D23B}', "\u{5C}"),
+    ('\u{1D23F}', "\u{16CB}"),
+    ('\u{1D245}', "\u{548}"),
+    ('\u{1D400}', "\u{41}"),
+    ('\u{1D401}', "\u{42}"),
+    ('\u{1D402}', "\u{43}"),
+    ('\u{1D403}', "\u{44}"),
+    ('\u{1D404}', "\u{45}"),
+    ('\u{1D405}', "\u{46}"),
+    ('\u{1D406}', "\u{47}"),
+    ('\u{1D407}', "\u{48}"),
+    ('\u{1D408}', "\u{6C}"),
+    ('\u{1D409}', "\u{4A}"),
+    ('\u{1D40A}', "\u{4B}"),
+    ('\u{1D40B}', "\u{4C}"),
+    ('\u{1D40C}', "\u{4D}"),
+    ('\u{1D40D}', "\u{4E}"),
+    ('\u{1D40E}', "\u{4F}"),
+    ('\u{1D40F}', "\u{50}"),
+    ('\u{1D410}', "\u{51}"),
+    ('\u{1D411}', "\u{52}"),
+    ('\u{1D412}', "\u{53}"),
+    ('\u{1D413}', "\u{54}"),
+    ('\u{1D414}', "\u{55}"),
+    ('\u{1D415}', "\u{56}"),
+    ('\u{1D416}', "\u{57}"),
+    ('\u{1D417}', "\u{58}"),
+    ('\u{1D418}', "\u{59}"),
+    ('\u{1D419}', "\u{5A}"),
+    ('\u{1D41A}', "\u{61}"),
+    ('\u{1D41B}', "\u{62}"),
+    ('\u{1D41C}', "\u{63}"),
+    ('\u{1D41D}', "\u{64}"),
+    ('\u{1D41E}', "\u{65}"),
+    ('\u{1D41F}', "\u{66}"),
+    ('\u{1D420}', "\u{67}"),
+    ('\u{1D421}', "\u{68}"),
+    ('\u{1D422}', "\u{69}"),
+    ('\u{1D423}', "\u{6A}"),
+    ('\u{1D424}', "\u{6B}"),
+    ('\u{1D425}', "\u{6C}"),
+    ('\u{1D426}', "\u{72}\u{6E}"),
+    ('\u{1D427}', "\u{6E}"),
+    ('\u{1D428}', "\u{6F}"),
+    ('\u{1D429}', "\u{70}"),
+    ('\u{1D42A}', "\u{71}"),
+    ('\u{1D42B}', "\u{72}"),
+    ('\u{1D42C}', "\u{73}"),
+    ('\u{1D42D}', "\u{74}"),
+    ('\u{1D42E}', "\u{75}"),
+    ('\u{1D42F}', "\u{76}"),
+    ('\u{1D430}', "\u{77}"),
+    ('\u{1D431}', "\u{78}"),
+    ('\u{1D432}', "\u{79}"),
+    ('\u{1D433}', "\u{7A}"),
+    ('\u{1D434}', "\u{41}"),
+    ('\u{1D435}', "\u{42}"),
+    ('\u{1D436}', "\u{43}"),
+    ('\u{1D437}', "\u{44}"),
+    ('\u{1D438}', "\u{45}"),
+    ('\u{1D439}', "\u{46}"),
+    ('\u{1D43A}', "\u{47}"),
+    ('\u{1D43B}', "\u{48}"),
+    ('\u{1D43C}', "\u{6C}"),
+    ('\u{1D43D}', "\u{4A}"),
+    ('\u{1D43E}', "\u{4B}"),
+    ('\u{1D43F}', "\u{4C}"),
+    ('\u{1D440}', "\u{4D}"),
+    ('\u{1D441}', "\u{4E}"),
+    ('\u{1D442}', "\u{4F}"),
+    ('\u{1D443}', "\u{50}"),
+    ('\u{1D444}', "\u{51}"),
+    ('\u{1D445}', "\u{52}"),
+    ('\u{1D446}', "\u{53}"),
+    ('\u{1D447}', "\u{54}"),
+    ('\u{1D448}', "\u{55}"),
+    ('\u{1D449}', "\u{56}"),
+    ('\u{1D44A}', "\u{57}"),
+    ('\u{1D44B}', "\u{58}"),
+    ('\u{1D44C}', "\u{59}"),
+    ('\u{1D44D}', "\u{5A}"),
+    ('\u{1D44E}', "\u{61}"),
+    ('\u{1D44F}', "\u{62}"),
+    ('\u{1D450}', "\u{63}"),
+    ('\u{1D451}', "\u{64}"),
+    ('\u{1D452}', "\u{65}"),
+    ('\u{1D453}', "\u{66}"),
+    ('\u{1D454}', "\u{67}"),
+    ('\u{1D456}', "\u{69}"),
+    ('\u{1D457}', "\u{6A}"),
+    ('\u{1D458}', "\u{6B}"),
+    ('\u{1D459}', "\u{6C}"),
+    ('\u{1D45A}', "\u{72}\u{6E}"),
+    ('\u{1D45B}', "\u{6E}"),
+    ('\u{1D45C}', "\u{6F}"),
+    ('\u{1D45D}', "\u{70}"),
+    ('\u{1D45E}', "\u{71}"),
+    ('\u{1D45F}', "\u{72}"),
+    ('\u{1D460}', "\u{73}"),
+    ('\u{1D461}', "\u{74}"),
+    ('\u{1D462}', "\u{75}"),
+    ('\u{1D463}', "\u{76}"),
+    ('\u{1D464}', "\u{77}"),
+    ('\u{1D465}', "\u{78}"),
+    ('\u{1D466}', "\u{79}"),
+    ('\u{1D467}', "\u{7A}"),
+    ('\u{1D468}', "\u{41}"),
+    ('\u{1D469}', "\u{42}"),
+    ('\u{1D46A}', "\u{43}"),
+    ('\u{1D46B}', "\u{44}"),
+    ('\u{1D46C}', "\u{45}"),
+    ('\u{1D46D}', "\u{46}"),
+    ('\u{1D46E}', "\u{47}"),
+    ('\u{1D46F}', "\u{48}"),
+    ('\u{1D470}', "\u{6C}"),
+    ('\u{1D471}', "\u{4A}"),
+    ('\u{1D472}', "\u{4B}"),
+    ('\u{1D473}', "\u{4C}"),
+    ('\u{1D474}', "\u{4D}"),
+    ('\u{1D475}', "\u{4E}"),
+    ('\u{1D476}', "\u{4F}"),
+    ('\u{1D477}', "\u{50}"),
+    ('\u{1D478}', "\u{51}"),
+    ('\u{1D479}', "\u{52}"),
+    ('\u{1D47A}', "\u{53}"),
+    ('\u{1D47B}', "\u{54}"),
+    ('\u{1D47C}', "\u{55}"),
+    ('\u{1D47D}', "\u{56}"),
+    ('\u{1D47E}', "\u{57}"),
+    ('\u{1D47F}', "\u{58}"),
+    ('\u{1D480}', "\u{59}"),
+    ('\u{1D481}', "\u{5A}"),
+    ('\u{1D482}', "\u{61}"),
+    ('\u{1D483}', "\u{62}"),
+    ('\u{1D484}', "\u{63}"),
+    ('\u{1D485}', "\u{64}"),
+    ('\u{1D486}', "\u{65}"),
+    ('\u{1D487}', "\u{66}"),
+    ('\u{1D488}', "\u{67}"),
+    ('\u{1D489}', "\u{68}"),
+    ('\u{1D48A}', "\u{69}"),
+    ('\u{1D48B}', "\u{6A}"),
+    ('\u{1D48C}', "\u{6B}"),
+    ('\u{1D48D}', "\u{6C}"),
+    ('\u{1D48E}', "\u{72}\u{6E}"),
+    ('\u{1D48F}', "\u{6E}"),
+    ('\u{1D490}', "\u{6F}"),
+    ('\u{1D491}', "\u{70}"),
+    ('\u{1D492}', "\u{71}"),
+    ('\u{1D493}', "\u{72}"),
+    ('\u{1D494}', "\u{73}"),
+    ('\u{1D495}', "\u{74}"),
+    ('\u{1D496}', "\u{75}"),
+    ('\u{1D497}', "\u{76}"),
+    ('\u{1D498}', "\u{77}"),
+    ('\u{1D499}', "\u{78}"),
+    ('\u{1D49A}', "\u{79}"),
+    ('\u{1D49B}', "\u{7A}"),
+    ('\u{1D49C}', "\u{41}"),
+    ('\u{1D49E}', "\u{43}"),
+    ('\u{1D49F}', "\u{44}"),
+    ('\u{1D4A2}', "\u{47}"),
+    ('\u{1D4A5}', "\u{4A}"),
+    ('\u{1D4A6}', "\u{4B}"),
+    ('\u{1D4A9}', "\u{4E}"),
+    ('\u{1D4AA}', "\u{4F}"),
+    ('\u{1D4AB}', "\u{50}"),
+    ('\u{1D4AC}', "\u{51}"),
+    ('\u{1D4AE}', "\u{53}"),
+    ('\u{1D4AF}', "\u{54}"),
+    ('\u{1D4B0}', "\u{55}"),
+    ('\u{1D4B1}', "\u{56}"),
+    ('\u{1D4B2}', "\u{57}"),
+    ('\u{1D4B3}', "\u{58}"),
+    ('\u{1D4B4}', "\u{59}"),
+    ('\u{1D4B5}', "\u{5A}"),
+    ('\u{1D4B6}', "\u{61}"),
+    ('\u{1D4B7}', "\u{62}"),
+    ('\u{1D4B8}', "\u{63}"),
+    ('\u{1D4B9}', "\u{64}"),
+    ('\u{1D4BB}', "\u{66}"),
+    ('\u{1D4BD}', "\u{68}"),
+    ('\u{1D4BE}', "\u{69}"),
+    ('\u{1D4BF}', "\u{6A}"),
+    ('\u{1D4C0}', "\u{6B}"),
+    ('\u{1D4C1}', "\u{6C}"),
+    ('\u{1D4C2}', "\u{72}\u{6E}"),
+    ('\u{1D4C3}', "\u{6E}"),
+    ('\u{1D4C5}', "\u{70}"),
+    ('\u{1D4C6}', "\u{71}"),
+    ('\u{1D4C7}', "\u{72}"),
+    ('\u{1D4C8}', "\u{73}"),
+    ('\u{1D4C9}', "\u{74}"),
+    ('\u{1D4CA}', "\u{75}"),
+    ('\u{1D4CB}', "\u{76}"),
+    ('\u{1D4CC}', "\u{77}"),
+    ('\u{1D4CD}', "\u{78}"),
+    ('\u{1D4CE}', "\u{79}"),
+    ('\u{1D4CF}', "\u{7A}"),
+    ('\u{1D4D0}', "\u{41}"),
+    ('\u{1D4D1}', "\u{42}"),
+    ('\u{1D4D2}', "\u{43}"),
+    ('\u{1D4D3}', "\u{44}"),
+    ('\u{1D4D4}', "\u{45}"),
+    ('\u{1D4D5}', "\u{46}"),
+    ('\u{1D4D6}', "\u{47}"),
+    ('\u{1D4D7}', "\u{48}"),
+    ('\u{1D4D8}', "\u{6C}"),
+    ('\u{1D4D9}', "\u{4A}"),
+    ('\u{1D4DA}', "\u{4B}"),
+    ('\u{1D4DB}', "\u{4C}"),
+    ('\u{1D4DC}', "\u{4D}"),
+    ('\u{1D4DD}', "\u{4E}"),
+    ('\u{1D4DE}', "\u{4F}"),
+    ('\u{1D4DF}', "\u{50}"),
+    ('\u{1D4E0}', "\u{51}"),
+    ('\u{1D4E1}', "\u{52}"),
+    ('\u{1D4E2}', "\u{53}"),
+    ('\u{1D4E3}', "\u{54}"),
+    ('\u{1D4E4}', "\u{55}"),
+    ('\u{1D4E5}', "\u{56}"),
+    ('\u{1D4E6}', "\u{57}"),
+    ('\u{1D4E7}', "\u{58}"),
+    ('\u{1D4E8}', "\u{59}"),
+    ('\u{1D4E9}', "\u{5A}"),
+    ('\u{1D4EA}', "\u{61}"),
+    ('\u{1D4EB}', "\u{62}"),
+    ('\u{1D4EC}', "\u{63}"),
+    ('\u{1D4ED}', "\u{64}"),
+    ('\u{1D4EE}', "\u{65}"),
+    ('\u{1D4EF}', "\u{66}"),
+    ('\u{1D4F0}', "\u{67}"),
+    ('\u{1D4F1}', "\u{68}"),
+    ('\u{1D4F2}', "\u{69}"),
+    ('\u{1D4F3}', "\u{6A}"),
+    ('\u{1D4F4}', "\u{6B}"),
+    ('\u{1D4F5}', "\u{6C}"),
+    ('\u{1D4F6}', "\u{72}\u{6E}"),
+    ('\u{1D4F7}', "\u{6E}"),
+    ('\u{1D4F8}', "\u{6F}"),
+    ('\u{1D4F9}', "\u{70}"),
+    ('\u{1D4FA}', "\u{71}"),
+    ('\u{1D4FB}', "\u{72}"),
+    ('\u{1D4FC}', "\u{73}"),
+    ('\u{1D4FD}', "\u{74}"),
+    ('\u{1D4FE}', "\u{75}"),
+    ('\u{1D4FF}', "\u{76}"),
+    ('\u{1D500}', "\u{77}"),
+    ('\u{1D501}', "\u{78}"),
+    ('\u{1D502}', "\u{79}"),
+    ('\u{1D503}', "\u{7A}"),
+    ('\u{1D504}', "\u{41}"),
+    ('\u{1D505}', "\u{42}"),
+    ('\u{1D507}', "\u{44}"),
+    ('\u{1D508}', "\u{45}"),
+    ('\u{1D509}', "\u{46}"),
+    ('\u{1D50A}', "\u{47}"),
+    ('\u{1D50D}', "\u{4A}"),
+    ('\u{1D50E}', "\u{4B}"),
+    ('\u{1D50F}', "\u{4C}"),
+    ('\u{1D510}', "\u{4D}"),
+    ('\u{1D511}', "\u{4E}"),
+    ('\u{1D512}', "\u{4F}"),
+    ('\u{1D513}', "\u{50}"),
+    ('\u{1D514}', "\u{51}"),
+    ('\u{1D516}', "\u{53}"),
+    ('\u{1D517}', "\u{54}"),
+    ('\u{1D518}', "\u{55}"),
+    ('\u{1D519}', "\u{56}"),
+    ('\u{1D51A}', "\u{57}"),
+    ('\u{1D51B}', "\u{58}"),
+    ('\u{1D51C}', "\u{59}"),
+    ('\u{1D51E}', "\u{61}"),
+    ('\u{1D51F}', "\u{62}"),
+    ('\u{1D520}', "\u{63}"),
+    ('\u{1D521}', "\u{64}"),
+    ('\u{1D522}', "\u{65}"),
+    ('\u{1D523}', "\u{66}"),
+    ('\u{1D524}', "\u{67}"),
+    ('\u{1D525}', "\u{68}"),
+    ('\u{1D526}', "\u{69}"),
+    ('\u{1D527}', "\u{6A}"),
+    ('\u{1D528}', "\u{6B}"),
+    ('\u{1D529}', "\u{6C}"),
+    ('\u{1D52A}', "\u{72}\u{6E}"),
+    ('\u{1D52B}', "\u{6E}"),
+    ('\u{1D52C}', "\u{6F}"),
+    ('\u{1D52D}', "\u{70}"),
+    ('\u{1D52E}', "\u{71}"),
+    ('\u{1D52F}', "\u{72}"),
+    ('\u{1D530}', "\u{73}"),
+    ('\u{1D531}', "\u{74}"),
+    ('\u{1D532}', "\u{75}"),
+    ('\u{1D533}', "\u{76}"),
+    ('\u{1D534}', "\u{77}"),
+    ('\u{1D535}', "\u{78}"),
+    ('\u{1D536}', "\u{79}"),
+    ('\u{1D537}', "\u{7A}"),
+    ('\u{1D538}', "\u{41}"),
+    ('\u{1D539}', "\u{42}"),
+    ('\u{1D53B}', "\u{44}"),
+    ('\u{1D53C}', "\u{45}"),
+    ('\u{1D53D}', "\u{46}"),
+    ('\u{1D53E}', "\u{47}"),
+    ('\u{1D540}', "\u{6C}"),
+    ('\u{1D541}', "\u{4A}"),
+    ('\u{1D542}', "\u{4B}"),
+    ('\u{1D543}', "\u{4C}"),
+    ('\u{1D544}', "\u{4D}"),
+    ('\u{1D546}', "\u{4F}"),
+    ('\u{1D54A}', "\u{53}"),
+    ('\u{1D54B}', "\u{54}"),
+    ('\u{1D54C}', "\u{55}"),
+    ('\u{1D54D}', "\u{56}"),
+    ('\u{1D54E}', "\u{57}"),
+    ('\u{1D54F}', "\u{58}"),
+    ('\u{1D550}', "\u{59}"),
+    ('\u{1D552}', "\u{61}"),
+    ('\u{1D553}', "\u{62}"),
+    ('\u{1D554}', "\u{63}"),
+    ('\u{1D555}', "\u{64}"),
+    ('\u{1D556}', "\u{65}"),
+    ('\u{1D557}', "\u{66}"),
+    ('\u{1D558}', "\u{67}"),
+    ('\u{1D559}', "\u{68}"),
+    ('\u{1D55A}', "\u{69}"),
+    ('\u{1D55B}', "\u{6A}"),
+    ('\u{1D55C}', "\u{6B}"),
+    ('\u{1D55D}', "\u{6C}"),
+    ('\u{1D55E}', "\u{72}\u{6E}"),
+    ('\u{1D55F}', "\u{6E}"),
+    ('\u{1D560}', "\u{6F}"),
+    ('\u{1D561}', "\u{70}"),
+    ('\u{1D562}', "\u{71}"),
+    ('\u{1D563}', "\u{72}"),
+    ('\u{1D564}', "\u{73}"),
+    ('\u{1D565}', "\u{74}"),
+    ('\u{1D566}', "\u{75}"),
+    ('\u{1D567}', "\u{76}"),
+    ('\u{1D568}', "\u{77}"),
+    ('\u{1D569}', "\u{78}"),
+    ('\u{1D56A}', "\u{79}"),
+    ('\u{1D56B}', "\u{7A}"),
+    ('\u{1D56C}', "\u{41}"),
+    ('\u{1D56D}', "\u{42}"),
+    ('\u{1D56E}', "\u{43}"),
+    ('\u{1D56F}', "\u{44}"),
+    ('\u{1D570}', "\u{45}"),
+    ('\u{1D571}', "\u{46}"),
+    ('\u{1D572}', "\u{47}"),
+    ('\u{1D573}', "\u{48}"),
+    ('\u{1D574}', "\u{6C}"),
+    ('\u{1D575}', "\u{4A}"),
+    ('\u{1D576}', "\u{4B}"),
+    ('\u{1D577}', "\u{4C}"),
+    ('\u{1D578}', "\u{4D}"),
+    ('\u{1D579}', "\u{4E}"),
+    ('\u{1D57A}', "\u{4F}"),
+    ('\u{1D57B}', "\u{50}"),
+    ('\u{1D57C}', "\u{51}"),
+    ('\u{1D57D}', "\u{52}"),
+    ('\u{1D57E}', "\u{53}"),
+    ('\u{1D57F}', "\u{54}"),
+    ('\u{1D580}', "\u{55}"),
+    ('\u{1D581}', "\u{56}"),
+    ('\u{1D582}', "\u{57}"),
+    ('\u{1D583}', "\u{58}"),
+    ('\u{1D584}', "\u{59}"),
+    ('\u{1D585}', "\u{5A}"),
+    ('\u{1D586}', "\u{61}"),
+    ('\u{1D587}', "\u{62}"),
+    ('\u{1D588}', "\u{63}"),
+    ('\u{1D589}', "\u{64}"),
+    ('\u{1D58A}', "\u{65}"),
+    ('\u{1D58B}', "\u{66}"),
+    ('\u{1D58C}', "\u{67}"),
+    ('\u{1D58D}', "\u{68}"),
+    ('\u{1D58E}', "\u{69}"),
+    ('\u{1D58F}', "\u{6A}"),
+    ('\u{1D590}', "\u{6B}"),
+    ('\u{1D591}', "\u{6C}"),
+    ('\u{1D592}', "\u{72}\u{6E}"),
+    ('\u{1D593}', "\u{6E}"),
+    ('\u{1D594}', "\u{6F}"),
+    ('\u{1D595}', "\u{70}"),
+    ('\u{1D596}', "\u{71}"),
+    ('\u{1D597}', "\u{72}"),
+    ('\u{1D598}', "\u{73}"),
+    ('\u{1D599}', "\u{74}"),
+    ('\u{1D59A}', "\u{75}"),
+    ('\u{1D59B}', "\u{76}"),
+    ('\u{1D59C}', "\u{77}"),
+    ('\u{1D59D}', "\u{78}"),
+    ('\u{1D59E}', "\u{79}"),
+    ('\u{1D59F}', "\u{7A}"),
+    ('\u{1D5A0}', "\u{41}"),
+    ('\u{1D5A1}', "\u{42}"),
+    ('\u{1D5A2}', "\u{43}"),
+    ('\u{1D5A3}', "\u{44}"),
+    ('\u{1D5A4}', "\u{45}"),
+    ('\u{1D5A5}', "\u{46}"),
+    ('\u{1D5A6}', "\u{47}"),
+    ('\u{1D5A7}', "\u{48}"),
+    ('\u{1D5A8}', "\u{6C}"),
+    ('\u{1D5A9}', "\u{4A}"),
+    ('\u{1D5AA}', "\u{4B}"),
+    ('\u{1D5AB}', "\u{4C}"),
+    ('\u{1D5AC}', "\u{4D}"),
+    ('\u{1D5AD}', "\u{4E}"),
+    ('\u{1D5AE}', "\u{4F}"),
+    ('\u{1D5AF}', "\u{50}"),
+    ('\u{1D5B0}', "\u{51}"),
+    ('\u{1D5B1}', "\u{52}"),
+    ('\u{1D5B2}', "\u{53}"),
+    ('\u{1D5B3}', "\u{54}"),
+    ('\u{1D5B4}', "\u{55}"),
+    ('\u{1D5B5}', "\u{56}"),
+    ('\u{1D5B6}', "\u{57}"),
+    ('\u{1D5B7}', "\u{58}"),
+    ('\u{1D5B8}', "\u{59}"),
+    ('\u{1D5B9}', "\u{5A}"),
+    ('\u{1D5BA}', "\u{61}"),
+    ('\u{1D5BB}', "\u{62}"),
+    ('\u{1D5BC}', "\u{63}"),
+    ('\u{1D5BD}', "\u{64}"),
+    ('\u{1D5BE}', "\u{65}"),
+    ('\u{1D5BF}', "\u{66}"),
+    ('\u{1D5C0}', "\u{67}"),
+    ('\u{1D5C1}', "\u{68}"),
+    ('\u{1D5C2}', "\u{69}"),
+    ('\u{1D5C3}', "\u{6A}"),
+    ('\u{1D5C4}', "\u{6B}"),
+    ('\u{1D5C5}', "\u{6C}"),
+    ('\u{1D5C6}', "\u{72}\u{6E}"),
+    ('\u{1D5C7}', "\u{6E}"),
+    ('\u{1D5C8}', "\u{6F}"),
+    ('\u{1D5C9}', "\u{70}"),
+    ('\u{1D5CA}', "\u{71}"),
+    ('\u{1D5CB}', "\u{72}"),
+    ('\u{1D5CC}', "\u{73}"),
+    ('\u{1D5CD}', "\u{74}"),
+    ('\u{1D5CE}', "\u{75}"),
+    ('\u{1D5CF}', "\u{76}"),
+    ('\u{1D5D0}', "\u{77}"),
+    ('\u{1D5D1}', "\u{78}"),
+    ('\u{1D5D2}', "\u{79}"),
+    ('\u{1D5D3}', "\u{7A}"),
+    ('\u{1D5D4}', "\u{41}"),
+    ('\u{1D5D5}', "\u{42}"),
+    ('\u{1D5D6}', "\u{43}"),
+    ('\u{1D5D7}', "\u{44}"),
+    ('\u{1D5D8}', "\u{45}"),
+    ('\u{1D5D9}', "\u{46}"),
+    ('\u{1D5DA}', "\u{47}"),
+    ('\u{1D5DB}', "\u{48}"),
+    ('\u{1D5DC}', "\u{6C}"),
+    ('\u{1D5DD}', "\u{4A}"),
+    ('\u{1D5DE}', "\u{4B}"),
+    ('\u{1D5DF}', "\u{4C}"),
+    ('\u{1D5E0}', "\u{4D}"),
+    ('\u{1D5E1}', "\u{4E}"),
+    ('\u{1D5E2}', "\u{4F}"),
+    ('\u{1D5E3}', "\u{50}"),
+    ('\u{1D5E4}', "\u{51}"),
+    ('\u{1D5E5}', "\u{52}"),
+    ('\u{1D5E6}', "\u{53}"),
+    ('\u{1D5E7}', "\u{54}"),
+    ('\u{1D5E8}', "\u{55}"),
+    ('\u{1D5E9}', "\u{56}"),
+    ('\u{1D5EA}', "\u{57}"),
+    ('\u{1D5EB}', "\u{58}"),
+    ('\u{1D5EC}', "\u{59}"),
+    ('\u{1D5ED}', "\u{5A}"),
+    ('\u{1D5EE}', "\u{61}"),
+    ('\u{1D5EF}', "\u{62}"),
+    ('\u{1D5F0}', "\u{63}"),
+    ('\u{1D5F1}', "\u{64}"),
+    ('\u{1D5F2}', "\u{65}"),
+    ('\u{1D5F3}', "\u{66}"),
+    ('\u{1D5F4}', "\u{67}"),
+    ('\u{1D5F5}', "\u{68}"),
+    ('\u{1D5F6}', "\u{69}"),
+    ('\u{1D5F7}', "\u{6A}"),
+    ('\u{1D5F8}', "\u{6B}"),
+    ('\u{1D5F9}', "\u{6C}"),
+    ('\u{1D5FA}', "\u{72}\u{6E}"),
+    ('\u{1D5FB}', "\u{6E}"),
+    ('\u{1D5FC}', "\u{6F}"),
+    ('\u{1D5FD}', "\u{70}"),
+    ('\u{1D5FE}', "\u{71}"),
+    ('\u{1D5FF}', "\u{72}"),
+    ('\u{1D600}', "\u{73}"),
+    ('\u{1D601}', "\u{74}"),
+    ('\u{1D602}', "\u{75}"),
+    ('\u{1D603}', "\u{76}"),
+    ('\u{1D604}', "\u{77}"),
+    ('\u{1D605}', "\u{78}"),
+    ('\u{1D606}', "\u{79}"),
+    ('\u{1D607}', "\u{7A}"),
+    ('\u{1D608}', "\u{41}"),
+    ('\u{1D609}', "\u{42}"),
+    ('\u{1D60A}', "\u{43}"),
+    ('\u{1D60B}', "\u{44}"),
+    ('\u{1D60C}', "\u{45}"),
+    ('\u{1D60D}', "\u{46}"),
+    ('\u{1D60E}', "\u{47}"),
+    ('\u{1D60F}', "\u{48}"),
+    ('\u{1D610}', "\u{6C}"),
+    ('\u{1D611}', "\u{4A}"),
+    ('\u{1D612}', "\u{4B}"),
+    ('\u{1D613}', "\u{4C}"),
+    ('\u{1D614}', "\u{4D}"),
+    ('\u{1D615}', "\u{4E}"),
+    ('\u{1D616}', "\u{4F}"),
+    ('\u{1D617}', "\u{50}"),
+    ('\u{1D618}', "\u{51}"),
+    ('\u{1D619}', "\u{52}"),
+    ('\u{1D61A}', "\u{53}"),
+    ('\u{1D61B}', "\u{54}"),
+    ('\u{1D61C}', "\u{55}"),
+    ('\u{1D61D}', "\u{56}"),
+    ('\u{1D61E}', "\u{57}"),
+    ('\u{1D61F}', "\u{58}"),
+    ('\u{1D620}', "\u{59}"),
+    ('\u{1D621}', "\u{5A}"),
+    ('\u{1D622}', "\u{61}"),
+    ('\u{1D623}', "\u{62}"),
+    ('\u{1D624}', "\u{63}"),
+    ('\u{1D625}', "\u{64}"),
+    ('\u{1D626}', "\u{65}"),
+    ('\u{1D627}', "\u{66}"),
+    ('\u{1D628}', "\u{67}"),
+    ('\u{1D629}', "\u{68}"),
+    ('\u{1D62A}', "\u{69}"),
+    ('\u{1D62B}', "\u{6A}"),
+    ('\u{1D62C}', "\u{6B}"),
+    ('\u{1D62D}', "\u{6C}"),
+    ('\u{1D62E}', "\u{72}\u{6E}"),
+    ('\u{1D62F}', "\u{6E}"),
+    ('\u{1D630}', "\u{6F}"),
+    ('\u{1D631}', "\u{70}"),
+    ('\u{1D632}', "\u{71}"),
+    ('\u{1D633}', "\u{72}"),
+    ('\u{1D634}', "\u{73}"),
+    ('\u{1D635}', "\u{74}"),
+    ('\u{1D636}', "\u{75}"),
+    ('\u{1D637}', "\u{76}"),
+    ('\u{1D638}', "\u{77}"),
+    ('\u{1D639}', "\u{78}"),
+    ('\u{1D63A}', "\u{79}"),
+    ('\u{1D63B}', "\u{7A}"),
+    ('\u{1D63C}', "\u{41}"),
+    ('\u{1D63D}', "\u{42}"),
+    ('\u{1D63E}', "\u{43}"),
+    ('\u{1D63F}', "\u{44}"),
+    ('\u{1D640}', "\u{45}"),
+    ('\u{1D641}', "\u{46}"),
+    ('\u{1D642}', "\u{47}"),
+    ('\u{1D643}', "\u{48}"),
+    ('\u{1D644}', "\u{6C}"),
+    ('\u{1D645}', "\u{4A}"),
+    ('\u{1D646}', "\u{4B}"),
+    ('\u{1D647}', "\u{4C}"),
+    ('\u{1D648}', "\u{4D}"),
+    ('\u{1D649}', "\u{4E}"),
+    ('\u{1D64A}', "\u{4F}"),
+    ('\u{1D64B}', "\u{50}"),
+    ('\u{1D64C}', "\u{51}"),
+    ('\u{1D64D}', "\u{52}"),
+    ('\u{1D64E}', "\u{53}"),
+    ('\u{1D64F}', "\u{54}"),
+    ('\u{1D650}', "\u{55}"),
+    ('\u{1D651}', "\u{56}"),
+    ('\u{1D652}', "\u{57}"),
+    ('\u{1D653}', "\u{58}"),
+    ('\u{1D654}', "\u{59}"),
+    ('\u{1D655}', "\u{5A}"),
+    ('\u{1D656}', "\u{61}"),
+    ('\u{1D657}', "\u{62}"),
+    ('\u{1D658}', "\u{63}"),
+    ('\u{1D659}', "\u{64}"),
+    ('\u{1D65A}', "\u{65}"),
+    ('\u{1D65B}', "\u{66}"),
+    ('\u{1D65C}', "\u{67}"),
+    ('\u{1D65D}', "\u{68}"),
+    ('\u{1D65E}', "\u{69}"),
+    ('\u{1D65F}', "\u{6A}"),
+    ('\u{1D660}', "\u{6B}"),
+    ('\u{1D661}', "\u{6C}"),
+    ('\u{1D662}', "\u{72}\u{6E}"),
+    ('\u{1D663}', "\u{6E}"),
+    ('\u{1D664}', "\u{6F}"),
+    ('\u{1D665}', "\u{70}"),
+    ('\u{1D666}', "\u{71}"),
+    ('\u{1D667}', "\u{72}"),
+    ('\u{1D668}', "\u{73}"),
+    ('\u{1D669}', "\u{74}"),
+    ('\u{1D66A}', "\u{75}"),
+    ('\u{1D66B}', "\u{76}"),
+    ('\u{1D66C}', "\u{77}"),
+    ('\u{1D66D}', "\u{78}"),
+    ('\u{1D66E}', "\u{79}"),
+    ('\u{1D66F}', "\u{7A}"),
+    ('\u{1D670}', "\u{41}"),
+    ('\u{1D671}', "\u{42}"),
+    ('\u{1D672}', "\u{43}"),
+    ('\u{1D673}', "\u{44}"),
+    ('\u{1D674}', "\u{45}"),
+    ('\u{1D675}', "\u{46}"),
+    ('\u{1D676}', "\u{47}"),
+    ('\u{1D677}', "\u{48}"),
+    ('\u{1D678}', "\u{6C}"),
+    ('\u{1D679}', "\u{4A}"),
+    ('\u{1D67A}', "\u{4B}"),
+    ('\u{1D67B}', "\u{4C}"),
+    ('\u{1D67C}', "\u{4D}"),
+    ('\u{1D67D}', "\u{4E}"),
+    ('\u{1D67E}', "\u{4F}"),
+    ('\u{1D67F}', "\u{50}"),
+    ('\u{1D680}', "\u{51}"),
+    ('\u{1D681}', "\u{52}"),
+    ('\u{1D682}', "\u{53}"),
+    ('\u{1D683}', "\u{54}"),
+    ('\u{1D684}', "\u{55}"),
+    ('\u{1D685}', "\u{56}"),
+    ('\u{1D686}', "\u{57}"),
+    ('\u{1D687}', "\u{58}"),
+    ('\u{1D688}', "\u{59}"),
+    ('\u{1D689}', "\u{5A}"),
+    ('\u{1D68A}', "\u{61}"),
+    ('\u{1D68B}', "\u{62}"),
+    ('\u{1D68C}', "\u{63}"),
+    ('\u{1D68D}', "\u{64}"),
+    ('\u{1D68E}', "\u{65}"),
+    ('\u{1D68F}', "\u{66}"),
+    ('\u{1D690}', "\u{67}"),
+    ('\u{1D691}', "\u{68}"),
+    ('\u{1D692}', "\u{69}"),
+    ('\u{1D693}', "\u{6A}"),
+    ('\u{1D694}', "\u{6B}"),
+    ('\u{1D695}', "\u{6C}"),
+    ('\u{1D696}', "\u{72}\u{6E}"),
+    ('\u{1D697}', "\u{6E}"),
+    ('\u{1D698}', "\u{6F}"),
+    ('\u{1D699}', "\u{70}"),
+    ('\u{1D69A}', "\u{71}"),
+    ('\u{1D69B}', "\u{72}"),
+    ('\u{1D69C}', "\u{73}"),
+    ('\u{1D69D}', "\u{74}"),
+    ('\u{1D69E}', "\u{75}"),
+    ('\u{1D69F}', "\u{76}"),
+    ('\u{1D6A0}', "\u{77}"),
+    ('\u{1D6A1}', "\u{78}"),
+    ('\u{1D6A2}', "\u{79}"),
+    ('\u{1D6A3}', "\u{7A}"),
+    ('\u{1D6A4}', "\u{69}"),
+    ('\u{1D6A5}', "\u{237}"),
+    ('\u{1D6A8}', "\u{41}"),
+    ('\u{1D6A9}', "\u{42}"),
+    ('\u{1D6AA}', "\u{393}"),
+    ('\u{1D6AB}', "\u{394}"),
+    ('\u{1D6AC}', "\u{45}"),
+    ('\u{1D6AD}', "\u{5A}"),
+    ('\u{1D6AE}', "\u{48}"),
+    ('\u{1D6AF}', "\u{4F}\u{335}"),
+    ('\u{1D6B0}', "\u{6C}"),
+    ('\u{1D6B1}', "\u{4B}"),
+    ('\u{1D6B2}', "\u{245}"),
+    ('\u{1D6B3}', "\u{4D}"),
+    ('\u{1D6B4}', "\u{4E}"),
+    ('\u{1D6B5}', "\u{39E}"),
+    ('\u{1D6B6}', "\u{4F}"),
+    ('\u{1D6B7}', "\u{3A0}"),
+    ('\u{1D6B8}', "\u{50}"),
+    ('\u{1D6B9}', "\u{4F}\u{335}"),
+    ('\u{1D6BA}', "\u{1A9}"),
+    ('\u{1D6BB}', "\u{54}"),
+    ('\u{1D6BC}', "\u{59}"),
+    ('\u{1D6BD}', "\u{3A6}"),
+    ('\u{1D6BE}', "\u{58}"),
+    ('\u{1D6BF}', "\u{3A8}"),
+    ('\u{1D6C0}', "\u{3A9}"),
+    ('\u{1D6C1}', "\u{2207}"),
+    ('\u{1D6C2}', "\u{61}"),
+    ('\u{1D6C3}', "\u{DF}"),
+    ('\u{1D6C4}', "\u{79}"),
+    ('\u{1D6C5}', "\u{1E9F}"),
+    ('\u{1D6C6}', "\u{A793}"),
+    ('\u{1D6C7}', "\u{3B6}"),
+    ('\u{1D6C8}', "\u{6E}\u{329}"),
+    ('\u{1D6C9}', "\u{4F}\u{335}"),
+    ('\u{1D6CA}', "\u{69}"),
+    ('\u{1D6CB}', "\u{138}"),
+    ('\u{1D6CC}', "\u{3BB}"),
+    ('\u{1D6CD}', "\u{3BC}"),
+    ('\u{1D6CE}', "\u{76}"),
+    ('\u{1D6CF}', "\u{3BE}"),
+    ('\u{1D6D0}', "\u{6F}"),
+    ('\u{1D6D1}', "\u{3C0}"),
+    ('\u{1D6D2}', "\u{70}"),
+    ('\u{1D6D3}', "\u{3C2}"),
+    ('\u{1D6D4}', "\u{6F}"),
+    ('\u{1D6D5}', "\u{1D1B}"),
+    ('\u{1D6D6}', "\u{75}"),
+    ('\u{1D6D7}', "\u{278}"),
+    ('\u{1D6D8}', "\u{3C7}"),
+    ('\u{1D6D9}', "\u{3C8}"),
+    ('\u{1D6DA}', "\u{3C9}"),
+    ('\u{1D6DB}', "\u{2202}"),
+    ('\u{1D6DC}', "\u{A793}"),
+    ('\u{1D6DD}', "\u{4F}\u{335}"),
+    ('\u{1D6DE}', "\u{138}"),
+    ('\u{1D6DF}', "\u{278}"),
+    ('\u{1D6E0}', "\u{70}"),
+    ('\u{1D6E1}', "\u{3C0}"),
+    ('\u{1D6E2}', "\u{41}"),
+    ('\u{1D6E3}', "\u{42}"),
+    ('\u{1D6E4}', "\u{393}"),
+    ('\u{1D6E5}', "\u{394}"),
+    ('\u{1D6E6}', "\u{45}"),
+    ('\u{1D6E7}', "\u{5A}"),
+    ('\u{1D6E8}', "\u{48}"),
+    ('\u{1D6E9}', "\u{4F}\u{335}"),
+    ('\u{1D6EA}', "\u{6C}"),
+    ('\u{1D6EB}', "\u{4B}"),
+    ('\u{1D6EC}', "\u{245}"),
+    ('\u{1D6ED}', "\u{4D}"),
+    ('\u{1D6EE}', "\u{4E}"),
+    ('\u{1D6EF}', "\u{39E}"),
+    ('\u{1D6F0}', "\u{4F}"),
+    ('\u{1D6F1}', "\u{3A0}"),
+    ('\u{1D6F2}', "\u{50}"),
+    ('\u{1D6F3}', "\u{4F}\u{335}"),
+    ('\u{1D6F4}', "\u{1A9}"),
+    ('\u{1D6F5}', "\u{54}"),
+    ('\u{1D6F6}', "\u{59}"),
+    ('\u{1D6F7}', "\u{3A6}"),
+    ('\u{1D6F8}', "\u{58}"),
+    ('\u{1D6F9}', "\u{3A8}"),
+    ('\u{1D6FA}', "\u{3A9}"),
+    ('\u{1D6FB}', "\u{2207}"),
+    ('\u{1D6FC}', "\u{61}"),
+    ('\u{1D6FD}', "\u{DF}"),
+    ('\u{1D6FE}', "\u{79}"),
+    ('\u{1D6FF}', "\u{1E9F}"),
+    ('\u{1D700}', "\u{A793}"),
+    ('\u{1D701}', "\u{3B6}"),
+    ('\u{1D702}', "\u{6E}\u{329}"),
+    ('\u{1D703}', "\u{4F}\u{335}"),
+    ('\u{1D704}', "\u{69}"),
+    ('\u{1D705}', "\u{138}"),
+    ('\u{1D706}', "\u{3BB}"),
+    ('\u{1D707}', "\u{3BC}"),
+    ('\u{1D708}', "\u{76}"),
+    ('\u{1D709}', "\u{3BE}"),
+    ('\u{1D70A}', "\u{6F}"),
+    ('\u{1D70B}', "\u{3C0}"),
+    ('\u{1D70C}', "\u{70}"),
+    ('\u{1D70D}', "\u{3C2}"),
+    ('\u{1D70E}', "\u{6F}"),
+    ('\u{1D70F}', "\u{1D1B}"),
+    ('\u{1D710}', "\u{75}"),
+    ('\u{1D711}', "\u{278}"),
+    ('\u{1D712}', "\u{3C7}"),
+    ('\u{1D713}', "\u{3C8}"),
+    ('\u{1D714}', "\u{3C9}"),
+    ('\u{1D715}', "\u{2202}"),
+    ('\u{1D716}', "\u{A793}"),
+    ('\u{1D717}', "\u{4F}\u{335}"),
+    ('\u{1D718}', "\u{138}"),
+    ('\u{1D719}', "\u{278}"),
+    ('\u{1D71A}', "\u{70}"),
+    ('\u{1D71B}', "\u{3C0}"),
+    ('\u{1D71C}', "\u{41}"),
+    ('\u{1D71D}', "\u{42}"),
+    ('\u{1D71E}', "\u{393}"),
+    ('\u{1D71F}', "\u{394}"),
+    ('\u{1D720}', "\u{45}"),
+    ('\u{1D721}', "\u{5A}"),
+    ('\u{1D722}', "\u{48}"),
+    ('\u{1D723}', "\u{4F}\u{335}"),
+    ('\u{1D724}', "\u{6C}"),
+    ('\u{1D725}', "\u{4B}"),
+    ('\u{1D726}', "\u{245}"),
+    ('\u{1D727}', "\u{4D}"),
+    ('\u{1D728}', "\u{4E}"),
+    ('\u{1D729}', "\u{39E}"),
+    ('\u{1D72A}', "\u{4F}"),
+    ('\u{1D72B}', "\u{3A0}"),
+    ('\u{1D72C}', "\u{50}"),
+    ('\u{1D72D}', "\u{4F}\u{335}"),
+    ('\u{1D72E}', "\u{1A9}"),
+    ('\u{1D72F}', "\u{54}"),
+    ('\u{1D730}', "\u{59}"),
+    ('\u{1D731}', "\u{3A6}"),
+    ('\u{1D732}', "\u{58}"),
+    ('\u{1D733}', "\u{3A8}"),
+    ('\u{1D734}', "\u{3A9}"),
+    ('\u{1D735}', "\u{2207}"),
+    ('\u{1D736}', "\u{61}"),
+    ('\u{1D737}', "\u{DF}"),
+    ('\u{1D738}', "\u{79}"),
+    ('\u{1D739}', "\u{1E9F}"),
+    ('\u{1D73A}', "\u{A793}"),
+    ('\u{1D73B}', "\u{3B6}"),
+    ('\u{1D73C}', "\u{6E}\u{329}"),
+    ('\u{1D73D}', "\u{4F}\u{335}"),
+    ('\u{1D73E}', "\u{69}"),
+    ('\u{1D73F}', "\u{138}"),
+    ('\u{1D740}', "\u{3BB}"),
+    ('\u{1D741}', "\u{3BC}"),
+    ('\u{1D742}', "\u{76}"),
+    ('\u{1D743}', "\u{3BE}"),
+    ('\u{1D744}', "\u{6F}"),
+    ('\u{1D745}', "\u{3C0}"),
+    ('\u{1D746}', "\u{70}"),
+    ('\u{1D747}', "\u{3C2}"),
+    ('\u{1D748}', "\u{6F}"),
+    ('\u{1D749}', "\u{1D1B}"),
+    ('\u{1D74A}', "\u{75}"),
+    ('\u{1D74B}', "\u{278}"),
+    ('\u{1D74C}', "\u{3C7}"),
+    ('\u{1D74D}', "\u{3C8}"),
+    ('\u{1D74E}', "\u{3C9}"),
+    ('\u{1D74F}', "\u{2202}"),
+    ('\u{1D750}', "\u{A793}"),
+    ('\u{1D751}', "\u{4F}\u{335}"),
+    ('\u{1D752}', "\u{138}"),
+    ('\u{1D753}', "\u{278}"),
+    ('\u{1D754}', "\u{70}"),
+    ('\u{1D755}', "\u{3C0}"),
+    ('\u{1D756}', "\u{41}"),
+    ('\u{1D757}', "\u{42}"),
+    ('\u{1D758}', "\u{393}"),
+    ('\u{1D759}', "\u{394}"),
+    ('\u{1D75A}', "\u{45}"),
+    ('\u{1D75B}', "\u{5A}"),
+    ('\u{1D75C}', "\u{48}"),
+    ('\u{1D75D}', "\u{4F}\u{335}"),
+    ('\u{1D75E}', "\u{6C}"),
+    ('\u{1D75F}', "\u{4B}"),
+    ('\u{1D760}', "\u{245}"),
+    ('\u{1D761}', "\u{4D}"),
+    ('\u{1D762}', "\u{4E}"),
+    ('\u{1D763}', "\u{39E}"),
+    ('\u{1D764}', "\u{4F}"),
+    ('\u{1D765}', "\u{3A0}"),
+    ('\u{1D766}', "\u{50}"),
+    ('\u{1D767}', "\u{4F}\u{335}"),
+    ('\u{1D768}', "\u{1A9}"),
+    ('\u{1D769}', "\u{54}"),
+    ('\u{1D76A}', "\u{59}"),
+    ('\u{1D76B}', "\u{3A6}"),
+    ('\u{1D76C}', "\u{58}"),
+    ('\u{1D76D}', "\u{3A8}"),
+    ('\u{1D76E}', "\u{3A9}"),
+    ('\u{1D76F}', "\u{2207}"),
+    ('\u{1D770}', "\u{61}"),
+    ('\u{1D771}', "\u{DF}"),
+    ('\u{1D772}', "\u{79}"),
+    ('\u{1D773}', "\u{1E9F}"),
+    ('\u{1D774}', "\u{A793}"),
+    ('\u{1D775}', "\u{3B6}"),
+    ('\u{1D776}', "\u{6E}\u{329}"),
+    ('\u{1D777}', "\u{4F}\u{335}"),
+    ('\u{1D778}', "\u{69}"),
+    ('\u{1D779}', "\u{138}"),
+    ('\u{1D77A}', "\u{3BB}"),
+    ('\u{1D77B}', "\u{3BC}"),
+    ('\u{1D77C}', "\u{76}"),
+    ('\u{1D77D}', "\u{3BE}"),
+    ('\u{1D77E}', "\u{6F}"),
+    ('\u{1D77F}', "\u{3C0}"),
+    ('\u{1D780}', "\u{70}"),
+    ('\u{1D781}', "\u{3C2}"),
+    ('\u{1D782}', "\u{6F}"),
+    ('\u{1D783}', "\u{1D1B}"),
+    ('\u{1D784}', "\u{75}"),
+    ('\u{1D785}', "\u{278}"),
+    ('\u{1D786}', "\u{3C7}"),
+    ('\u{1D787}', "\u{3C8}"),
+    ('\u{1D788}', "\u{3C9}"),
+    ('\u{1D789}', "\u{2202}"),
+    ('\u{1D78A}', "\u{A793}"),
+    ('\u{1D78B}', "\u{4F}\u{335}"),
+    ('\u{1D78C}', "\u{138}"),
+    ('\u{1D78D}', "\u{278}"),
+    ('\u{1D78E}', "\u{70}"),
+    ('\u{1D78F}', "\u{3C0}"),
+    ('\u{1D790}', "\u{41}"),
+    ('\u{1D791}', "\u{42}"),
+    ('\u{1D792}', "\u{393}"),
+    ('\u{1D793}', "\u{394}"),
+    ('\u{1D794}', "\u{45}"),
+    ('\u{1D795}', "\u{5A}"),
+    ('\u{1D796}', "\u{48}"),
+    ('\u{1D797}', "\u{4F}\u{335}"),
+    ('\u{1D798}', "\u{6C}"),
+    ('\u{1D799}', "\u{4B}"),
+    ('\u{1D79A}', "\u{245}"),
+    ('\u{1D79B}', "\u{4D}"),
+    ('\u{1D79C}', "\u{4E}"),
+    ('\u{1D79D}', "\u{39E}"),
+    ('\u{1D79E}', "\u{4F}"),
+    ('\u{1D79F}', "\u{3A0}"),
+    ('\u{1D7A0}', "\u{50}"),
+    ('\u{1D7A1}', "\u{4F}\u{335}"),
+    ('\u{1D7A2}', "\u{1A9}"),
+    ('\u{1D7A3}', "\u{54}"),
+    ('\u{1D7A4}', "\u{59}"),
+    ('\u{1D7A5}', "\u{3A6}"),
+    ('\u{1D7A6}', "\u{58}"),
+    ('\u{1D7A7}', "\u{3A8}"),
+    ('\u{1D7A8}', "\u{3A9}"),
+    ('\u{1D7A9}', "\u{2207}"),
+    ('\u{1D7AA}', "\u{61}"),
+    ('\u{1D7AB}', "\u{DF}"),
+    ('\u{1D7AC}', "\u{79}"),
+    ('\u{1D7AD}', "\u{1E9F}"),
+    ('\u{1D7AE}', "\u{A793}"),
+    ('\u{1D7AF}', "\u{3B6}"),
+    ('\u{1D7B0}', "\u{6E}\u{329}"),
+    ('\u{1D7B1}', "\u{4F}\u{335}"),
+    ('\u{1D7B2}', "\u{69}"),
+    ('\u{1D7B3}', "\u{138}"),
+    ('\u{1D7B4}', "\u{3BB}"),
+    ('\u{1D7B5}', "\u{3BC}"),
+    ('\u{1D7B6}', "\u{76}"),
+    ('\u{1D7B7}', "\u{3BE}"),
+    ('\u{1D7B8}', "\u{6F}"),
+    ('\u{1D7B9}', "\u{3C0}"),
+    ('\u{1D7BA}', "\u{70}"),
+    ('\u{1D7BB}', "\u{3C2}"),
+    ('\u{1D7BC}', "\u{6F}"),
+    ('\u{1D7BD}', "\u{1D1B}"),
+    ('\u{1D7BE}', "\u{75}"),
+    ('\u{1D7BF}', "\u{278}"),
+    ('\u{1D7C0}', "\u{3C7}"),
+    ('\u{1D7C1}', "\u{3C8}"),
+    ('\u{1D7C2}', "\u{3C9}"),
+    ('\u{1D7C3}', "\u{2202}"),
+    ('\u{1D7C4}', "\u{A793}"),
+    ('\u{1D7C5}', "\u{4F}\u{335}"),
+    ('\u{1D7C6}', "\u{138}"),
+    ('\u{1D7C7}', "\u{278}"),
+    ('\u{1D7C8}', "\u{70}"),
+    ('\u{1D7C9}', "\u{3C0}"),
+    ('\u{1D7CA}', "\u{46}"),
+    ('\u{1D7CB}', "\u{3DD}"),
+    ('\u{1D7CE}', "\u{4F}"),
+    ('\u{1D7CF}', "\u{6C}"),
+    ('\u{1D7D0}', "\u{32}"),
+    ('\u{1D7D1}', "\u{33}"),
+    ('\u{1D7D2}', "\u{34}"),
+    ('\u{1D7D3}', "\u{35}"),
+    ('\u{1D7D4}', "\u{36}"),
+    ('\u{1D7D5}', "\u{37}"),
+    ('\u{1D7D6}', "\u{38}"),
+    ('\u{1D7D7}', "\u{39}"),
+    ('\u{1D7D8}', "\u{4F}"),
+    ('\u{1D7D9}', "\u{6C}"),
+    ('\u{1D7DA}', "\u{32}"),
+    ('\u{1D7DB}', "\u{33}"),
+    ('\u{1D7DC}', "\u{34}"),
+    ('\u{1D7DD}', "\u{35}"),
+    ('\u{1D7DE}', "\u{36}"),
+    ('\u{1D7DF}', "\u{37}"),
+    ('\u{1D7E0}', "\u{38}"),
+    ('\u{1D7E1}', "\u{39}"),
+    ('\u{1D7E2}', "\u{4F}"),
+    ('\u{1D7E3}', "\u{6C}"),
+    ('\u{1D7E4}', "\u{32}"),
+    ('\u{1D7E5}', "\u{33}"),
+    ('\u{1D7E6}', "\u{34}"),
+    ('\u{1D7E7}', "\u{35}"),
+    ('\u{1D7E8}', "\u{36}"),
+    ('\u{1D7E9}', "\u{37}"),
+    ('\u{1D7EA}', "\u{38}"),
+    ('\u{1D7EB}', "\u{39}"),
+    ('\u{1D7EC}', "\u{4F}"),
+    ('\u{1D7ED}', "\u{6C}"),
+    ('\u{1D7EE}', "\u{32}"),
+    ('\u{1D7EF}', "\u{33}"),
+    ('\u{1D7F0}', "\u{34}"),
+    ('\u{1D7F1}', "\u{35}"),
+    ('\u{1D7F2}', "\u{36}"),
+    ('\u{1D7F3}', "\u{37}"),
+    ('\u{1D7F4}', "\u{38}"),
+    ('\u{1D7F5}', "\u{39}"),
+    ('\u{1D7F6}', "\u{4F}"),
+    ('\u{1D7F7}', "\u{6C}"),
+    ('\u{1D7F8}', "\u{32}"),
+    ('\u{1D7F9}', "\u{33}"),
+    ('\u{1D7FA}', "\u{34}"),
+    ('\u{1D7FB}', "\u{35}"),
+    ('\u{1D7FC}', "\u{36}"),
+    ('\u{1D7FD}', "\u{37}"),
+    ('\u{1D7FE}', "\u{38}"),
+    ('\u{1D7FF}', "\u{39}"),
+    ('\u{1E8C7}', "\u{6C}"),
+    ('\u{1E8C8}', "\u{2220}"),
+    ('\u{1E8C9}', "\u{663}"),
+    ('\u{1E8CB}', "\u{38}"),
+    ('\u{1E8CC}', "\u{2202}"),
+    ('\u{1E8CD}', "\u{2202}\u{335}"),
+    ('\u{1EE00}', "\u{6C}"),
+    ('\u{1EE01}', "\u{628}"),
+    ('\u{1EE02}', "\u{62C}"),
+    ('\u{1EE03}', "\u{62F}"),
+    ('\u{1EE05}', "\u{648}"),
+    ('\u{1EE06}', "\u{632}"),
+    ('\u{1EE07}', "\u{62D}"),
+    ('\u{1EE08}', "\u{637}"),
+    ('\u{1EE09}', "\u{649}"),
+    ('\u{1EE0A}', "\u{643}"),
+    ('\u{1EE0B}', "\u{644}"),
+    ('\u{1EE0C}', "\u{645}"),
+    ('\u{1EE0D}', "\u{646}"),
+    ('\u{1EE0E}', "\u{633}"),
+    ('\u{1EE0F}', "\u{639}"),
+    ('\u{1EE10}', "\u{641}"),
+    ('\u{1EE11}', "\u{635}"),
+    ('\u{1EE12}', "\u{642}"),
+    ('\u{1EE13}', "\u{631}"),
+    ('\u{1EE14}', "\u{633}\u{6DB}"),
+    ('\u{1EE15}', "\u{62A}"),
+    ('\u{1EE16}', "\u{649}\u{6DB}"),
+    ('\u{1EE17}', "\u{62E}"),
+    ('\u{1EE18}', "\u{630}"),
+    ('\u{1EE19}', "\u{636}"),
+    ('\u{1EE1A}', "\u{638}"),
+    ('\u{1EE1B}', "\u{63A}"),
+    ('\u{1EE1C}', "\u{649}"),
+    ('\u{1EE1D}', "\u{649}"),
+    ('\u{1EE1E}', "\u{6A1}"),
+    ('\u{1EE1F}', "\u{6A1}"),
+    ('\u{1EE21}', "\u{628}"),
+    ('\u{1EE22}', "\u{62C}"),
+    ('\u{1EE24}', "\u{6F}"),
+    ('\u{1EE27}', "\u{62D}"),
+    ('\u{1EE29}', "\u{649}"),
+    ('\u{1EE2A}', "\u{643}"),
+    ('\u{1EE2B}', "\u{644}"),
+    ('\u{1EE2C}', "\u{645}"),
+    ('\u{1EE2D}', "\u{646}"),
+    ('\u{1EE2E}', "\u{633}"),
+    ('\u{1EE2F}', "\u{639}"),
+    ('\u{1EE30}', "\u{641}"),
+    ('\u{1EE31}', "\u{635}"),
+    ('\u{1EE32}', "\u{642}"),
+    ('\u{1EE34}', "\u{633}\u{6DB}"),
+    ('\u{1EE35}', "\u{62A}"),
+    ('\u{1EE36}', "\u{649}\u{6DB}"),
+    ('\u{1EE37}', "\u{62E}"),
+    ('\u{1EE39}', "\u{636}"),
+    ('\u{1EE3B}', "\u{63A}"),
+    ('\u{1EE42}', "\u{62C}"),
+    ('\u{1EE47}', "\u{62D}"),
+    ('\u{1EE49}', "\u{649}"),
+    ('\u{1EE4B}', "\u{644}"),
+    ('\u{1EE4D}', "\u{646}"),
+    ('\u{1EE4E}', "\u{633}"),
+    ('\u{1EE4F}', "\u{639}"),
+    ('\u{1EE51}', "\u{635}"),
+    ('\u{1EE52}', "\u{642}"),
+    ('\u{1EE54}', "\u{633}\u{6DB}"),
+    ('\u{1EE57}', "\u{62E}"),
+    ('\u{1EE59}', "\u{636}"),
+    ('\u{1EE5B}', "\u{63A}"),
+    ('\u{1EE5D}', "\u{649}"),
+    ('\u{1EE5F}', "\u{6A1}"),
+    ('\u{1EE61}', "\u{628}"),
+    ('\u{1EE62}', "\u{62C}"),
+    ('\u{1EE64}', "\u{6F}"),
+    ('\u{1EE67}', "\u{62D}"),
+    ('\u{1EE68}', "\u{637}"),
+    ('\u{1EE69}', "\u{649}"),
+    ('\u{1EE6A}', "\u{643}"),
+    ('\u{1EE6C}', "\u{645}"),
+    ('\u{1EE6D}', "\u{646}"),
+    ('\u{1EE6E}', "\u{633}"),
+    ('\u{1EE6F}', "\u{639}"),
+    ('\u{1EE70}', "\u{641}"),
+    ('\u{1EE71}', "\u{635}"),
+    ('\u{1EE72}', "\u{642}"),
+    ('\u{1EE74}', "\u{633}\u{6DB}"),
+    ('\u{1EE75}', "\u{62A}"),
+    ('\u{1EE76}', "\u{649}\u{6DB}"),
+    ('\u{1EE77}', "\u{62E}"),
+    ('\u{1EE79}', "\u{636}"),
+    ('\u{1EE7A}', "\u{638}"),
+    ('\u{1EE7B}', "\u{63A}"),
+    ('\u{1EE7C}', "\u{649}"),
+    ('\u{1EE7E}', "\u{6A1}"),
+    ('\u{1EE80}', "\u{6C}"),
+    ('\u{1EE81}', "\u{628}"),
+    ('\u{1EE82}', "\u{62C}"),
+    ('\u{1EE83}', "\u{62F}"),
+    ('\u{1EE84}', "\u{6F}"),
+    ('\u{1EE85}', "\u{648}"),
+    ('\u{1EE86}', "\u{632}"),
+    ('\u{1EE87}', "\u{62D}"),
+    ('\u{1EE88}', "\u{637}"),
+    ('\u{1EE89}', "\u{649}"),
+    ('\u{1EE8B}', "\u{644}"),
+    ('\u{1EE8C}', "\u{645}"),
+    ('\u{1EE8D}', "\u{646}"),
+    ('\u{1EE8E}', "\u{633}"),
+    ('\u{1EE8F}', "\u{639}"),
+    ('\u{1EE90}', "\u{641}"),
+    ('\u{1EE91}', "\u{635}"),
+    ('\u{1EE92}', "\u{642}"),
+    ('\u{1EE93}', "\u{631}"),
+    ('\u{1EE94}', "\u{633}\u{6DB}"),
+    ('\u{1EE95}', "\u{62A}"),
+    ('\u{1EE96}', "\u{649}\u{6DB}"),
+    ('\u{1EE97}', "\u{62E}"),
+    ('\u{1EE98}', "\u{630}"),
+    ('\u{1EE99}', "\u{636}"),
+    ('\u{1EE9A}', "\u{638}"),
+    ('\u{1EE9B}', "\u{63A}"),
+    ('\u{1EEA1}', "\u{628}"),
+    ('\u{1EEA2}', "\u{62C}"),
+    ('\u{1EEA3}', "\u{62F}"),
+    ('\u{1EEA5}', "\u{648}"),
+    ('\u{1EEA6}', "\u{632}"),
+    ('\u{1EEA7}', "\u{62D}"),
+    ('\u{1EEA8}', "\u{637}"),
+    ('\u{1EEA9}', "\u{649}"),
+    ('\u{1EEAB}', "\u{644}"),
+    ('\u{1EEAC}', "\u{645}"),
+    ('\u{1EEAD}', "\u{646}"),
+    ('\u{1EEAE}', "\u{633}"),
+    ('\u{1EEAF}', "\u{639}"),
+    ('\u{1EEB0}', "\u{641}"),
+    ('\u{1EEB1}', "\u{635}"),
+    ('\u{1EEB2}', "\u{642}"),
+    ('\u{1EEB3}', "\u{631}"),
+    ('\u{1EEB4}', "\u{633}\u{6DB}"),
+    ('\u{1EEB5}', "\u{62A}"),
+    ('\u{1EEB6}', "\u{649}\u{6DB}"),
+    ('\u{1EEB7}', "\u{62E}"),
+    ('\u{1EEB8}', "\u{630}"),
+    ('\u{1EEB9}', "\u{636}"),
+    ('\u{1EEBA}', "\u{638}"),
+    ('\u{1EEBB}', "\u{63A}"),
+    ('\u{1F100}', "\u{4F}\u{2E}"),
+    ('\u{1F101}', "\u{4F}\u{2C}"),
+    ('\u{1F102}', "\u{6C}\u{2C}"),
+    ('\u{1F103}', "\u{32}\u{2C}"),
+    ('\u{1F104}', "\u{33}\u{2C}"),
+    ('\u{1F105}', "\u{34}\u{2C}"),
+    ('\u{1F106}', "\u{35}\u{2C}"),
+    ('\u{1F107}', "\u{36}\u{2C}"),
+    ('\u{1F108}', "\u{37}\u{2C}"),
+    ('\u{1F109}', "\u{38}\u{2C}"),
+    ('\u{1F10A}', "\u{39}\u{2C}"),
+    ('\u{1F10F}', "\u{24}\u{20E0}"),
+    ('\u{1F110}', "\u{28}\u{41}\u{29}"),
+    ('\u{1F111}', "\u{28}\u{42}\u{29}"),
+    ('\u{1F112}', "\u{28}\u{43}\u{29}"),
+    ('\u{1F113}', "\u{28}\u{44}\u{29}"),
+    ('\u{1F114}', "\u{28}\u{45}\u{29}"),
+    ('\u{1F115}', "\u{28}\u{46}\u{29}"),
+    ('\u{1F116}', "\u{28}\u{47}\u{29}"),
+    ('\u{1F117}', "\u{28}\u{48}\u{29}"),
+    ('\u{1F118}', "\u{28}\u{6C}\u{29}"),
+    ('\u{1F119}', "\u{28}\u{4A}\u{29}"),
+    ('\u{1F11A}', "\u{28}\u{4B}\u{29}"),
+    ('\u{1F11B}', "\u{28}\u{4C}\u{29}"),
+    ('\u{1F11C}', "\u{28}\u{4D}\u{29}"),
+    ('\u{1F11D}', "\u{28}\u{4E}\u{29}"),
+    ('\u{1F11E}', "\u{28}\u{4F}\u{29}"),
+    ('\u{1F11F}', "\u{28}\u{50}\u{29}"),
+    ('\u{1F120}', "\u{28}\u{51}\u{29}"),
+    ('\u{1F121}', "\u{28}\u{52}\u{29}"),
+    ('\u{1F122}', "\u{28}\u{53}\u{29}"),
+    ('\u{1F123}', "\u{28}\u{54}\u{29}"),
+    ('\u{1F124}', "\u{28}\u{55}\u{29}"),
+    ('\u{1F125}', "\u{28}\u{56}\u{29}"),
+    ('\u{1F126}', "\u{28}\u{57}\u{29}"),
+    ('\u{1F127}', "\u{28}\u{58}\u{29}"),
+    ('\u{1F128}', "\u{28}\u{59}\u{29}"),
+    ('\u{1F129}', "\u{28}\u{5A}\u{29}"),
+    ('\u{1F12A}', "\u{28}\u{53}\u{29}"),
+    ('\u{1F16D}', "\u{33C4}\u{9}\u{20DD}"),
+    ('\u{1F16E}', "\u{43}\u{20E0}"),
+    ('\u{1F240}', "\u{28}\u{672C}\u{29}"),
+    ('\u{1F241}', "\u{28}\u{4E09}\u{29}"),
+    ('\u{1F242}', "\u{28}\u{4E8C}\u{29}"),
+    ('\u{1F243}', "\u{28}\u{5B89}\u{29}"),
+    ('\u{1F244}', "\u{28}\u{70B9}\u{29}"),
+    ('\u{1F245}', "\u{28}\u{6253}\u{29}"),
+    ('\u{1F246}', "\u{28}\u{76D7}\u{29}"),
+    ('\u{1F247}', "\u{28}\u{52DD}\u{29}"),
+    ('\u{1F248}', "\u{28}\u{6557}\u{29}"),
+    ('\u{1F312}', "\u{263D}"),
+    ('\u{1F318}', "\u{263E}"),
+    ('\u{1F319}', "\u{263D}"),
+    ('\u{1F700}', "\u{51}\u{45}"),
+    ('\u{1F701}', "\u{A658}"),
+    ('\u{1F702}', "\u{394}"),
+    ('\u{1F704}', "\u{102BC}"),
+    ('\u{1F707}', "\u{41}\u{52}"),
+    ('\u{1F708}', "\u{56}\u{1DE4}"),
+    ('\u{1F70A}', "\u{2629}"),
+    ('\u{1F714}', "\u{4F}\u{335}"),
+    ('\u{1F728}', "\u{102A8}"),
+    ('\u{1F73A}', "\u{29DF}"),
+    ('\u{1F74C}', "\u{43}"),
+    ('\u{1F754}', "\u{16DC}"),
+    ('\u{1F755}', "\u{22A1}"),
+    ('\u{1F75C}', "\u{73}\u{73}\u{73}"),
+    ('\u{1F75E}', "\u{224F}"),
+    ('\u{1F768}', "\u{54}"),
+    ('\u{1F76B}', "\u{4D}\u{42}"),
+    ('\u{1F76C}', "\u{56}\u{42}"),
+    ('\u{1F771}', "\u{22A0}"),
+    ('\u{1FBF0}', "\u{4F}"),
+    ('\u{1FBF1}', "\u{6C}"),
+    ('\u{1FBF2}', "\u{32}"),
+    ('\u{1FBF3}', "\u{33}"),
+    ('\u{1FBF4}', "\u{34}"),
+    ('\u{1FBF5}', "\u{35}"),
+    ('\u{1FBF6}', "\u{36}"),
+    ('\u{1FBF7}', "\u{37}"),
+    ('\u{1FBF8}', "\u{38}"),
+    ('\u{1FBF9}', "\u{39}"),
+    ('\u{21FE8}', "\u{276C}"),
+];
+
+/// The indices of [`CONFUSABLES`] sorted by prototype, then by character
+#[rustfmt::skip]
+pub(super) static BY_PROTOTYPE: &[u16] = &[
+    8, 1367, 1504, 1505, 1506, 1507, 1508, 1509, 1510, 1511, 1512, 1532,
+    1533, 1534, 1560, 78, 2018, 3704, 1545, 1552, 5175, 2768, 5, 12,
+    150, 152, 153, 154, 155, 161, 162, 163, 177, 210, 396, 397,
+    431, 437, 566, 567, 1158, 1372, 1500, 1501, 1503, 1520, 1521, 1523,
+    1537, 1540, 2771, 3706, 3730, 3990, 3991, 0, 151, 172, 176, 178,
+    436, 438, 1445, 1524, 1525, 1526, 1538, 1541, 2338, 3705, 1539, 1542,
+    1556, 40, 46, 60, 64, 67, 33, 1888, 1892, 2343, 3427, 3726,
+    2036, 1782, 1800, 1783, 1784, 1785, 1786, 1787, 1788, 1789, 5176, 5177,
+    5178, 5179, 5180, 5181, 5182, 5183, 5185, 5186, 5187, 5188, 5189, 5190,
+    5191, 5192, 5193, 5194, 5202, 5195, 5196, 5197, 5198, 5199, 5200, 5201,
+    1821, 1822, 1823, 1824, 1825, 1826, 1827, 1828, 1829, 1830, 1831, 1781,
+    1832, 5184, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1790, 1791,
+    1834, 1835, 1836, 1837, 1838, 1833, 1839, 1840, 1841, 1842, 1843, 1844,
+    1845, 1846, 2473, 2487, 2474, 2488, 2475, 2489, 2476, 2490, 2477, 2491,
+    2478, 2492, 2479, 2493, 2480, 2494, 2502, 2503, 2481, 2495, 2501, 2482,
+    2496, 2483, 2497, 2484, 2498, 2485, 2499, 2486, 2500, 2504, 2510, 2506,
+    5206, 2512, 2505, 5207, 2508, 2529, 2533, 2537, 2511, 2509, 2528, 5212,
+    2513, 2535, 2524, 2530, 2507, 2519, 2531, 5208, 5210, 5213, 2520, 2514,
+    2522, 2517, 5205, 2521, 2516, 2515, 5209, 2525, 5211, 2532, 2523, 2527,
+    2536, 2538, 2539, 2526, 2534, 2518, 1889, 1893, 2344, 3428, 3728, 2037,
+    475, 1553, 1682, 3800, 1379, 1896, 3770, 1938, 1939, 1679, 1937, 1940,
+    1941, 1942, 14, 441, 473, 1522, 2707, 166, 521, 1513, 1514, 1515,
+    1516, 1548, 1678, 1897, 1995, 3577, 2711, 1697, 2964, 2031, 1943, 1944,
+    467, 528, 541, 542, 1528, 2706, 2713, 3851, 3993, 2709, 1529, 2708,
+    1530, 1381, 1547, 1549, 1680, 1857, 1902, 1924, 1998, 2126, 2349, 2365,
+    2466, 2613, 4017, 1960, 1959, 1923, 63, 251, 1233, 2714, 2736, 2762,
+    4969, 4979, 4989, 4999, 5009, 5238, 5167, 1802, 2598, 2574, 2599, 2575,
+    2600, 2576, 2601, 2602, 2603, 2604, 2605, 1820, 2596, 2572, 2597, 2573,
+    72, 2578, 2541, 2554, 71, 93, 274, 375, 2000, 2764, 2781, 3939,
+    3984, 3995, 4970, 4980, 4990, 5000, 5010, 5239, 5168, 1803, 2606, 2607,
+    338, 2579, 2542, 2555, 1098, 3925, 4971, 4981, 4991, 5001, 5011, 5240,
+    5169, 1804, 1288, 2580, 2543, 2556, 73, 3930, 4972, 4982, 4992, 5002,
+    5012, 5241, 5170, 1805, 2581, 2544, 2557, 291, 1111, 2004, 3942, 4973,
+    4983, 4993, 5003, 5013, 5242, 5171, 1806, 2582, 2545, 2558, 3835, 3937,
+    3999, 4974, 4984, 4994, 5004, 5014, 5243, 5172, 1807, 2583, 2546, 2559,
+    94, 95, 628, 645, 668, 3799, 4975, 4985, 4995, 5005, 5015, 5020,
+    5244, 5173, 1808, 2584, 2547, 2560, 629, 644, 673, 765, 1999, 2766,
+    3923, 3940, 3943, 4976, 4986, 4996, 5006, 5016, 5245, 5174, 1809, 2585,
+    2548, 2561, 164, 179, 412, 426, 543, 544, 603, 648, 1378, 1394,
+    1395, 1557, 1696, 2710, 2770, 3561, 3708, 1952, 1921, 157, 1143, 1368,
+    1543, 1890, 4013, 1706, 1725, 1155, 1723, 1994, 1118, 2049, 2358, 2712,
+    1953, 1954, 1702, 1703, 1699, 1701, 1700, 1704, 1951, 158, 1141, 1544,
+    1891, 3986, 4014, 1955, 1707, 1935, 1726, 1151, 100, 138, 621, 1083,
+    2735, 1551, 1550, 211, 269, 1080, 1333, 2697, 3709, 3771, 3987, 4021,
+    4073, 4124, 4176, 4217, 4269, 4316, 4361, 4413, 4465, 4517, 4569, 4621,
+    4675, 4733, 4791, 4849, 4907, 2745, 15, 371, 2747, 5221, 2749, 2751,
+    2753, 2755, 212, 271, 1115, 1338, 1606, 2672, 2785, 3710, 3758, 3772,
+    3792, 4022, 4074, 4125, 4218, 4270, 4317, 4362, 4414, 4466, 4518, 4570,
+    4622, 4676, 4734, 4792, 4850, 4908, 261, 282, 1106, 1577, 1607, 1646,
+    1984, 2680, 3711, 3773, 3793, 3808, 3845, 3952, 3955, 4023, 4075, 4126,
+    4177, 4219, 4363, 4415, 4467, 4519, 4571, 4623, 5227, 44, 346, 5204,
+    1563, 1073, 1334, 1335, 1628, 1647, 2675, 4024, 4076, 4127, 4178, 4220,
+    4271, 4318, 4364, 4416, 4468, 4520, 4572, 4624, 90, 79, 91, 80,
+    16, 22, 45, 213, 273, 1082, 1610, 1730, 2012, 2699, 3712, 3760,
+    3920, 3924, 4025, 4077, 4128, 4221, 4272, 4319, 4365, 4417, 4469, 4521,
+    4573, 4625, 4679, 4737, 4795, 4853, 4911, 102, 250, 1329, 1611, 2683,
+    2774, 3761, 3775, 3847, 3917, 3934, 4000, 4026, 4078, 4129, 4222, 4273,
+    4320, 4366, 4418, 4470, 4522, 4574, 4626, 4965, 1619, 49, 381, 1092,
+    1114, 2677, 4027, 4079, 4130, 4179, 4223, 4274, 4321, 4367, 4419, 4471,
+    4523, 4575, 4627, 51, 88, 215, 278, 1088, 1310, 1585, 1586, 1587,
+    1972, 2692, 3713, 3786, 4028, 4080, 4131, 4224, 4368, 4420, 4472, 4524,
+    4576, 4628, 4681, 4739, 4797, 4855, 4913, 362, 364, 344, 1965, 24,
+    209, 268, 1081, 1202, 2679, 2783, 3715, 4030, 4082, 4133, 4180, 4226,
+    4275, 4323, 4370, 4422, 4474, 4526, 4578, 4630, 1210, 104, 218, 275,
+    1108, 1373, 1974, 2678, 3716, 3844, 4031, 4083, 4134, 4181, 4227, 4276,
+    4324, 4371, 4423, 4475, 4527, 4579, 4631, 4684, 4742, 4800, 4858, 4916,
+    54, 340, 1966, 342, 1570, 2757, 1105, 1218, 1592, 1645, 2002, 2687,
+    3809, 3848, 3918, 3926, 3976, 4010, 4032, 4084, 4135, 4228, 4277, 4325,
+    4372, 4424, 4476, 4528, 4580, 4632, 82, 83, 31, 220, 262, 277,
+    1087, 1337, 1374, 1612, 1648, 1977, 2685, 3717, 3778, 3795, 4033, 4085,
+    4136, 4229, 4278, 4326, 4373, 4425, 4477, 4529, 4581, 4633, 4686, 4744,
+    4802, 4860, 4918, 5233, 368, 221, 1594, 1978, 2686, 3718, 3842, 4034,
+    4086, 4137, 4182, 4230, 4279, 4374, 4426, 4478, 4530, 4582, 4634, 4687,
+    4745, 4803, 4861, 4919, 85, 86, 1595, 3752, 57, 2, 222, 279,
+    395, 560, 627, 670, 672, 1072, 1979, 2019, 2339, 2702, 3719, 3765,
+    3776, 3806, 3828, 3843, 3900, 3927, 3947, 4035, 4087, 4138, 4183, 4231,
+    4280, 4327, 4375, 4427, 4479, 4531, 4583, 4635, 4689, 4747, 4805, 4863,
+    4921, 4967, 4977, 4987, 4997, 5007, 5236, 1076, 5165, 5164, 34, 2725,
+    2760, 59, 216, 234, 245, 257, 322, 377, 1090, 1110, 1711, 1713,
+    1746, 2009, 2758, 4005, 4682, 4692, 4708, 4728, 4740, 4750, 4766, 4786,
+    4798, 4808, 4824, 4844, 4856, 4866, 4882, 4902, 4914, 4924, 4940, 4960,
+    5224, 18, 2014, 2552, 223, 281, 1107, 1180, 1596, 1982, 2673, 3720,
+    3767, 4036, 4088, 4139, 4184, 4232, 4281, 4376, 4428, 4480, 4532, 4584,
+    4636, 4691, 4749, 4807, 4865, 4923, 1199, 1597, 2020, 4037, 4089, 4140,
+    4185, 4233, 4282, 4377, 4429, 4481, 4533, 4585, 4637, 5217, 62, 1074,
+    1100, 1320, 1598, 1599, 1600, 2689, 3826, 3982, 4003, 4038, 4090, 4141,
+    4234, 4378, 4430, 4482, 4534, 4586, 4638, 1566, 266, 393, 1102, 1104,
+    2688, 3721, 3768, 3811, 3983, 4039, 4091, 4142, 4186, 4235, 4283, 4328,
+    4379, 4431, 4483, 4535, 4587, 4639, 225, 283, 1075, 1714, 1904, 1986,
+    2676, 3722, 3769, 3779, 3797, 3931, 3975, 4040, 4092, 4143, 4187, 4236,
+    4284, 4329, 4380, 4432, 4484, 4536, 4588, 4640, 4694, 4752, 4810, 4868,
+    4926, 5232, 2742, 1601, 1738, 66, 348, 36, 99, 1571, 392, 1067,
+    1159, 1690, 1719, 2703, 3832, 3929, 3988, 4041, 4093, 4144, 4188, 4237,
+    4285, 4330, 4381, 4433, 4485, 4537, 4589, 4641, 1176, 1163, 101, 1097,
+    324, 470, 535, 1103, 1139, 1637, 2011, 2691, 2734, 3846, 3916, 3974,
+    3997, 4042, 4094, 4145, 4189, 4238, 4286, 4331, 4382, 4434, 4486, 4538,
+    4590, 4642, 5234, 1638, 1639, 1640, 1145, 3754, 5222, 386, 1086, 1101,
+    2693, 3951, 3954, 4043, 4095, 4146, 4190, 4239, 4287, 4332, 4383, 4435,
+    4487, 4539, 4591, 4643, 1567, 227, 286, 1350, 1369, 1642, 1858, 1990,
+    2022, 2694, 2784, 3723, 3764, 3782, 3798, 3802, 3849, 3953, 4044, 4096,
+    4147, 4191, 4240, 4288, 4333, 4384, 4436, 4488, 4540, 4592, 4644, 4697,
+    4755, 4813, 4871, 4929, 1643, 1644, 354, 3753, 226, 246, 284, 350,
+    1079, 1089, 1987, 2695, 3724, 3780, 3919, 3989, 4045, 4097, 4148, 4192,
+    4241, 4289, 4334, 4385, 4437, 4489, 4541, 4593, 4645, 4695, 4753, 4811,
+    4869, 4927, 10, 107, 352, 214, 1094, 1602, 1604, 2682, 3725, 3791,
+    3922, 3950, 4046, 4098, 4149, 4193, 4242, 4386, 4438, 4490, 4542, 4594,
+    4646, 4680, 4738, 4796, 4854, 4912, 96, 69, 1681, 1903, 1922, 1925,
+    2125, 2467, 2612, 3578, 3727, 3998, 4018, 1770, 2008, 1900, 159, 160,
+    568, 3574, 3575, 3576, 109, 228, 290, 1754, 3731, 4047, 4099, 4150,
+    4194, 4243, 4290, 4335, 4387, 4439, 4491, 4543, 4595, 4647, 4701, 4759,
+    4817, 4875, 4933, 1575, 1576, 2746, 19, 372, 2748, 2750, 2752, 2754,
+    2756, 1497, 1750, 43, 288, 1099, 1182, 1328, 4048, 4100, 4151, 4195,
+    4244, 4291, 4336, 4388, 4440, 4492, 4544, 4596, 4648, 1201, 287, 1195,
+    41, 42, 270, 1183, 1197, 110, 39, 318, 319, 330, 331, 255,
+    303, 1454, 1662, 1985, 2868, 3732, 3817, 4049, 4101, 4152, 4196, 4245,
+    4292, 4337, 4389, 4441, 4493, 4545, 4597, 4649, 1579, 1580, 347, 9,
+    98, 379, 1109, 1181, 1629, 1663, 2674, 4050, 4102, 4153, 4197, 4246,
+    4293, 4338, 4390, 4442, 4494, 4546, 4598, 4650, 1200, 92, 140, 81,
+    1191, 141, 142, 47, 112, 111, 23, 1568, 294, 356, 1608, 1609,
+    1630, 2826, 3733, 4051, 4103, 4154, 4247, 4294, 4339, 4391, 4443, 4495,
+    4547, 4599, 4651, 358, 103, 38, 409, 1498, 2775, 2827, 4052, 4104,
+    4155, 4198, 4248, 4295, 4340, 4392, 4444, 4496, 4548, 4600, 4652, 2944,
+    2947, 2948, 2945, 2946, 146, 50, 1475, 48, 117, 408, 1489, 1584,
+    3734, 4053, 4105, 4156, 4249, 4296, 4341, 4393, 4445, 4497, 4549, 4601,
+    4653, 116, 89, 355, 402, 1093, 1588, 3735, 4054, 4157, 4199, 4250,
+    4297, 4342, 4394, 4446, 4498, 4550, 4602, 4654, 119, 1113, 2724, 25,
+    316, 1589, 26, 121, 122, 170, 206, 235, 314, 370, 1077, 1618,
+    1631, 1649, 1747, 2716, 2848, 3736, 3935, 4055, 4106, 4158, 4200, 4251,
+    4298, 4343, 4395, 4447, 4499, 4551, 4603, 4655, 4673, 4709, 4767, 4825,
+    4883, 4941, 1650, 1651, 28, 1652, 1657, 1752, 120, 1484, 1485, 256,
+    315, 1632, 3737, 4056, 4107, 4159, 4201, 4252, 4299, 4344, 4396, 4448,
+    4500, 4552, 4604, 4656, 105, 4057, 4108, 4160, 4202, 4253, 4300, 4345,
+    4397, 4449, 4501, 4553, 4605, 4657, 55, 3, 4, 7, 52, 76,
+    217, 267, 359, 423, 429, 432, 446, 468, 529, 561, 1370, 1590,
+    1591, 1593, 1633, 1661, 1686, 1768, 1973, 2017, 2701, 3592, 3593, 3714,
+    3738, 3749, 3762, 3794, 3801, 3980, 4029, 4058, 4081, 4109, 4132, 4161,
+    4203, 4225, 4254, 4301, 4322, 4346, 4369, 4398, 4421, 4450, 4473, 4502,
+    4525, 4554, 4577, 4606, 4629, 4658, 4683, 4741, 4799, 4857, 4915, 4968,
+    4978, 4988, 4998, 5008, 5017, 5023, 5112, 5237, 435, 5166, 1801, 1812,
+    2588, 2551, 2564, 1813, 2589, 2565, 1814, 2590, 2566, 1815, 2591, 2567,
+    1816, 2592, 2568, 1817, 2593, 2569, 1818, 2594, 2570, 1819, 2595, 2571,
+    27, 289, 1810, 2586, 2549, 2562, 1636, 1641, 84, 77, 434, 1519,
+    1634, 1687, 1811, 1635, 2587, 2550, 2563, 147, 1572, 148, 29, 30,
+    1230, 125, 3425, 3426, 124, 123, 53, 56, 3756, 3755, 32, 3550,
+    3549, 479, 3586, 3587, 478, 480, 3582, 3583, 2577, 2540, 2553, 404,
+    406, 4060, 4111, 4163, 4205, 4256, 4303, 4348, 4400, 4452, 4504, 4556,
+    4608, 4660, 87, 128, 58, 233, 4707, 4765, 4823, 4881, 4939, 1477,
+    238, 240, 300, 410, 433, 451, 469, 508, 509, 522, 533, 619,
+    643, 661, 682, 698, 713, 715, 730, 735, 746, 761, 772, 790,
+    806, 826, 832, 844, 1458, 1460, 1613, 1980, 2828, 3052, 3053, 3054,
+    3055, 3056, 3057, 3058, 3059, 3684, 3685, 3686, 3687, 3739, 3816, 3838,
+    3938, 3944, 4061, 4112, 4164, 4257, 4304, 4349, 4401, 4453, 4505, 4557,
+    4609, 4661, 4715, 4719, 4773, 4777, 4831, 4835, 4889, 4893, 4947, 4951,
+    5056, 5091, 5116, 35, 1685, 2726, 2761, 540, 2847, 129, 323, 378,
+    2759, 2858, 2871, 21, 2829, 3190, 3324, 3191, 3325, 3494, 3495, 3192,
+    3193, 3326, 759, 825, 130, 239, 254, 302, 1748, 1983, 3740, 4062,
+    4113, 4165, 4206, 4258, 4305, 4350, 4402, 4454, 4506, 4558, 4610, 4662,
+    4717, 4731, 4775, 4789, 4833, 4847, 4891, 4905, 4949, 4963, 1187, 61,
+    1486, 385, 399, 400, 4063, 4114, 4166, 4207, 4259, 4306, 4351, 4403,
+    4455, 4507, 4559, 4611, 4663, 139, 293, 1467, 1968, 2833, 2834, 2854,
+    4064, 4115, 4167, 4208, 4260, 4307, 4352, 4404, 4456, 4508, 4560, 4612,
+    4664, 333, 6, 1664, 3911, 3948, 4059, 4110, 4162, 4204, 4255, 4302,
+    4347, 4399, 4451, 4503, 4555, 4607, 4659, 127, 1476, 1565, 132, 131,
+    1478, 106, 335, 74, 313, 2744, 2866, 3741, 3821, 3933, 4065, 4116,
+    4168, 4209, 4261, 4308, 4353, 4405, 4457, 4509, 4561, 4613, 4665, 5230,
+    2949, 133, 1480, 4066, 4117, 4169, 4210, 4262, 4309, 4354, 4406, 4458,
+    4510, 4562, 4614, 4666, 2767, 143, 2743, 145, 144, 65, 1481, 37,
+    134, 242, 407, 1462, 2780, 2836, 2837, 3840, 3945, 4067, 4118, 4170,
+    4211, 4263, 4310, 4355, 4407, 4459, 4511, 4563, 4615, 4667, 4721, 4779,
+    4837, 4895, 4953, 1474, 2843, 1487, 2862, 237, 325, 430, 1463, 1653,
+    1688, 1717, 2865, 3742, 3912, 3932, 4068, 4119, 4171, 4212, 4264, 4311,
+    4356, 4408, 4460, 4512, 4564, 4616, 4668, 4713, 4771, 4829, 4887, 4945,
+    1654, 1655, 1656, 126, 317, 387, 398, 1464, 2855, 3913, 3914, 3915,
+    4069, 4120, 4172, 4213, 4265, 4312, 4357, 4409, 4461, 4513, 4565, 4617,
+    4669, 3899, 2763, 327, 17, 307, 1302, 1311, 1351, 1658, 1907, 1908,
+    1945, 3743, 4070, 4121, 4173, 4214, 4266, 4313, 4358, 4410, 4462, 4514,
+    4566, 4618, 4670, 1659, 1660, 1946, 118, 135, 230, 305, 351, 842,
+    1490, 1499, 1621, 2840, 3744, 3946, 4071, 4122, 4174, 4215, 4267, 4314,
+    4359, 4411, 4463, 4515, 4567, 4619, 4671, 4703, 4761, 4819, 4877, 4935,
+    68, 108, 353, 1465, 2860, 3936, 4072, 4123, 4175, 4216, 4268, 4315,
+    4360, 4412, 4464, 4516, 4568, 4620, 4672, 97, 136, 1482, 70, 1894,
+    3992, 1895, 171, 1502, 1555, 1698, 1950, 2032, 1743, 2033, 1564, 1847,
+    1849, 169, 1683, 1874, 1877, 2042, 1578, 1583, 1741, 1736, 2048, 1134,
+    1377, 1527, 1531, 1684, 1721, 2043, 2369, 2772, 3747, 3751, 1287, 1154,
+    1142, 1150, 1724, 1209, 1229, 1186, 1162, 1144, 1194, 1196, 1190, 1728,
+    2015, 1146, 1221, 1122, 1164, 1120, 1124, 1126, 1128, 1130, 1132, 1148,
+    1152, 1156, 1166, 1161, 1168, 1170, 1172, 1174, 1184, 1188, 1192, 1203,
+    1205, 1207, 1211, 1213, 1215, 1219, 1223, 1225, 1227, 1231, 1234, 1402,
+    1404, 1406, 1408, 1236, 1238, 1241, 1243, 1245, 1247, 1249, 1251, 1253,
+    1255, 1257, 1259, 1261, 1263, 1265, 1267, 1273, 1275, 1277, 1279, 1281,
+    1283, 1285, 1289, 1291, 1293, 1295, 1297, 1299, 1410, 1411, 1412, 1413,
+    1414, 1415, 1303, 1305, 1307, 1398, 1399, 1400, 1401, 1473, 1561, 1,
+    472, 1554, 439, 1535, 440, 1536, 259, 3830, 229, 244, 1112, 2786,
+    4702, 4760, 4818, 4876, 4934, 1898, 75, 260, 236, 253, 297, 1456,
+    1975, 2870, 4710, 4729, 4768, 4787, 4826, 4845, 4884, 4903, 4942, 4961,
+    341, 343, 310, 2863, 309, 263, 1665, 2681, 3812, 1674, 2013, 2700,
+    373, 383, 1096, 1581, 3805, 3981, 4008, 252, 1466, 2715, 224, 1624,
+    1677, 2016, 4693, 4751, 4809, 4867, 4925, 1091, 1346, 1348, 1603, 113,
+    374, 1461, 2832, 2831, 114, 380, 137, 376, 843, 2001, 2765, 403,
+    4674, 2852, 219, 276, 471, 536, 1140, 2010, 2690, 2732, 3763, 3825,
+    3985, 4685, 4743, 4801, 4859, 4917, 1147, 360, 1491, 207, 1459, 1666,
+    3822, 2842, 2830, 295, 1455, 339, 3819, 382, 1116, 2859, 405, 1068,
+    3818, 243, 247, 306, 1989, 4722, 4730, 4780, 4788, 4838, 4846, 4896,
+    4904, 4954, 4962, 1479, 1605, 2845, 2864, 1691, 2835, 1692, 1693, 1933,
+    1488, 1468, 3836, 298, 1457, 2856, 369, 1712, 1878, 1926, 2021, 2720,
+    3829, 292, 1117, 2778, 3815, 299, 2857, 363, 365, 345, 2003, 2867,
+    3820, 2731, 175, 167, 2723, 11, 1546, 1861, 3570, 3571, 3572, 3573,
+    3748, 308, 2719, 203, 180, 2741, 2740, 2337, 616, 413, 414, 443,
+    454, 548, 617, 185, 463, 564, 1443, 2737, 195, 457, 181, 461,
+    562, 1444, 2738, 182, 201, 460, 462, 2722, 184, 524, 601, 622,
+    646, 667, 697, 714, 734, 3894, 199, 421, 422, 424, 425, 427,
+    525, 545, 546, 563, 588, 602, 630, 647, 680, 565, 589, 2347,
+    200, 419, 456, 523, 674, 789, 805, 830, 1387, 1389, 2026, 2355,
+    3862, 786, 798, 453, 592, 1449, 459, 186, 444, 455, 595, 465,
+    1453, 420, 428, 464, 590, 614, 624, 640, 656, 671, 1451, 3850,
+    3857, 3897, 591, 1452, 817, 2348, 189, 191, 194, 190, 196, 1435,
+    458, 1450, 1446, 1448, 1447, 188, 615, 197, 192, 193, 198, 596,
+    599, 600, 597, 598, 2029, 2030, 2027, 2028, 272, 1085, 1217, 1622,
+    1967, 3973, 4677, 4735, 4793, 4851, 4909, 332, 1222, 334, 1119, 1675,
+    1866, 1969, 2023, 3759, 3774, 3977, 4678, 4736, 4794, 4852, 4910, 5219,
+    1123, 1734, 1136, 4688, 4746, 4804, 4862, 4920, 280, 1623, 1676, 1981,
+    2733, 4690, 4748, 4806, 4864, 4922, 285, 394, 1069, 1380, 1988, 3781,
+    4696, 4754, 4812, 4870, 4928, 320, 1375, 1992, 3783, 3834, 4698, 4756,
+    4814, 4872, 4930, 1347, 1349, 3784, 4699, 4757, 4815, 4873, 4931, 4706,
+    4764, 4822, 4880, 4938, 1976, 3837, 4711, 4769, 4827, 4885, 4943, 13,
+    4712, 4770, 4828, 4886, 4944, 4714, 4772, 4830, 4888, 4946, 248, 301,
+    1469, 1620, 4716, 4732, 4774, 4790, 4832, 4848, 4890, 4906, 4948, 4964,
+    249, 4718, 4776, 4834, 4892, 4950, 1991, 2838, 2839, 4723, 4781, 4839,
+    4897, 4955, 321, 3841, 4724, 4782, 4840, 4898, 4956, 1749, 1993, 2717,
+    2788, 4725, 4783, 4841, 4899, 4957, 1753, 2006, 3777, 3796, 4966, 2005,
+    3831, 336, 204, 2728, 3813, 3996, 328, 1996, 2718, 1582, 337, 329,
+    1471, 361, 1997, 2841, 1095, 1336, 4009, 1425, 326, 366, 367, 357,
+    3827, 3994, 1070, 1160, 1689, 1718, 2704, 4020, 1177, 1165, 1573, 156,
+    165, 411, 1071, 2951, 2952, 2954, 2950, 2953, 417, 418, 416, 415,
+    1614, 2956, 2965, 1615, 1616, 1617, 2957, 2958, 2959, 2960, 2961, 2955,
+    2962, 2963, 474, 2044, 2045, 2041, 3579, 538, 3580, 3581, 3594, 3595,
+    3596, 3597, 5024, 5054, 5089, 5113, 5139, 3269, 3335, 3114, 3265, 3115,
+    3266, 3541, 3116, 3184, 3267, 3319, 3505, 3215, 3216, 3117, 3217, 3268,
+    3334, 3218, 3118, 3119, 3219, 3220, 569, 549, 579, 510, 1742, 3598,
+    3599, 3600, 3601, 3602, 3603, 5043, 5069, 5104, 5132, 5157, 3274, 3337,
+    3120, 3270, 3429, 3506, 3507, 3121, 3271, 3430, 3431, 3432, 3122, 3272,
+    3433, 3508, 3509, 3221, 3222, 3123, 3223, 3273, 3336, 3434, 3435, 3436,
+    3510, 3511, 3224, 3124, 3125, 3225, 3226, 3608, 3609, 3610, 3611, 5025,
+    5055, 5074, 5090, 5114, 5140, 3130, 3276, 3513, 3537, 3558, 3131, 3277,
+    3437, 3438, 3512, 3514, 3366, 3367, 3394, 3395, 3612, 3613, 3614, 3615,
+    5029, 5057, 5075, 5092, 5119, 5144, 3132, 3278, 3538, 3133, 3279, 3439,
+    3440, 3364, 3365, 3392, 3393, 486, 558, 487, 3616, 3617, 3618, 3619,
+    5045, 5071, 5084, 5106, 5134, 5159, 3134, 3280, 3135, 3136, 3281, 3368,
+    3369, 3396, 3397, 3620, 3621, 3787, 5026, 5115, 5141, 526, 574, 488,
+    3022, 3023, 490, 3020, 3021, 3622, 3623, 5046, 5135, 5160, 3200, 3624,
+    3625, 5041, 5130, 5155, 527, 492, 581, 491, 3026, 3027, 3553, 3559,
+    556, 3201, 493, 3024, 3025, 3626, 3627, 5028, 5118, 5143, 578, 3628,
+    3629, 3630, 3631, 5036, 5063, 5079, 5098, 5125, 5150, 3341, 3414, 559,
+    3137, 3282, 3417, 3442, 3443, 3138, 3283, 3418, 3441, 3139, 3284, 3419,
+    3515, 3545, 3379, 3407, 3140, 3285, 3340, 3446, 3444, 3445, 3447, 3448,
+    3360, 3361, 3388, 3389, 448, 3632, 3633, 3634, 3635, 5042, 5068, 5083,
+    5103, 5131, 5156, 3343, 3415, 3374, 3402, 3410, 3420, 3454, 3375, 3403,
+    3411, 3421, 3452, 3453, 3517, 3376, 3404, 3412, 3422, 3378, 3406, 3342,
+    3377, 3405, 3413, 3455, 3456, 3457, 3458, 3362, 3363, 3390, 3391, 3636,
+    3637, 3638, 3639, 3790, 5039, 5066, 5081, 5101, 5128, 5153, 575, 3141,
+    3286, 3449, 3450, 3516, 3287, 3380, 3408, 3552, 3547, 3556, 3557, 3142,
+    3288, 3451, 3544, 3370, 3371, 3398, 3399, 494, 3640, 3641, 3642, 3643,
+    5047, 5072, 5085, 5107, 5136, 5161, 3143, 3289, 3144, 3290, 3459, 3518,
+    3145, 3291, 3460, 3461, 3381, 3409, 3146, 3292, 3372, 3373, 3400, 3401,
+    3644, 3645, 3646, 3647, 3789, 5030, 5093, 5120, 5145, 3147, 3293, 3148,
+    3416, 3423, 3462, 3463, 3464, 3465, 3354, 3355, 3382, 3383, 495, 3648,
+    3649, 3650, 3651, 5048, 5108, 5137, 5162, 3149, 3294, 3424, 442, 3652,
+    3653, 3654, 3655, 5037, 5064, 5080, 5099, 5126, 5151, 3150, 3295, 3466,
+    3543, 3554, 3151, 3296, 3467, 3468, 3469, 3529, 3356, 3357, 3384, 3385,
+    3656, 3657, 3658, 3659, 5049, 5073, 5086, 5109, 5138, 5163, 3152, 3297,
+    3153, 3298, 3470, 3471, 3472, 3358, 3359, 3386, 3387, 497, 3660, 3661,
+    3662, 3663, 5038, 5065, 5100, 5127, 5152, 3154, 3299, 3155, 3300, 3156,
+    3301, 3473, 3474, 3157, 3302, 3540, 3158, 3159, 3233, 3234, 3664, 3665,
+    3666, 3667, 5040, 5067, 5082, 5102, 5129, 5154, 3160, 3303, 3548, 3161,
+    3304, 3475, 3527, 3476, 3525, 3162, 3163, 3235, 3236, 499, 500, 3028,
+    3029, 3030, 3031, 3668, 3669, 3670, 3671, 5032, 5059, 5095, 3164, 3237,
+    3165, 3305, 3166, 3306, 3167, 3307, 3168, 3238, 3308, 3344, 3169, 3239,
+    3309, 3345, 3534, 3542, 3530, 3170, 3171, 3240, 3241, 501, 552, 3064,
+    3065, 3066, 3067, 3672, 3673, 3674, 3675, 5033, 5060, 5077, 5122, 5147,
+    3702, 3703, 3700, 3701, 3698, 3699, 3314, 503, 3696, 3697, 3172, 3310,
+    3480, 3481, 3533, 3535, 3519, 3173, 3311, 3477, 3528, 3478, 3479, 3174,
+    3312, 3482, 3483, 3175, 3242, 3313, 3346, 3484, 3485, 3520, 3176, 3177,
+    3243, 3244, 504, 3676, 3677, 3678, 3679, 5034, 5061, 5096, 5123, 5148,
+    3245, 539, 3178, 3315, 3489, 3493, 3490, 3539, 3179, 3316, 3486, 3487,
+    3551, 3488, 3180, 3317, 3491, 3492, 3532, 3181, 3246, 3318, 3524, 3182,
+    3183, 571, 3680, 3681, 3682, 3683, 5035, 5062, 5078, 5097, 5124, 5149,
+    3323, 3348, 555, 554, 3531, 3536, 3498, 3499, 3500, 3546, 3185, 3320,
+    3496, 3497, 3526, 3186, 3321, 3247, 3248, 3187, 3249, 3322, 3347, 3501,
+    3502, 3250, 3188, 3189, 3251, 3252, 577, 3688, 3689, 3788, 5027, 5117,
+    5142, 514, 3079, 3080, 511, 3070, 3071, 512, 3068, 3069, 482, 3074,
+    3555, 513, 3072, 3073, 481, 3584, 3585, 515, 3075, 3076, 452, 476,
+    505, 516, 520, 585, 3044, 3045, 3060, 3061, 3085, 3086, 3105, 3106,
+    3107, 3108, 3690, 3691, 3692, 3693, 3694, 3695, 5031, 5050, 5051, 5058,
+    5076, 5087, 5094, 5110, 5121, 5146, 3331, 3350, 449, 517, 550, 582,
+    484, 506, 2988, 2989, 2990, 2991, 3046, 3047, 3048, 3049, 3194, 3327,
+    3522, 3195, 3328, 3521, 3196, 3329, 3254, 3255, 3197, 3256, 3330, 3349,
+    3503, 3504, 3523, 3257, 3198, 3199, 3258, 3259, 572, 3202, 3253, 483,
+    3588, 3589, 3590, 3591, 3087, 3088, 3089, 3090, 3264, 3333, 3109, 3260,
+    3110, 3261, 3262, 3209, 3210, 3111, 3211, 3263, 3332, 3212, 3091, 3092,
+    3095, 3096, 3093, 3094, 3097, 3098, 3102, 3103, 3104, 3112, 3113, 3213,
+    3214, 3099, 3100, 3101, 447, 450, 485, 507, 519, 2972, 2973, 2974,
+    2975, 3604, 3605, 3606, 3607, 5044, 5070, 5105, 5133, 5158, 3339, 3126,
+    3227, 3228, 3127, 3229, 3275, 3338, 3230, 3128, 3129, 3231, 3232, 580,
+    586, 587, 593, 594, 187, 445, 466, 530, 2823, 531, 5019, 532,
+    534, 537, 620, 3949, 183, 2966, 2967, 2980, 2981, 2982, 2983, 518,
+    2968, 2969, 2970, 2971, 3081, 3082, 3083, 3084, 2984, 2985, 2986, 2987,
+    2976, 2977, 2978, 2979, 3004, 3005, 3006, 3007, 3000, 3001, 3002, 3003,
+    3008, 3009, 3010, 3011, 3012, 3013, 3014, 3015, 489, 3018, 3019, 3016,
+    3017, 557, 477, 583, 584, 5052, 5053, 5088, 5111, 496, 498, 2992,
+    2993, 2994, 2995, 570, 2996, 2997, 2998, 2999, 551, 576, 3032, 3033,
+    3034, 3035, 502, 3040, 3041, 3042, 3043, 3036, 3037, 3038, 3039, 3077,
+    3078, 3062, 3063, 3050, 3051, 1434, 1574, 547, 553, 573, 605, 611,
+    612, 613, 604, 610, 607, 608, 609, 606, 3858, 657, 658, 659,
+    641, 642, 660, 2790, 618, 662, 663, 664, 665, 666, 1887, 3855,
+    3856, 168, 757, 631, 699, 716, 736, 773, 831, 3895, 623, 625,
+    626, 3870, 3871, 3872, 3873, 3874, 3875, 3886, 3877, 3878, 3879, 3880,
+    3881, 3882, 3885, 3883, 3884, 3887, 3876, 3888, 3889, 3898, 3890, 3891,
+    3892, 3896, 3893, 3901, 3902, 3903, 632, 638, 639, 633, 634, 637,
+    635, 636, 649, 653, 654, 655, 650, 651, 652, 669, 689, 677,
+    678, 686, 684, 738, 675, 739, 688, 694, 676, 745, 683, 685,
+    687, 691, 751, 747, 696, 679, 692, 690, 681, 749, 750, 695,
+    752, 753, 693, 717, 718, 719, 711, 712, 720, 701, 722, 700,
+    721, 723, 724, 703, 725, 704, 705, 726, 702, 727, 728, 707,
+    708, 706, 709, 710, 731, 732, 733, 729, 737, 741, 742, 743,
+    760, 763, 740, 744, 769, 767, 770, 764, 758, 748, 762, 771,
+    766, 768, 754, 755, 756, 775, 776, 774, 777, 778, 781, 782,
+    784, 791, 779, 780, 783, 793, 794, 795, 796, 797, 785, 792,
+    1390, 788, 1383, 1384, 1385, 1386, 799, 800, 787, 801, 1388, 802,
+    803, 804, 1392, 1391, 1393, 807, 808, 812, 813, 814, 816, 821,
+    815, 811, 810, 818, 809, 819, 820, 824, 839, 837, 827, 836,
+    835, 828, 829, 834, 833, 838, 840, 979, 2370, 845, 980, 2371,
+    1061, 912, 1006, 1062, 981, 2372, 1007, 1063, 1064, 1065, 982, 2373,
+    850, 1008, 851, 1066, 2422, 852, 1009, 2423, 2895, 853, 913, 1010,
+    2424, 914, 983, 2374, 2896, 1012, 915, 984, 2375, 1011, 2425, 985,
+    2376, 854, 1013, 846, 2377, 2897, 2898, 916, 1014, 2791, 2792, 2899,
+    2793, 2900, 2901, 2794, 2902, 2903, 2904, 986, 2378, 987, 2379, 2795,
+    2796, 2905, 1015, 2426, 2906, 855, 1016, 1017, 2427, 2797, 2798, 1018,
+    856, 1019, 2907, 988, 2380, 2799, 1020, 1021, 2908, 989, 2381, 2800,
+    2909, 2801, 1022, 2428, 1024, 2802, 2910, 1023, 990, 2382, 2803, 1025,
+    858, 2913, 2804, 1027, 2805, 991, 2383, 992, 2384, 857, 890, 993,
+    2385, 2934, 1026, 2429, 2911, 1028, 2430, 2912, 994, 2386, 1029, 2806,
+    2914, 2915, 2807, 1030, 2916, 859, 1031, 2431, 2917, 1032, 2432, 2808,
+    1033, 860, 1037, 2434, 2918, 1035, 1036, 1034, 2433, 995, 2387, 861,
+    2435, 862, 863, 2436, 2919, 1038, 2920, 2921, 847, 2388, 2922, 875,
+    2442, 864, 996, 2389, 865, 2437, 866, 2438, 2923, 867, 868, 869,
+    2809, 874, 1041, 2441, 870, 2439, 2924, 871, 2925, 2810, 872, 2440,
+    873, 1039, 1040, 2811, 997, 2390, 876, 1042, 2443, 877, 2444, 878,
+    1043, 2445, 879, 1044, 880, 2926, 881, 1045, 2446, 882, 2927, 848,
+    998, 2391, 2928, 2929, 2812, 883, 884, 885, 2447, 2931, 886, 2932,
+    887, 888, 2933, 889, 2930, 999, 2392, 893, 1047, 1048, 894, 2813,
+    895, 896, 897, 1052, 2451, 899, 1049, 2449, 900, 901, 1050, 902,
+    903, 2814, 898, 1053, 2452, 1000, 2393, 2939, 2940, 904, 849, 2394,
+    2941, 2815, 1001, 2395, 907, 908, 1002, 2396, 1003, 2397, 2816, 1004,
+    2398, 909, 1054, 2942, 910, 1055, 2453, 2943, 2817, 1005, 2399, 1056,
+    1057, 1058, 1059, 2818, 911, 2454, 891, 892, 1046, 2448, 2935, 2936,
+    1051, 2450, 2937, 2938, 905, 906, 1060, 2455, 2819, 2421, 2400, 930,
+    931, 974, 917, 2401, 2402, 932, 933, 975, 918, 2403, 2404, 934,
+    935, 936, 919, 2405, 2406, 976, 937, 938, 920, 2407, 2408, 921,
+    2409, 922, 2410, 977, 978, 939, 940, 2872, 941, 942, 2873, 943,
+    923, 2411, 2412, 2874, 2875, 944, 946, 2456, 945, 2457, 2876, 947,
+    948, 2458, 2413, 949, 950, 924, 2414, 951, 925, 2415, 2877, 952,
+    953, 926, 2416, 2878, 2417, 954, 2879, 955, 956, 957, 2459, 958,
+    2460, 2880, 959, 960, 2461, 2462, 2893, 970, 2894, 971, 973, 972,
+    2463, 389, 391, 388, 390, 1121, 1135, 1125, 1127, 1137, 1129, 1131,
+    1138, 1133, 1418, 1240, 1301, 1149, 1153, 1744, 1157, 1167, 1709, 2035,
+    1178, 1901, 1169, 1958, 1171, 1708, 2034, 1179, 1173, 1175, 1198, 1185,
+    1189, 1193, 1340, 1204, 1206, 1208, 1212, 1339, 1214, 1216, 1220, 1224,
+    1226, 1228, 1232, 1235, 1403, 1405, 1407, 1409, 1237, 1239, 1341, 1242,
+    1244, 1246, 1248, 1250, 1342, 1252, 1254, 1256, 1258, 1260, 1262, 1264,
+    1266, 1268, 1269, 1271, 1270, 1272, 1274, 1276, 1278, 1280, 1282, 1284,
+    1286, 1290, 1292, 1294, 1296, 1298, 1300, 1343, 1419, 1344, 1345, 1304,
+    1313, 1317, 1318, 1315, 1352, 1312, 1314, 1316, 1319, 1306, 1420, 1421,
+    1308, 1422, 1423, 1324, 1353, 1321, 1322, 1323, 1325, 1326, 1327, 1356,
+    1354, 1355, 1357, 1358, 1359, 1424, 1360, 1361, 1362, 1363, 1364, 1365,
+    1366, 1957, 1956, 2705, 1426, 1427, 1495, 2696, 2729, 1376, 1371, 1755,
+    4019, 1667, 1672, 1909, 1911, 1671, 1936, 1720, 1872, 1873, 1884, 3766,
+    3928, 5228, 1735, 1722, 1934, 3833, 1668, 2024, 1382, 1396, 1397, 1494,
+    1472, 1417, 173, 149, 174, 1416, 1428, 1429, 1430, 1431, 1432, 1433,
+    1436, 1437, 1438, 1439, 1440, 1441, 1442, 2849, 2844, 2851, 2850, 205,
+    296, 3823, 1470, 2869, 311, 241, 304, 2846, 4720, 4778, 4836, 4894,
+    4952, 349, 1483, 1492, 1493, 2025, 231, 401, 1309, 2046, 4704, 4762,
+    4820, 4878, 4936, 1766, 2342, 2350, 2721, 1848, 1330, 2684, 4006, 1961,
+    1962, 1963, 1964, 1669, 1912, 1910, 4726, 4784, 4842, 4900, 4958, 5021,
+    20, 5022, 1731, 3921, 4700, 4758, 4816, 4874, 4932, 1739, 1745, 1859,
+    1864, 1949, 5018, 1716, 1694, 1695, 2039, 2038, 2040, 3861, 1883, 5231,
+    1705, 1929, 1930, 4015, 4016, 1931, 1932, 1928, 1737, 5235, 5229, 1871,
+    1867, 1740, 3563, 1876, 1947, 1732, 1918, 1875, 1915, 1917, 1914, 1764,
+    1763, 1762, 1765, 1882, 3564, 3565, 3566, 3567, 3568, 3569, 1865, 1767,
+    1850, 1854, 3562, 3745, 1855, 1856, 1860, 1862, 1863, 1879, 3750, 1868,
+    1869, 2007, 5223, 5214, 5216, 1769, 5215, 1905, 2340, 2354, 2470, 5246,
+    1906, 2341, 1771, 1772, 1773, 1774, 1775, 1776, 1777, 1778, 1779, 1780,
+    2345, 2346, 1920, 5226, 1948, 2820, 1332, 1673, 2698, 4004, 3810, 202,
+    1078, 1084, 2730, 2853, 1881, 3757, 3785, 1559, 1913, 2047, 1558, 1727,
+    3560, 1496, 3746, 1733, 1916, 2367, 927, 1517, 1518, 1852, 1853, 2123,
+    2418, 2464, 2611, 2789, 3707, 2881, 2882, 2883, 2884, 961, 962, 928,
+    2419, 963, 5203, 2054, 2609, 2608, 2062, 2625, 2628, 2639, 2623, 2610,
+    2631, 2659, 2626, 2629, 2646, 929, 1756, 1757, 1758, 1759, 1760, 1761,
+    2124, 2420, 2465, 964, 965, 2885, 2886, 2887, 2888, 966, 2889, 2890,
+    967, 2891, 969, 968, 2892, 2073, 2127, 2472, 2051, 2471, 2050, 2468,
+    2121, 2128, 2469, 2129, 2364, 2130, 2131, 2052, 2359, 2614, 2615, 2132,
+    2055, 2133, 2134, 2366, 2135, 2136, 2137, 2138, 2139, 2140, 2053, 2141,
+    2361, 2142, 2143, 2144, 2145, 2146, 2351, 2352, 2353, 823, 822, 2147,
+    2363, 2148, 2149, 2150, 2151, 2152, 2153, 2368, 2617, 2616, 2154, 2155,
+    2619, 2618, 2620, 2156, 2157, 2158, 2362, 2159, 2160, 2621, 2161, 2162,
+    2163, 2164, 2057, 2165, 2056, 2166, 2167, 2168, 2169, 2170, 2360, 2171,
+    2058, 2172, 2622, 2173, 2059, 2174, 2175, 2176, 2177, 2178, 2179, 2180,
+    2060, 2181, 2182, 2183, 2061, 2184, 2185, 2624, 2186, 2063, 2187, 2188,
+    2064, 2189, 2115, 2190, 2191, 2192, 2193, 2065, 2194, 2627, 2195, 2196,
+    2636, 2638, 2637, 2640, 2641, 2643, 2197, 2630, 2632, 2198, 2199, 2117,
+    2200, 2066, 2201, 2202, 2067, 2203, 2204, 2205, 2068, 2206, 2207, 2069,
+    2070, 2633, 2208, 2071, 2209, 2072, 2210, 2211, 2212, 2213, 2214, 2215,
+    2216, 2074, 2217, 2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225, 2226,
+    2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2634, 2235, 2076, 2236,
+    2237, 2238, 2239, 2119, 2240, 2241, 2242, 2077, 2635, 2243, 2244, 2075,
+    2079, 2078, 2245, 2246, 2247, 2080, 2248, 2249, 2250, 2251, 2081, 2252,
+    2642, 2253, 2254, 2255, 2256, 2257, 2258, 2259, 2260, 2261, 2262, 2082,
+    2083, 2084, 2644, 2645, 2263, 2085, 2264, 2265, 2266, 2267, 2086, 2268,
+    2088, 2087, 2269, 2089, 2270, 2271, 2647, 2648, 2090, 2272, 2273, 2274,
+    2649, 2275, 2276, 2091, 2277, 2278, 2650, 2279, 2651, 2652, 2280, 2281,
+    2653, 2092, 2282, 2283, 2284, 2093, 2094, 2285, 2654, 2286, 2287, 2288,
+    2289, 2655, 2096, 2097, 2290, 2098, 2099, 2291, 2100, 2292, 2095, 2101,
+    2293, 2656, 2294, 2295, 2296, 2102, 2297, 2298, 2299, 2300, 2103, 2301,
+    2302, 2303, 2104, 2304, 2105, 2305, 2106, 2107, 2306, 2108, 2109, 2307,
+    2308, 2309, 2110, 2310, 2311, 2312, 2313, 2314, 2315, 2111, 2316, 2317,
+    2112, 2318, 2657, 2319, 2320, 2321, 2113, 2322, 2323, 2114, 2324, 2325,
+    2658, 2326, 2327, 2328, 2329, 2330, 2331, 2332, 2116, 2333, 2118, 2334,
+    2120, 2335, 2122, 2336, 2662, 2665, 2661, 2664, 2669, 2668, 2660, 2670,
+    2671, 2667, 2666, 2663, 1715, 1899, 2782, 4007, 2779, 1625, 1626, 3807,
+    3979, 4002, 4011, 2787, 3978, 5218, 3839, 2739, 2773, 264, 208, 1562,
+    2769, 841, 265, 1569, 1970, 115, 232, 258, 312, 384, 1729, 1971,
+    2861, 3814, 3941, 4705, 4727, 4763, 4785, 4821, 4843, 4879, 4901, 4937,
+    4959, 1751, 1331, 4012, 3860, 3859, 2821, 2822, 2824, 2825, 3729, 3203,
+    3204, 3205, 3351, 3206, 3352, 3207, 3353, 3208, 2356, 2357, 1710, 1927,
+    2727, 5225, 1870, 4001, 5220, 1919, 3803, 3804, 3971, 2776, 2777, 3824,
+    1880, 3972, 3852, 3854, 3853, 3863, 3864, 3865, 3866, 3867, 3868, 3869,
+    3904, 3905, 3906, 3907, 3908, 3909, 3910, 3958, 3959, 3960, 3956, 3957,
+    3962, 3963, 3961, 3964, 3965, 3966, 3967, 3968, 3969, 3970, 1627, 1885,
+    1886, 1851, 1670,
+];
@@ -21,14 +21,23 @@
 //! * _VerifyDnsLength_ - _true_: [`DnsLength::Verify`], _false_: [`DnsLength::Ignore`]; the WHATWG URL Standard sets this to _false_ for normal (non-conformance-checker) user agents.
 //! * _IgnoreInvalidPunycode_ - Always _false_; cannot be configured. (Not yet covered by the WHATWG URL Standard, but 2 out of 3 major browser clearly behave as if this was _false_).
 
+#[cfg(feature = "provider")]
+pub use crate::adapter::Uts46DataProvider;
+use crate::backend::{Backend, BidiClass as _, JoiningType as _, Mask as _};
 use crate::punycode::Decoder;
 use crate::punycode::InternalCaller;
+use crate::punycode::PunycodeCodeUnit;
 use alloc::borrow::Cow;
 use alloc::string::String;
+#[cfg(feature = "utf16")]
+use alloc::vec::Vec;
 use core::fmt::Write;
-use idna_adapter::*;
 use smallvec::SmallVec;
+#[cfg(feature = "utf16")]
+use utf16_iter::Utf16CharsEx;
 use utf8_iter::Utf8CharsEx;
+#[cfg(feature = "utf16")]
+pub use write16::Write16;
 
 /// ICU4C-compatible constraint.
 /// https://unicode-org.atlassian.net/browse/ICU-13727
@@ -110,28 +119,32 @@ fn write_punycode_label<W: Write + ?Sized>(
     sink: &mut W,
 ) -> Result<(), ProcessingError> {
     sink.write_str("xn--")?;
-    crate::punycode::encode_into::<_, _, InternalCaller>(label.iter().copied(), sink)?;
+    crate::punycode::encode_impl::<_, _, InternalCaller>(
+        label.iter().map(|&c| (c, false)),
+        false,
+        sink,
+    )?;
     Ok(())
 }
 
 #[inline(always)]
-fn has_punycode_prefix(slice: &[u8]) -> bool {
+fn has_punycode_prefix<T: InputUnit>(slice: &[T]) -> bool {
     if slice.len() < 4 {
         return false;
     }
     // Sadly, the optimizer doesn't figure out that more idiomatic code
     // should compile to masking on 32-bit value.
-    let a = slice[0];
-    let b = slice[1];
-    let c = slice[2];
-    let d = slice[3];
-    let u = (u32::from(d) << 24) | (u32::from(c) << 16) | (u32::from(b) << 8) | u32::from(a);
+    let a: u32 = slice[0].into();
+    let b: u32 = slice[1].into();
+    let c: u32 = slice[2].into();
+    let d: u32 = slice[3].into();
+    let u = (d << 24) | (c << 16) | (b << 8) | a;
     (u & PUNYCODE_PREFIX_MASK) == PUNYCODE_PREFIX
 }
 
 #[inline(always)]
-fn in_inclusive_range8(u: u8, start: u8, end: u8) -> bool {
-    u.wrapping_sub(start) <= (end - start)
+fn in_inclusive_range8<T: InputUnit>(u: T, start: u8, end: u8) -> bool {
+    u.into().wrapping_sub(u32::from(start)) <= u32::from(end - start)
 }
 
 #[inline(always)]
@@ -140,10 +153,10 @@ fn in_inclusive_range_char(c: char, start: char, end: char) -> bool {
 }
 
 #[inline(always)]
-fn is_passthrough_ascii_label(label: &[u8]) -> bool {
+fn is_passthrough_ascii_label<T: InputUnit>(label: &[T]) -> bool {
     // XXX if we aren't performing _CheckHyphens_, this could
     // check for "xn--" and pass through YouTube CDN node names.
-    if label.len() >= 4 && label[2] == b'-' && label[3] == b'-' {
+    if label.len() >= 4 && label[2] == T::from(b'-') && label[3] == T::from(b'-') {
         return false;
     }
     if let Some((&first, tail)) = label.split_first() {
@@ -170,12 +183,12 @@ fn is_passthrough_ascii_label(label: &[u8]) -> bool {
             if in_inclusive_range8(b, b'0', b'9') {
                 continue;
             }
-            if b == b'-' {
+            if b == T::from(b'-') {
                 continue;
             }
             return false;
         }
-        label.last() != Some(&b'-')
+        label.last() != Some(&T::from(b'-'))
     } else {
         // empty
         true
@@ -183,7 +196,7 @@ fn is_passthrough_ascii_label(label: &[u8]) -> bool {
 }
 
 #[inline(always)]
-fn split_ascii_fast_path_prefix(label: &[u8]) -> (&[u8], &[u8]) {
+fn split_ascii_fast_path_prefix<T: InputUnit>(label: &[T]) -> (&[T], &[T]) {
     if let Some(pos) = label.iter().position(|b| !b.is_ascii()) {
         if pos == 0 {
             // First is non-ASCII
@@ -217,16 +230,75 @@ fn apply_ascii_deny_list_to_lower_cased_unicode(c: char, deny_list: u128) -> cha
 
 // Input known to be ASCII, but may contain upper case ASCII.
 #[inline(always)]
-fn apply_ascii_deny_list_to_potentially_upper_case_ascii(b: u8, deny_list: u128) -> char {
-    if (deny_list & (1u128 << b)) == 0 {
-        return char::from(b);
+fn apply_ascii_deny_list_to_potentially_upper_case_ascii<T: InputUnit>(
+    b: T,
+    deny_list: u128,
+) -> char {
+    let shift: u32 = b.into();
+    if (deny_list & (1u128 << shift)) == 0 {
+        return b.char();
     }
     if in_inclusive_range8(b, b'A', b'Z') {
-        return char::from(b + 0x20);
+        return b.char_ascii_lower_case();
     }
     '\u{FFFD}'
 }
 
+/// A code unit of the input that processing works on: `u8` for UTF-8 and, for
+/// [`Uts46::process_utf16`], `u16` for UTF-16.
+trait InputUnit: Copy + PartialEq + From<u8> + Into<u32> + PunycodeCodeUnit {
+    /// Calls `f` with each character of the UTS 46 mapping and normalization of
+    /// `units`, with ill-formed sequences as U+FFFD, until `f` returns an error.
+    fn try_map_normalize<U: Backend, E, F: FnMut(char) -> Result<(), E>>(
+        data: &U,
+        units: &[Self],
+        f: F,
+    ) -> Result<(), E>;
+
+    /// Writes `units` to `sink`.
+    ///
+    /// # Safety
+    ///
+    /// `units` must be ASCII.
+    unsafe fn write_ascii<W: Write + ?Sized>(units: &[Self], sink: &mut W) -> core::fmt::Result;
+}
+
+impl InputUnit for u8 {
+    #[inline(always)]
+    fn try_map_normalize<U: Backend, E, F: FnMut(char) -> Result<(), E>>(
+        data: &U,
+        units: &[Self],
+        f: F,
+    ) -> Result<(), E> {
+        data.try_map_normalize(units.chars(), f)
+    }
+
+    #[inline(always)]
+    unsafe fn write_ascii<W: Write + ?Sized>(units: &[Self], sink: &mut W) -> core::fmt::Result {
+        sink.write_str(core::str::from_utf8_unchecked(units))
+    }
+}
+
+#[cfg(feature = "utf16")]
+impl InputUnit for u16 {
+    #[inline(always)]
+    fn try_map_normalize<U: Backend, E, F: FnMut(char) -> Result<(), E>>(
+        data: &U,
+        units: &[Self],
+        f: F,
+    ) -> Result<(), E> {
+        data.try_map_normalize(units.chars(), f)
+    }
+
+    #[inline(always)]
+    unsafe fn write_ascii<W: Write + ?Sized>(units: &[Self], sink: &mut W) -> core::fmt::Result {
+        for &u in units {
+            sink.write_char(u.char())?;
+        }
+        Ok(())
+    }
+}
+
 #[inline(always)]
 fn is_ascii(label: &[char]) -> bool {
     for c in label.iter() {
@@ -267,7 +339,7 @@ fn classify_for_punycode(label: &[char]) -> PunycodeClassification {
 }
 
 /// The ASCII deny list to be applied.
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 #[repr(transparent)]
 pub struct AsciiDenyList {
     bits: u128,
@@ -352,7 +424,7 @@ impl AsciiDenyList {
 }
 
 /// The _CheckHyphens_ mode.
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 #[non_exhaustive] // non_exhaustive in case a middle mode that prohibits only first and last position needs to be added
 pub enum Hyphens {
     /// _CheckHyphens=false_: Do not place positional restrictions on hyphens.
@@ -376,7 +448,7 @@ pub enum Hyphens {
 }
 
 /// The UTS 46 _VerifyDNSLength_ flag.
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 #[non_exhaustive]
 pub enum DnsLength {
     /// _VerifyDNSLength=false_. (Possibly relevant for allowing non-DNS naming systems.)
@@ -389,7 +461,7 @@ pub enum DnsLength {
 }
 
 /// Policy for customizing behavior in case of an error.
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 #[non_exhaustive]
 pub enum ErrorPolicy {
     /// Return as early as possible without producing output in case of error.
@@ -400,12 +472,219 @@ pub enum ErrorPolicy {
     MarkErrors,
 }
 
+/// Policy for which labels [`Uts46::to_unicode_with_options`] and
+/// [`Uts46::process_with_options`] output as Unicode.
+///
+/// Only error-free labels that contain non-ASCII characters are subject to
+/// the policy. See the `output_as_unicode` argument of [`Uts46::process`].
+#[derive(Copy, Clone)]
+#[non_exhaustive]
+pub enum UnicodeOutput {
+    /// Output all labels as Unicode, as in the _ToUnicode_ operation.
+    Always,
+    /// Output all labels as Punycode, as in the _ToASCII_ operation.
+    Never,
+    /// Output as Unicode the labels that the [UTS 39 display policy](crate::uts39::display_policy)
+    /// with the given maximum restriction level allows and the others as Punycode.
+    #[cfg(feature = "uts39")]
+    Uts39(crate::uts39::RestrictionLevel),
+    /// Decide with a function that takes the same arguments as the `output_as_unicode`
+    /// closure of [`Uts46::process`].
+    Custom(fn(&[char], &[char], bool) -> bool),
+}
+
+// Not derived, because function pointers with lifetime parameters don't
+// implement `Debug` before Rust 1.70.
+impl core::fmt::Debug for UnicodeOutput {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            UnicodeOutput::Always => f.write_str("Always"),
+            UnicodeOutput::Never => f.write_str("Never"),
+            #[cfg(feature = "uts39")]
+            UnicodeOutput::Uts39(max_level) => f.debug_tuple("Uts39").field(&max_level).finish(),
+            UnicodeOutput::Custom(output_as_unicode) => f
+                .debug_tuple("Custom")
+                .field(&(output_as_unicode as *const ()))
+                .finish(),
+        }
+    }
+}
+
+impl UnicodeOutput {
+    /// The `output_as_unicode` closure for [`Uts46::process`]
+    fn output_as_unicode(self) -> impl FnMut(&[char], &[char], bool) -> bool {
+        move |label, tld, is_bidi| match self {
+            UnicodeOutput::Always => true,
+            UnicodeOutput::Never => false,
+            #[cfg(feature = "uts39")]
+            UnicodeOutput::Uts39(max_level) => {
+                crate::uts39::display_policy(max_level)(label, tld, is_bidi)
+            }
+            UnicodeOutput::Custom(output_as_unicode) => output_as_unicode(label, tld, is_bidi),
+        }
+    }
+}
+
+/// The options of UTS 46 processing bundled together, for the `_with_options`
+/// methods of [`Uts46`].
+///
+/// The options are set from one of the presets, or from the [`Default`], which is
+/// [`Uts46Options::URL`], with the methods named after them:
+///
+/// ```
+/// use idna::uts46::{DnsLength, Hyphens, Uts46, Uts46Options};
+///
+/// const OPTIONS: Uts46Options = Uts46Options::URL
+///     .hyphens(Hyphens::CheckFirstLast)
+///     .dns_length(DnsLength::Verify);
+///
+/// let uts46 = Uts46::new();
+/// assert_eq!(
+///     uts46.to_ascii_with_options("Bücher.example".as_bytes(), OPTIONS).unwrap(),
+///     "xn--bcher-kva.example"
+/// );
+/// assert!(uts46.to_ascii_with_options(b"-bucher.example", OPTIONS).is_err());
+/// ```
+///
+/// `Uts46Options` is `Copy`, `Send` and `Sync`, so it can be kept in a `const`, a
+/// `static` or a struct.
+#[derive(Copy, Clone, Debug)]
+#[must_use]
+pub struct Uts46Options {
+    ascii_deny_list: AsciiDenyList,
+    hyphens: Hyphens,
+    dns_length: DnsLength,
+    error_policy: ErrorPolicy,
+    unicode_output: UnicodeOutput,
+}
+
+/// The default is [`Uts46Options::URL`].
+impl Default for Uts46Options {
+    fn default() -> Self {
+        Uts46Options::URL
+    }
+}
+
+impl Uts46Options {
+    /// The [domain to ASCII](https://url.spec.whatwg.org/#concept-domain-to-ascii) and
+    /// [domain to Unicode](https://url.spec.whatwg.org/#concept-domain-to-unicode)
+    /// algorithms of the WHATWG URL Standard with _beStrict_ set to false, as used by
+    /// the `url` crate, plus the forbidden domain code point check.
+    ///
+    /// [`Uts46::to_unicode_with_options`] outputs all labels as Unicode, as domain to
+    /// Unicode does.
+    pub const URL: Uts46Options = Uts46Options {
+        ascii_deny_list: AsciiDenyList::URL,
+        hyphens: Hyphens::Allow,
+        dns_length: DnsLength::Ignore,
+        error_policy: ErrorPolicy::FailFast,
+        unicode_output: UnicodeOutput::Always,
+    };
+
+    /// Display of a domain name to the user, as in the address bar of a browser.
+    ///
+    /// Errors are marked in the output instead of failing. All labels are output
+    /// as Unicode; applications that render potentially misleading labels as Punycode
+    /// should set [`UnicodeOutput::Custom`] (or `UnicodeOutput::Uts39` with the `uts39`
+    /// feature).
+    pub const BROWSER_DISPLAY: Uts46Options = Uts46Options {
+        ascii_deny_list: AsciiDenyList::URL,
+        hyphens: Hyphens::Allow,
+        dns_length: DnsLength::Ignore,
+        error_policy: ErrorPolicy::MarkErrors,
+        unicode_output: UnicodeOutput::Always,
+    };
+
+    /// Strict conversion of a domain name for looking it up in the DNS.
+    ///
+    /// As [`Uts46Options::STD3`], i.e. the STD3 deny list and the _CheckHyphens_ and
+    /// _VerifyDNSLength_ checks, except that the trailing root label dot is allowed.
+    ///
+    /// Note that this rejects the underscore of pseudo-hosts, such as those of TXT
+    /// record-based protocols. See [`Uts46Options::LENIENT_DNS_LOOKUP`] for those.
+    pub const STRICT_DNS_LOOKUP: Uts46Options = Uts46Options {
+        ascii_deny_list: AsciiDenyList::STD3,
+        hyphens: Hyphens::Check,
+        dns_length: DnsLength::VerifyAllowRootDot,
+        error_policy: ErrorPolicy::FailFast,
+        unicode_output: UnicodeOutput::Never,
+    };
+
+    /// Lenient conversion of a domain name for looking it up in the DNS.
+    ///
+    /// The name must fit the DNS length limits, allowing the trailing root label dot,
+    /// and must not have the characters of [`AsciiDenyList::URL`]. Unlike
+    /// [`Uts46Options::STRICT_DNS_LOOKUP`], this allows the underscore of pseudo-hosts,
+    /// such as those of TXT record-based protocols, and hyphens in any position, as in
+    /// some real-world names.
+    pub const LENIENT_DNS_LOOKUP: Uts46Options = Uts46Options {
+        ascii_deny_list: AsciiDenyList::URL,
+        hyphens: Hyphens::Allow,
+        dns_length: DnsLength::VerifyAllowRootDot,
+        error_policy: ErrorPolicy::FailFast,
+        unicode_output: UnicodeOutput::Never,
+    };
+
+    /// UTS 46 with _UseSTD3ASCIIRules=true_, _CheckHyphens=true_ and _VerifyDNSLength=true_,
+    /// which is the WHATWG URL Standard with _beStrict_ set to true, as in
+    /// [`domain_to_ascii_strict`](crate::domain_to_ascii_strict).
+    ///
+    /// Note that this rejects various real-world names.
+    pub const STD3: Uts46Options = Uts46Options {
+        ascii_deny_list: AsciiDenyList::STD3,
+        hyphens: Hyphens::Check,
+        dns_length: DnsLength::Verify,
+        error_policy: ErrorPolicy::FailFast,
+        unicode_output: UnicodeOutput::Never,
+    };
+
+    /// Sets the ASCII deny list. See the `ascii_deny_list` argument of [`Uts46::to_ascii`].
+    #[inline]
+    pub const fn ascii_deny_list(mut self, value: AsciiDenyList) -> Self {
+        self.ascii_deny_list = value;
+        self
+    }
+
+    /// Sets the UTS 46 _CheckHyphens_ mode.
+    #[inline]
+    pub const fn hyphens(mut self, value: Hyphens) -> Self {
+        self.hyphens = value;
+        self
+    }
+
+    /// Sets the UTS 46 _VerifyDNSLength_ mode. It is used only by
+    /// [`Uts46::to_ascii_with_options`].
+    #[inline]
+    pub const fn dns_length(mut self, value: DnsLength) -> Self {
+        self.dns_length = value;
+        self
+    }
+
+    /// Sets the error policy. It is used only by [`Uts46::process_with_options`]:
+    /// [`Uts46::to_ascii_with_options`] always fails fast and
+    /// [`Uts46::to_unicode_with_options`] always marks errors.
+    #[inline]
+    pub const fn error_policy(mut self, value: ErrorPolicy) -> Self {
+        self.error_policy = value;
+        self
+    }
+
+    /// Sets which labels are output as Unicode. It is used only by
+    /// [`Uts46::to_unicode_with_options`] and [`Uts46::process_with_options`].
+    #[inline]
+    pub const fn unicode_output(mut self, value: UnicodeOutput) -> Self {
+        self.unicode_output = value;
+        self
+    }
+}
+
 /// The success outcome of [`Uts46::process`]
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub enum ProcessingSuccess {
     /// There were no errors. The caller must consider the input to be the output.
     ///
     /// This asserts that the input can be safely passed to [`core::str::from_utf8_unchecked`].
+    /// (For `Uts46::process_utf16`, this asserts that the input is ASCII.)
     ///
     /// (Distinct from `WroteToSink` in order to allow `Cow` behavior to be implemented on top of
     /// [`Uts46::process`].)
@@ -439,20 +718,41 @@ impl From<core::fmt::Error> for ProcessingError {
 }
 
 impl From<crate::punycode::PunycodeEncodeError> for ProcessingError {
-    fn from(_: crate::punycode::PunycodeEncodeError) -> Self {
-        unreachable!(
-            "Punycode overflows should not be possible due to PUNYCODE_ENCODE_MAX_INPUT_LENGTH"
-        );
+    fn from(e: crate::punycode::PunycodeEncodeError) -> Self {
+        match e {
+            crate::punycode::PunycodeEncodeError::Sink => ProcessingError::SinkError,
+            crate::punycode::PunycodeEncodeError::Overflow => unreachable!(
+                "Punycode overflows should not be possible due to PUNYCODE_ENCODE_MAX_INPUT_LENGTH"
+            ),
+        }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum AlreadyAsciiLabel<'a> {
-    MixedCaseAscii(&'a [u8]),
-    MixedCasePunycode(&'a [u8]),
+enum AlreadyAsciiLabel<'a, T> {
+    MixedCaseAscii(&'a [T]),
+    MixedCasePunycode(&'a [T]),
     Other,
 }
 
+/// Adapts a [`Write16`] to the [`Write`] that [`Uts46::process`] writes to.
+#[cfg(feature = "utf16")]
+struct Utf16Sink<'a, W: Write16 + ?Sized>(&'a mut W);
+
+#[cfg(feature = "utf16")]
+impl<W: Write16 + ?Sized> Write for Utf16Sink<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            self.0.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.0.write_char(c)
+    }
+}
+
 /// Performs the _VerifyDNSLength_ check on the output of the _ToASCII_ operation.
 ///
 /// If the second argument is `false`, the trailing root label dot is allowed.
@@ -463,18 +763,31 @@ enum AlreadyAsciiLabel<'a> {
 pub fn verify_dns_length(domain_name: &str, allow_trailing_dot: bool) -> bool {
     let bytes = domain_name.as_bytes();
     debug_assert!(bytes.is_ascii());
-    let domain_name_without_trailing_dot = if let Some(without) = bytes.strip_suffix(b".") {
-        if !allow_trailing_dot {
-            return false;
+    verify_dns_length_impl(bytes, b'.', allow_trailing_dot)
+}
+
+/// [`verify_dns_length`] for ASCII in any code unit width.
+fn verify_dns_length_impl<T: Copy + PartialEq>(
+    domain_name: &[T],
+    dot: T,
+    allow_trailing_dot: bool,
+) -> bool {
+    let domain_name_without_trailing_dot = if let Some((last, without)) = domain_name.split_last() {
+        if *last == dot {
+            if !allow_trailing_dot {
+                return false;
+            }
+            without
+        } else {
+            domain_name
         }
-        without
     } else {
-        bytes
+        domain_name
     };
     if domain_name_without_trailing_dot.len() > 253 {
         return false;
     }
-    for label in domain_name_without_trailing_dot.split(|b| *b == b'.') {
+    for label in domain_name_without_trailing_dot.split(|u| *u == dot) {
         if label.is_empty() {
             return false;
         }
@@ -487,7 +800,40 @@ pub fn verify_dns_length(domain_name: &str, allow_trailing_dot: bool) -> bool {
 
 /// An implementation of UTS #46.
 pub struct Uts46 {
-    data: idna_adapter::Adapter,
+    data: Data,
+}
+
+/// Where the Unicode data of a [`Uts46`] comes from
+// The compiled data can't be boxed, since `Uts46::new` is a `const fn`
+#[allow(clippy::large_enum_variant)]
+enum Data {
+    /// Compiled into the binary, through `idna_adapter`
+    #[cfg(feature = "compiled_data")]
+    Compiled(idna_adapter::Adapter),
+    /// Loaded at run time from an ICU4X data provider
+    #[cfg(feature = "provider")]
+    Loaded(alloc::boxed::Box<crate::adapter::Adapter>),
+}
+
+/// Evaluates `$body` with `$processor` bound to a [`Processor`] for the data of `$uts46`.
+///
+/// This checks once per call where the data comes from, and the processing is
+/// monomorphized for each [`Backend`].
+macro_rules! with_processor {
+    ($uts46:expr, |$processor:ident| $body:expr) => {
+        match &$uts46.data {
+            #[cfg(feature = "compiled_data")]
+            Data::Compiled(data) => {
+                let $processor = Processor { data };
+                $body
+            }
+            #[cfg(feature = "provider")]
+            Data::Loaded(data) => {
+                let $processor = Processor { data: &**data };
+                $body
+            }
+        }
+    };
 }
 
 #[cfg(feature = "compiled_data")]
@@ -502,12 +848,44 @@ impl Uts46 {
     #[cfg(feature = "compiled_data")]
     pub const fn new() -> Self {
         Self {
-            data: idna_adapter::Adapter::new(),
+            data: Data::Compiled(idna_adapter::Adapter::new()),
         }
     }
 
-    // XXX Should there be an `icu_provider` feature for enabling
-    // a constructor for run-time data loading?
+    /// Constructor using mapping, normalization and bidi data loaded at run time
+    /// from an ICU4X [`BufferProvider`](icu_provider::BufferProvider),
+    /// such as a `BlobDataProvider` from the `icu_provider_blob` crate.
+    ///
+    /// The buffers are deserialized with the formats enabled on the `icu_provider` crate;
+    /// enable its `deserialize_postcard_1` feature for blob data.
+    /// Data can be exported for these keys with `icu_datagen`:
+    /// `normalizer/comp@1`, `normalizer/nfd@1`, `normalizer/nfdex@1`, `normalizer/nfkdex@1`,
+    /// `normalizer/uts46d@1`, `props/gc@1`, `props/bc@1` and `props/jt@1`.
+    ///
+    /// The data should be for the same version of Unicode as the data compiled into
+    /// `url` and other users of [`Uts46::new`], if any, for consistent results.
+    #[cfg(feature = "provider")]
+    pub fn try_new_with_provider<P: icu_provider::BufferProvider + ?Sized>(
+        provider: &P,
+    ) -> Result<Self, icu_provider::DataError> {
+        use icu_provider::serde::AsDeserializingBufferProvider;
+        Self::try_new_unstable(&provider.as_deserializing())
+    }
+
+    /// Constructor using data loaded at run time from a typed ICU4X data provider.
+    ///
+    /// This is "unstable" in the ICU4X sense: the set of data keys that the provider
+    /// has to support may change in minor releases of this crate.
+    #[cfg(feature = "provider")]
+    pub fn try_new_unstable<P: Uts46DataProvider + ?Sized>(
+        provider: &P,
+    ) -> Result<Self, icu_provider::DataError> {
+        Ok(Self {
+            data: Data::Loaded(alloc::boxed::Box::new(
+                crate::adapter::Adapter::try_new_unstable(provider)?,
+            )),
+        })
+    }
 
     /// Performs the [ToASCII](https://www.unicode.org/reports/tr46/#ToASCII) operation
     /// from UTS #46 with the options indicated.
@@ -776,13 +1154,95 @@ impl Uts46 {
         ascii_deny_list: AsciiDenyList,
         hyphens: Hyphens,
         error_policy: ErrorPolicy,
+        output_as_unicode: OutputUnicode,
+        sink: &mut W,
+        ascii_sink: Option<&mut W>,
+    ) -> Result<ProcessingSuccess, ProcessingError> {
+        self.process_with_buffers(
+            domain_name,
+            ascii_deny_list,
+            hyphens,
+            error_policy,
+            output_as_unicode,
+            sink,
+            ascii_sink,
+            &mut SmallVec::<[char; 253]>::new(),
+            &mut SmallVec::<[AlreadyAsciiLabel<u8>; 8]>::new(),
+        )
+    }
+
+    /// [`Uts46::to_ascii`] with the options bundled in `options`.
+    pub fn to_ascii_with_options<'a>(
+        &self,
+        domain_name: &'a [u8],
+        options: Uts46Options,
+    ) -> Result<Cow<'a, str>, crate::Errors> {
+        self.to_ascii(
+            domain_name,
+            options.ascii_deny_list,
+            options.hyphens,
+            options.dns_length,
+        )
+    }
+
+    /// [`Uts46::to_user_interface`] with the options bundled in `options`, which
+    /// decide which labels are output as Unicode.
+    pub fn to_unicode_with_options<'a>(
+        &self,
+        domain_name: &'a [u8],
+        options: Uts46Options,
+    ) -> (Cow<'a, str>, Result<(), crate::Errors>) {
+        self.to_user_interface(
+            domain_name,
+            options.ascii_deny_list,
+            options.hyphens,
+            options.unicode_output.output_as_unicode(),
+        )
+    }
+
+    /// [`Uts46::process`] with the options bundled in `options`.
+    ///
+    /// As with [`Uts46::process`], the DNS length is not verified.
+    pub fn process_with_options<W: Write + ?Sized>(
+        &self,
+        domain_name: &[u8],
+        options: Uts46Options,
+        sink: &mut W,
+        ascii_sink: Option<&mut W>,
+    ) -> Result<ProcessingSuccess, ProcessingError> {
+        self.process(
+            domain_name,
+            options.ascii_deny_list,
+            options.hyphens,
+            options.error_policy,
+            options.unicode_output.output_as_unicode(),
+            sink,
+            ascii_sink,
+        )
+    }
+
+    /// [`Uts46::process`] for UTF-8 or UTF-16 input, with the given storage for the
+    /// mapped domain name.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    fn process_with_buffers<
+        'a,
+        T: InputUnit,
+        W: Write + ?Sized,
+        OutputUnicode: FnMut(&[char], &[char], bool) -> bool,
+    >(
+        &self,
+        domain_name: &'a [T],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+        error_policy: ErrorPolicy,
         mut output_as_unicode: OutputUnicode,
         sink: &mut W,
         ascii_sink: Option<&mut W>,
+        domain_buffer: &mut SmallVec<[char; 253]>,
+        already_punycode: &mut SmallVec<[AlreadyAsciiLabel<'a, T>; 8]>,
     ) -> Result<ProcessingSuccess, ProcessingError> {
         let fail_fast = error_policy == ErrorPolicy::FailFast;
-        let mut domain_buffer = SmallVec::<[char; 253]>::new();
-        let mut already_punycode = SmallVec::<[AlreadyAsciiLabel; 8]>::new();
         // `process_inner` could be pasted inline here, but it's out of line in order
         // to avoid duplicating that code when monomorphizing over `W` and `OutputUnicode`.
         let (passthrough_up_to, is_bidi, had_errors) = self.process_inner(
@@ -790,8 +1250,8 @@ impl Uts46 {
             ascii_deny_list,
             hyphens,
             fail_fast,
-            &mut domain_buffer,
-            &mut already_punycode,
+            domain_buffer,
+            already_punycode,
         );
         if passthrough_up_to == domain_name.len() {
             debug_assert!(!had_errors);
@@ -822,7 +1282,7 @@ impl Uts46 {
                 if flushed_prefix {
                     sink.write_char('.')?;
                 } else {
-                    debug_assert_eq!(domain_name[passthrough_up_to_extended], b'.');
+                    debug_assert!(domain_name[passthrough_up_to_extended] == T::from(b'.'));
                     passthrough_up_to_extended += 1;
                     if passthrough_up_to_extended == domain_name.len() {
                         debug_assert!(!had_errors);
@@ -846,13 +1306,13 @@ impl Uts46 {
                         &domain_name[..passthrough_up_to_extended]
                     };
                     // SAFETY: `mixed_case` and `domain_name` up to `passthrough_up_to_extended` are known to be ASCII.
-                    sink.write_str(unsafe { core::str::from_utf8_unchecked(slice_to_write) })?;
+                    unsafe { T::write_ascii(slice_to_write, sink) }?;
                     for c in tail.iter() {
-                        sink.write_char(char::from(c.to_ascii_lowercase()))?;
+                        sink.write_char(c.char_ascii_lower_case())?;
                     }
                 } else if flushed_prefix {
                     // SAFETY: `mixed_case` is known to be ASCII.
-                    sink.write_str(unsafe { core::str::from_utf8_unchecked(mixed_case) })?;
+                    unsafe { T::write_ascii(mixed_case, sink) }?;
                 } else {
                     passthrough_up_to_extended += mixed_case.len();
                     if passthrough_up_to_extended == domain_name.len() {
@@ -880,9 +1340,7 @@ impl Uts46 {
                 if !flushed_prefix {
                     flushed_prefix = true;
                     // SAFETY: `domain_name` up to `passthrough_up_to_extended` is known to be ASCII.
-                    sink.write_str(unsafe {
-                        core::str::from_utf8_unchecked(&domain_name[..passthrough_up_to_extended])
-                    })?;
+                    unsafe { T::write_ascii(&domain_name[..passthrough_up_to_extended], sink) }?;
                 }
                 for c in label.iter().copied() {
                     sink.write_char(c)?;
@@ -901,13 +1359,13 @@ impl Uts46 {
                         &domain_name[..passthrough_up_to_extended]
                     };
                     // SAFETY: `mixed_case` and `domain_name` up to `passthrough_up_to_extended` are known to be ASCII.
-                    sink.write_str(unsafe { core::str::from_utf8_unchecked(slice_to_write) })?;
+                    unsafe { T::write_ascii(slice_to_write, sink) }?;
                     for c in tail.iter() {
-                        sink.write_char(char::from(c.to_ascii_lowercase()))?;
+                        sink.write_char(c.char_ascii_lower_case())?;
                     }
                 } else if flushed_prefix {
                     // SAFETY: `mixed_case` is known to be ASCII.
-                    sink.write_str(unsafe { core::str::from_utf8_unchecked(mixed_case) })?;
+                    unsafe { T::write_ascii(mixed_case, sink) }?;
                 } else {
                     passthrough_up_to_extended += mixed_case.len();
                     if passthrough_up_to_extended == domain_name.len() {
@@ -919,9 +1377,7 @@ impl Uts46 {
                 if !flushed_prefix {
                     flushed_prefix = true;
                     // SAFETY: `domain_name` up to `passthrough_up_to_extended` is known to be ASCII.
-                    sink.write_str(unsafe {
-                        core::str::from_utf8_unchecked(&domain_name[..passthrough_up_to_extended])
-                    })?;
+                    unsafe { T::write_ascii(&domain_name[..passthrough_up_to_extended], sink) }?;
                 }
                 write_punycode_label(label, sink)?;
             }
@@ -945,7 +1401,7 @@ impl Uts46 {
                         if flushed_prefix {
                             sink.write_char('.')?;
                         } else {
-                            debug_assert_eq!(domain_name[passthrough_up_to_extended], b'.');
+                            debug_assert!(domain_name[passthrough_up_to_extended] == T::from(b'.'));
                             passthrough_up_to_extended += 1;
                         }
                     }
@@ -965,15 +1421,13 @@ impl Uts46 {
                                 &domain_name[..passthrough_up_to_extended]
                             };
                             // SAFETY: `mixed_case` and `domain_name` up to `passthrough_up_to_extended` are known to be ASCII.
-                            sink.write_str(unsafe {
-                                core::str::from_utf8_unchecked(slice_to_write)
-                            })?;
+                            unsafe { T::write_ascii(slice_to_write, sink) }?;
                             for c in tail.iter() {
-                                sink.write_char(char::from(c.to_ascii_lowercase()))?;
+                                sink.write_char(c.char_ascii_lower_case())?;
                             }
                         } else if flushed_prefix {
                             // SAFETY: `mixed_case` is known to be ASCII.
-                            sink.write_str(unsafe { core::str::from_utf8_unchecked(mixed_case) })?;
+                            unsafe { T::write_ascii(mixed_case, sink) }?;
                         } else {
                             passthrough_up_to_extended += mixed_case.len();
                         }
@@ -984,11 +1438,9 @@ impl Uts46 {
                         if !flushed_prefix {
                             flushed_prefix = true;
                             // SAFETY: `domain_name` up to `passthrough_up_to_extended` is known to be ASCII.
-                            sink.write_str(unsafe {
-                                core::str::from_utf8_unchecked(
-                                    &domain_name[..passthrough_up_to_extended],
-                                )
-                            })?;
+                            unsafe {
+                                T::write_ascii(&domain_name[..passthrough_up_to_extended], sink)
+                            }?;
                         }
                         for c in label.iter().copied() {
                             sink.write_char(c)?;
@@ -1008,15 +1460,13 @@ impl Uts46 {
                                 &domain_name[..passthrough_up_to_extended]
                             };
                             // SAFETY: `mixed_case` and `domain_name` up to `passthrough_up_to_extended` are known to be ASCII.
-                            sink.write_str(unsafe {
-                                core::str::from_utf8_unchecked(slice_to_write)
-                            })?;
+                            unsafe { T::write_ascii(slice_to_write, sink) }?;
                             for c in tail.iter() {
-                                sink.write_char(char::from(c.to_ascii_lowercase()))?;
+                                sink.write_char(c.char_ascii_lower_case())?;
                             }
                         } else if flushed_prefix {
                             // SAFETY: `mixed_case` is known to be ASCII.
-                            sink.write_str(unsafe { core::str::from_utf8_unchecked(mixed_case) })?;
+                            unsafe { T::write_ascii(mixed_case, sink) }?;
                         } else {
                             passthrough_up_to_extended += mixed_case.len();
                         }
@@ -1024,36 +1474,164 @@ impl Uts46 {
                         if !flushed_prefix {
                             flushed_prefix = true;
                             // SAFETY: `domain_name` up to `passthrough_up_to_extended` is known to be ASCII.
-                            sink.write_str(unsafe {
-                                core::str::from_utf8_unchecked(
-                                    &domain_name[..passthrough_up_to_extended],
-                                )
-                            })?;
+                            unsafe {
+                                T::write_ascii(&domain_name[..passthrough_up_to_extended], sink)
+                            }?;
                         }
                         write_punycode_label(label, sink)?;
                     }
                 }
                 if !flushed_prefix {
                     // SAFETY: `domain_name` up to `passthrough_up_to_extended` is known to be ASCII.
-                    sink.write_str(unsafe {
-                        core::str::from_utf8_unchecked(&domain_name[..passthrough_up_to_extended])
-                    })?;
+                    unsafe { T::write_ascii(&domain_name[..passthrough_up_to_extended], sink) }?;
                 }
             }
         }
         Ok(ProcessingSuccess::WroteToSink)
     }
 
+    /// [`Uts46::to_ascii`] for UTF-16 input and output.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The input domain name as UTF-16 code units. Unpaired surrogates
+    ///   are treated as U+FFFD REPLACEMENT CHARACTER, as with conversion to `USVString`
+    ///   in the WHATWG URL Standard, which makes the input be in error.
+    /// * `ascii_deny_list` - What ASCII deny list, if any, to apply. The UTS 46
+    ///   _UseSTD3ASCIIRules_ flag or the WHATWG URL Standard forbidden domain code point
+    ///   processing is handled via this argument. Most callers are probably the best off
+    ///   by using [`AsciiDenyList::URL`] here.
+    /// * `hyphens` - The UTS 46 _CheckHyphens_ flag. Most callers are probably the best
+    ///   off by using [`Hyphens::Allow`] here.
+    /// * `dns_length` - The UTS 46 _VerifyDNSLength_ flag.
+    #[cfg(feature = "utf16")]
+    pub fn to_ascii_utf16<'a>(
+        &self,
+        domain_name: &'a [u16],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+        dns_length: DnsLength,
+    ) -> Result<Cow<'a, [u16]>, crate::Errors> {
+        let mut v = Vec::new();
+        let cow = match self.process_utf16(
+            domain_name,
+            ascii_deny_list,
+            hyphens,
+            ErrorPolicy::FailFast,
+            |_, _, _| false,
+            &mut v,
+            None,
+        ) {
+            Ok(ProcessingSuccess::Passthrough) => Cow::Borrowed(domain_name),
+            Ok(ProcessingSuccess::WroteToSink) => Cow::Owned(v),
+            Err(ProcessingError::ValidityError) => return Err(crate::Errors::default()),
+            Err(ProcessingError::SinkError) => unreachable!(),
+        };
+        if dns_length != DnsLength::Ignore
+            && !verify_dns_length_impl(
+                &cow,
+                u16::from(b'.'),
+                dns_length == DnsLength::VerifyAllowRootDot,
+            )
+        {
+            Err(crate::Errors::default())
+        } else {
+            Ok(cow)
+        }
+    }
+
+    /// [`Uts46::to_unicode`] for UTF-16 input and output.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The input domain name as UTF-16 code units. Unpaired surrogates
+    ///   are treated as U+FFFD REPLACEMENT CHARACTER, as with conversion to `USVString`
+    ///   in the WHATWG URL Standard, which makes the input be in error.
+    /// * `ascii_deny_list` - What ASCII deny list, if any, to apply. The UTS 46
+    ///   _UseSTD3ASCIIRules_ flag or the WHATWG URL Standard forbidden domain code point
+    ///   processing is handled via this argument. Most callers are probably the best off
+    ///   by using [`AsciiDenyList::URL`] here.
+    /// * `hyphens` - The UTS 46 _CheckHyphens_ flag. Most callers are probably the best
+    ///   off by using [`Hyphens::Allow`] here.
+    #[cfg(feature = "utf16")]
+    pub fn to_unicode_utf16<'a>(
+        &self,
+        domain_name: &'a [u16],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+    ) -> (Cow<'a, [u16]>, Result<(), crate::Errors>) {
+        let mut v = Vec::new();
+        match self.process_utf16(
+            domain_name,
+            ascii_deny_list,
+            hyphens,
+            ErrorPolicy::MarkErrors,
+            |_, _, _| true,
+            &mut v,
+            None,
+        ) {
+            Ok(ProcessingSuccess::Passthrough) => (Cow::Borrowed(domain_name), Ok(())),
+            Ok(ProcessingSuccess::WroteToSink) => (Cow::Owned(v), Ok(())),
+            Err(ProcessingError::ValidityError) => (Cow::Owned(v), Err(crate::Errors::default())),
+            Err(ProcessingError::SinkError) => unreachable!(),
+        }
+    }
+
+    /// [`Uts46::process`] for UTF-16 input and output (e.g. for string types of
+    /// JavaScript engines or Windows).
+    ///
+    /// The arguments and the return value are as for [`Uts46::process`] except:
+    ///
+    /// * `domain_name` is UTF-16 code units. Unpaired surrogates are treated as
+    ///   U+FFFD REPLACEMENT CHARACTER, as with conversion to `USVString` in the
+    ///   WHATWG URL Standard, which makes the input be in error.
+    /// * `sink` and `ascii_sink` receive UTF-16.
+    ///
+    /// The input is processed as UTF-16 without converting it to UTF-8 first, with
+    /// the same fast path for ASCII as [`Uts46::process`].
+    ///
+    /// # Safety-usable invariant
+    ///
+    /// If the return value is `Ok(ProcessingSuccess::Passthrough)`, `domain_name` is
+    /// ASCII. (Note: Other return values do _not_ imply that `domain_name` wasn't ASCII!)
+    #[cfg(feature = "utf16")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_utf16<
+        W: Write16 + ?Sized,
+        OutputUnicode: FnMut(&[char], &[char], bool) -> bool,
+    >(
+        &self,
+        domain_name: &[u16],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+        error_policy: ErrorPolicy,
+        output_as_unicode: OutputUnicode,
+        sink: &mut W,
+        ascii_sink: Option<&mut W>,
+    ) -> Result<ProcessingSuccess, ProcessingError> {
+        self.process_with_buffers(
+            domain_name,
+            ascii_deny_list,
+            hyphens,
+            error_policy,
+            output_as_unicode,
+            &mut Utf16Sink(sink),
+            ascii_sink.map(Utf16Sink).as_mut(),
+            &mut SmallVec::<[char; 253]>::new(),
+            &mut SmallVec::<[AlreadyAsciiLabel<u16>; 8]>::new(),
+        )
+    }
+
     /// The part of `process` that doesn't need to be generic over the sink.
     #[inline(always)]
-    fn process_inner<'a>(
+    fn process_inner<'a, T: InputUnit>(
         &self,
-        domain_name: &'a [u8],
+        domain_name: &'a [T],
         ascii_deny_list: AsciiDenyList,
         hyphens: Hyphens,
         fail_fast: bool,
         domain_buffer: &mut SmallVec<[char; 253]>,
-        already_punycode: &mut SmallVec<[AlreadyAsciiLabel<'a>; 8]>,
+        already_punycode: &mut SmallVec<[AlreadyAsciiLabel<'a, T>; 8]>,
     ) -> (usize, bool, bool) {
         // Sadly, this even faster-path ASCII tier is needed to avoid regressing
         // performance.
@@ -1064,11 +1642,11 @@ impl Uts46 {
                 if in_inclusive_range8(b, b'a', b'z') {
                     continue;
                 }
-                if b == b'.' {
+                if b == T::from(b'.') {
                     most_recent_label_start = iter.clone();
                     continue;
                 }
-                return self.process_innermost(
+                return with_processor!(self, |processor| processor.process_innermost(
                     domain_name,
                     ascii_deny_list,
                     hyphens,
@@ -1076,29 +1654,36 @@ impl Uts46 {
                     domain_buffer,
                     already_punycode,
                     most_recent_label_start.as_slice(),
-                );
+                ));
             } else {
                 // Success! The whole input passes through on the fastest path!
                 return (domain_name.len(), false, false);
             }
         }
     }
+}
+
+/// The processing that uses the Unicode data, monomorphized for each [`Backend`]
+struct Processor<'d, U> {
+    data: &'d U,
+}
 
+impl<U: Backend> Processor<'_, U> {
     /// The part of `process` that doesn't need to be generic over the sink and
     /// can avoid monomorphizing in the interest of code size.
     /// Separating this into a different stack frame compared to `process_inner`
     /// improves performance in the ICU4X case.
     #[allow(clippy::too_many_arguments)]
     #[inline(never)]
-    fn process_innermost<'a>(
+    fn process_innermost<'a, T: InputUnit>(
         &self,
-        domain_name: &'a [u8],
+        domain_name: &'a [T],
         ascii_deny_list: AsciiDenyList,
         hyphens: Hyphens,
         fail_fast: bool,
         domain_buffer: &mut SmallVec<[char; 253]>,
-        already_punycode: &mut SmallVec<[AlreadyAsciiLabel<'a>; 8]>,
-        tail: &'a [u8],
+        already_punycode: &mut SmallVec<[AlreadyAsciiLabel<'a, T>; 8]>,
+        tail: &'a [T],
     ) -> (usize, bool, bool) {
         let deny_list = ascii_deny_list.bits;
         let deny_list_deny_dot = deny_list | DOT_MASK;
@@ -1111,7 +1696,7 @@ impl Uts46 {
         let mut current_label_start; // Index into `domain_buffer`
         let mut seen_label = false;
         let mut in_prefix = true;
-        for label in tail.split(|b| *b == b'.') {
+        for label in tail.split(|b| *b == T::from(b'.')) {
             // We check for passthrough only for the prefix. That is, if we
             // haven't moved on and started filling `domain_buffer`. Keeping
             // this stuff in one loop where the first items keep being skipped
@@ -1126,7 +1711,7 @@ impl Uts46 {
             // of not coming from e.g. normalizing an ideographic dot).
             if in_prefix && is_passthrough_ascii_label(label) {
                 if seen_label {
-                    debug_assert_eq!(domain_name[passthrough_up_to], b'.');
+                    debug_assert!(domain_name[passthrough_up_to] == T::from(b'.'));
                     passthrough_up_to += 1;
                 }
                 seen_label = true;
@@ -1136,7 +1721,7 @@ impl Uts46 {
             }
             if seen_label {
                 if in_prefix {
-                    debug_assert_eq!(domain_name[passthrough_up_to], b'.');
+                    debug_assert!(domain_name[passthrough_up_to] == T::from(b'.'));
                     passthrough_up_to += 1;
                 } else {
                     domain_buffer.push('.');
@@ -1149,11 +1734,11 @@ impl Uts46 {
                 let (ascii, non_ascii) = split_ascii_fast_path_prefix(label);
                 let non_punycode_ascii_label = if non_ascii.is_empty() {
                     if has_punycode_prefix(ascii) {
-                        if (ascii.last() != Some(&b'-'))
+                        if (ascii.last() != Some(&T::from(b'-')))
                             && (ascii.len() - 4 <= PUNYCODE_DECODE_MAX_INPUT_LENGTH)
                         {
                             if let Ok(decode) =
-                                Decoder::default().decode::<u8, InternalCaller>(&ascii[4..])
+                                Decoder::default().decode_impl::<T, InternalCaller>(&ascii[4..])
                             {
                                 // 63 ASCII characters is the max length for a valid DNS label and xn-- takes 4
                                 // characters.
@@ -1191,13 +1776,15 @@ impl Uts46 {
                                 let mut iter = ascii.iter();
                                 // Discard the first character that we replaced.
                                 let _ = iter.next();
-                                domain_buffer.extend(iter.map(|c| {
+                                for c in iter {
                                     // Can't have dot here, so `deny_list` vs `deny_list_deny_dot` does
                                     // not matter.
-                                    apply_ascii_deny_list_to_potentially_upper_case_ascii(
-                                        *c, deny_list,
-                                    )
-                                }));
+                                    domain_buffer.push(
+                                        apply_ascii_deny_list_to_potentially_upper_case_ascii(
+                                            *c, deny_list,
+                                        ),
+                                    );
+                                }
                             };
                             // If there were errors, we won't be trying to use this
                             // anyway later, so it's fine to put it here unconditionally.
@@ -1249,12 +1836,8 @@ impl Uts46 {
                 already_punycode.push(AlreadyAsciiLabel::Other);
                 let mut first_needs_combining_mark_check = ascii.is_empty();
                 let mut needs_contextj_check = !non_ascii.is_empty();
-                let mut mapping = self
-                    .data
-                    .map_normalize(non_ascii.chars())
-                    .map(|c| apply_ascii_deny_list_to_lower_cased_unicode(c, deny_list));
-                loop {
-                    let n = mapping.next();
+                // Called with each mapped character, and with `None` at the end
+                let mut next = |n: Option<char>| -> Result<(), ()> {
                     match n {
                         None | Some('.') => {
                             if domain_buffer[current_label_start..]
@@ -1264,7 +1847,7 @@ impl Uts46 {
                                 for c in domain_buffer[current_label_start + 4..].iter_mut() {
                                     if !c.is_ascii() {
                                         if fail_fast {
-                                            return (0, false, true);
+                                            return Err(());
                                         }
                                         had_errors = true;
                                         *c = '\u{FFFD}';
@@ -1280,7 +1863,7 @@ impl Uts46 {
                                         // which would result in Punycode decode outputting
                                         // ASCII only.
                                         if fail_fast {
-                                            return (0, false, true);
+                                            return Err(());
                                         }
                                         had_errors = true;
                                         *last = '\u{FFFD}';
@@ -1297,7 +1880,7 @@ impl Uts46 {
                                     > PUNYCODE_DECODE_MAX_INPUT_LENGTH
                                 {
                                     if fail_fast {
-                                        return (0, false, true);
+                                        return Err(());
                                     }
                                     had_errors = true;
                                     domain_buffer[current_label_start
@@ -1308,7 +1891,7 @@ impl Uts46 {
 
                                 if !punycode_precondition_failed {
                                     if let Ok(decode) = Decoder::default()
-                                        .decode::<char, InternalCaller>(
+                                        .decode_impl::<char, InternalCaller>(
                                             &domain_buffer[current_label_start + 4..],
                                         )
                                     {
@@ -1328,12 +1911,12 @@ impl Uts46 {
                                             fail_fast,
                                             &mut had_errors,
                                         ) {
-                                            return (0, false, true);
+                                            return Err(());
                                         }
                                     } else {
                                         // Punycode failed
                                         if fail_fast {
-                                            return (0, false, true);
+                                            return Err(());
                                         }
                                         had_errors = true;
                                         domain_buffer[current_label_start] = '\u{FFFD}';
@@ -1353,11 +1936,11 @@ impl Uts46 {
                                 first_needs_combining_mark_check,
                                 needs_contextj_check,
                             ) {
-                                return (0, false, true);
+                                return Err(());
                             }
 
                             if n.is_none() {
-                                break;
+                                return Ok(());
                             }
                             domain_buffer.push('.');
                             current_label_start = domain_buffer.len();
@@ -1368,13 +1951,22 @@ impl Uts46 {
                         Some(c) => {
                             if c == '\u{FFFD}' {
                                 if fail_fast {
-                                    return (0, false, true);
+                                    return Err(());
                                 }
                                 had_errors = true;
                             }
                             domain_buffer.push(c);
                         }
                     }
+                    Ok(())
+                };
+                let mapped = T::try_map_normalize(self.data, non_ascii, |c| {
+                    next(Some(apply_ascii_deny_list_to_lower_cased_unicode(
+                        c, deny_list,
+                    )))
+                });
+                if mapped.and_then(|()| next(None)).is_err() {
+                    return (0, false, true);
                 }
             } else {
                 // Empty label
@@ -1387,7 +1979,7 @@ impl Uts46 {
             for label in domain_buffer.split_mut(|c| *c == '.') {
                 if let Some((first, tail)) = label.split_first_mut() {
                     let first_bc = self.data.bidi_class(*first);
-                    if !FIRST_BC_MASK.intersects(first_bc.to_mask()) {
+                    if !U::FIRST_BC_MASK.intersects(first_bc.to_mask()) {
                         // Neither RTL label nor LTR label
                         if fail_fast {
                             return (0, false, true);
@@ -1407,7 +1999,11 @@ impl Uts46 {
                                 middle = prior;
                                 continue;
                             }
-                            let last_mask = if is_ltr { LAST_LTR_MASK } else { LAST_RTL_MASK };
+                            let last_mask = if is_ltr {
+                                U::LAST_LTR_MASK
+                            } else {
+                                U::LAST_RTL_MASK
+                            };
                             if !last_mask.intersects(last_bc.to_mask()) {
                                 if fail_fast {
                                     return (0, false, true);
@@ -1418,7 +2014,7 @@ impl Uts46 {
                             if is_ltr {
                                 for c in prior.iter_mut() {
                                     let bc = self.data.bidi_class(*c);
-                                    if !MIDDLE_LTR_MASK.intersects(bc.to_mask()) {
+                                    if !U::MIDDLE_LTR_MASK.intersects(bc.to_mask()) {
                                         if fail_fast {
                                             return (0, false, true);
                                         }
@@ -1430,7 +2026,7 @@ impl Uts46 {
                                 let mut numeral_state = RtlNumeralState::Undecided;
                                 for c in prior.iter_mut() {
                                     let bc = self.data.bidi_class(*c);
-                                    if !MIDDLE_RTL_MASK.intersects(bc.to_mask()) {
+                                    if !U::MIDDLE_RTL_MASK.intersects(bc.to_mask()) {
                                         if fail_fast {
                                             return (0, false, true);
                                         }
@@ -1503,18 +2099,21 @@ impl Uts46 {
         fail_fast: bool,
         had_errors: &mut bool,
     ) -> bool {
-        for c in self
+        let normalized = self
             .data
-            .normalize_validate(label_buffer.iter().copied())
-            .map(|c| apply_ascii_deny_list_to_lower_cased_unicode(c, deny_list_deny_dot))
-        {
-            if c == '\u{FFFD}' {
-                if fail_fast {
-                    return true;
+            .try_normalize_validate(label_buffer.iter().copied(), |c| {
+                let c = apply_ascii_deny_list_to_lower_cased_unicode(c, deny_list_deny_dot);
+                if c == '\u{FFFD}' {
+                    if fail_fast {
+                        return Err(());
+                    }
+                    *had_errors = true;
                 }
-                *had_errors = true;
-            }
-            domain_buffer.push(c);
+                domain_buffer.push(c);
+                Ok(())
+            });
+        if normalized.is_err() {
+            return true;
         }
         let normalized = &mut domain_buffer[current_label_start..];
         if let Err(()) =
@@ -1605,10 +2204,10 @@ impl Uts46 {
                     debug_assert_eq!(c, '\u{200C}');
                     if !self.has_appropriately_joining_char(
                         head.iter().rev().copied(),
-                        LEFT_OR_DUAL_JOINING_MASK,
+                        U::LEFT_OR_DUAL_JOINING_MASK,
                     ) || !self.has_appropriately_joining_char(
                         tail.iter().copied(),
-                        RIGHT_OR_DUAL_JOINING_MASK,
+                        U::RIGHT_OR_DUAL_JOINING_MASK,
                     ) {
                         if fail_fast {
                             return true;
@@ -1639,7 +2238,7 @@ impl Uts46 {
     fn has_appropriately_joining_char<I: Iterator<Item = char>>(
         &self,
         iter: I,
-        required_mask: JoiningTypeMask,
+        required_mask: U::JoiningTypeMask,
     ) -> bool {
         for c in iter {
             let jt = self.data.joining_type(c);
@@ -1674,7 +2273,7 @@ impl Uts46 {
             if in_inclusive_range_char(c, '\u{11000}', '\u{1E7FF}') {
                 continue;
             }
-            if RTL_MASK.intersects(self.data.bidi_class(c).to_mask()) {
+            if U::RTL_MASK.intersects(self.data.bidi_class(c).to_mask()) {
                 return true;
             }
         }
@@ -1719,3 +2318,542 @@ fn check_hyphens(
     }
     false
 }
+
+/// A rule of UTS 46 processing that a label breaks.
+///
+/// The list may grow, so exhaustive matching is not possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LabelErrorKind {
+    /// The input is not well-formed UTF-8.
+    InvalidUtf8,
+    /// A code point that is disallowed by UTS 46 or by the ASCII deny list, or that is
+    /// not PVALID in IDNA2008 registration.
+    /// This is the code point from the input (or from the decoded Punycode of an A-label).
+    DisallowedCodePoint(char),
+    /// The label starts with a combining mark.
+    LeadingCombiningMark(char),
+    /// A ZERO WIDTH NON-JOINER or ZERO WIDTH JOINER that the
+    /// [ContextJ rules](https://www.rfc-editor.org/rfc/rfc5892#appendix-A.1) do not allow.
+    ContextJ(char),
+    /// A code point that the [ContextO rules](https://www.rfc-editor.org/rfc/rfc5892#appendix-A.3)
+    /// do not allow. Only reported by IDNA2008 registration.
+    ContextO(char),
+    /// A broken rule of [RFC 5893 section 2](https://www.rfc-editor.org/rfc/rfc5893#section-2),
+    /// numbered 1 to 6, in a bidi domain name.
+    Bidi(u8),
+    /// The label starts with a hyphen.
+    LeadingHyphen,
+    /// The label ends with a hyphen.
+    TrailingHyphen,
+    /// The label has hyphens in both the third and fourth positions.
+    HyphensInThirdAndFourthPositions,
+    /// The label starts with `xn--` but is not valid Punycode.
+    InvalidPunycode,
+    /// The label starts with `xn--`, but its decoded Punycode is not in the form
+    /// that UTS 46 mapping and NFC normalization would give. In IDNA2008 registration,
+    /// the label is not in NFC.
+    NotNormalized,
+    /// The label is too long to be processed with Punycode.
+    TooLongForPunycode,
+    /// The label is empty, which DNS does not allow.
+    EmptyLabel,
+    /// The ASCII form of the label is longer than 63 bytes.
+    LabelTooLong,
+    /// The ASCII form of the domain name is longer than 253 bytes, not counting a trailing dot.
+    /// This is reported on the label that goes over.
+    DomainTooLong,
+}
+
+impl core::fmt::Display for LabelErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            LabelErrorKind::InvalidUtf8 => f.write_str("invalid UTF-8"),
+            LabelErrorKind::DisallowedCodePoint(c) => {
+                write!(f, "disallowed code point U+{:04X}", u32::from(c))
+            }
+            LabelErrorKind::LeadingCombiningMark(c) => {
+                write!(f, "leading combining mark U+{:04X}", u32::from(c))
+            }
+            LabelErrorKind::ContextJ(c) => {
+                write!(
+                    f,
+                    "U+{:04X} not allowed by the ContextJ rules",
+                    u32::from(c)
+                )
+            }
+            LabelErrorKind::ContextO(c) => {
+                write!(
+                    f,
+                    "U+{:04X} not allowed by the ContextO rules",
+                    u32::from(c)
+                )
+            }
+            LabelErrorKind::Bidi(rule) => write!(f, "bidi rule {} not satisfied", rule),
+            LabelErrorKind::LeadingHyphen => f.write_str("leading hyphen"),
+            LabelErrorKind::TrailingHyphen => f.write_str("trailing hyphen"),
+            LabelErrorKind::HyphensInThirdAndFourthPositions => {
+                f.write_str("hyphens in the third and fourth positions")
+            }
+            LabelErrorKind::InvalidPunycode => f.write_str("invalid Punycode"),
+            LabelErrorKind::NotNormalized => f.write_str("A-label not in normalized form"),
+            LabelErrorKind::TooLongForPunycode => f.write_str("label too long for Punycode"),
+            LabelErrorKind::EmptyLabel => f.write_str("empty label"),
+            LabelErrorKind::LabelTooLong => f.write_str("label longer than 63 bytes"),
+            LabelErrorKind::DomainTooLong => f.write_str("domain name longer than 253 bytes"),
+        }
+    }
+}
+
+/// An error in one label of a domain name, as returned by [`Uts46::label_errors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelError {
+    index: usize,
+    label: String,
+    kind: LabelErrorKind,
+}
+
+impl LabelError {
+    #[cfg(feature = "idna2008")]
+    pub(crate) fn new(index: usize, label: String, kind: LabelErrorKind) -> Self {
+        LabelError { index, label, kind }
+    }
+
+    /// The index of the label in the domain name, starting at 0.
+    ///
+    /// Labels are counted after mapping, so for example U+3002 IDEOGRAPHIC FULL STOP
+    /// separates labels like `.` does.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The label after UTS 46 mapping (or as given, in IDNA2008 registration),
+    /// or its ASCII form for DNS length errors.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The rule that the label breaks.
+    pub fn kind(&self) -> LabelErrorKind {
+        self.kind
+    }
+}
+
+impl core::fmt::Display for LabelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "label {} ({:?}): {}", self.index, self.label, self.kind)
+    }
+}
+
+impl Uts46 {
+    /// Performs the [ToASCII](https://www.unicode.org/reports/tr46/#ToASCII) operation
+    /// like [`Uts46::to_ascii`], but in case of error the returned [`crate::Errors`] lists
+    /// what is wrong with each label. See [`crate::Errors::label_errors`].
+    ///
+    /// Finding the details takes a second, slower pass over the input,
+    /// which only happens in case of error.
+    pub fn to_ascii_detailed<'a>(
+        &self,
+        domain_name: &'a [u8],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+        dns_length: DnsLength,
+    ) -> Result<Cow<'a, str>, crate::Errors> {
+        self.to_ascii(domain_name, ascii_deny_list, hyphens, dns_length)
+            .map_err(|_| crate::Errors {
+                label_errors: self.label_errors(domain_name, ascii_deny_list, hyphens, dns_length),
+            })
+    }
+
+    /// Returns the reasons why the [ToASCII](https://www.unicode.org/reports/tr46/#ToASCII)
+    /// operation with the options indicated fails, for each label. The result is empty
+    /// if and only if [`Uts46::to_ascii`] succeeds.
+    ///
+    /// This is meant for explaining errors to users, and is much slower than
+    /// [`Uts46::to_ascii`]. The arguments are the same as for that method.
+    ///
+    /// DNS length errors are only reported when there are no other errors,
+    /// since they are about the ASCII form of the domain name.
+    ///
+    /// [`LabelErrorKind::ContextO`] is never among the results: UTS 46 processing does
+    /// not check the ContextO rules, which only apply to IDNA2008 registration.
+    pub fn label_errors(
+        &self,
+        domain_name: &[u8],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+        dns_length: DnsLength,
+    ) -> alloc::vec::Vec<LabelError> {
+        // Whether there are errors at all is decided by the same processing as
+        // `to_ascii`; the second pass below only finds out what they are.
+        if self
+            .to_ascii(domain_name, ascii_deny_list, hyphens, dns_length)
+            .is_ok()
+        {
+            return alloc::vec::Vec::new();
+        }
+        let mut errors = with_processor!(self, |processor| processor.label_errors(
+            domain_name,
+            ascii_deny_list,
+            hyphens
+        ));
+        if errors.is_empty() && dns_length != DnsLength::Ignore {
+            if let Ok(ascii) =
+                self.to_ascii(domain_name, ascii_deny_list, hyphens, DnsLength::Ignore)
+            {
+                dns_length_errors(&ascii, dns_length, &mut errors);
+            }
+        }
+        debug_assert!(
+            !errors.is_empty(),
+            "no label errors found for {:?}",
+            domain_name
+        );
+        errors
+    }
+
+    #[cfg(feature = "idna2008")]
+    /// The checks of `check_label`, without marking errors
+    pub(crate) fn label_validity_errors(
+        &self,
+        label: &[char],
+        hyphens: Hyphens,
+    ) -> SmallVec<[LabelErrorKind; 2]> {
+        with_processor!(self, |processor| processor
+            .label_validity_errors(label, hyphens))
+    }
+
+    #[cfg(feature = "idna2008")]
+    pub(crate) fn is_bidi(&self, buffer: &[char]) -> bool {
+        with_processor!(self, |processor| processor.is_bidi(buffer))
+    }
+
+    #[cfg(feature = "idna2008")]
+    /// The numbers of the rules of RFC 5893 section 2 that a label breaks
+    pub(crate) fn broken_bidi_rules(&self, label: &[char]) -> SmallVec<[u8; 2]> {
+        with_processor!(self, |processor| processor.broken_bidi_rules(label))
+    }
+}
+
+impl<U: Backend> Processor<'_, U> {
+    /// The errors of [`Uts46::label_errors`] other than DNS length errors
+    fn label_errors(
+        &self,
+        domain_name: &[u8],
+        ascii_deny_list: AsciiDenyList,
+        hyphens: Hyphens,
+    ) -> alloc::vec::Vec<LabelError> {
+        let deny_list = ascii_deny_list.bits;
+        let mut errors = alloc::vec::Vec::new();
+
+        // Map the input, and find disallowed code points one by one
+        let mut input = alloc::vec::Vec::new();
+        let mut label_index = 0;
+        let mut disallowed = alloc::vec::Vec::new();
+        let mut rest = domain_name;
+        while !rest.is_empty() {
+            let (valid, invalid_len) = match core::str::from_utf8(rest) {
+                Ok(valid) => (valid, 0),
+                Err(e) => (
+                    // SAFETY: `valid_up_to` is the end of the valid UTF-8 prefix.
+                    unsafe { core::str::from_utf8_unchecked(&rest[..e.valid_up_to()]) },
+                    e.error_len().unwrap_or(rest.len() - e.valid_up_to()),
+                ),
+            };
+            for c in valid.chars() {
+                input.push(c);
+                if c == '.' {
+                    label_index += 1;
+                    continue;
+                }
+                let mut is_disallowed = false;
+                let _ =
+                    self.data
+                        .try_map_normalize(core::iter::once(c), |mapped| -> Result<(), ()> {
+                            if mapped == '.' {
+                                label_index += 1;
+                            } else if mapped == '\u{FFFD}' {
+                                is_disallowed = true;
+                            }
+                            Ok(())
+                        });
+                if is_disallowed {
+                    disallowed.push((label_index, LabelErrorKind::DisallowedCodePoint(c)));
+                }
+            }
+            if invalid_len > 0 {
+                input.push('\u{FFFD}');
+                disallowed.push((label_index, LabelErrorKind::InvalidUtf8));
+            }
+            rest = &rest[valid.len() + invalid_len..];
+        }
+        // The ASCII deny list applies after normalization, which may compose
+        // for example `=` and U+0338 COMBINING LONG SOLIDUS OVERLAY into `≠`
+        let mut mapped = alloc::vec::Vec::new();
+        let _ = self
+            .data
+            .try_map_normalize(input.iter().copied(), |c| -> Result<(), ()> {
+                mapped.push(c);
+                Ok(())
+            });
+
+        // Labels as checked: decoded for A-labels, `None` if already in error
+        let mut labels = alloc::vec::Vec::new();
+        for (index, label) in mapped.split(|c| *c == '.').enumerate() {
+            let mut push = |kind| {
+                errors.push(LabelError {
+                    index,
+                    label: label.iter().collect(),
+                    kind,
+                })
+            };
+            let mut in_error = false;
+            for &(_, kind) in disallowed.iter().filter(|(i, _)| *i == index) {
+                push(kind);
+                in_error = true;
+            }
+            for &c in label {
+                if c != '\u{FFFD}'
+                    && apply_ascii_deny_list_to_lower_cased_unicode(c, deny_list) == '\u{FFFD}'
+                {
+                    push(LabelErrorKind::DisallowedCodePoint(c));
+                    in_error = true;
+                }
+            }
+            if in_error {
+                labels.push(None);
+                continue;
+            }
+            let checked = if let Some(punycode) = label.strip_prefix(&['x', 'n', '-', '-'][..]) {
+                if !is_ascii(punycode) || punycode.last().map_or(true, |c| *c == '-') {
+                    push(LabelErrorKind::InvalidPunycode);
+                    labels.push(None);
+                    continue;
+                }
+                if punycode.len() > PUNYCODE_DECODE_MAX_INPUT_LENGTH {
+                    push(LabelErrorKind::TooLongForPunycode);
+                    labels.push(None);
+                    continue;
+                }
+                let decoded: alloc::vec::Vec<char> =
+                    match Decoder::default().decode_impl::<char, InternalCaller>(punycode) {
+                        Ok(decode) => decode.collect(),
+                        Err(()) => {
+                            push(LabelErrorKind::InvalidPunycode);
+                            labels.push(None);
+                            continue;
+                        }
+                    };
+                let deny_list_deny_dot = deny_list | DOT_MASK;
+                for &c in &decoded {
+                    let disallowed = self
+                        .data
+                        .try_normalize_validate(core::iter::once(c), |c| {
+                            match apply_ascii_deny_list_to_lower_cased_unicode(
+                                c,
+                                deny_list_deny_dot,
+                            ) {
+                                '\u{FFFD}' => Err(()),
+                                _ => Ok(()),
+                            }
+                        })
+                        .is_err();
+                    if disallowed {
+                        push(LabelErrorKind::DisallowedCodePoint(c));
+                        in_error = true;
+                    }
+                }
+                let mut normalized = alloc::vec::Vec::with_capacity(decoded.len());
+                let _ = self.data.try_normalize_validate(
+                    decoded.iter().copied(),
+                    |c| -> Result<(), ()> {
+                        normalized.push(c);
+                        Ok(())
+                    },
+                );
+                if !in_error && normalized != decoded {
+                    push(LabelErrorKind::NotNormalized);
+                    in_error = true;
+                }
+                if in_error {
+                    labels.push(None);
+                    continue;
+                }
+                decoded
+            } else {
+                label.to_vec()
+            };
+            for kind in self.label_validity_errors(&checked, hyphens) {
+                push(kind);
+                in_error = true;
+            }
+            labels.push(if in_error { None } else { Some(checked) });
+        }
+
+        // Bidi rules, for labels without other errors
+        if labels.iter().flatten().any(|label| self.is_bidi(label)) {
+            for (index, label) in labels.iter().enumerate() {
+                if let Some(label) = label {
+                    for rule in self.broken_bidi_rules(label) {
+                        errors.push(LabelError {
+                            index,
+                            label: label.iter().collect(),
+                            kind: LabelErrorKind::Bidi(rule),
+                        });
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+
+    /// The checks of `check_label`, without marking errors
+    fn label_validity_errors(
+        &self,
+        label: &[char],
+        hyphens: Hyphens,
+    ) -> SmallVec<[LabelErrorKind; 2]> {
+        let mut errors = SmallVec::new();
+        if hyphens != Hyphens::Allow {
+            if label.first() == Some(&'-') {
+                errors.push(LabelErrorKind::LeadingHyphen);
+            }
+            if label.last() == Some(&'-') {
+                errors.push(LabelErrorKind::TrailingHyphen);
+            }
+            if hyphens == Hyphens::Check && label.len() >= 4 && label[2] == '-' && label[3] == '-' {
+                errors.push(LabelErrorKind::HyphensInThirdAndFourthPositions);
+            }
+        }
+        if let Some(&first) = label.first() {
+            if self.data.is_mark(first) {
+                errors.push(LabelErrorKind::LeadingCombiningMark(first));
+            }
+        }
+        for (i, &c) in label.iter().enumerate() {
+            if !in_inclusive_range_char(c, '\u{200C}', '\u{200D}') {
+                continue;
+            }
+            let allowed = match label[..i].last() {
+                None => false,
+                Some(&previous) if self.data.is_virama(previous) => true,
+                Some(_) if c == '\u{200D}' => false,
+                Some(_) => {
+                    self.has_appropriately_joining_char(
+                        label[..i].iter().rev().copied(),
+                        U::LEFT_OR_DUAL_JOINING_MASK,
+                    ) && self.has_appropriately_joining_char(
+                        label[i + 1..].iter().copied(),
+                        U::RIGHT_OR_DUAL_JOINING_MASK,
+                    )
+                }
+            };
+            if !allowed {
+                errors.push(LabelErrorKind::ContextJ(c));
+            }
+        }
+        if !is_ascii(label) && label.len() > PUNYCODE_ENCODE_MAX_INPUT_LENGTH {
+            errors.push(LabelErrorKind::TooLongForPunycode);
+        }
+        errors
+    }
+
+    /// The numbers of the rules of RFC 5893 section 2 that a label breaks
+    fn broken_bidi_rules(&self, label: &[char]) -> SmallVec<[u8; 2]> {
+        let mut rules = SmallVec::new();
+        let (first, tail) = match label.split_first() {
+            Some(split) => split,
+            None => return rules,
+        };
+        let first_bc = self.data.bidi_class(*first);
+        if !U::FIRST_BC_MASK.intersects(first_bc.to_mask()) {
+            rules.push(1);
+            return rules;
+        }
+        let is_ltr = first_bc.is_ltr();
+        let trimmed_len = tail.len()
+            - tail
+                .iter()
+                .rev()
+                .take_while(|c| self.data.bidi_class(**c).is_nonspacing_mark())
+                .count();
+        let (last, middle) = match tail[..trimmed_len].split_last() {
+            Some(split) => split,
+            None => return rules,
+        };
+        let (middle_mask, middle_rule) = if is_ltr {
+            (U::MIDDLE_LTR_MASK, 5)
+        } else {
+            (U::MIDDLE_RTL_MASK, 2)
+        };
+        if middle
+            .iter()
+            .any(|c| !middle_mask.intersects(self.data.bidi_class(*c).to_mask()))
+        {
+            rules.push(middle_rule);
+        }
+        let last_bc = self.data.bidi_class(*last);
+        let (last_mask, last_rule) = if is_ltr {
+            (U::LAST_LTR_MASK, 6)
+        } else {
+            (U::LAST_RTL_MASK, 3)
+        };
+        if !last_mask.intersects(last_bc.to_mask()) {
+            rules.push(last_rule);
+        }
+        if !is_ltr {
+            let classes = || middle.iter().map(|c| self.data.bidi_class(*c));
+            let european =
+                classes().any(|bc| bc.is_european_number()) || last_bc.is_european_number();
+            let arabic = classes().any(|bc| bc.is_arabic_number()) || last_bc.is_arabic_number();
+            if european && arabic {
+                rules.push(4);
+            }
+        }
+        rules
+    }
+}
+
+/// The checks of `verify_dns_length`, as label errors
+pub(crate) fn dns_length_errors(
+    domain_name: &str,
+    dns_length: DnsLength,
+    errors: &mut alloc::vec::Vec<LabelError>,
+) {
+    let (without_dot, trailing_dot) = match domain_name.strip_suffix('.') {
+        Some(without_dot) => (without_dot, true),
+        None => (domain_name, false),
+    };
+    let mut total_len = 0;
+    let mut label_count = 0;
+    for (index, label) in without_dot.split('.').enumerate() {
+        let mut push = |kind| {
+            errors.push(LabelError {
+                index,
+                label: label.into(),
+                kind,
+            })
+        };
+        if label.is_empty() {
+            push(LabelErrorKind::EmptyLabel);
+        }
+        if label.len() > 63 {
+            push(LabelErrorKind::LabelTooLong);
+        }
+        if total_len <= 253 {
+            total_len += label.len() + usize::from(index > 0);
+            if total_len > 253 {
+                push(LabelErrorKind::DomainTooLong);
+            }
+        }
+        label_count += 1;
+    }
+    if trailing_dot && dns_length != DnsLength::VerifyAllowRootDot {
+        errors.push(LabelError {
+            index: label_count,
+            label: String::new(),
+            kind: LabelErrorKind::EmptyLabel,
+        });
+    }
+}
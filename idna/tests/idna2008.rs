@@ -0,0 +1,149 @@
+use idna::idna2008::*;
+use idna::uts46::LabelErrorKind;
+
+fn kinds(domain_name: &str) -> Vec<(usize, LabelErrorKind)> {
+    registration_errors(domain_name)
+        .iter()
+        .map(|e| (e.index(), e.kind()))
+        .collect()
+}
+
+#[test]
+fn derived_properties() {
+    for c in [
+        'a', '0', '-', 'ß', 'ς', 'é', 'ж', 'α', '가', '\u{0301}', '\u{3007}',
+    ] {
+        assert_eq!(derived_property(c), DerivedProperty::Pvalid, "{:?}", c);
+    }
+    for c in ['\u{200C}', '\u{200D}'] {
+        assert_eq!(derived_property(c), DerivedProperty::ContextJ, "{:?}", c);
+    }
+    for c in [
+        '\u{00B7}', '\u{0375}', '\u{05F3}', '\u{30FB}', '\u{0661}', '\u{06F1}',
+    ] {
+        assert_eq!(derived_property(c), DerivedProperty::ContextO, "{:?}", c);
+    }
+    for c in [
+        'A', ' ', '!', '.', '\u{0640}', '\u{212B}', '\u{0958}', '\u{FB01}', '\u{FE00}', '\u{20D0}',
+        '\u{1100}', '\u{FDD0}', '\u{2460}', '\u{3002}', '😀',
+    ] {
+        assert_eq!(derived_property(c), DerivedProperty::Disallowed, "{:?}", c);
+    }
+    assert_eq!(derived_property('\u{0378}'), DerivedProperty::Unassigned);
+}
+
+#[test]
+fn registration() {
+    assert_eq!(
+        to_ascii_for_registration("bücher.example").unwrap(),
+        "xn--bcher-kva.example"
+    );
+    assert_eq!(
+        to_ascii_for_registration("xn--bcher-kva.example").unwrap(),
+        "xn--bcher-kva.example"
+    );
+    assert_eq!(
+        to_ascii_for_registration("faß.de").unwrap(),
+        "xn--fa-hia.de"
+    );
+    assert_eq!(
+        to_ascii_for_registration("\u{05D0}\u{05D1}.example").unwrap(),
+        "xn--4dbc.example"
+    );
+
+    // Nothing is mapped
+    assert_eq!(
+        kinds("Bücher.example"),
+        [(0, LabelErrorKind::DisallowedCodePoint('B'))]
+    );
+    assert_eq!(
+        kinds("example\u{3002}com"),
+        [(0, LabelErrorKind::DisallowedCodePoint('\u{3002}'))]
+    );
+    assert_eq!(
+        kinds("bu\u{0308}cher.example"),
+        [(0, LabelErrorKind::NotNormalized)]
+    );
+
+    // A-labels
+    assert_eq!(
+        kinds("xn--BCHER-KVA.example"),
+        [(0, LabelErrorKind::InvalidPunycode)]
+    );
+    assert_eq!(
+        kinds("xn--a-.example"),
+        [(0, LabelErrorKind::InvalidPunycode)]
+    );
+    assert_eq!(
+        kinds("xn--bcher-kva-.example"),
+        [(0, LabelErrorKind::InvalidPunycode)]
+    );
+    let too_long = format!("xn--{}-kva.example", "a".repeat(60));
+    assert_eq!(kinds(&too_long), [(0, LabelErrorKind::LabelTooLong)]);
+
+    // Hyphens, combining marks and ContextJ
+    assert_eq!(
+        kinds("ab--cd.example"),
+        [(0, LabelErrorKind::HyphensInThirdAndFourthPositions)]
+    );
+    assert_eq!(kinds("-ab.example"), [(0, LabelErrorKind::LeadingHyphen)]);
+    assert_eq!(
+        kinds("\u{0301}a.example"),
+        [(0, LabelErrorKind::LeadingCombiningMark('\u{0301}'))]
+    );
+    assert_eq!(
+        kinds("a\u{200D}b.example"),
+        [(0, LabelErrorKind::ContextJ('\u{200D}'))]
+    );
+
+    // Empty labels and DNS length
+    assert_eq!(kinds("a..b"), [(1, LabelErrorKind::EmptyLabel)]);
+    assert_eq!(kinds("example.com."), [(2, LabelErrorKind::EmptyLabel)]);
+    assert_eq!(
+        kinds(&format!("{}.example", "a".repeat(64))),
+        [(0, LabelErrorKind::LabelTooLong)]
+    );
+}
+
+#[test]
+fn context_o() {
+    assert!(registration_errors("l\u{00B7}l.example").is_empty());
+    assert_eq!(
+        kinds("a\u{00B7}b.example"),
+        [(0, LabelErrorKind::ContextO('\u{00B7}'))]
+    );
+    assert!(registration_errors("\u{0375}\u{03B1}.example").is_empty());
+    assert_eq!(
+        kinds("\u{0375}a.example"),
+        [(0, LabelErrorKind::ContextO('\u{0375}'))]
+    );
+    assert!(registration_errors("\u{05D0}\u{05F3}\u{05D1}.example").is_empty());
+    assert_eq!(
+        kinds("a\u{05F3}b.example"),
+        [(0, LabelErrorKind::ContextO('\u{05F3}'))]
+    );
+    assert!(registration_errors("\u{30A2}\u{30FB}\u{30A4}.example").is_empty());
+    assert_eq!(
+        kinds("a\u{30FB}b.example"),
+        [(0, LabelErrorKind::ContextO('\u{30FB}'))]
+    );
+    assert!(registration_errors("\u{0628}\u{0661}\u{0662}.example").is_empty());
+    assert_eq!(
+        kinds("\u{0628}\u{0661}\u{06F2}.example"),
+        [
+            (0, LabelErrorKind::ContextO('\u{0661}')),
+            (0, LabelErrorKind::ContextO('\u{06F2}'))
+        ]
+    );
+}
+
+#[test]
+fn bidi() {
+    assert_eq!(kinds("\u{05D0}a.example"), [(0, LabelErrorKind::Bidi(3))]);
+    assert_eq!(kinds("1a.\u{05D0}\u{05D1}"), [(0, LabelErrorKind::Bidi(1))]);
+    let errors = to_ascii_for_registration("\u{05D0}a.example").unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "label 0 (\"\u{05D0}a\"): bidi rule 3 not satisfied"
+    );
+}
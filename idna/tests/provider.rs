@@ -0,0 +1,131 @@
+// Copyright 2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use icu_normalizer::provider::{
+    CanonicalCompositionsV1Marker, CanonicalDecompositionDataV1Marker,
+    CanonicalDecompositionTablesV1Marker, CompatibilityDecompositionTablesV1Marker,
+    Uts46DecompositionSupplementV1Marker,
+};
+use icu_properties::provider::{BidiClassV1Marker, GeneralCategoryV1Marker, JoiningTypeV1Marker};
+use icu_provider::prelude::*;
+use idna::uts46::{AsciiDenyList, DnsLength, Hyphens, Uts46};
+
+/// Serves the data compiled into the ICU4X crates, as if it had been loaded at run time.
+struct CompiledProvider;
+
+macro_rules! compiled_data {
+    ($baked:path: $($marker:ident),*) => {
+        $(
+            impl DataProvider<$marker> for CompiledProvider {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    $baked.load(req)
+                }
+            }
+        )*
+    };
+}
+
+compiled_data!(
+    icu_normalizer::provider::Baked:
+    CanonicalCompositionsV1Marker,
+    CanonicalDecompositionDataV1Marker,
+    CanonicalDecompositionTablesV1Marker,
+    CompatibilityDecompositionTablesV1Marker,
+    Uts46DecompositionSupplementV1Marker
+);
+compiled_data!(
+    icu_properties::provider::Baked:
+    GeneralCategoryV1Marker,
+    BidiClassV1Marker,
+    JoiningTypeV1Marker
+);
+
+/// A buffer provider without any data.
+struct EmptyBufferProvider;
+
+impl BufferProvider for EmptyBufferProvider {
+    fn load_buffer(
+        &self,
+        key: DataKey,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        Err(DataErrorKind::MissingDataKey.with_req(key, req))
+    }
+}
+
+#[test]
+fn test_provider_matches_compiled_data() {
+    let loaded = Uts46::try_new_unstable(&CompiledProvider).unwrap();
+    let compiled = Uts46::new();
+    for domain in [
+        "example.com",
+        "Bücher.example",
+        "xn--bcher-kva.example",
+        "faß.de",
+        "\u{5D0}\u{5D1}.com",
+        "a\u{200D}b.com",
+        "\u{0644}\u{200C}\u{0627}.com",
+        "ab--c.com",
+        "a..b",
+        "\u{0301}a.com",
+    ] {
+        assert_eq!(
+            loaded.to_ascii(
+                domain.as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                DnsLength::Ignore
+            ),
+            compiled.to_ascii(
+                domain.as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                DnsLength::Ignore
+            ),
+            "{}",
+            domain
+        );
+        assert_eq!(
+            loaded.to_ascii(
+                domain.as_bytes(),
+                AsciiDenyList::STD3,
+                Hyphens::Check,
+                DnsLength::Verify
+            ),
+            compiled.to_ascii(
+                domain.as_bytes(),
+                AsciiDenyList::STD3,
+                Hyphens::Check,
+                DnsLength::Verify
+            ),
+            "{}",
+            domain
+        );
+        assert_eq!(
+            loaded.to_user_interface(
+                domain.as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                |_, _, _| true
+            ),
+            compiled.to_user_interface(
+                domain.as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                |_, _, _| true
+            ),
+            "{}",
+            domain
+        );
+    }
+}
+
+#[test]
+fn test_provider_missing_data() {
+    assert!(Uts46::try_new_with_provider(&EmptyBufferProvider).is_err());
+}
@@ -7,7 +7,9 @@
 // except according to those terms.
 
 use crate::test::TestFn;
-use idna::punycode::{decode, decode_to_string, encode_str};
+use idna::punycode::{
+    decode, decode_to_string, encode_into, encode_str, encode_with_case_flags_into, Decoder,
+};
 use serde_json::map::Map;
 use serde_json::Value;
 use std::panic::catch_unwind;
@@ -49,6 +51,89 @@ fn one_test(decoded: &str, encoded: &str) {
             encoded
         ),
     }
+
+    let mut decoder = Decoder::default();
+    let result: String = decoder.decode(encoded.as_bytes()).unwrap().collect();
+    assert_eq!(result, decoded, "streaming decoding of {:?}", encoded);
+    let encoded_chars: Vec<char> = encoded.chars().collect();
+    let result: String = decoder.decode(&encoded_chars).unwrap().collect();
+    assert_eq!(result, decoded, "streaming decoding of {:?}", encoded);
+
+    let mut result = String::new();
+    encode_into(decoded.chars(), &mut result).unwrap();
+    assert_eq!(result, encoded, "streaming encoding of {:?}", decoded);
+
+    // The test vectors only have case flags on basic code points
+    let mut result = String::new();
+    encode_with_case_flags_into(decoded.chars().map(|c| (c, c.is_uppercase())), &mut result)
+        .unwrap();
+    assert_eq!(result, encoded, "mixed-case encoding of {:?}", decoded);
+    let flags: Vec<(char, bool)> = decoder
+        .decode(encoded.as_bytes())
+        .unwrap()
+        .with_case_flags()
+        .collect();
+    let expected: Vec<(char, bool)> = decoded
+        .chars()
+        .map(|c| (c, c.is_ascii_uppercase()))
+        .collect();
+    assert_eq!(flags, expected, "mixed-case decoding of {:?}", encoded);
+}
+
+fn mixed_case_test() {
+    let flagged = [('ü', true), ('b', true), ('e', false), ('r', false)];
+    let mut encoded = String::new();
+    encode_with_case_flags_into(flagged.iter().copied(), &mut encoded).unwrap();
+    assert_eq!(encoded, "Ber-goA");
+
+    let mut decoder = Decoder::default();
+    let decode = decoder.decode(encoded.as_bytes()).unwrap();
+    assert_eq!(decode.len(), 4);
+    assert_eq!(decode.collect::<String>(), "üBer");
+    let decoded: Vec<(char, bool)> = decoder
+        .decode(encoded.as_bytes())
+        .unwrap()
+        .with_case_flags()
+        .collect();
+    assert_eq!(
+        decoded,
+        [('ü', true), ('B', true), ('e', false), ('r', false)]
+    );
+
+    // Round trip
+    let mut reencoded = String::new();
+    encode_with_case_flags_into(decoded.iter().copied(), &mut reencoded).unwrap();
+    assert_eq!(reencoded, encoded);
+
+    // Without the annotation
+    assert_eq!(encode_str("über").unwrap(), "ber-goa");
+    let mut plain = String::new();
+    encode_into(['ü', 'B', 'e', 'r'].iter().copied(), &mut plain).unwrap();
+    assert_eq!(plain, "Ber-goa");
+
+    // Upper-case digits decode like lower-case ones, including over `char`s
+    assert_eq!(decode_to_string("BCHER-KVA").unwrap(), "BüCHER");
+    let chars: Vec<char> = "bcher-KVA".chars().collect();
+    let decoded: Vec<(char, bool)> = decoder.decode(&chars).unwrap().with_case_flags().collect();
+    assert_eq!(decoded[1], ('ü', true));
+
+    // A numeral last digit can't carry the flag
+    let mut encoded = String::new();
+    encode_with_case_flags_into(
+        [('a', false), ('\u{10FFFF}', true)].iter().copied(),
+        &mut encoded,
+    )
+    .unwrap();
+    let decoded: Vec<(char, bool)> = decoder
+        .decode(encoded.as_bytes())
+        .unwrap()
+        .with_case_flags()
+        .collect();
+    assert_eq!(decoded[0], ('a', false));
+    assert_eq!(decoded[1].0, '\u{10FFFF}');
+
+    assert!(decoder.decode(b"a-\xFF").is_none());
+    assert!(decoder.decode(&['a', '-', 'é']).is_none());
 }
 
 fn one_bad_test(encode: &str) {
@@ -96,6 +181,11 @@ pub fn collect_tests<F: FnMut(String, TestFn)>(add_test: &mut F) {
         other => panic!("{:?}", other),
     }
 
+    add_test(
+        "Punycode mixed-case annotation".to_owned(),
+        TestFn::DynTestFn(Box::new(mixed_case_test)),
+    );
+
     match Value::from_str(include_str!("bad_punycode_tests.json")) {
         Ok(Value::Array(tests)) => {
             for (i, test) in tests.into_iter().enumerate() {
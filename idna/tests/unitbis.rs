@@ -1,6 +1,7 @@
 use idna::uts46::AsciiDenyList;
 use idna::uts46::DnsLength;
 use idna::uts46::Hyphens;
+use idna::uts46::ProcessingSuccess;
 
 /// https://github.com/servo/rust-url/issues/373
 #[test]
@@ -372,3 +373,429 @@ fn upper_case_ascii_in_punycode() {
     assert!(result.is_ok());
     assert_eq!(&unicode, "aö");
 }
+
+#[test]
+fn label_errors() {
+    use idna::uts46::LabelErrorKind;
+
+    let config = idna::uts46::Uts46::new();
+    let kinds = |input: &str, hyphens| {
+        config
+            .label_errors(
+                input.as_bytes(),
+                AsciiDenyList::STD3,
+                hyphens,
+                DnsLength::Verify,
+            )
+            .iter()
+            .map(|e| (e.index(), e.kind()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(kinds("example.com", Hyphens::Check), []);
+    assert_eq!(
+        kinds("a_b.com", Hyphens::Check),
+        [(0, LabelErrorKind::DisallowedCodePoint('_'))]
+    );
+    assert_eq!(
+        kinds("ok.-a-", Hyphens::Check),
+        [
+            (1, LabelErrorKind::LeadingHyphen),
+            (1, LabelErrorKind::TrailingHyphen)
+        ]
+    );
+    assert_eq!(
+        kinds("ab--c", Hyphens::Check),
+        [(0, LabelErrorKind::HyphensInThirdAndFourthPositions)]
+    );
+    assert_eq!(kinds("ab--c", Hyphens::CheckFirstLast), []);
+    assert_eq!(
+        kinds("a.\u{301}b", Hyphens::Check),
+        [(1, LabelErrorKind::LeadingCombiningMark('\u{301}'))]
+    );
+    assert_eq!(
+        kinds("a\u{200D}b", Hyphens::Check),
+        [(0, LabelErrorKind::ContextJ('\u{200D}'))]
+    );
+    assert_eq!(
+        kinds("xn--a-.com", Hyphens::Check),
+        [(0, LabelErrorKind::InvalidPunycode)]
+    );
+    // "xn--a-vum" is the Punycode of "aẞ", which maps to "ass"
+    assert_eq!(
+        kinds("xn--a-vum", Hyphens::Check),
+        [(0, LabelErrorKind::NotNormalized)]
+    );
+    // A Hebrew label with a Latin letter in the middle, and then ending with it
+    assert_eq!(
+        kinds("\u{5D0}a1.com", Hyphens::Check),
+        [(0, LabelErrorKind::Bidi(2))]
+    );
+    assert_eq!(
+        kinds("\u{5D0}a.com", Hyphens::Check),
+        [(0, LabelErrorKind::Bidi(3))]
+    );
+    assert_eq!(
+        kinds("a..b", Hyphens::Check),
+        [(1, LabelErrorKind::EmptyLabel)]
+    );
+    assert_eq!(
+        kinds(&"a".repeat(64), Hyphens::Check),
+        [(0, LabelErrorKind::LabelTooLong)]
+    );
+    let long = ["a".repeat(63).as_str(); 5].join(".");
+    assert_eq!(
+        kinds(&long, Hyphens::Check),
+        [(3, LabelErrorKind::DomainTooLong)]
+    );
+}
+
+#[test]
+fn to_ascii_detailed() {
+    let config = idna::uts46::Uts46::new();
+    assert_eq!(
+        config
+            .to_ascii_detailed(
+                "Bücher.example".as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                DnsLength::Ignore,
+            )
+            .unwrap(),
+        "xn--bcher-kva.example"
+    );
+    let errors = config
+        .to_ascii_detailed(
+            "a.b<c".as_bytes(),
+            AsciiDenyList::URL,
+            Hyphens::Allow,
+            DnsLength::Ignore,
+        )
+        .unwrap_err();
+    assert_eq!(errors.label_errors().len(), 1);
+    assert_eq!(errors.label_errors()[0].label(), "b<c");
+    assert_eq!(
+        errors.to_string(),
+        "label 1 (\"b<c\"): disallowed code point U+003C"
+    );
+    // Errors without details keep their previous formatting
+    let errors = idna::domain_to_ascii("xn--a-.com").unwrap_err();
+    assert!(errors.label_errors().is_empty());
+    assert_eq!(format!("{:?}", errors), "Errors");
+}
+
+#[cfg(feature = "utf16")]
+#[test]
+fn utf16() {
+    use idna::uts46::ErrorPolicy;
+    use std::borrow::Cow;
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+    let config = idna::uts46::Uts46::new();
+    let to_ascii = |input: &[u16]| {
+        config
+            .to_ascii_utf16(input, AsciiDenyList::URL, Hyphens::Allow, DnsLength::Ignore)
+            .map(|cow| {
+                (
+                    matches!(cow, Cow::Borrowed(_)),
+                    String::from_utf16(&cow).unwrap(),
+                )
+            })
+    };
+
+    assert_eq!(
+        to_ascii(&utf16("example.com")).unwrap(),
+        (true, "example.com".to_owned())
+    );
+    assert_eq!(
+        to_ascii(&utf16("xn--bcher-kva.example")).unwrap(),
+        (true, "xn--bcher-kva.example".to_owned())
+    );
+    assert_eq!(
+        to_ascii(&utf16("example-1.com")).unwrap(),
+        (true, "example-1.com".to_owned())
+    );
+    assert_eq!(
+        to_ascii(&utf16("XN--Bcher-KVA.example")).unwrap(),
+        (false, "xn--bcher-kva.example".to_owned())
+    );
+    assert_eq!(
+        to_ascii(&utf16("Example.COM")).unwrap(),
+        (false, "example.com".to_owned())
+    );
+    assert_eq!(
+        to_ascii(&utf16("Bücher.example")).unwrap(),
+        (false, "xn--bcher-kva.example".to_owned())
+    );
+    assert_eq!(
+        to_ascii(&utf16("\u{1F4A9}.example")).unwrap(),
+        (false, "xn--ls8h.example".to_owned())
+    );
+
+    let punycode = utf16("xn--bcher-kva.example");
+    let (unicode, result) = config.to_unicode_utf16(&punycode, AsciiDenyList::URL, Hyphens::Allow);
+    assert!(result.is_ok());
+    assert_eq!(String::from_utf16(&unicode).unwrap(), "bücher.example");
+
+    // Unpaired surrogates are treated as U+FFFD, which is an error
+    let lone_surrogate = [0xD800, u16::from(b'.'), u16::from(b'c'), u16::from(b'o')];
+    assert!(to_ascii(&lone_surrogate).is_err());
+    let (unicode, result) =
+        config.to_unicode_utf16(&lone_surrogate, AsciiDenyList::URL, Hyphens::Allow);
+    assert!(result.is_err());
+    assert_eq!(String::from_utf16(&unicode).unwrap(), "\u{FFFD}.co");
+    let reversed_pair = [0xDC00, 0xD800];
+    assert!(to_ascii(&reversed_pair).is_err());
+
+    // The UTF-16 and UTF-8 forms of the Punycode output match
+    let mut v = Vec::new();
+    let mut ascii = Vec::new();
+    assert_eq!(
+        config.process_utf16(
+            &utf16("Bücher.example"),
+            AsciiDenyList::URL,
+            Hyphens::Allow,
+            ErrorPolicy::MarkErrors,
+            |_, _, _| true,
+            &mut v,
+            Some(&mut ascii),
+        ),
+        Ok(ProcessingSuccess::WroteToSink)
+    );
+    assert_eq!(String::from_utf16(&v).unwrap(), "bücher.example");
+    assert_eq!(String::from_utf16(&ascii).unwrap(), "xn--bcher-kva.example");
+
+    assert!(config
+        .to_ascii_utf16(
+            &utf16(&"a".repeat(64)),
+            AsciiDenyList::URL,
+            Hyphens::Allow,
+            DnsLength::Verify
+        )
+        .is_err());
+}
+
+#[test]
+fn dns_wire_format() {
+    use idna::dns::{from_wire, to_wire, WireError};
+
+    assert_eq!(to_wire("example.com").unwrap(), b"\x07example\x03com\x00");
+    assert_eq!(to_wire("example.com.").unwrap(), b"\x07example\x03com\x00");
+    assert_eq!(to_wire(".").unwrap(), b"\x00");
+    assert_eq!(to_wire("a\\.b.c").unwrap(), b"\x03a.b\x01c\x00");
+    assert_eq!(to_wire("a\\\\b").unwrap(), b"\x03a\\b\x00");
+    assert_eq!(to_wire("a\\000\\255").unwrap(), b"\x03a\x00\xff\x00");
+    assert_eq!(to_wire(""), Err(WireError::EmptyLabel));
+    assert_eq!(to_wire("a..b"), Err(WireError::EmptyLabel));
+    assert_eq!(to_wire(".a"), Err(WireError::EmptyLabel));
+    assert_eq!(to_wire("a.."), Err(WireError::EmptyLabel));
+    assert_eq!(to_wire("a\\"), Err(WireError::InvalidEscape));
+    assert_eq!(to_wire("a\\25"), Err(WireError::InvalidEscape));
+    assert_eq!(to_wire("a\\256"), Err(WireError::InvalidEscape));
+    assert_eq!(to_wire(&"a".repeat(63)).unwrap().len(), 65);
+    assert_eq!(to_wire(&"a".repeat(64)), Err(WireError::LabelTooLong));
+    // 255 bytes in wire format is the limit
+    let longest = ["a".repeat(63).as_str(); 4].join(".")[2..].to_owned();
+    assert_eq!(longest.len(), 253);
+    assert_eq!(to_wire(&longest).unwrap().len(), 255);
+    assert_eq!(to_wire(&format!("{}.", longest)).unwrap().len(), 255);
+    assert_eq!(
+        to_wire(&format!("a{}", longest)),
+        Err(WireError::NameTooLong)
+    );
+    // Escapes count once
+    let escaped = ["\\097".repeat(63).as_str(); 4].join(".")[8..].to_owned();
+    assert_eq!(to_wire(&escaped).unwrap().len(), 255);
+
+    assert_eq!(from_wire(b"\x07example\x03com\x00").unwrap(), "example.com");
+    assert_eq!(from_wire(b"\x00").unwrap(), ".");
+    assert_eq!(
+        from_wire(b"\x0dxn--bcher-kva\x07example\x00").unwrap(),
+        "bücher.example"
+    );
+    assert_eq!(
+        from_wire(b"\x0dXN--BCHER-KVA\x07example\x00").unwrap(),
+        "bücher.example"
+    );
+    // Invalid A-labels are shown as they are
+    assert_eq!(from_wire(b"\x07xn--a-1\x00").unwrap(), "xn--a-1");
+    assert_eq!(
+        from_wire(b"\x05a.b\\c\x04a b\xff\x00").unwrap(),
+        "a\\.b\\\\c.a\\032b\\255"
+    );
+    assert_eq!(from_wire(b""), Err(WireError::Truncated));
+    assert_eq!(from_wire(b"\x07example"), Err(WireError::Truncated));
+    assert_eq!(from_wire(b"\x08example\x00"), Err(WireError::Truncated));
+    assert_eq!(
+        from_wire(b"\x07example\xc0\x0c"),
+        Err(WireError::UnsupportedLabelType)
+    );
+    assert_eq!(from_wire(b"\x00\x00"), Err(WireError::TrailingData));
+    let wire = to_wire(&longest).unwrap();
+    assert_eq!(from_wire(&wire).unwrap(), longest);
+    let mut too_long = vec![1, b'a'];
+    too_long.extend_from_slice(&wire);
+    assert_eq!(from_wire(&too_long), Err(WireError::NameTooLong));
+
+    for name in ["a\\.b.c", "a\\\\b", "a\\000\\255.example", "example.com"] {
+        assert_eq!(from_wire(&to_wire(name).unwrap()).unwrap(), name);
+    }
+}
+
+#[test]
+fn options() {
+    use idna::uts46::{UnicodeOutput, Uts46, Uts46Options};
+
+    fn assert_send_sync<T: Send + Sync + 'static>(_: &T) {}
+    static OPTIONS: Uts46Options = Uts46Options::URL.hyphens(Hyphens::Check);
+    assert_send_sync(&OPTIONS);
+    assert!(format!("{:?}", OPTIONS).contains("unicode_output: Always"));
+    assert!(format!("{:?}", UnicodeOutput::Custom(|_, _, _| true)).starts_with("Custom("));
+
+    let uts46 = Uts46::new();
+
+    // The presets match the positional arguments
+    let inputs = [
+        "Bücher.example",
+        "_dmarc.example",
+        "-a-.example",
+        "ab--c.example",
+        "a b.example",
+        "example.com.",
+        "xn--a.example",
+    ];
+    for input in inputs.iter().copied().chain([&*"a".repeat(64)]) {
+        let input = input.as_bytes();
+        assert_eq!(
+            uts46.to_ascii_with_options(input, Uts46Options::default()),
+            uts46.to_ascii(input, AsciiDenyList::URL, Hyphens::Allow, DnsLength::Ignore)
+        );
+        assert_eq!(
+            uts46.to_ascii_with_options(input, Uts46Options::STD3),
+            uts46.to_ascii(
+                input,
+                AsciiDenyList::STD3,
+                Hyphens::Check,
+                DnsLength::Verify
+            )
+        );
+        assert_eq!(
+            uts46.to_ascii_with_options(input, Uts46Options::STRICT_DNS_LOOKUP),
+            uts46.to_ascii(
+                input,
+                AsciiDenyList::STD3,
+                Hyphens::Check,
+                DnsLength::VerifyAllowRootDot
+            )
+        );
+        assert_eq!(
+            uts46.to_ascii_with_options(input, Uts46Options::LENIENT_DNS_LOOKUP),
+            uts46.to_ascii(
+                input,
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                DnsLength::VerifyAllowRootDot
+            )
+        );
+        assert_eq!(
+            uts46.to_unicode_with_options(input, Uts46Options::BROWSER_DISPLAY),
+            uts46.to_unicode(input, AsciiDenyList::URL, Hyphens::Allow)
+        );
+    }
+    assert!(uts46
+        .to_ascii_with_options(b"_dmarc.example.", Uts46Options::LENIENT_DNS_LOOKUP)
+        .is_ok());
+    assert!(uts46
+        .to_ascii_with_options(b"_dmarc.example.", Uts46Options::STRICT_DNS_LOOKUP)
+        .is_err());
+    assert_eq!(
+        uts46
+            .to_ascii_with_options(b"example.com.", Uts46Options::STRICT_DNS_LOOKUP)
+            .unwrap(),
+        "example.com."
+    );
+    assert!(uts46
+        .to_ascii_with_options(b"_dmarc.example", Uts46Options::STD3)
+        .is_err());
+    assert!(uts46
+        .to_ascii_with_options(b"ab--c.example", OPTIONS)
+        .is_err());
+
+    // Unicode output
+    assert_eq!(
+        uts46.to_unicode_with_options(b"xn--bcher-kva.example", Uts46Options::URL),
+        uts46.to_unicode(b"xn--bcher-kva.example", AsciiDenyList::URL, Hyphens::Allow)
+    );
+    assert_eq!(
+        uts46
+            .to_unicode_with_options(b"xn--bcher-kva.example", Uts46Options::default())
+            .0,
+        "bücher.example"
+    );
+    let input = "xn--bcher-kva.пример".as_bytes();
+    assert_eq!(
+        uts46
+            .to_unicode_with_options(input, Uts46Options::BROWSER_DISPLAY)
+            .0,
+        "bücher.пример"
+    );
+    assert_eq!(
+        uts46
+            .to_unicode_with_options(
+                input,
+                Uts46Options::BROWSER_DISPLAY.unicode_output(UnicodeOutput::Never)
+            )
+            .0,
+        "xn--bcher-kva.xn--e1afmkfd"
+    );
+    let latin_only =
+        Uts46Options::BROWSER_DISPLAY.unicode_output(UnicodeOutput::Custom(|label, _, _| {
+            label.iter().all(|c| c.is_ascii() || *c <= '\u{024F}')
+        }));
+    assert_eq!(
+        uts46.to_unicode_with_options(input, latin_only).0,
+        "bücher.xn--e1afmkfd"
+    );
+
+    // Error policy
+    let mut output = String::new();
+    assert_eq!(
+        uts46.process_with_options(
+            b"a\xE2\x80\x8Db.example",
+            Uts46Options::BROWSER_DISPLAY,
+            &mut output,
+            None
+        ),
+        Err(idna::uts46::ProcessingError::ValidityError)
+    );
+    assert_eq!(output, "a\u{FFFD}b.example");
+    output.clear();
+    assert_eq!(
+        uts46.process_with_options(
+            b"a\xE2\x80\x8Db.example",
+            Uts46Options::URL,
+            &mut output,
+            None
+        ),
+        Err(idna::uts46::ProcessingError::ValidityError)
+    );
+    assert_eq!(output, "");
+    assert_eq!(
+        uts46.process_with_options(b"example.com", Uts46Options::URL, &mut output, None),
+        Ok(ProcessingSuccess::Passthrough)
+    );
+    let mut ascii = String::new();
+    assert_eq!(
+        uts46.process_with_options(
+            "Bücher.example".as_bytes(),
+            Uts46Options::BROWSER_DISPLAY,
+            &mut output,
+            Some(&mut ascii)
+        ),
+        Ok(ProcessingSuccess::WroteToSink)
+    );
+    assert_eq!(output, "bücher.example");
+    assert_eq!(ascii, "xn--bcher-kva.example");
+}
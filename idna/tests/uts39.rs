@@ -0,0 +1,150 @@
+use idna::uts39::*;
+use idna::uts46::{AsciiDenyList, Hyphens, Uts46};
+
+#[test]
+fn restriction_levels() {
+    assert_eq!(restriction_level("example"), RestrictionLevel::Ascii);
+    assert_eq!(
+        restriction_level("παράδειγμα"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(restriction_level("bücher"), RestrictionLevel::SingleScript);
+    assert_eq!(
+        restriction_level("日本語ひらがな"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(
+        restriction_level("abcひらがな"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        restriction_level("abc한국어"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        restriction_level("abcअबक"),
+        RestrictionLevel::ModeratelyRestrictive
+    );
+    assert_eq!(
+        restriction_level("abcабв"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert_eq!(
+        restriction_level("अबक한국어"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert!(RestrictionLevel::SingleScript < RestrictionLevel::ModeratelyRestrictive);
+}
+
+#[test]
+fn confusables() {
+    assert_eq!(skeleton("\u{440}\u{430}\u{443}\u{440}\u{430}l"), "paypal");
+    assert_eq!(skeleton("m"), "rn");
+    assert!(is_confusable("rnicrosoft", "microsoft"));
+    assert!(is_confusable("g00gle", "gOOgle"));
+    // Decomposed before mapping: Cyrillic "ї" is "і" with a diaeresis
+    assert!(is_confusable("\u{457}", "\u{EF}"));
+    assert!(!is_confusable("paypal", "paypai"));
+    // Beyond Latin, Greek and Cyrillic: Cherokee and mathematical letters
+    assert!(is_confusable("\u{13A0}\u{13AA}\u{13A2}\u{13AA}", "DATA"));
+    assert!(is_confusable("\u{1D41A}pple", "apple"));
+
+    assert!(is_mixed_script_confusable("p\u{430}ypal"));
+    assert!(!is_mixed_script_confusable("paypal"));
+    assert!(!is_mixed_script_confusable("abcअबक"));
+
+    assert!(is_whole_script_confusable("\u{430}\u{440}\u{435}"));
+    assert!(is_whole_script_confusable(
+        "\u{430}\u{440}\u{440}\u{4CF}\u{435}"
+    ));
+    assert!(!is_whole_script_confusable(
+        "\u{43F}\u{440}\u{438}\u{432}\u{435}\u{442}"
+    ));
+    assert!(!is_whole_script_confusable("bücher"));
+    assert!(!is_whole_script_confusable("παράδειγμα"));
+}
+
+#[test]
+fn invisible_characters_and_numbers() {
+    assert!(has_invisible_characters("a\u{200B}b"));
+    assert!(has_invisible_characters("a\u{301}\u{301}"));
+    // "á" followed by another acute accent, once decomposed
+    assert!(has_invisible_characters("\u{E1}\u{301}"));
+    assert!(!has_invisible_characters("a\u{301}\u{300}"));
+
+    assert!(!has_mixed_numbers("abc123"));
+    assert!(!has_mixed_numbers("\u{9E7}\u{9E8}"));
+    assert!(has_mixed_numbers("1\u{9E8}"));
+    // Consecutive runs of mathematical digits
+    assert!(has_mixed_numbers("\u{1D7CE}\u{1D7D8}"));
+    assert!(!has_mixed_numbers("\u{1D7CE}\u{1D7D7}"));
+}
+
+#[test]
+fn display() {
+    let uts46 = Uts46::new();
+    let policy = display_policy(RestrictionLevel::ModeratelyRestrictive);
+    let display = |domain: &str| {
+        uts46
+            .to_user_interface(
+                domain.as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                &policy,
+            )
+            .0
+            .into_owned()
+    };
+    let ascii = |domain: &str| {
+        uts46
+            .to_ascii(
+                domain.as_bytes(),
+                AsciiDenyList::URL,
+                Hyphens::Allow,
+                idna::uts46::DnsLength::Ignore,
+            )
+            .unwrap()
+            .into_owned()
+    };
+
+    assert_eq!(display("xn--bcher-kva.example"), "bücher.example");
+    assert_eq!(display("example.com"), "example.com");
+    let apple = ascii("\u{430}\u{440}\u{440}\u{4CF}\u{435}.com");
+    assert_eq!(display(&apple), apple);
+    let paypal = ascii("p\u{430}ypal.com");
+    assert_eq!(display(&paypal), paypal);
+    let mixed = ascii("abc\u{430}\u{431}\u{432}.com");
+    assert_eq!(display(&mixed), mixed);
+    // Whole-script confusable, but expected under a Cyrillic top-level domain
+    assert_eq!(
+        display(&ascii("\u{430}\u{440}\u{435}.\u{440}\u{444}")),
+        "\u{430}\u{440}\u{435}.\u{440}\u{444}"
+    );
+    assert_eq!(
+        display(&ascii("\u{43F}\u{440}\u{438}\u{432}\u{435}\u{442}.com")),
+        "\u{43F}\u{440}\u{438}\u{432}\u{435}\u{442}.com"
+    );
+    assert_eq!(display(&ascii("παράδειγμα.com")), "παράδειγμα.com");
+}
+
+#[test]
+fn display_policy_options() {
+    use idna::uts46::{UnicodeOutput, Uts46Options};
+
+    let uts46 = Uts46::new();
+    let options = Uts46Options::BROWSER_DISPLAY.unicode_output(UnicodeOutput::Uts39(
+        RestrictionLevel::ModeratelyRestrictive,
+    ));
+    let display = |domain: &str| {
+        uts46
+            .to_unicode_with_options(domain.as_bytes(), options)
+            .0
+            .into_owned()
+    };
+    assert_eq!(display("xn--bcher-kva.example"), "bücher.example");
+    assert_eq!(display("p\u{430}ypal.com"), "xn--pypal-4ve.com");
+    assert_eq!(
+        display("\u{430}\u{440}\u{440}\u{4CF}\u{435}.com"),
+        "xn--80ak6aa92e.com"
+    );
+}
@@ -90,6 +90,36 @@ pub fn collect_tests<F: FnMut(String, TestFn)>(add_test: &mut F) {
                     Hyphens::Check,
                     DnsLength::VerifyAllowRootDot,
                 );
+                // `label_errors` is empty if and only if `to_ascii` succeeds, with any options
+                for (ascii_deny_list, hyphens, dns_length) in [
+                    (
+                        AsciiDenyList::STD3,
+                        Hyphens::Check,
+                        DnsLength::VerifyAllowRootDot,
+                    ),
+                    (AsciiDenyList::URL, Hyphens::Allow, DnsLength::Ignore),
+                    (
+                        AsciiDenyList::EMPTY,
+                        Hyphens::CheckFirstLast,
+                        DnsLength::Verify,
+                    ),
+                ] {
+                    let label_errors = config.label_errors(
+                        source.as_bytes(),
+                        ascii_deny_list,
+                        hyphens,
+                        dns_length,
+                    );
+                    assert_eq!(
+                        label_errors.is_empty(),
+                        config
+                            .to_ascii(source.as_bytes(), ascii_deny_list, hyphens, dns_length)
+                            .is_ok(),
+                        "label errors for {:?}: {:?}",
+                        source,
+                        label_errors,
+                    );
+                }
                 check(
                     &source,
                     (&to_ascii_n, &to_ascii_n_status),
@@ -97,6 +127,33 @@ pub fn collect_tests<F: FnMut(String, TestFn)>(add_test: &mut F) {
                     |_| false,
                 );
 
+                #[cfg(feature = "utf16")]
+                {
+                    let source_utf16: Vec<u16> = source.encode_utf16().collect();
+                    let (to_unicode_utf16_value, to_unicode_utf16_result) =
+                        config.to_unicode_utf16(&source_utf16, AsciiDenyList::STD3, Hyphens::Check);
+                    check(
+                        &source,
+                        (&to_unicode, &to_unicode_status),
+                        to_unicode_utf16_result
+                            .map(|()| String::from_utf16(&to_unicode_utf16_value).unwrap()),
+                        |e| e == "X4_2",
+                    );
+                    check(
+                        &source,
+                        (&to_ascii_n, &to_ascii_n_status),
+                        config
+                            .to_ascii_utf16(
+                                &source_utf16,
+                                AsciiDenyList::STD3,
+                                Hyphens::Check,
+                                DnsLength::VerifyAllowRootDot,
+                            )
+                            .map(|cow| String::from_utf16(&cow).unwrap()),
+                        |_| false,
+                    );
+                }
+
                 let mut to_unicode_simultaneous = String::new();
                 let mut to_ascii_simultaneous = String::new();
                 let (to_unicode_simultaneous_result, to_ascii_simultaneous_result) = match config
@@ -6,25 +6,27 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use core::{mem, ops};
+use core::{fmt, mem, ops};
 
 /// Represents a set of characters or bytes in the ASCII range.
 ///
 /// This is used in [`percent_encode`] and [`utf8_percent_encode`].
 /// This is similar to [percent-encode sets](https://url.spec.whatwg.org/#percent-encoded-bytes).
 ///
-/// Use the `add` method of an existing set to define a new set. For example:
+/// Use the `add` method of an existing set, or the [`ascii_set!`] macro, to define a new set.
+/// For example:
 ///
 /// [`percent_encode`]: crate::percent_encode
 /// [`utf8_percent_encode`]: crate::utf8_percent_encode
+/// [`ascii_set!`]: crate::ascii_set
 ///
 /// ```
-/// use percent_encoding::{AsciiSet, CONTROLS};
+/// use percent_encoding::{AsciiSet, QUERY};
 ///
-/// /// https://url.spec.whatwg.org/#fragment-percent-encode-set
-/// const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+/// /// The query percent-encode set, plus `&` and `=`
+/// const QUERY_VALUE: &AsciiSet = &QUERY.add(b'&').add(b'=');
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(PartialEq, Eq)]
 pub struct AsciiSet {
     mask: [Chunk; ASCII_RANGE_LEN / BITS_PER_CHUNK],
 }
@@ -41,14 +43,50 @@ impl AsciiSet {
         mask: [0; ASCII_RANGE_LEN / BITS_PER_CHUNK],
     };
 
-    /// Called with UTF-8 bytes rather than code points.
-    /// Not used for non-ASCII bytes.
-    pub(crate) const fn contains(&self, byte: u8) -> bool {
+    /// Return whether the set contains the given byte.
+    ///
+    /// Non-ASCII bytes are never in the set,
+    /// although `percent_encode` always encodes them.
+    pub const fn contains(&self, byte: u8) -> bool {
+        if byte >= ASCII_RANGE_LEN as u8 {
+            return false;
+        }
         let chunk = self.mask[byte as usize / BITS_PER_CHUNK];
         let mask = 1 << (byte as usize % BITS_PER_CHUNK);
         (chunk & mask) != 0
     }
 
+    /// Return the set of ASCII bytes for which `predicate` returns true.
+    ///
+    /// This builds a set at runtime, for example from configuration:
+    ///
+    /// ```
+    /// use percent_encoding::{utf8_percent_encode, AsciiSet};
+    ///
+    /// let safe_characters = "-_.";
+    /// let set = AsciiSet::from_fn(|byte| {
+    ///     !byte.is_ascii_alphanumeric() && !safe_characters.as_bytes().contains(&byte)
+    /// });
+    /// assert_eq!(utf8_percent_encode("a-b c", &set).to_string(), "a-b%20c");
+    /// ```
+    pub fn from_fn<F: FnMut(u8) -> bool>(mut predicate: F) -> Self {
+        let mut set = AsciiSet::EMPTY;
+        for byte in 0..ASCII_RANGE_LEN as u8 {
+            if predicate(byte) {
+                set = set.add(byte)
+            }
+        }
+        set
+    }
+
+    /// Return an iterator of the bytes in the set, in increasing order.
+    pub fn iter(&self) -> AsciiSetIter {
+        AsciiSetIter {
+            mask: self.mask,
+            next_byte: 0,
+        }
+    }
+
     pub(crate) fn should_percent_encode(&self, byte: u8) -> bool {
         !byte.is_ascii() || self.contains(byte)
     }
@@ -59,6 +97,19 @@ impl AsciiSet {
         AsciiSet { mask }
     }
 
+    /// Return the set with all of the given bytes added.
+    ///
+    /// Like `add`, this fails (at compile time in `const` context) if a byte is not ASCII.
+    pub const fn add_all(&self, bytes: &[u8]) -> Self {
+        let mut set = AsciiSet { mask: self.mask };
+        let mut i = 0;
+        while i < bytes.len() {
+            set = set.add(bytes[i]);
+            i += 1;
+        }
+        set
+    }
+
     pub const fn remove(&self, byte: u8) -> Self {
         let mut mask = self.mask;
         mask[byte as usize / BITS_PER_CHUNK] &= !(1 << (byte as usize % BITS_PER_CHUNK));
@@ -83,6 +134,69 @@ impl AsciiSet {
     }
 }
 
+impl fmt::Debug for AsciiSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AsciiSet ")?;
+        f.debug_set().entries(self.iter().map(char::from)).finish()
+    }
+}
+
+impl IntoIterator for &AsciiSet {
+    type Item = u8;
+    type IntoIter = AsciiSetIter;
+
+    fn into_iter(self) -> AsciiSetIter {
+        self.iter()
+    }
+}
+
+/// The return type of [`AsciiSet::iter`].
+#[derive(Debug, Clone)]
+pub struct AsciiSetIter {
+    mask: [Chunk; ASCII_RANGE_LEN / BITS_PER_CHUNK],
+    next_byte: u8,
+}
+
+impl Iterator for AsciiSetIter {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let set = AsciiSet { mask: self.mask };
+        while self.next_byte < ASCII_RANGE_LEN as u8 {
+            let byte = self.next_byte;
+            self.next_byte += 1;
+            if set.contains(byte) {
+                return Some(byte);
+            }
+        }
+        None
+    }
+}
+
+/// Build an [`AsciiSet`] in `const` context from a string literal of the bytes it contains.
+///
+/// ```
+/// use percent_encoding::{ascii_set, AsciiSet, CONTROLS};
+///
+/// const SEPARATORS: AsciiSet = ascii_set!("&=;");
+/// const FRAGMENT: &AsciiSet = &CONTROLS.union(ascii_set!(" \"<>`"));
+///
+/// assert!(SEPARATORS.contains(b'='));
+/// assert_eq!(*FRAGMENT, *percent_encoding::FRAGMENT);
+/// ```
+///
+/// Non-ASCII characters are a compile-time error:
+///
+/// ```compile_fail
+/// const SET: percent_encoding::AsciiSet = percent_encoding::ascii_set!("é");
+/// ```
+#[macro_export]
+macro_rules! ascii_set {
+    ($bytes: expr) => {
+        $crate::AsciiSet::EMPTY.add_all($bytes.as_bytes())
+    };
+}
+
 impl ops::Add for AsciiSet {
     type Output = Self;
 
@@ -131,6 +245,36 @@ static_assert! {
     CONTROLS.contains(0x7F),
 }
 
+/// The same set as [`CONTROLS`], named as in the URL Standard.
+///
+/// <https://url.spec.whatwg.org/#c0-control-percent-encode-set>
+pub const C0_CONTROL: &AsciiSet = CONTROLS;
+
+/// <https://url.spec.whatwg.org/#fragment-percent-encode-set>
+pub const FRAGMENT: &AsciiSet = &CONTROLS.union(crate::ascii_set!(" \"<>`"));
+
+/// <https://url.spec.whatwg.org/#query-percent-encode-set>
+pub const QUERY: &AsciiSet = &CONTROLS.union(crate::ascii_set!(" \"#<>"));
+
+/// <https://url.spec.whatwg.org/#special-query-percent-encode-set>
+pub const SPECIAL_QUERY: &AsciiSet = &QUERY.add(b'\'');
+
+/// <https://url.spec.whatwg.org/#path-percent-encode-set>
+pub const PATH: &AsciiSet = &QUERY.union(crate::ascii_set!("?^`{}"));
+
+/// <https://url.spec.whatwg.org/#userinfo-percent-encode-set>
+pub const USERINFO: &AsciiSet = &PATH.union(crate::ascii_set!("/:;=@[\\]^|"));
+
+/// <https://url.spec.whatwg.org/#component-percent-encode-set>
+pub const COMPONENT: &AsciiSet = &USERINFO.union(crate::ascii_set!("$%&+,"));
+
+/// <https://url.spec.whatwg.org/#application-x-www-form-urlencoded-percent-encode-set>
+///
+/// Note that the `application/x-www-form-urlencoded` serializer also replaces spaces with `+`,
+/// which `form_urlencoded::byte_serialize` does.
+pub const APPLICATION_X_WWW_FORM_URLENCODED: &AsciiSet =
+    &COMPONENT.union(crate::ascii_set!("!'()~"));
+
 /// Everything that is not an ASCII letter or digit.
 ///
 /// This is probably more eager than necessary in any context.
@@ -200,6 +344,34 @@ mod tests {
         assert_eq!(UNION, EXPECTED);
     }
 
+    #[test]
+    fn url_sets() {
+        // https://url.spec.whatwg.org/#application-x-www-form-urlencoded-percent-encode-set
+        // leaves only ASCII alphanumerics and `*-._` unencoded
+        let unencoded: alloc::vec::Vec<u8> = APPLICATION_X_WWW_FORM_URLENCODED
+            .complement()
+            .iter()
+            .filter(|byte| !byte.is_ascii_alphanumeric())
+            .collect();
+        assert_eq!(unencoded, b"*-._");
+        assert!(FRAGMENT.contains(b'`') && !FRAGMENT.contains(b'#'));
+        assert!(QUERY.contains(b'#') && !QUERY.contains(b'\''));
+        assert!(SPECIAL_QUERY.contains(b'\''));
+        assert!(PATH.contains(b'?') && !PATH.contains(b'/'));
+        assert!(PATH.contains(b'^') && PATH.contains(b'`'));
+        assert!(USERINFO.contains(b'/') && USERINFO.contains(b'^'));
+        assert!(COMPONENT.contains(b'+') && !COMPONENT.contains(b'!'));
+    }
+
+    #[test]
+    fn iter_and_debug() {
+        let set = ascii_set!("b\ta");
+        assert_eq!(set.iter().collect::<alloc::vec::Vec<_>>(), b"\tab");
+        assert_eq!(alloc::format!("{:?}", set), "AsciiSet {'\\t', 'a', 'b'}");
+        assert!(!set.contains(b'\xE9'));
+        assert_eq!(CONTROLS.iter().count(), 33);
+    }
+
     /// This test ensures that we can get the complement of a set as a constant value, which is
     /// useful for defining sets in a modular way.
     #[test]
@@ -0,0 +1,232 @@
+// Copyright 2013-2016 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::AsciiSet;
+use core::{fmt, mem, ops};
+
+/// Represents a set of bytes, including non-ASCII bytes.
+///
+/// This is used in [`percent_encode_bytes_with`] for byte strings that are not necessarily UTF-8,
+/// where non-ASCII bytes may be left unencoded.
+/// Unlike with [`AsciiSet`], non-ASCII bytes are only encoded if they are in the set.
+///
+/// [`percent_encode_bytes_with`]: crate::percent_encode_bytes_with
+///
+/// ```
+/// use percent_encoding::{ByteSet, CONTROLS};
+///
+/// /// Controls, space, and non-ASCII bytes except for Latin-1 letters
+/// const LATIN1_TOKEN: &ByteSet = &ByteSet::from_ascii_set(CONTROLS)
+///     .add(b' ')
+///     .union(ByteSet::range(0x80, 0xBF));
+/// ```
+#[derive(PartialEq, Eq)]
+pub struct ByteSet {
+    mask: [Chunk; 0x100 / BITS_PER_CHUNK],
+}
+
+type Chunk = u32;
+
+const BITS_PER_CHUNK: usize = 8 * mem::size_of::<Chunk>();
+
+impl ByteSet {
+    /// An empty set.
+    pub const EMPTY: ByteSet = ByteSet {
+        mask: [0; 0x100 / BITS_PER_CHUNK],
+    };
+
+    /// The set of non-ASCII bytes, 0x80 to 0xFF.
+    pub const NON_ASCII: ByteSet = ByteSet::range(0x80, 0xFF);
+
+    /// Return the set with the same bytes as the given `AsciiSet`.
+    ///
+    /// Note that this does not contain non-ASCII bytes,
+    /// although [`percent_encode`](crate::percent_encode) encodes them with an `AsciiSet`.
+    /// Use `.union(ByteSet::NON_ASCII)` to get the same behavior.
+    pub const fn from_ascii_set(ascii_set: &AsciiSet) -> Self {
+        let mut set = ByteSet::EMPTY;
+        let mut byte = 0;
+        while byte < 0x80 {
+            if ascii_set.contains(byte) {
+                set = set.add(byte);
+            }
+            byte += 1;
+        }
+        set
+    }
+
+    /// Return the set of bytes from `first` to `last`, inclusive.
+    pub const fn range(first: u8, last: u8) -> Self {
+        let mut set = ByteSet::EMPTY;
+        let mut byte = first as usize;
+        while byte <= last as usize {
+            set = set.add(byte as u8);
+            byte += 1;
+        }
+        set
+    }
+
+    /// Return the set of bytes for which `predicate` returns true.
+    pub fn from_fn<F: FnMut(u8) -> bool>(mut predicate: F) -> Self {
+        let mut set = ByteSet::EMPTY;
+        for byte in 0..=0xFF {
+            if predicate(byte) {
+                set = set.add(byte)
+            }
+        }
+        set
+    }
+
+    pub const fn contains(&self, byte: u8) -> bool {
+        let chunk = self.mask[byte as usize / BITS_PER_CHUNK];
+        let mask = 1 << (byte as usize % BITS_PER_CHUNK);
+        (chunk & mask) != 0
+    }
+
+    pub const fn add(&self, byte: u8) -> Self {
+        let mut mask = self.mask;
+        mask[byte as usize / BITS_PER_CHUNK] |= 1 << (byte as usize % BITS_PER_CHUNK);
+        ByteSet { mask }
+    }
+
+    pub const fn remove(&self, byte: u8) -> Self {
+        let mut mask = self.mask;
+        mask[byte as usize / BITS_PER_CHUNK] &= !(1 << (byte as usize % BITS_PER_CHUNK));
+        ByteSet { mask }
+    }
+
+    /// Return the union of two sets.
+    pub const fn union(&self, other: Self) -> Self {
+        let mut mask = self.mask;
+        let mut i = 0;
+        while i < mask.len() {
+            mask[i] |= other.mask[i];
+            i += 1;
+        }
+        ByteSet { mask }
+    }
+
+    /// Return the negation of the set.
+    pub const fn complement(&self) -> Self {
+        let mut mask = self.mask;
+        let mut i = 0;
+        while i < mask.len() {
+            mask[i] = !mask[i];
+            i += 1;
+        }
+        ByteSet { mask }
+    }
+
+    /// Return an iterator of the bytes in the set, in increasing order.
+    pub fn iter(&self) -> ByteSetIter {
+        ByteSetIter {
+            mask: self.mask,
+            next_byte: 0,
+        }
+    }
+}
+
+impl IntoIterator for &ByteSet {
+    type Item = u8;
+    type IntoIter = ByteSetIter;
+
+    fn into_iter(self) -> ByteSetIter {
+        self.iter()
+    }
+}
+
+/// The return type of [`ByteSet::iter`].
+#[derive(Debug, Clone)]
+pub struct ByteSetIter {
+    mask: [Chunk; 0x100 / BITS_PER_CHUNK],
+    next_byte: u16,
+}
+
+impl Iterator for ByteSetIter {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let set = ByteSet { mask: self.mask };
+        while self.next_byte <= 0xFF {
+            let byte = self.next_byte as u8;
+            self.next_byte += 1;
+            if set.contains(byte) {
+                return Some(byte);
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Debug for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ByteSet ")?;
+        f.debug_set().entries(self.iter().map(DebugByte)).finish()
+    }
+}
+
+/// ASCII bytes as characters, others in hexadecimal
+struct DebugByte(u8);
+
+impl fmt::Debug for DebugByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_ascii() {
+            fmt::Debug::fmt(&char::from(self.0), f)
+        } else {
+            write!(f, "0x{:02X}", self.0)
+        }
+    }
+}
+
+impl From<&AsciiSet> for ByteSet {
+    fn from(ascii_set: &AsciiSet) -> Self {
+        ByteSet::from_ascii_set(ascii_set)
+    }
+}
+
+impl ops::Add for ByteSet {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl ops::Not for ByteSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NON_ALPHANUMERIC;
+
+    #[test]
+    fn from_ascii_set() {
+        const SET: ByteSet = ByteSet::from_ascii_set(NON_ALPHANUMERIC);
+        for byte in 0..=0xFF {
+            assert_eq!(SET.contains(byte), NON_ALPHANUMERIC.contains(byte));
+        }
+        let with_non_ascii = SET.union(ByteSet::NON_ASCII);
+        assert!(with_non_ascii.contains(0x80) && with_non_ascii.contains(0xFF));
+    }
+
+    #[test]
+    fn const_operations() {
+        const SET: ByteSet = ByteSet::range(0xFE, 0xFF).add(b'a').remove(0xFF);
+        assert_eq!(SET.iter().collect::<alloc::vec::Vec<_>>(), [b'a', 0xFE]);
+        assert_eq!(alloc::format!("{:?}", SET), "ByteSet {'a', 0xFE}");
+        const COMPLEMENT: ByteSet = SET.complement();
+        assert_eq!(COMPLEMENT.iter().count(), 254);
+        assert_eq!(!COMPLEMENT + ByteSet::EMPTY.add(0), SET.add(0));
+    }
+}
@@ -20,19 +20,19 @@
 //! inside of a query string, and therefore does not need to be encoded.
 //! The [`AsciiSet`] parameter of [`percent_encode`] and [`utf8_percent_encode`]
 //! lets callers configure this.
+//! Sets are usually constants, but they can also be built at runtime and borrowed for as long
+//! as the input, for example with [`AsciiSet::from_fn`].
 //!
-//! This crate deliberately does not provide many different sets.
-//! Users should consider in what context the encoded string will be used,
+//! This crate provides the percent-encode sets defined in the URL Standard,
+//! such as [`FRAGMENT`] and [`QUERY`].
+//! For other contexts, users should consider in what context the encoded string will be used,
 //! read relevant specifications, and define their own set.
-//! This is done by using the `add` method of an existing set.
+//! This is done by using the `add` method of an existing set, or the [`ascii_set!`] macro.
 //!
 //! # Examples
 //!
 //! ```
-//! use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
-//!
-//! /// https://url.spec.whatwg.org/#fragment-percent-encode-set
-//! const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+//! use percent_encoding::{utf8_percent_encode, FRAGMENT};
 //!
 //! assert_eq!(utf8_percent_encode("foo <bar>", FRAGMENT).to_string(), "foo%20%3Cbar%3E");
 //! ```
@@ -42,6 +42,10 @@
 #[cfg(feature = "std")]
 extern crate std as _;
 
+// For the `std::io` and `std::error::Error` implementations
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
@@ -51,11 +55,16 @@ use alloc::{
     string::String,
     vec::Vec,
 };
-use core::{fmt, slice, str};
+use core::{fmt, mem, slice, str};
 
-pub use self::ascii_set::{AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+pub use self::ascii_set::{
+    AsciiSet, AsciiSetIter, APPLICATION_X_WWW_FORM_URLENCODED, C0_CONTROL, COMPONENT, CONTROLS,
+    FRAGMENT, NON_ALPHANUMERIC, PATH, QUERY, SPECIAL_QUERY, USERINFO,
+};
+pub use self::byte_set::{ByteSet, ByteSetIter};
 
 mod ascii_set;
+mod byte_set;
 
 /// Return the percent-encoding of the given byte.
 ///
@@ -114,7 +123,7 @@ pub fn percent_encode_byte(byte: u8) -> &'static str {
 /// assert_eq!(percent_encode(b"foo bar?", NON_ALPHANUMERIC).to_string(), "foo%20bar%3F");
 /// ```
 #[inline]
-pub fn percent_encode<'a>(input: &'a [u8], ascii_set: &'static AsciiSet) -> PercentEncode<'a> {
+pub fn percent_encode<'i, 's>(input: &'i [u8], ascii_set: &'s AsciiSet) -> PercentEncode<'i, 's> {
     PercentEncode {
         bytes: input,
         ascii_set,
@@ -133,38 +142,35 @@ pub fn percent_encode<'a>(input: &'a [u8], ascii_set: &'static AsciiSet) -> Perc
 /// assert_eq!(utf8_percent_encode("foo bar?", NON_ALPHANUMERIC).to_string(), "foo%20bar%3F");
 /// ```
 #[inline]
-pub fn utf8_percent_encode<'a>(input: &'a str, ascii_set: &'static AsciiSet) -> PercentEncode<'a> {
+pub fn utf8_percent_encode<'i, 's>(
+    input: &'i str,
+    ascii_set: &'s AsciiSet,
+) -> PercentEncode<'i, 's> {
     percent_encode(input.as_bytes(), ascii_set)
 }
 
 /// The return type of [`percent_encode`] and [`utf8_percent_encode`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PercentEncode<'a> {
-    bytes: &'a [u8],
-    ascii_set: &'static AsciiSet,
+pub struct PercentEncode<'i, 's> {
+    bytes: &'i [u8],
+    ascii_set: &'s AsciiSet,
 }
 
-impl<'a> Iterator for PercentEncode<'a> {
-    type Item = &'a str;
+impl<'i> Iterator for PercentEncode<'i, '_> {
+    type Item = &'i str;
 
-    fn next(&mut self) -> Option<&'a str> {
+    fn next(&mut self) -> Option<&'i str> {
         if let Some((&first_byte, remaining)) = self.bytes.split_first() {
             if self.ascii_set.should_percent_encode(first_byte) {
                 self.bytes = remaining;
                 Some(percent_encode_byte(first_byte))
             } else {
-                // The unsafe blocks here are appropriate because the bytes are
+                // 1 for first_byte
+                let unchanged_len = 1 + unchanged_prefix_len(remaining, self.ascii_set);
+                let (unchanged_slice, remaining) = self.bytes.split_at(unchanged_len);
+                self.bytes = remaining;
+                // The unsafe block here is appropriate because the bytes are
                 // confirmed as a subset of UTF-8 in should_percent_encode.
-                for (i, &byte) in remaining.iter().enumerate() {
-                    if self.ascii_set.should_percent_encode(byte) {
-                        // 1 for first_byte + i for previous iterations of this loop
-                        let (unchanged_slice, remaining) = self.bytes.split_at(1 + i);
-                        self.bytes = remaining;
-                        return Some(unsafe { str::from_utf8_unchecked(unchanged_slice) });
-                    }
-                }
-                let unchanged_slice = self.bytes;
-                self.bytes = &[][..];
                 Some(unsafe { str::from_utf8_unchecked(unchanged_slice) })
             }
         } else {
@@ -181,7 +187,225 @@ impl<'a> Iterator for PercentEncode<'a> {
     }
 }
 
-impl fmt::Display for PercentEncode<'_> {
+/// Return the length of the longest prefix of `bytes` that is left unchanged by `ascii_set`,
+/// that is made of ASCII bytes not in the set.
+///
+/// Most input is ASCII, so this loads a machine word at a time and only falls back to
+/// looking at individual bytes in words that contain non-ASCII bytes or bytes in the set.
+#[inline]
+fn unchanged_prefix_len(bytes: &[u8], ascii_set: &AsciiSet) -> usize {
+    const WORD_LEN: usize = mem::size_of::<usize>();
+    // 0x8080…80: the high bit of each byte
+    const HIGH_BITS: usize = usize::MAX / 0xFF * 0x80;
+
+    let mut len = 0;
+    for word in bytes.chunks_exact(WORD_LEN) {
+        let mut word_bytes = [0; WORD_LEN];
+        word_bytes.copy_from_slice(word);
+        if usize::from_ne_bytes(word_bytes) & HIGH_BITS != 0
+            || word_bytes.iter().any(|&byte| ascii_set.contains(byte))
+        {
+            break;
+        }
+        len += WORD_LEN;
+    }
+    len + bytes[len..]
+        .iter()
+        .position(|&byte| ascii_set.should_percent_encode(byte))
+        .unwrap_or(bytes.len() - len)
+}
+
+impl PercentEncode<'_, '_> {
+    /// Return the exact length of the percent-encoded string.
+    pub fn encoded_len(&self) -> usize {
+        self.bytes
+            .iter()
+            .map(|&byte| {
+                if self.ascii_set.should_percent_encode(byte) {
+                    3
+                } else {
+                    1
+                }
+            })
+            .sum()
+    }
+
+    /// Write the percent-encoded string to `dest`.
+    pub fn encode_to_fmt<W: fmt::Write + ?Sized>(self, dest: &mut W) -> fmt::Result {
+        for chunk in self {
+            dest.write_str(chunk)?
+        }
+        Ok(())
+    }
+
+    /// Write the percent-encoded string to `dest`.
+    #[cfg(feature = "std")]
+    pub fn encode_to_io<W: std::io::Write + ?Sized>(self, dest: &mut W) -> std::io::Result<()> {
+        for chunk in self {
+            dest.write_all(chunk.as_bytes())?
+        }
+        Ok(())
+    }
+
+    /// Write the percent-encoded string at the start of `dest` and return its length.
+    ///
+    /// If `dest` is shorter than [`encoded_len`][Self::encoded_len],
+    /// this returns an error and the contents of `dest` are unspecified.
+    ///
+    /// ```
+    /// use percent_encoding::{utf8_percent_encode, FRAGMENT};
+    ///
+    /// let mut buffer = [0; 16];
+    /// let len = utf8_percent_encode("<a b>", FRAGMENT).encode_into_slice(&mut buffer).unwrap();
+    /// assert_eq!(&buffer[..len], b"%3Ca%20b%3E");
+    /// ```
+    pub fn encode_into_slice(self, dest: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut len = 0;
+        for chunk in self {
+            write_into_slice(dest, &mut len, chunk.as_bytes())?
+        }
+        Ok(len)
+    }
+}
+
+/// The error returned when writing into a slice that is too short.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall(());
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer too small")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+fn write_into_slice(dest: &mut [u8], len: &mut usize, bytes: &[u8]) -> Result<(), BufferTooSmall> {
+    let end = *len + bytes.len();
+    dest.get_mut(*len..end)
+        .ok_or(BufferTooSmall(()))?
+        .copy_from_slice(bytes);
+    *len = end;
+    Ok(())
+}
+
+/// Percent-encode the bytes in `byte_set`, including non-ASCII bytes only if they are in the set.
+///
+/// Unlike [`percent_encode`], the result is not necessarily UTF-8 if `byte_set` does not contain
+/// all non-ASCII bytes, so the return type:
+///
+/// * Implements `Iterator<Item = &[u8]>`, so `.flatten().copied().collect::<Vec<u8>>()` collects it,
+/// * Implements `Into<Cow<[u8]>>` borrowing `input` when none of its bytes are encoded,
+/// * Has `encoded_len()`, `encode_into_slice()`, and `encode_to_io()` methods.
+///
+/// # Examples
+///
+/// ```
+/// use percent_encoding::{percent_encode_bytes_with, ByteSet, CONTROLS};
+///
+/// const SET: &ByteSet = &ByteSet::from_ascii_set(CONTROLS).add(b' ').add(0xFF);
+///
+/// let encoded: Vec<u8> = percent_encode_bytes_with(b"caf\xE9 \xFF", SET).flatten().copied().collect();
+/// assert_eq!(encoded, b"caf\xE9%20%FF");
+/// ```
+#[inline]
+pub fn percent_encode_bytes_with<'i, 's>(
+    input: &'i [u8],
+    byte_set: &'s ByteSet,
+) -> PercentEncodeBytes<'i, 's> {
+    PercentEncodeBytes {
+        bytes: input,
+        byte_set,
+    }
+}
+
+/// The return type of [`percent_encode_bytes_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PercentEncodeBytes<'i, 's> {
+    bytes: &'i [u8],
+    byte_set: &'s ByteSet,
+}
+
+impl<'i> Iterator for PercentEncodeBytes<'i, '_> {
+    type Item = &'i [u8];
+
+    fn next(&mut self) -> Option<&'i [u8]> {
+        let (&first_byte, remaining) = self.bytes.split_first()?;
+        if self.byte_set.contains(first_byte) {
+            self.bytes = remaining;
+            Some(percent_encode_byte(first_byte).as_bytes())
+        } else {
+            // 1 for first_byte
+            let unchanged_len = 1 + remaining
+                .iter()
+                .position(|&byte| self.byte_set.contains(byte))
+                .unwrap_or(remaining.len());
+            let (unchanged_slice, remaining) = self.bytes.split_at(unchanged_len);
+            self.bytes = remaining;
+            Some(unchanged_slice)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.bytes.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.bytes.len()))
+        }
+    }
+}
+
+impl PercentEncodeBytes<'_, '_> {
+    /// Return the exact length of the percent-encoded bytes.
+    pub fn encoded_len(&self) -> usize {
+        self.bytes
+            .iter()
+            .map(|&byte| if self.byte_set.contains(byte) { 3 } else { 1 })
+            .sum()
+    }
+
+    /// Write the percent-encoded bytes to `dest`.
+    #[cfg(feature = "std")]
+    pub fn encode_to_io<W: std::io::Write + ?Sized>(self, dest: &mut W) -> std::io::Result<()> {
+        for chunk in self {
+            dest.write_all(chunk)?
+        }
+        Ok(())
+    }
+
+    /// Write the percent-encoded bytes at the start of `dest` and return their length.
+    ///
+    /// If `dest` is shorter than [`encoded_len`][Self::encoded_len],
+    /// this returns an error and the contents of `dest` are unspecified.
+    pub fn encode_into_slice(self, dest: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut len = 0;
+        for chunk in self {
+            write_into_slice(dest, &mut len, chunk)?
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'i> From<PercentEncodeBytes<'i, '_>> for Cow<'i, [u8]> {
+    fn from(mut iter: PercentEncodeBytes<'i, '_>) -> Self {
+        match iter.next() {
+            None => Cow::Borrowed(&[]),
+            Some(first) => match iter.next() {
+                None => first.into(),
+                Some(second) => {
+                    let mut bytes = first.to_owned();
+                    bytes.extend_from_slice(second);
+                    bytes.extend(iter.flatten());
+                    bytes.into()
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for PercentEncode<'_, '_> {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         for c in (*self).clone() {
             formatter.write_str(c)?
@@ -191,8 +415,8 @@ impl fmt::Display for PercentEncode<'_> {
 }
 
 #[cfg(feature = "alloc")]
-impl<'a> From<PercentEncode<'a>> for Cow<'a, str> {
-    fn from(mut iter: PercentEncode<'a>) -> Self {
+impl<'i> From<PercentEncode<'i, '_>> for Cow<'i, str> {
+    fn from(mut iter: PercentEncode<'i, '_>) -> Self {
         match iter.next() {
             None => "".into(),
             Some(first) => match iter.next() {
@@ -243,6 +467,120 @@ pub fn percent_decode(input: &[u8]) -> PercentDecode<'_> {
     }
 }
 
+/// Percent-decode the given bytes, or return an error if a `%` sign
+/// is not followed by two hexadecimal digits.
+///
+/// See [`percent_decode`] regarding the return type.
+///
+/// # Examples
+///
+/// ```
+/// use percent_encoding::percent_decode_strict;
+///
+/// assert_eq!(percent_decode_strict(b"foo%20bar").unwrap().decode_utf8().unwrap(), "foo bar");
+/// assert_eq!(percent_decode_strict(b"100%").unwrap_err().offset(), 3);
+/// assert_eq!(percent_decode_strict(b"%41%G1").unwrap_err().offset(), 3);
+/// ```
+pub fn percent_decode_strict(input: &[u8]) -> Result<PercentDecode<'_>, InvalidPercentEncoding> {
+    let mut bytes_iter = input.iter();
+    while bytes_iter.any(|&b| b == b'%') {
+        if after_percent_sign(&mut bytes_iter).is_none() {
+            return Err(InvalidPercentEncoding {
+                offset: input.len() - bytes_iter.len() - 1,
+            });
+        }
+    }
+    Ok(percent_decode(input))
+}
+
+/// The error returned by [`percent_decode_strict`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPercentEncoding {
+    offset: usize,
+}
+
+impl InvalidPercentEncoding {
+    /// Return the byte offset in the input of the first `%` sign
+    /// not followed by two hexadecimal digits.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for InvalidPercentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected 2 hex digits after % at byte offset {}",
+            self.offset
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidPercentEncoding {}
+
+/// Percent-decode the given bytes, except for percent-encoded bytes in `keep_encoded`
+/// which are left unchanged.
+///
+/// See [`percent_decode`] regarding the return type, [`PercentDecodeExcept`],
+/// which has the same methods as [`PercentDecode`].
+///
+/// # Examples
+///
+/// ```
+/// use percent_encoding::{percent_decode_except, AsciiSet};
+///
+/// const SLASH: &AsciiSet = &AsciiSet::EMPTY.add(b'/');
+///
+/// let decoded = percent_decode_except(b"a%2fb%2Fc%20d", SLASH);
+/// assert_eq!(decoded.decode_utf8().unwrap(), "a%2fb%2Fc d");
+/// ```
+#[inline]
+pub fn percent_decode_except<'i, 's>(
+    input: &'i [u8],
+    keep_encoded: &'s AsciiSet,
+) -> PercentDecodeExcept<'i, 's> {
+    PercentDecodeExcept {
+        bytes: input.iter(),
+        keep_encoded,
+        uppercase_kept_hex: false,
+        pending_hex: [0; 2],
+        pending_len: 0,
+    }
+}
+
+/// Normalize percent-encoding: decode percent-encoded bytes that are not in `keep_encoded`,
+/// and use uppercase hexadecimal digits for those that are.
+///
+/// Percent-encoded non-ASCII bytes, which an [`AsciiSet`] cannot contain, are always kept
+/// encoded, so that the result is still ASCII if the input is.
+/// This is the percent-encoding normalization of
+/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-6.2.2.2)
+/// when `keep_encoded` contains every ASCII byte except unreserved characters.
+/// See [`percent_decode_except`] regarding the return type.
+///
+/// # Examples
+///
+/// ```
+/// use percent_encoding::{normalize_percent_encoding, AsciiSet, NON_ALPHANUMERIC};
+///
+/// const NOT_UNRESERVED: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+///
+/// let normalized = normalize_percent_encoding(b"%7euser%2fname%c3%a9", NOT_UNRESERVED);
+/// assert_eq!(normalized.collect::<Vec<u8>>(), b"~user%2Fname%C3%A9");
+/// ```
+#[inline]
+pub fn normalize_percent_encoding<'i, 's>(
+    input: &'i [u8],
+    keep_encoded: &'s AsciiSet,
+) -> PercentDecodeExcept<'i, 's> {
+    PercentDecodeExcept {
+        uppercase_kept_hex: true,
+        ..percent_decode_except(input, keep_encoded)
+    }
+}
+
 /// The return type of [`percent_decode`].
 #[derive(Clone, Debug)]
 pub struct PercentDecode<'a> {
@@ -287,6 +625,67 @@ impl<'a> From<PercentDecode<'a>> for Cow<'a, [u8]> {
 }
 
 impl<'a> PercentDecode<'a> {
+    /// Return the exact length of the percent-decoded bytes.
+    pub fn decoded_len(&self) -> usize {
+        let mut bytes_iter = self.bytes.clone();
+        let mut len = bytes_iter.len();
+        while bytes_iter.any(|&b| b == b'%') {
+            if after_percent_sign(&mut bytes_iter).is_some() {
+                len -= 2
+            }
+        }
+        len
+    }
+
+    /// Call `write` with consecutive slices of the percent-decoded bytes.
+    fn decode_chunks<E, F>(&self, mut write: F) -> Result<(), E>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        let mut bytes_iter = self.bytes.clone();
+        loop {
+            let run = bytes_iter.as_slice();
+            if !bytes_iter.any(|&b| b == b'%') {
+                return if run.is_empty() { Ok(()) } else { write(run) };
+            }
+            let unchanged_bytes_len = run.len() - bytes_iter.len() - 1;
+            match after_percent_sign(&mut bytes_iter) {
+                Some(decoded_byte) => {
+                    if unchanged_bytes_len > 0 {
+                        write(&run[..unchanged_bytes_len])?
+                    }
+                    write(&[decoded_byte])?
+                }
+                // Keep the % sign
+                None => write(&run[..unchanged_bytes_len + 1])?,
+            }
+        }
+    }
+
+    /// Write the percent-decoded bytes to `dest`.
+    #[cfg(feature = "std")]
+    pub fn decode_to_io<W: std::io::Write + ?Sized>(self, dest: &mut W) -> std::io::Result<()> {
+        self.decode_chunks(|bytes| dest.write_all(bytes))
+    }
+
+    /// Write the percent-decoded bytes at the start of `dest` and return their length.
+    ///
+    /// If `dest` is shorter than [`decoded_len`][Self::decoded_len],
+    /// this returns an error and the contents of `dest` are unspecified.
+    ///
+    /// ```
+    /// use percent_encoding::percent_decode_str;
+    ///
+    /// let mut buffer = [0; 16];
+    /// let len = percent_decode_str("a%20b%").decode_into_slice(&mut buffer).unwrap();
+    /// assert_eq!(&buffer[..len], b"a b%");
+    /// ```
+    pub fn decode_into_slice(self, dest: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut len = 0;
+        self.decode_chunks(|bytes| write_into_slice(dest, &mut len, bytes))?;
+        Ok(len)
+    }
+
     /// If the percent-decoding is different from the input, return it as a new bytes vector.
     #[cfg(feature = "alloc")]
     fn if_any(&self) -> Option<Vec<u8>> {
@@ -332,6 +731,228 @@ impl<'a> PercentDecode<'a> {
     }
 }
 
+/// The return type of [`percent_decode_except`] and [`normalize_percent_encoding`].
+#[derive(Clone, Debug)]
+pub struct PercentDecodeExcept<'i, 's> {
+    bytes: slice::Iter<'i, u8>,
+    keep_encoded: &'s AsciiSet,
+    uppercase_kept_hex: bool,
+    /// Hexadecimal digits of a percent-encoded byte kept encoded,
+    /// after its `%` sign was returned by `next()`
+    pending_hex: [u8; 2],
+    pending_len: u8,
+}
+
+enum PercentSign {
+    /// Followed by the two hexadecimal digits of this byte
+    Decoded(u8),
+    /// Followed by the two hexadecimal digits of a byte in `keep_encoded`, to be written as these
+    KeptEncoded([u8; 2]),
+    /// Not followed by two hexadecimal digits
+    Literal,
+}
+
+impl PercentDecodeExcept<'_, '_> {
+    /// Called after a `%` sign, consumes the hexadecimal digits that follow it if any.
+    fn after_percent_sign(&self, iter: &mut slice::Iter<'_, u8>) -> PercentSign {
+        let hex = iter.as_slice();
+        match after_percent_sign(iter) {
+            // Normalization keeps non-ASCII bytes encoded
+            Some(byte)
+                if self.keep_encoded.contains(byte)
+                    || (self.uppercase_kept_hex && !byte.is_ascii()) =>
+            {
+                let mut hex = [hex[0], hex[1]];
+                if self.uppercase_kept_hex {
+                    hex.make_ascii_uppercase()
+                }
+                PercentSign::KeptEncoded(hex)
+            }
+            Some(byte) => PercentSign::Decoded(byte),
+            None => PercentSign::Literal,
+        }
+    }
+
+    fn pending_hex(&self) -> &[u8] {
+        &self.pending_hex[2 - self.pending_len as usize..]
+    }
+}
+
+impl Iterator for PercentDecodeExcept<'_, '_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if let Some(&hex_digit) = self.pending_hex().first() {
+            self.pending_len -= 1;
+            return Some(hex_digit);
+        }
+        let byte = *self.bytes.next()?;
+        if byte != b'%' {
+            return Some(byte);
+        }
+        let mut bytes_iter = self.bytes.clone();
+        let after = self.after_percent_sign(&mut bytes_iter);
+        self.bytes = bytes_iter;
+        Some(match after {
+            PercentSign::Decoded(decoded_byte) => decoded_byte,
+            PercentSign::KeptEncoded(hex) => {
+                self.pending_hex = hex;
+                self.pending_len = 2;
+                byte
+            }
+            PercentSign::Literal => byte,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let bytes = self.bytes.len();
+        let pending = self.pending_len as usize;
+        ((bytes + 2) / 3 + pending, Some(bytes + pending))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'i> From<PercentDecodeExcept<'i, '_>> for Cow<'i, [u8]> {
+    fn from(iter: PercentDecodeExcept<'i, '_>) -> Self {
+        match iter.if_any() {
+            Some(vec) => Cow::Owned(vec),
+            None => Cow::Borrowed(iter.bytes.as_slice()),
+        }
+    }
+}
+
+impl<'i> PercentDecodeExcept<'i, '_> {
+    /// Return the exact length of the percent-decoded bytes.
+    pub fn decoded_len(&self) -> usize {
+        let mut bytes_iter = self.bytes.clone();
+        let mut len = bytes_iter.len() + self.pending_len as usize;
+        while bytes_iter.any(|&b| b == b'%') {
+            if let PercentSign::Decoded(_) = self.after_percent_sign(&mut bytes_iter) {
+                len -= 2
+            }
+        }
+        len
+    }
+
+    /// Call `write` with consecutive slices of the percent-decoded bytes.
+    fn decode_chunks<E, F>(&self, mut write: F) -> Result<(), E>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        if self.pending_len > 0 {
+            write(self.pending_hex())?
+        }
+        let mut bytes_iter = self.bytes.clone();
+        loop {
+            let run = bytes_iter.as_slice();
+            if !bytes_iter.any(|&b| b == b'%') {
+                return if run.is_empty() { Ok(()) } else { write(run) };
+            }
+            let unchanged_bytes_len = run.len() - bytes_iter.len() - 1;
+            match self.after_percent_sign(&mut bytes_iter) {
+                PercentSign::Decoded(decoded_byte) => {
+                    if unchanged_bytes_len > 0 {
+                        write(&run[..unchanged_bytes_len])?
+                    }
+                    write(&[decoded_byte])?
+                }
+                PercentSign::KeptEncoded([h, l]) => {
+                    if unchanged_bytes_len > 0 {
+                        write(&run[..unchanged_bytes_len])?
+                    }
+                    write(&[b'%', h, l])?
+                }
+                // Keep the % sign
+                PercentSign::Literal => write(&run[..unchanged_bytes_len + 1])?,
+            }
+        }
+    }
+
+    /// If the percent-decoding is different from the input, return it as a new bytes vector.
+    #[cfg(feature = "alloc")]
+    fn if_any(&self) -> Option<Vec<u8>> {
+        let initial_bytes = self.bytes.as_slice();
+        let mut unchanged_bytes_len = 0;
+        if self.pending_len == 0 {
+            let mut bytes_iter = self.bytes.clone();
+            loop {
+                if !bytes_iter.any(|&b| b == b'%') {
+                    // Nothing to change
+                    return None;
+                }
+                let hex = bytes_iter.as_slice();
+                let changed = match self.after_percent_sign(&mut bytes_iter) {
+                    PercentSign::Decoded(_) => true,
+                    PercentSign::KeptEncoded(kept_hex) => kept_hex != hex[..2],
+                    PercentSign::Literal => false,
+                };
+                if changed {
+                    unchanged_bytes_len = initial_bytes.len() - hex.len() - 1;
+                    break;
+                }
+            }
+        }
+        let mut decoded = initial_bytes[..unchanged_bytes_len].to_owned();
+        decoded.extend(PercentDecodeExcept {
+            bytes: initial_bytes[unchanged_bytes_len..].iter(),
+            ..self.clone()
+        });
+        Some(decoded)
+    }
+
+    /// Write the percent-decoded bytes to `dest`.
+    #[cfg(feature = "std")]
+    pub fn decode_to_io<W: std::io::Write + ?Sized>(self, dest: &mut W) -> std::io::Result<()> {
+        self.decode_chunks(|bytes| dest.write_all(bytes))
+    }
+
+    /// Write the percent-decoded bytes at the start of `dest` and return their length.
+    ///
+    /// If `dest` is shorter than [`decoded_len`][Self::decoded_len],
+    /// this returns an error and the contents of `dest` are unspecified.
+    ///
+    /// ```
+    /// use percent_encoding::{percent_decode_except, AsciiSet};
+    ///
+    /// let mut buffer = [0; 16];
+    /// let slash = AsciiSet::EMPTY.add(b'/');
+    /// let decoded = percent_decode_except(b"a%20b%2F", &slash);
+    /// let len = decoded.decode_into_slice(&mut buffer).unwrap();
+    /// assert_eq!(&buffer[..len], b"a b%2F");
+    /// ```
+    pub fn decode_into_slice(self, dest: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut len = 0;
+        self.decode_chunks(|bytes| write_into_slice(dest, &mut len, bytes))?;
+        Ok(len)
+    }
+
+    /// Decode the result of percent-decoding as UTF-8.
+    ///
+    /// This is return `Err` when the percent-decoded bytes are not well-formed in UTF-8.
+    #[cfg(feature = "alloc")]
+    pub fn decode_utf8(self) -> Result<Cow<'i, str>, str::Utf8Error> {
+        match self.clone().into() {
+            Cow::Borrowed(bytes) => match str::from_utf8(bytes) {
+                Ok(s) => Ok(s.into()),
+                Err(e) => Err(e),
+            },
+            Cow::Owned(bytes) => match String::from_utf8(bytes) {
+                Ok(s) => Ok(s.into()),
+                Err(e) => Err(e.utf8_error()),
+            },
+        }
+    }
+
+    /// Decode the result of percent-decoding as UTF-8, lossily.
+    ///
+    /// Invalid UTF-8 percent-encoded byte sequences will be replaced � U+FFFD,
+    /// the replacement character.
+    #[cfg(feature = "alloc")]
+    pub fn decode_utf8_lossy(self) -> Cow<'i, str> {
+        decode_utf8_lossy(self.clone().into())
+    }
+}
+
 // std::ptr::addr_eq was stabilized in rust 1.76. Once we upgrade
 // the MSRV we can remove this lint override.
 #[cfg(feature = "alloc")]
@@ -351,7 +972,7 @@ fn decode_utf8_lossy(input: Cow<'_, [u8]>) -> Cow<'_, str> {
 
                     // First we do a debug_assert to confirm our description above.
                     let raw_utf8: *const [u8] = utf8.as_bytes();
-                    debug_assert!(raw_utf8 == &*bytes as *const [u8]);
+                    debug_assert!(core::ptr::eq(raw_utf8, &*bytes));
 
                     // Given we know the original input bytes are valid UTF-8,
                     // and we have ownership of those bytes, we re-use them and
@@ -392,6 +1013,22 @@ mod tests {
         assert_eq!(encoded.collect::<String>(), String::from("%00%01%02%03"));
     }
 
+    #[test]
+    fn percent_encode_long_input() {
+        // Input is scanned a machine word at a time,
+        // so put bytes to encode at every offset within a word.
+        const SET: &AsciiSet = &CONTROLS.add(b' ');
+        for i in 0..20 {
+            let run = "a".repeat(i);
+            let input = alloc::format!("{0} {0}é{0}", run);
+            let expected = alloc::format!("{0}%20{0}%C3%A9{0}", run);
+            assert_eq!(
+                super::utf8_percent_encode(&input, SET).collect::<String>(),
+                expected
+            );
+        }
+    }
+
     #[test]
     fn percent_encode_display() {
         let encoded = percent_encode(b"foo bar?", NON_ALPHANUMERIC);
@@ -478,4 +1115,156 @@ mod tests {
             "\u{0}���"
         );
     }
+
+    #[test]
+    fn encode_to_sinks() {
+        let encoded = || super::utf8_percent_encode("a <b>é", FRAGMENT);
+        let expected = "a%20%3Cb%3E%C3%A9";
+        assert_eq!(encoded().encoded_len(), expected.len());
+
+        let mut string = String::new();
+        encoded().encode_to_fmt(&mut string).unwrap();
+        assert_eq!(string, expected);
+
+        let mut buffer = [0; 17];
+        assert_eq!(encoded().encode_into_slice(&mut buffer), Ok(17));
+        assert_eq!(&buffer[..], expected.as_bytes());
+        let mut buffer = [0; 16];
+        assert!(encoded().encode_into_slice(&mut buffer).is_err());
+
+        #[cfg(feature = "std")]
+        {
+            let mut vec = Vec::new();
+            encoded().encode_to_io(&mut vec).unwrap();
+            assert_eq!(vec, expected.as_bytes());
+        }
+    }
+
+    #[test]
+    fn decode_to_sinks() {
+        for &(input, expected) in &[
+            (&b""[..], &b""[..]),
+            (b"abc", b"abc"),
+            (b"%41%", b"A%"),
+            (b"%%41%4", b"%A%4"),
+            (b"a%zz%42c%c3%a9", b"a%zzBc\xC3\xA9"),
+        ] {
+            let decoded = super::percent_decode(input);
+            assert_eq!(decoded.decoded_len(), expected.len());
+
+            let mut buffer = [0; 32];
+            let len = decoded.clone().decode_into_slice(&mut buffer).unwrap();
+            assert_eq!(&buffer[..len], expected);
+            if !expected.is_empty() {
+                let mut buffer = alloc::vec![0; expected.len() - 1];
+                assert!(decoded.clone().decode_into_slice(&mut buffer).is_err());
+            }
+
+            #[cfg(feature = "std")]
+            {
+                let mut vec = Vec::new();
+                decoded.decode_to_io(&mut vec).unwrap();
+                assert_eq!(vec, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn strict_decoding() {
+        for &(input, offset) in &[(&b"%"[..], 0), (b"a%4", 1), (b"%41%G1", 3), (b"%%41", 0)] {
+            assert_eq!(
+                super::percent_decode_strict(input).unwrap_err().offset(),
+                offset
+            );
+        }
+        let decoded = super::percent_decode_strict(b"a%2fb").unwrap();
+        assert_eq!(decoded.collect::<Vec<u8>>(), b"a/b");
+    }
+
+    #[test]
+    fn decode_except_and_normalize() {
+        const SLASH: &AsciiSet = &AsciiSet::EMPTY.add(b'/');
+        let input = b"%2f%41%2F%zz%c3%A9";
+
+        let decoded = percent_decode_except(input, SLASH);
+        assert_eq!(decoded.decoded_len(), 12);
+        assert_eq!(decoded.clone().collect::<Vec<u8>>(), b"%2fA%2F%zz\xC3\xA9");
+        assert_eq!(&*Cow::from(decoded), b"%2fA%2F%zz\xC3\xA9");
+
+        let normalized = normalize_percent_encoding(input, SLASH);
+        let expected = b"%2FA%2F%zz%C3%A9";
+        assert_eq!(normalized.decoded_len(), expected.len());
+        assert_eq!(normalized.clone().collect::<Vec<u8>>(), expected);
+        assert_eq!(&*Cow::from(normalized.clone()), expected);
+        let mut buffer = [0; 16];
+        let len = normalized.clone().decode_into_slice(&mut buffer).unwrap();
+        assert_eq!(&buffer[..len], expected);
+
+        // Partially consumed, with hex digits pending
+        let mut partial = normalized;
+        assert_eq!(partial.next(), Some(b'%'));
+        assert_eq!(partial.size_hint(), (7, Some(17)));
+        assert_eq!(partial.decoded_len(), expected.len() - 1);
+        assert_eq!(&*Cow::from(partial.clone()), &expected[1..]);
+        assert_eq!(partial.collect::<Vec<u8>>(), &expected[1..]);
+
+        // Changed after an unchanged prefix
+        let changed = normalize_percent_encoding(b"a%zzb%2fc", SLASH);
+        assert_eq!(&*Cow::from(changed), b"a%zzb%2Fc");
+
+        // Nothing to change: borrowed
+        let unchanged = normalize_percent_encoding(b"a%2Fb%", SLASH);
+        assert!(matches!(Cow::from(unchanged), Cow::Borrowed(b"a%2Fb%")));
+        let unchanged = percent_decode_except(b"a%2fb", SLASH);
+        assert!(matches!(Cow::from(unchanged), Cow::Borrowed(b"a%2fb")));
+    }
+
+    #[test]
+    fn runtime_sets() {
+        fn encode_with_safe_characters(input: &str, safe_characters: &str) -> String {
+            let set = AsciiSet::from_fn(|byte| {
+                !byte.is_ascii_alphanumeric() && !safe_characters.as_bytes().contains(&byte)
+            });
+            super::utf8_percent_encode(input, &set).collect()
+        }
+        assert_eq!(encode_with_safe_characters("a b/c", "/"), "a%20b/c");
+        assert_eq!(encode_with_safe_characters("a b/c", " "), "a b%2Fc");
+
+        let keep_encoded = AsciiSet::EMPTY.add(b'/');
+        let decoded = percent_decode_except(b"%2F%20", &keep_encoded).collect::<Vec<u8>>();
+        assert_eq!(decoded, b"%2F ");
+
+        let byte_set = ByteSet::from_fn(|byte| byte >= 0xF0);
+        let encoded = percent_encode_bytes_with(b"\xEF\xF0", &byte_set);
+        assert_eq!(Cow::from(encoded), &b"\xEF%F0"[..]);
+
+        // The output borrows from the input only, so it can outlive a set built at runtime.
+        fn first_chunk(input: &str) -> &str {
+            let set = AsciiSet::from_fn(|byte| byte == b' ');
+            super::utf8_percent_encode(input, &set).next().unwrap()
+        }
+        assert_eq!(first_chunk("a/b c"), "a/b");
+    }
+
+    #[test]
+    fn percent_encode_bytes() {
+        const SET: &ByteSet = &ByteSet::from_ascii_set(CONTROLS)
+            .union(ByteSet::range(0x80, 0x9F))
+            .add(b'%');
+        let input = b"a\x00%\xE9\x85b";
+        let encoded = percent_encode_bytes_with(input, SET);
+        let expected = b"a%00%25\xE9%85b";
+        assert_eq!(encoded.encoded_len(), expected.len());
+        assert_eq!(
+            encoded.clone().flatten().copied().collect::<Vec<u8>>(),
+            expected
+        );
+        assert_eq!(&*Cow::from(encoded.clone()), expected);
+        let mut buffer = [0; 16];
+        let len = encoded.encode_into_slice(&mut buffer).unwrap();
+        assert_eq!(&buffer[..len], expected);
+
+        let unchanged = percent_encode_bytes_with(b"\xE9t\xE9", SET);
+        assert!(matches!(Cow::from(unchanged), Cow::Borrowed(b"\xE9t\xE9")));
+    }
 }
@@ -26,6 +26,15 @@ fn fragment(bench: &mut Bencher) {
     bench.iter(|| black_box(url).parse::<Url>().unwrap());
 }
 
+fn long_ascii(bench: &mut Bencher) {
+    let url = "https://example.com/static/assets/javascripts/application-bundle/vendor.min.js\
+               ?version=2024.10.18&cache_control=max_age_3600&utm_source=newsletter\
+               #section-getting-started-with-the-application";
+
+    bench.bytes = url.len() as u64;
+    bench.iter(|| black_box(url).parse::<Url>().unwrap());
+}
+
 fn plain(bench: &mut Bencher) {
     let url = "https://example.com/";
 
@@ -114,6 +123,7 @@ benchmark_group!(
     short,
     long,
     fragment,
+    long_ascii,
     plain,
     port,
     hyphen,
@@ -0,0 +1,408 @@
+// Copyright 2013-2024 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::host::Host;
+use crate::net::Ipv4Addr;
+use crate::Url;
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter, Write};
+use core::str;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// The characters that are percent-encoded in the addresses of `mailto:` URLs:
+/// all but the unreserved and `some-delims` characters of RFC 6068, except `,`,
+/// and the brackets of address literals.
+const MAILTO: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'!')
+    .remove(b'$')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')')
+    .remove(b'*')
+    .remove(b'+')
+    .remove(b';')
+    .remove(b':')
+    .remove(b'@')
+    .remove(b'[')
+    .remove(b']');
+
+/// Errors that can occur when parsing an [`EmailAddress`].
+///
+/// This may be extended in the future so exhaustive matching is
+/// discouraged with an unused variant.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum EmailError {
+    /// There is no `@` after the local part.
+    MissingAt,
+    /// The local part is neither a dot-atom nor a quoted string.
+    InvalidLocalPart,
+    /// The local part is longer than 64 bytes.
+    LocalPartTooLong,
+    /// The domain is not a valid domain name.
+    InvalidDomain,
+    /// The domain is a bracketed address literal, but not an IPv4 or IPv6 one.
+    InvalidAddressLiteral,
+    /// The address is longer than 254 bytes.
+    TooLong,
+    /// The address in a `mailto:` URL is not percent-encoded UTF-8.
+    InvalidEncoding,
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match *self {
+            EmailError::MissingAt => "missing @ in email address",
+            EmailError::InvalidLocalPart => "invalid local part in email address",
+            EmailError::LocalPartTooLong => "local part of email address longer than 64 bytes",
+            EmailError::InvalidDomain => "invalid domain in email address",
+            EmailError::InvalidAddressLiteral => "invalid address literal in email address",
+            EmailError::TooLong => "email address longer than 254 bytes",
+            EmailError::InvalidEncoding => "invalid percent-encoded UTF-8 in mailto: URL",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmailError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for EmailError {}
+
+/// An email address: an `addr-spec` of [RFC 5322](https://www.rfc-editor.org/rfc/rfc5322)
+/// in the form that [RFC 5321](https://www.rfc-editor.org/rfc/rfc5321) allows for SMTP,
+/// with the UTF-8 extensions of [RFC 6531](https://www.rfc-editor.org/rfc/rfc6531).
+///
+/// The local part is a dot-atom or a quoted string, without comments or folding white
+/// space. The domain goes through IDNA processing like the hosts of URLs do, but with the
+/// STD3 ASCII rules, or is an IPv4 or IPv6 address literal in brackets.
+///
+/// The local part is kept as it is, apart from unnecessary quoting, since its
+/// interpretation is up to the host of the mailbox.
+///
+/// ```rust
+/// use url::{EmailAddress, Host};
+/// # use url::EmailError;
+///
+/// # fn run() -> Result<(), EmailError> {
+/// let address = EmailAddress::parse("用户@例子.广告")?;
+/// assert_eq!(address.local_part(), "用户");
+/// assert_eq!(address.host(), Host::Domain("xn--fsqu00a.xn--4rr70v"));
+/// assert_eq!(address.to_string(), "用户@xn--fsqu00a.xn--4rr70v");
+/// assert_eq!(address.to_unicode(), "用户@例子.广告");
+/// assert_eq!(address.to_ascii(), None);
+///
+/// let address = EmailAddress::parse("\"john\"@[IPv6:2001:DB8::1]")?;
+/// assert_eq!(address.to_ascii().as_deref(), Some("john@[IPv6:2001:db8::1]"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmailAddress {
+    /// The local part without quotes and quoted-pair backslashes
+    local_part: String,
+    host: Host<String>,
+}
+
+impl EmailAddress {
+    /// Parse an email address.
+    pub fn parse(input: &str) -> Result<EmailAddress, EmailError> {
+        let (local_part, domain) = if let Some(quoted) = input.strip_prefix('"') {
+            let mut local_part = String::new();
+            let mut chars = quoted.char_indices();
+            let end = loop {
+                match chars.next() {
+                    Some((i, '"')) => break i + 1,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, c)) if (' '..='~').contains(&c) => local_part.push(c),
+                        _ => return Err(EmailError::InvalidLocalPart),
+                    },
+                    Some((_, c)) if is_qtext(c) => local_part.push(c),
+                    _ => return Err(EmailError::InvalidLocalPart),
+                }
+            };
+            let domain = quoted[end..]
+                .strip_prefix('@')
+                .ok_or(EmailError::MissingAt)?;
+            (local_part, domain)
+        } else {
+            let at = input.find('@').ok_or(EmailError::MissingAt)?;
+            let local_part = &input[..at];
+            if !is_dot_atom(local_part) {
+                return Err(EmailError::InvalidLocalPart);
+            }
+            (local_part.to_owned(), &input[at + 1..])
+        };
+        if local_part.is_empty() {
+            return Err(EmailError::InvalidLocalPart);
+        }
+        let address = EmailAddress {
+            local_part,
+            host: parse_domain(domain)?,
+        };
+        let mut serialization = String::new();
+        address.write_local_part(&mut serialization).unwrap();
+        if serialization.len() > 64 {
+            return Err(EmailError::LocalPartTooLong);
+        }
+        address.write_host(&mut serialization).unwrap();
+        if serialization.len() + 1 > 254 {
+            return Err(EmailError::TooLong);
+        }
+        Ok(address)
+    }
+
+    /// Return the local part, without quotes or backslash escapes.
+    pub fn local_part(&self) -> &str {
+        &self.local_part
+    }
+
+    /// Return the domain (in ASCII form), or the address of an address literal.
+    pub fn host(&self) -> Host<&str> {
+        match self.host {
+            Host::Domain(ref domain) => Host::Domain(domain),
+            Host::Ipv4(address) => Host::Ipv4(address),
+            Host::Ipv6(address) => Host::Ipv6(address),
+        }
+    }
+
+    /// Return the ASCII-only form of the address, with the domain in A-labels,
+    /// or `None` if the local part is not ASCII.
+    ///
+    /// There is no ASCII form of a non-ASCII local part, so such an address
+    /// can only be used with mail systems that support RFC 6531.
+    pub fn to_ascii(&self) -> Option<String> {
+        if self.local_part.is_ascii() {
+            Some(self.to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Return the form of the address for display, with the domain in Unicode.
+    pub fn to_unicode(&self) -> String {
+        let mut unicode = String::new();
+        self.write_local_part(&mut unicode).unwrap();
+        unicode.push('@');
+        match self.host {
+            Host::Domain(ref domain) => unicode.push_str(&idna::domain_to_unicode(domain).0),
+            _ => self.write_host(&mut unicode).unwrap(),
+        }
+        unicode
+    }
+
+    /// Return a `mailto:` URL for this address.
+    ///
+    /// ```rust
+    /// use url::EmailAddress;
+    /// # use url::EmailError;
+    ///
+    /// # fn run() -> Result<(), EmailError> {
+    /// let address = EmailAddress::parse("用户@例子.广告")?;
+    /// let url = address.to_mailto_url();
+    /// assert_eq!(url.as_str(), "mailto:%E7%94%A8%E6%88%B7@xn--fsqu00a.xn--4rr70v");
+    /// assert_eq!(url.mailto_addresses(), Some(Ok(vec![address])));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn to_mailto_url(&self) -> Url {
+        let mut serialization = String::from("mailto:");
+        serialization.extend(utf8_percent_encode(&self.to_string(), MAILTO));
+        Url::parse(&serialization).expect("percent-encoded mailto: URLs are valid")
+    }
+
+    fn write_local_part<W: Write>(&self, w: &mut W) -> fmt::Result {
+        if is_dot_atom(&self.local_part) {
+            return w.write_str(&self.local_part);
+        }
+        w.write_char('"')?;
+        for c in self.local_part.chars() {
+            if c == '"' || c == '\\' {
+                w.write_char('\\')?;
+            }
+            w.write_char(c)?;
+        }
+        w.write_char('"')
+    }
+
+    fn write_host<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self.host {
+            Host::Domain(ref domain) => w.write_str(domain),
+            Host::Ipv4(address) => write!(w, "[{}]", address),
+            Host::Ipv6(address) => {
+                // `Host` serializes IPv6 addresses in brackets
+                let host = Host::<&str>::Ipv6(address).to_string();
+                write!(w, "[IPv6:{}]", &host[1..host.len() - 1])
+            }
+        }
+    }
+}
+
+/// Serializes the address with the domain in A-labels.
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_local_part(f)?;
+        f.write_char('@')?;
+        self.write_host(f)
+    }
+}
+
+/// Parse an email address with `EmailAddress::parse`.
+impl str::FromStr for EmailAddress {
+    type Err = EmailError;
+
+    #[inline]
+    fn from_str(input: &str) -> Result<EmailAddress, EmailError> {
+        EmailAddress::parse(input)
+    }
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c) || !c.is_ascii()
+}
+
+fn is_qtext(c: char) -> bool {
+    matches!(c, ' ' | '!' | '#'..='[' | ']'..='~') || !c.is_ascii()
+}
+
+fn is_dot_atom(s: &str) -> bool {
+    s.split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+}
+
+fn parse_domain(domain: &str) -> Result<Host<String>, EmailError> {
+    if let Some(literal) = domain.strip_prefix('[') {
+        let literal = literal
+            .strip_suffix(']')
+            .ok_or(EmailError::InvalidAddressLiteral)?;
+        let is_ipv6 = literal
+            .as_bytes()
+            .get(..5)
+            .map_or(false, |tag| tag.eq_ignore_ascii_case(b"IPv6:"));
+        let host = if is_ipv6 {
+            let address = &literal[5..];
+            // No zone identifiers or other URL syntax
+            if !address
+                .chars()
+                .all(|c| c.is_ascii_hexdigit() || c == ':' || c == '.')
+            {
+                return Err(EmailError::InvalidAddressLiteral);
+            }
+            Host::parse(&["[", address, "]"].concat())
+        } else if let Some(address) = parse_ipv4_address_literal(literal) {
+            // Not `Host::parse`, which would read leading zeros as octal
+            return Ok(Host::Ipv4(address));
+        } else {
+            // General address literals with other tags are not supported
+            return Err(EmailError::InvalidAddressLiteral);
+        };
+        return match host {
+            Ok(host @ Host::Ipv4(_)) | Ok(host @ Host::Ipv6(_)) => Ok(host),
+            _ => Err(EmailError::InvalidAddressLiteral),
+        };
+    }
+    let ascii = idna::domain_to_ascii_cow(domain.as_bytes(), idna::AsciiDenyList::STD3)
+        .map_err(|_| EmailError::InvalidDomain)?;
+    if ascii.is_empty() || !idna::uts46::verify_dns_length(&ascii, false) {
+        return Err(EmailError::InvalidDomain);
+    }
+    // Names that a URL would take as an IPv4 address have to be address literals
+    match Host::parse(&ascii) {
+        Ok(Host::Domain(domain)) => Ok(Host::Domain(domain)),
+        _ => Err(EmailError::InvalidDomain),
+    }
+}
+
+/// Four decimal numbers up to 255, as in RFC 5321 `IPv4-address-literal`
+fn parse_ipv4_address_literal(literal: &str) -> Option<Ipv4Addr> {
+    let mut octets = [0u8; 4];
+    let mut numbers = literal.split('.');
+    for octet in &mut octets {
+        let number = numbers.next()?;
+        if number.is_empty() || number.len() > 3 || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        *octet = number.parse().ok()?;
+    }
+    match numbers.next() {
+        Some(_) => None,
+        None => Some(Ipv4Addr::from(octets)),
+    }
+}
+
+/// Split a list of addresses at the commas that are not in quoted local parts
+fn split_addresses(list: &str) -> Vec<&str> {
+    let mut addresses = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (i, c) in list.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                addresses.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    addresses.push(&list[start..]);
+    addresses
+}
+
+fn parse_percent_encoded_addresses(
+    list: &str,
+    addresses: &mut Vec<EmailAddress>,
+) -> Result<(), EmailError> {
+    let list = percent_decode_str(list)
+        .decode_utf8()
+        .map_err(|_| EmailError::InvalidEncoding)?;
+    if list.is_empty() {
+        return Ok(());
+    }
+    for address in split_addresses(&list) {
+        addresses.push(EmailAddress::parse(address)?);
+    }
+    Ok(())
+}
+
+/// <https://www.rfc-editor.org/rfc/rfc6068#section-2>
+pub(crate) fn url_mailto_addresses(url: &Url) -> Option<Result<Vec<EmailAddress>, EmailError>> {
+    if url.scheme() != "mailto" {
+        return None;
+    }
+    let mut addresses = Vec::new();
+    let result = parse_percent_encoded_addresses(url.path(), &mut addresses).and_then(|()| {
+        // `to` header fields add to the addresses in the path. Not `query_pairs`,
+        // which would decode `+` as a space.
+        for field in url.query().unwrap_or("").split('&') {
+            let (name, value) = match field.find('=') {
+                Some(equals) => (&field[..equals], &field[equals + 1..]),
+                None => continue,
+            };
+            if percent_decode_str(name)
+                .decode_utf8()
+                .map_or(false, |name| name.eq_ignore_ascii_case("to"))
+            {
+                parse_percent_encoded_addresses(value, &mut addresses)?;
+            }
+        }
+        Ok(())
+    });
+    Some(result.map(|()| addresses))
+}
@@ -90,6 +90,14 @@ impl Host<String> {
 
 impl<'a> Host<Cow<'a, str>> {
     pub(crate) fn parse_cow(input: Cow<'a, str>) -> Result<Self, ParseError> {
+        Self::parse_cow_reporting(input, None)
+    }
+
+    /// Like `parse_cow`, but call `idna_error_fn` with the details of IDNA errors.
+    pub(crate) fn parse_cow_reporting(
+        input: Cow<'a, str>,
+        idna_error_fn: Option<&dyn Fn(&idna::Errors)>,
+    ) -> Result<Self, ParseError> {
         if input.starts_with('[') {
             if !input.ends_with(']') {
                 return Err(ParseError::InvalidIpv6Address);
@@ -106,7 +114,22 @@ impl<'a> Host<Cow<'a, str>> {
             },
         };
 
-        let domain = idna::domain_to_ascii_from_cow(domain, idna::AsciiDenyList::URL)?;
+        let domain = match idna_error_fn {
+            None => idna::domain_to_ascii_from_cow(domain, idna::AsciiDenyList::URL)?,
+            // Errors are rare, so give up the `Cow` optimization of the common path
+            Some(idna_error_fn) => idna::uts46::Uts46::new()
+                .to_ascii_detailed(
+                    &domain,
+                    idna::AsciiDenyList::URL,
+                    idna::uts46::Hyphens::Allow,
+                    idna::uts46::DnsLength::Ignore,
+                )
+                .map(|domain| Cow::Owned(domain.into_owned()))
+                .map_err(|errors| {
+                    idna_error_fn(&errors);
+                    errors
+                })?,
+        };
 
         if domain.is_empty() {
             return Err(ParseError::EmptyHost);
@@ -186,6 +209,51 @@ impl<S: AsRef<str>> fmt::Display for Host<S> {
     }
 }
 
+#[cfg(feature = "uts39")]
+impl<S: AsRef<str>> Host<S> {
+    /// Return the host as it should be shown to users.
+    ///
+    /// Labels of a domain are converted from Punycode to Unicode when they pass the
+    /// [`idna::uts39::display_policy`] checks at the moderately restrictive level,
+    /// and are left in Punycode when they may be mistaken for another domain.
+    /// Addresses and domains that are not valid IDNA are returned like with `to_string`.
+    ///
+    /// ```
+    /// use url::Url;
+    ///
+    /// let url = Url::parse("https://bücher.example/").unwrap();
+    /// assert_eq!(url.host_str(), Some("xn--bcher-kva.example"));
+    /// assert_eq!(url.host().unwrap().to_display_string(), "bücher.example");
+    ///
+    /// // "аpple" with a Cyrillic "а"
+    /// let url = Url::parse("https://\u{430}pple.example/").unwrap();
+    /// assert_eq!(url.host().unwrap().to_display_string(), "xn--pple-43d.example");
+    ///
+    /// // "аррӏе" entirely in Cyrillic
+    /// let url = Url::parse("https://\u{430}\u{440}\u{440}\u{4CF}\u{435}.com/").unwrap();
+    /// assert_eq!(url.host().unwrap().to_display_string(), "xn--80ak6aa92e.com");
+    /// ```
+    pub fn to_display_string(&self) -> String {
+        use alloc::string::ToString;
+        use idna::uts39::{display_policy, RestrictionLevel};
+
+        let domain = match *self {
+            Host::Domain(ref domain) => domain.as_ref(),
+            _ => return self.to_string(),
+        };
+        let (display, result) = idna::uts46::Uts46::new().to_user_interface(
+            domain.as_bytes(),
+            idna::AsciiDenyList::URL,
+            idna::uts46::Hyphens::Allow,
+            display_policy(RestrictionLevel::ModeratelyRestrictive),
+        );
+        match result {
+            Ok(()) => display.into_owned(),
+            Err(_) => domain.to_owned(),
+        }
+    }
+}
+
 impl<S, T> PartialEq<Host<T>> for Host<S>
 where
     S: PartialEq<T>,
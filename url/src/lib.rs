@@ -178,6 +178,7 @@ use alloc::borrow::Cow;
 use alloc::borrow::ToOwned;
 use alloc::str;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::convert::TryFrom;
 use core::fmt::Write;
@@ -207,6 +208,7 @@ pub(crate) mod net {
     pub use core::net::*;
 }
 
+pub use crate::email::{EmailAddress, EmailError};
 pub use crate::host::Host;
 pub use crate::origin::{OpaqueOrigin, Origin};
 pub use crate::parser::{ParseError, SyntaxViolation};
@@ -214,6 +216,7 @@ pub use crate::path_segments::PathSegmentsMut;
 pub use crate::slicing::Position;
 pub use form_urlencoded::EncodingOverride;
 
+mod email;
 mod host;
 mod origin;
 mod parser;
@@ -256,6 +259,7 @@ pub struct ParseOptions<'a> {
     base_url: Option<&'a Url>,
     encoding_override: EncodingOverride<'a>,
     violation_fn: Option<&'a dyn Fn(SyntaxViolation)>,
+    idna_error_fn: Option<&'a dyn Fn(&idna::Errors)>,
 }
 
 impl<'a> ParseOptions<'a> {
@@ -302,6 +306,31 @@ impl<'a> ParseOptions<'a> {
         self
     }
 
+    /// Call the provided function or closure with the details of an IDNA error
+    /// before parsing fails with [`ParseError::IdnaError`].
+    /// The [`idna::Errors`] argument says what is wrong with each label of the host.
+    ///
+    /// [`ParseError::IdnaError`] itself deliberately carries no detail, so that it stays
+    /// a small `Copy` value that compares equal for every IDNA error; this callback is
+    /// the way to get the details.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::cell::RefCell;
+    /// use url::{Url, ParseError};
+    ///
+    /// let details = RefCell::new(Vec::new());
+    /// let result = Url::options()
+    ///     .idna_error_callback(Some(&|e| details.borrow_mut().push(e.to_string())))
+    ///     .parse("https://ok.xn--a-.example/");
+    /// assert_eq!(result, Err(ParseError::IdnaError));
+    /// assert_eq!(details.into_inner(), ["label 1 (\"xn--a-\"): invalid Punycode"]);
+    /// ```
+    pub fn idna_error_callback(mut self, new: Option<&'a dyn Fn(&idna::Errors)>) -> Self {
+        self.idna_error_fn = new;
+        self
+    }
+
     /// Parse an URL string with the configuration so far.
     pub fn parse(self, input: &str) -> Result<Url, crate::ParseError> {
         Parser {
@@ -309,6 +338,7 @@ impl<'a> ParseOptions<'a> {
             base_url: self.base_url,
             query_encoding_override: self.encoding_override,
             violation_fn: self.violation_fn,
+            idna_error_fn: self.idna_error_fn,
             context: Context::UrlParser,
         }
         .parse_url(input)
@@ -628,6 +658,7 @@ impl Url {
             base_url: None,
             encoding_override: None,
             violation_fn: None,
+            idna_error_fn: None,
         }
     }
 
@@ -884,6 +915,34 @@ impl Url {
         origin::url_origin(self)
     }
 
+    /// Return the email addresses of a `mailto:` URL, or `None` for URLs with other schemes.
+    ///
+    /// These are the comma-separated addresses of the path, followed by those of any
+    /// `to` header fields in the query, as in [RFC 6068](https://www.rfc-editor.org/rfc/rfc6068).
+    /// Addresses are percent-decoded as UTF-8 and parsed with [`EmailAddress::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// # use url::ParseError;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let url = Url::parse("mailto:a@example.com,b@b%C3%BCcher.example?to=c%2Bd@example.com&subject=Hi")?;
+    /// let addresses = url.mailto_addresses().unwrap().unwrap();
+    /// let addresses: Vec<String> = addresses.iter().map(|a| a.to_string()).collect();
+    /// assert_eq!(addresses, ["a@example.com", "b@xn--bcher-kva.example", "c+d@example.com"]);
+    ///
+    /// let url = Url::parse("https://example.com/")?;
+    /// assert!(url.mailto_addresses().is_none());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn mailto_addresses(&self) -> Option<Result<Vec<EmailAddress>, EmailError>> {
+        email::url_mailto_addresses(self)
+    }
+
     /// Return the scheme of this URL, lower-cased, as an ASCII string without the ':' delimiter.
     ///
     /// # Examples
@@ -14,26 +14,15 @@ use core::str;
 use crate::host::{Host, HostInternal};
 use crate::Url;
 use form_urlencoded::EncodingOverride;
-use percent_encoding::{percent_encode, utf8_percent_encode, AsciiSet, CONTROLS};
-
-/// https://url.spec.whatwg.org/#fragment-percent-encode-set
-const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+pub(crate) use percent_encoding::USERINFO;
+use percent_encoding::{
+    percent_encode, utf8_percent_encode, AsciiSet, CONTROLS, FRAGMENT, QUERY, SPECIAL_QUERY,
+};
 
 /// https://url.spec.whatwg.org/#path-percent-encode-set
-const PATH: &AsciiSet = &FRAGMENT.add(b'#').add(b'?').add(b'{').add(b'}');
-
-/// https://url.spec.whatwg.org/#userinfo-percent-encode-set
-pub(crate) const USERINFO: &AsciiSet = &PATH
-    .add(b'/')
-    .add(b':')
-    .add(b';')
-    .add(b'=')
-    .add(b'@')
-    .add(b'[')
-    .add(b'\\')
-    .add(b']')
-    .add(b'^')
-    .add(b'|');
+/// without `^`, which this crate does not percent-encode in paths yet,
+/// unlike [`percent_encoding::PATH`].
+const PATH: &AsciiSet = &QUERY.add(b'?').add(b'`').add(b'{').add(b'}');
 
 pub(crate) const PATH_SEGMENT: &AsciiSet = &PATH.add(b'/').add(b'%');
 
@@ -41,10 +30,6 @@ pub(crate) const PATH_SEGMENT: &AsciiSet = &PATH.add(b'/').add(b'%');
 // so it needs to be additionally escaped in that case.
 pub(crate) const SPECIAL_PATH_SEGMENT: &AsciiSet = &PATH_SEGMENT.add(b'\\');
 
-// https://url.spec.whatwg.org/#query-state
-const QUERY: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
-const SPECIAL_QUERY: &AsciiSet = &QUERY.add(b'\'');
-
 pub type ParseResult<T> = Result<T, ParseError>;
 
 macro_rules! simple_enum_error {
@@ -281,6 +266,17 @@ impl<'i> Input<'i> {
         }
     }
 
+    /// Advance to the first of the given ASCII bytes, or to the end of the input.
+    ///
+    /// Unlike `next`, this does not skip ASCII tabs and newlines,
+    /// so they should be part of `delimiters`.
+    #[inline]
+    fn skip_until_any<const N: usize>(&mut self, delimiters: [u8; N]) {
+        let remaining = self.chars.as_str();
+        let position = position_of_any(remaining.as_bytes(), delimiters);
+        self.chars = remaining[position..].chars();
+    }
+
     #[inline]
     fn next_utf8(&mut self) -> Option<(char, &'i str)> {
         loop {
@@ -327,7 +323,15 @@ impl<F: FnMut(char) -> bool> Pattern for F {
 impl Iterator for Input<'_> {
     type Item = char;
     fn next(&mut self) -> Option<char> {
-        self.chars.by_ref().find(|&c| !ascii_tab_or_new_line(c))
+        // Fast path for ASCII, which does not need UTF-8 decoding
+        let remaining = self.chars.as_str();
+        match remaining.as_bytes().first() {
+            Some(&b) if b.is_ascii() && !matches!(b, b'\t' | b'\n' | b'\r') => {
+                self.chars = remaining[1..].chars();
+                Some(b as char)
+            }
+            _ => self.chars.by_ref().find(|&c| !ascii_tab_or_new_line(c)),
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -340,6 +344,7 @@ pub struct Parser<'a> {
     pub base_url: Option<&'a Url>,
     pub query_encoding_override: EncodingOverride<'a>,
     pub violation_fn: Option<&'a dyn Fn(SyntaxViolation)>,
+    pub idna_error_fn: Option<&'a dyn Fn(&idna::Errors)>,
     pub context: Context,
 }
 
@@ -371,6 +376,7 @@ impl<'a> Parser<'a> {
             base_url: None,
             query_encoding_override: None,
             violation_fn: None,
+            idna_error_fn: None,
             context: Context::Setter,
         }
     }
@@ -957,7 +963,7 @@ impl<'a> Parser<'a> {
         scheme_end: u32,
         scheme_type: SchemeType,
     ) -> ParseResult<(u32, HostInternal, Option<u16>, Input<'i>)> {
-        let (host, remaining) = Parser::parse_host(input, scheme_type)?;
+        let (host, remaining) = Parser::parse_host(input, scheme_type, self.idna_error_fn)?;
         write!(&mut self.serialization, "{}", host).unwrap();
         let host_end = to_u32(self.serialization.len())?;
         if let Host::Domain(h) = &host {
@@ -988,12 +994,13 @@ impl<'a> Parser<'a> {
         Ok((host_end, host.into(), port, remaining))
     }
 
-    pub fn parse_host(
-        mut input: Input<'_>,
+    pub fn parse_host<'i>(
+        mut input: Input<'i>,
         scheme_type: SchemeType,
-    ) -> ParseResult<(Host<Cow<'_, str>>, Input<'_>)> {
+        idna_error_fn: Option<&dyn Fn(&idna::Errors)>,
+    ) -> ParseResult<(Host<Cow<'i, str>>, Input<'i>)> {
         if scheme_type.is_file() {
-            return Parser::get_file_host(input);
+            return Parser::get_file_host(input, idna_error_fn);
         }
         // Undo the Input abstraction here to avoid allocating in the common case
         // where the host part of the input does not contain any tab or newline
@@ -1039,17 +1046,18 @@ impl<'a> Parser<'a> {
             let host = Host::parse_opaque_cow(host_str)?;
             return Ok((host, input));
         }
-        let host = Host::parse_cow(host_str)?;
+        let host = Host::parse_cow_reporting(host_str, idna_error_fn)?;
         Ok((host, input))
     }
 
-    fn get_file_host(input: Input<'_>) -> ParseResult<(Host<Cow<'_, str>>, Input<'_>)> {
+    fn get_file_host<'i>(
+        input: Input<'i>,
+        idna_error_fn: Option<&dyn Fn(&idna::Errors)>,
+    ) -> ParseResult<(Host<Cow<'i, str>>, Input<'i>)> {
         let (_, host_str, remaining) = Parser::file_host(input)?;
-        let host = match Host::parse(&host_str)? {
+        let host = match Host::parse_cow_reporting(host_str, idna_error_fn)? {
             Host::Domain(ref d) if d == "localhost" => Host::Domain(Cow::Borrowed("")),
-            Host::Domain(s) => Host::Domain(Cow::Owned(s)),
-            Host::Ipv4(ip) => Host::Ipv4(ip),
-            Host::Ipv6(ip) => Host::Ipv6(ip),
+            host => host,
         };
         Ok((host, remaining))
     }
@@ -1064,7 +1072,7 @@ impl<'a> Parser<'a> {
             has_host = false;
             HostInternal::None
         } else {
-            match Host::parse_cow(host_str)? {
+            match Host::parse_cow_reporting(host_str, self.idna_error_fn)? {
                 Host::Domain(ref d) if d == "localhost" => {
                     has_host = false;
                     HostInternal::None
@@ -1216,12 +1224,19 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // Without violation reporting or Windows drive letters, only delimiters
+        // and ignored characters need to be looked at individually.
+        let skip_plain_runs = self.violation_fn.is_none() && !scheme_type.is_file();
+
         // Relative path state
         loop {
             let mut segment_start = self.serialization.len();
             let mut ends_with_slash = false;
             let mut start_str = input.chars.as_str();
             loop {
+                if skip_plain_runs {
+                    input.skip_until_any([b'\t', b'\n', b'\r', b'/', b'\\', b'?', b'#']);
+                }
                 let input_before_c = input.clone();
                 // bypass input.next() and manually handle ascii_tab_or_new_line
                 // in order to encode string slices in bulk
@@ -1560,7 +1575,14 @@ impl<'a> Parser<'a> {
                 let start = self.input.chars.as_str();
                 // bypass self.input.next() in order to get string slices
                 // which are faster to operate on
-                while let Some(c) = self.input.chars.next() {
+                loop {
+                    if self.violation_fn.is_none() {
+                        self.input.skip_until_any([b'\t', b'\n', b'\r', b'#']);
+                    }
+                    let c = match self.input.chars.next() {
+                        Some(c) => c,
+                        None => break,
+                    };
                     match c {
                         ascii_tab_or_new_line_pattern!() => {
                             return Some((
@@ -1655,7 +1677,14 @@ impl<'a> Parser<'a> {
                 let start = self.input.chars.as_str();
                 // bypass self.input.next() in order to get string slices
                 // which are faster to operate on
-                while let Some(c) = self.input.chars.next() {
+                loop {
+                    if self.violation_fn.is_none() {
+                        self.input.skip_until_any([b'\t', b'\n', b'\r']);
+                    }
+                    let c = match self.input.chars.next() {
+                        Some(c) => c,
+                        None => break,
+                    };
                     match c {
                         ascii_tab_or_new_line_pattern!() => {
                             return Some(
@@ -1813,6 +1842,44 @@ fn starts_with_windows_drive_letter_segment(input: &Input<'_>) -> bool {
     }
 }
 
+/// Return the index of the first byte of `haystack` that is one of the ASCII `needles`,
+/// or the length of `haystack` if there is none.
+///
+/// This compares a machine word at a time: URLs are mostly long runs of bytes that the parser
+/// copies without looking at them individually, and non-ASCII bytes never match.
+#[inline]
+fn position_of_any<const N: usize>(haystack: &[u8], needles: [u8; N]) -> usize {
+    const WORD_LEN: usize = core::mem::size_of::<usize>();
+    // 0x0101…01: the low bit of each byte
+    const LOW_BITS: usize = usize::MAX / 0xFF;
+    // 0x8080…80: the high bit of each byte
+    const HIGH_BITS: usize = LOW_BITS * 0x80;
+
+    let mut position = 0;
+    for word in haystack.chunks_exact(WORD_LEN) {
+        let mut word_bytes = [0; WORD_LEN];
+        word_bytes.copy_from_slice(word);
+        let word = usize::from_ne_bytes(word_bytes);
+        let mut has_needle = 0;
+        for &needle in &needles {
+            // Bytes equal to `needle` become zero, which the subtraction turns into
+            // bytes with the high bit set. Borrows can flag more bytes after a zero byte,
+            // but only in words that do contain a needle.
+            let xor = word ^ (LOW_BITS * needle as usize);
+            has_needle |= xor.wrapping_sub(LOW_BITS) & !xor & HIGH_BITS;
+        }
+        if has_needle != 0 {
+            break;
+        }
+        position += WORD_LEN;
+    }
+    position
+        + haystack[position..]
+            .iter()
+            .position(|byte| needles.contains(byte))
+            .unwrap_or(haystack.len() - position)
+}
+
 #[inline]
 fn fast_u16_to_str(
     // max 5 digits for u16 (65535)
@@ -165,7 +165,7 @@ pub fn set_host(url: &mut Url, new_host: &str) -> Result<(), ()> {
             return Ok(());
         }
 
-        if let Ok((h, remaining)) = Parser::parse_host(input, scheme_type) {
+        if let Ok((h, remaining)) = Parser::parse_host(input, scheme_type, None) {
             host = h;
             opt_port = if let Some(remaining) = remaining.split_prefix(':') {
                 if remaining.is_empty() {
@@ -212,7 +212,7 @@ pub fn set_hostname(url: &mut Url, new_hostname: &str) -> Result<(), ()> {
         return Ok(());
     }
 
-    if let Ok((host, _remaining)) = Parser::parse_host(input, scheme_type) {
+    if let Ok((host, _remaining)) = Parser::parse_host(input, scheme_type, None) {
         if let Host::Domain(h) = &host {
             if h.is_empty() {
                 // Empty host on special not file url
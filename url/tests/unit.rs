@@ -460,6 +460,399 @@ fn test_form_serialize() {
     assert_eq!(encoded, "foo=%C3%A9%26&bar=&foo=%23&json");
 }
 
+#[test]
+fn form_urlencoded_streaming_parser() {
+    let input: &[u8] = b"a=1&&b=%41%2&%%4=%G1+%2B=&=x&c%3D=%C3%A9%\xFF&last";
+    let expected: Vec<(String, String)> = form_urlencoded::parse(input).into_owned().collect();
+    // Split the input in two at every position, and in single bytes
+    for split in 0..=input.len() {
+        let mut parser = form_urlencoded::FormParser::new();
+        let (first, second) = input.split_at(split);
+        let mut pairs: Vec<_> = parser.feed(first).unwrap().collect();
+        pairs.extend(parser.feed(second).unwrap());
+        pairs.extend(parser.finish().unwrap());
+        assert_eq!(pairs, expected, "split at {}", split);
+    }
+    let mut parser = form_urlencoded::FormParser::new();
+    let mut pairs = Vec::new();
+    for byte in input.chunks(1) {
+        pairs.extend(parser.feed(byte).unwrap());
+    }
+    pairs.extend(parser.finish().unwrap());
+    assert_eq!(pairs, expected);
+
+    let read: Vec<_> = form_urlencoded::FormParser::new()
+        .parse_reader(input)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(read, expected);
+}
+
+#[test]
+fn form_urlencoded_streaming_parser_limits() {
+    use form_urlencoded::{FormParser, LimitExceeded};
+
+    let mut parser = FormParser::new().max_pairs(2);
+    assert_eq!(parser.feed(b"a&&b&").unwrap().count(), 2);
+    assert_eq!(parser.feed(b"c&").unwrap_err(), LimitExceeded::PairCount);
+    assert_eq!(parser.feed(b"").unwrap_err(), LimitExceeded::PairCount);
+
+    // Lengths are checked after percent-decoding
+    let parser = FormParser::new().max_name_len(3).max_value_len(1);
+    let mut pairs = parser.clone().parse_reader(&b"%41%42%43=%44&e=f"[..]);
+    assert_eq!(pairs.next().unwrap().unwrap(), ("ABC".into(), "D".into()));
+    assert_eq!(pairs.next().unwrap().unwrap(), ("e".into(), "f".into()));
+    assert!(pairs.next().is_none());
+    let mut long_name = parser.clone();
+    assert_eq!(
+        long_name.feed(b"abcd").unwrap_err(),
+        LimitExceeded::NameLength
+    );
+    let mut long_value = parser.clone();
+    assert_eq!(
+        long_value.feed(b"a=b").unwrap().count(),
+        0,
+        "the pair is not complete"
+    );
+    assert_eq!(
+        long_value.feed(b"c").unwrap_err(),
+        LimitExceeded::ValueLength
+    );
+
+    // Pairs before the error are read first
+    let mut pairs = parser.parse_reader(&b"a=b&c=de"[..]);
+    assert_eq!(pairs.next().unwrap().unwrap(), ("a".into(), "b".into()));
+    let error = pairs.next().unwrap().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(pairs.next().is_none());
+}
+
+#[test]
+fn form_urlencoded_strict() {
+    use form_urlencoded::ParseErrorKind::*;
+
+    let errors = |input: &str| -> Vec<_> {
+        form_urlencoded::parse_strict(input.as_bytes())
+            .map(|item| match item {
+                Ok((name, value)) => Ok((name.into_owned(), value.into_owned())),
+                Err(e) => Err((e.kind(), e.offset())),
+            })
+            .collect()
+    };
+    assert_eq!(
+        errors("a=b+c&&d=%C3%A9;e"),
+        [
+            Ok(("a".into(), "b c".into())),
+            Ok(("d".into(), "é;e".into()))
+        ]
+    );
+    assert_eq!(
+        errors("a=%2&b=%zz1&c%=d&e=%41%E9&=f&%C3=g&h"),
+        [
+            Err((MalformedEscape, 2)),
+            Err((MalformedEscape, 7)),
+            Err((MalformedEscape, 13)),
+            Err((InvalidUtf8, 22)),
+            Err((EmptyName, 26)),
+            Err((InvalidUtf8, 29)),
+            Ok(("h".into(), "".into())),
+        ]
+    );
+    let bytes: Vec<_> = form_urlencoded::parse_strict(b"a\xFF=b").collect();
+    assert_eq!(bytes[0].as_ref().unwrap_err().offset(), 1);
+    assert_eq!(
+        form_urlencoded::parse_strict(b"%=")
+            .next()
+            .unwrap()
+            .unwrap_err()
+            .to_string(),
+        "malformed percent-encoded byte at byte 0"
+    );
+
+    // Valid input gives the same pairs as `parse`
+    let input = b"a=1&&b=%41%2B+x=y=z&c%3D=%C3%A9&last";
+    let lossy: Vec<_> = form_urlencoded::parse(input).collect();
+    let strict: Vec<_> = form_urlencoded::parse_strict(input)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(strict, lossy);
+}
+
+#[test]
+fn form_urlencoded_generic_query() {
+    let input = b"a=1+1;b=%2B&c;;=d";
+    let pairs: Vec<_> = form_urlencoded::parse(input)
+        .generic_query()
+        .into_owned()
+        .collect();
+    assert_eq!(
+        pairs,
+        [
+            ("a".to_owned(), "1+1".to_owned()),
+            ("b".to_owned(), "+".to_owned()),
+            ("c".to_owned(), "".to_owned()),
+            ("".to_owned(), "d".to_owned()),
+        ]
+    );
+    let strict: Vec<_> = form_urlencoded::parse_strict(input)
+        .generic_query()
+        .map(|item| item.map_err(|e| (e.kind(), e.offset())))
+        .collect();
+    assert_eq!(
+        strict,
+        [
+            Ok(("a".into(), "1+1".into())),
+            Ok(("b".into(), "+".into())),
+            Ok(("c".into(), "".into())),
+            Err((form_urlencoded::ParseErrorKind::EmptyName, 15)),
+        ]
+    );
+}
+
+#[test]
+fn form_urlencoded_sinks() {
+    use form_urlencoded::{ByteCount, Serializer, SinkSerializer, WriteSink};
+
+    let pairs = [("foo", "bar & baz"), ("saison", "Été+hiver"), ("", "")];
+    let expected = Serializer::new(String::new())
+        .extend_pairs(&pairs)
+        .append_key_only("key")
+        .finish();
+    assert_eq!(
+        expected,
+        "foo=bar+%26+baz&saison=%C3%89t%C3%A9%2Bhiver&=&key"
+    );
+
+    let string = SinkSerializer::new(String::new())
+        .extend_pairs(&pairs)
+        .append_key_only("key")
+        .finish();
+    assert_eq!(string, expected);
+    let mut vec = b"body: ".to_vec();
+    SinkSerializer::new(&mut vec)
+        .extend_pairs(&pairs)
+        .extend_keys_only::<_, &str>(&["key"])
+        .finish();
+    assert_eq!(vec, [&b"body: "[..], expected.as_bytes()].concat());
+    let len = SinkSerializer::new(ByteCount::default())
+        .extend_pairs(&pairs)
+        .append_key_only("key")
+        .finish();
+    assert_eq!(len, expected.len());
+
+    let written = SinkSerializer::new(WriteSink::new(Vec::new()))
+        .encoding_override(Some(&|s| s.as_bytes().to_ascii_uppercase().into()))
+        .append_pair("a", "b")
+        .finish()
+        .unwrap();
+    assert_eq!(written, b"A=B");
+
+    // The first error is returned, and nothing is written after it
+    #[derive(Debug)]
+    struct Limited(Vec<u8>);
+    impl std::io::Write for Limited {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.0.len() + buf.len() > 5 {
+                return Err(std::io::Error::new(std::io::ErrorKind::WriteZero, "full"));
+            }
+            self.0.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let mut limited = Limited(Vec::new());
+    let error = SinkSerializer::new(WriteSink::new(&mut limited))
+        .append_pair("a", "b")
+        .append_pair("cdef", "g")
+        .append_key_only("h")
+        .finish()
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::WriteZero);
+    assert_eq!(limited.0, b"a=b&");
+}
+
+#[test]
+fn form_urlencoded_multipart() {
+    use form_urlencoded::{
+        parse_multipart, MultipartEvent, MultipartParser, MultipartSerializer, PartHeaders,
+    };
+
+    let mut serializer = MultipartSerializer::with_boundary(Vec::new(), "boundary");
+    let body = serializer
+        .append_pair("a\rb\"", "1\n2\r\n3")
+        .append_file("f", "x\ny.bin", "", b"\r\n--boundar\0\xFF")
+        .append_file("g", "", "text/plain;\r\ncharset=utf-8", b"")
+        .finish();
+    assert_eq!(
+        String::from_utf8_lossy(&body),
+        "--boundary\r\n\
+         Content-Disposition: form-data; name=\"a%0D%0Ab%22\"\r\n\
+         \r\n\
+         1\r\n2\r\n3\r\n\
+         --boundary\r\n\
+         Content-Disposition: form-data; name=\"f\"; filename=\"x%0Ay.bin\"\r\n\
+         Content-Type: application/octet-stream\r\n\
+         \r\n\
+         \r\n--boundar\0\u{FFFD}\r\n\
+         --boundary\r\n\
+         Content-Disposition: form-data; name=\"g\"; filename=\"\"\r\n\
+         Content-Type: text/plain;charset=utf-8\r\n\
+         \r\n\
+         \r\n\
+         --boundary--\r\n"
+    );
+
+    let expected = [
+        (
+            PartHeaders {
+                name: "a\r\nb\"".into(),
+                filename: None,
+                content_type: None,
+            },
+            &b"1\r\n2\r\n3"[..],
+        ),
+        (
+            PartHeaders {
+                name: "f".into(),
+                filename: Some("x\ny.bin".into()),
+                content_type: Some("application/octet-stream".into()),
+            },
+            &b"\r\n--boundar\0\xFF"[..],
+        ),
+        (
+            PartHeaders {
+                name: "g".into(),
+                filename: Some("".into()),
+                content_type: Some("text/plain;charset=utf-8".into()),
+            },
+            &b""[..],
+        ),
+    ];
+    let parts = parse_multipart(&body, "boundary").unwrap();
+    assert_eq!(parts.len(), expected.len());
+    for (part, (headers, body)) in parts.iter().zip(&expected) {
+        assert_eq!(part.headers, *headers);
+        assert_eq!(part.body, *body);
+    }
+
+    // Split the input in two at every position
+    for split in 0..=body.len() {
+        let mut parser = MultipartParser::new("boundary");
+        let (first, second) = body.split_at(split);
+        let mut events: Vec<_> = parser.feed(first).unwrap().collect();
+        events.extend(parser.feed(second).unwrap());
+        parser.finish().unwrap();
+        let mut parts = Vec::new();
+        for event in events {
+            match event {
+                MultipartEvent::PartStart(headers) => parts.push((headers, Vec::new())),
+                MultipartEvent::Data(data) => parts.last_mut().unwrap().1.extend(data),
+                MultipartEvent::PartEnd => {}
+            }
+        }
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(headers, body)| (headers.clone(), body.to_vec()))
+            .collect();
+        assert_eq!(parts, expected, "split at {}", split);
+    }
+
+    // Feed one byte at a time
+    let mut parser = MultipartParser::new("boundary");
+    let mut data = Vec::new();
+    for byte in &body {
+        for event in parser.feed(&[*byte]).unwrap() {
+            if let MultipartEvent::Data(chunk) = event {
+                data.extend(chunk)
+            }
+        }
+    }
+    parser.finish().unwrap();
+    let expected_data: Vec<u8> = expected
+        .iter()
+        .flat_map(|(_, body)| body.to_vec())
+        .collect();
+    assert_eq!(data, expected_data);
+
+    let serializer = MultipartSerializer::new(Vec::new());
+    assert!(serializer.boundary().len() <= 70);
+    assert_ne!(
+        serializer.boundary(),
+        MultipartSerializer::new(Vec::new()).boundary()
+    );
+}
+
+#[test]
+fn form_urlencoded_multipart_invalid() {
+    use form_urlencoded::{parse_multipart, MultipartParser};
+
+    let part = "Content-Disposition: form-data; name=\"a\"\r\n\r\nb\r\n";
+    let valid = format!("--XyZ\r\n{}--XyZ--\r\n", part);
+    assert_eq!(parse_multipart(valid.as_bytes(), "XyZ").unwrap().len(), 1);
+    for invalid in [
+        format!("preamble\r\n--XyZ\r\n{}--XyZ--\r\n", part),
+        format!("--XyZ\r\n{}--XyZ--\r\nepilogue", part),
+        format!("--XyZ\r\n{}--XyZ--", part),
+        format!("--XyZ\r\n{}", part),
+        "--XyZ\r\n\r\nb\r\n--XyZ--\r\n".to_owned(),
+        "--XyZ\r\nContent-Type: text/plain\r\n\r\nb\r\n--XyZ--\r\n".to_owned(),
+        "--XyZ\r\nContent-Disposition: inline\r\n\r\nb\r\n--XyZ--\r\n".to_owned(),
+        "--XyZ\r\nBad Header: x\r\n\r\nb\r\n--XyZ--\r\n".to_owned(),
+        "--XyZ \r\n".to_owned(),
+    ] {
+        assert!(
+            parse_multipart(invalid.as_bytes(), "XyZ").is_err(),
+            "{:?}",
+            invalid
+        );
+    }
+
+    // Header names are case-insensitive and trimmed, and other headers are ignored
+    let parts = parse_multipart(
+        b"--XyZ\r\ncontent-disposition \t:form-data; name=\"a\"; filename=\"%22.txt\"  \r\n\
+          X-Other: y\r\nCONTENT-TYPE: \ttext/plain \r\n\r\n\r\n--XyZ--\r\n",
+        "XyZ",
+    )
+    .unwrap();
+    assert_eq!(parts[0].headers.filename.as_deref(), Some("\".txt"));
+    assert_eq!(parts[0].headers.content_type.as_deref(), Some("text/plain"));
+
+    let mut parser = MultipartParser::new("XyZ").max_headers_len(10);
+    assert!(parser.feed(valid.as_bytes()).is_err());
+    assert!(parser.feed(b"").is_err());
+    let mut parser = MultipartParser::new("XyZ").max_headers_len(10);
+    parser.feed(b"--XyZ\r\n").unwrap();
+    assert!(parser.feed(&[b'a'; 20]).is_err());
+}
+
+#[test]
+fn form_urlencoded_text_plain() {
+    use form_urlencoded::{parse_text_plain, TextPlainSerializer};
+
+    let mut body = String::from("x=y\r\n");
+    TextPlainSerializer::new(&mut body)
+        .extend_pairs(&[("a\nb", "c=d\re"), ("", "")])
+        .append_file("f", "g.txt")
+        .finish();
+    assert_eq!(body, "x=y\r\na\r\nb=c=d\r\ne\r\n=\r\nf=g.txt\r\n");
+    let pairs: Vec<_> = parse_text_plain(&body).collect();
+    assert_eq!(
+        pairs,
+        [
+            ("x", "y"),
+            ("a", ""),
+            ("b", "c=d"),
+            ("e", ""),
+            ("", ""),
+            ("f", "g.txt")
+        ]
+    );
+    let pairs: Vec<_> = parse_text_plain("a=1\n\nb").collect();
+    assert_eq!(pairs, [("a", "1"), ("b", "")]);
+}
+
 #[test]
 fn form_urlencoded_encoding_override() {
     let encoded = form_urlencoded::Serializer::new(String::new())
@@ -944,6 +1337,32 @@ fn test_syntax_violation_callback_types() {
     }
 }
 
+#[test]
+fn test_idna_error_callback() {
+    use idna::uts46::LabelErrorKind;
+    let errors = RefCell::new(Vec::new());
+    let efn = |e: &idna::Errors| errors.borrow_mut().extend_from_slice(e.label_errors());
+    let options = Url::options().idna_error_callback(Some(&efn));
+
+    let url = options.parse("https://B\u{FC}cher.example/").unwrap();
+    assert_eq!(url.host_str(), Some("xn--bcher-kva.example"));
+    assert!(errors.borrow().is_empty());
+
+    assert_eq!(
+        options.parse("https://a\u{200D}b.example/"),
+        Err(url::ParseError::IdnaError)
+    );
+    assert_eq!(
+        options.parse("file://xn--a-/path"),
+        Err(url::ParseError::IdnaError)
+    );
+    let errors = errors.into_inner();
+    assert_eq!(errors.len(), 2);
+    assert_eq!((errors[0].index(), errors[0].label()), (0, "a\u{200D}b"));
+    assert_eq!(errors[0].kind(), LabelErrorKind::ContextJ('\u{200D}'));
+    assert_eq!(errors[1].kind(), LabelErrorKind::InvalidPunycode);
+}
+
 #[test]
 fn test_options_reuse() {
     use url::SyntaxViolation::*;
@@ -1387,3 +1806,203 @@ fn serde_error_message() {
         r#"relative URL without a base: "§invalid#+#*Ä" at line 1 column 25"#
     );
 }
+
+#[test]
+fn long_ascii_runs() {
+    // Runs of ASCII are scanned a machine word at a time,
+    // so put delimiters and ignored characters at every offset within a word.
+    for i in 0..20 {
+        let run = "a".repeat(i);
+        let input = format!("http://example.com/{run}\t/{run}\\é{run}?{run} \n#{run}\r{run}`");
+        let expected = format!("http://example.com/{run}/{run}/%C3%A9{run}?{run}%20#{run}{run}%60");
+        assert_eq!(Url::parse(&input).unwrap().as_str(), expected);
+
+        // With a syntax violation callback, each code point is looked at individually
+        let violations = RefCell::new(Vec::new());
+        let url = Url::options()
+            .syntax_violation_callback(Some(&|v| violations.borrow_mut().push(v)))
+            .parse(&input)
+            .unwrap();
+        assert_eq!(url.as_str(), expected);
+        assert!(!violations.borrow().is_empty());
+    }
+}
+
+#[test]
+fn email_addresses() {
+    use url::{EmailAddress, EmailError};
+
+    let address = EmailAddress::parse("Jo.Smith@Bücher.Example").unwrap();
+    assert_eq!(address.local_part(), "Jo.Smith");
+    assert_eq!(address.host(), Host::Domain("xn--bcher-kva.example"));
+    assert_eq!(
+        address.to_ascii().as_deref(),
+        Some("Jo.Smith@xn--bcher-kva.example")
+    );
+    assert_eq!(address.to_unicode(), "Jo.Smith@bücher.example");
+
+    // Quoted local parts are kept quoted only when needed
+    let address: EmailAddress = "\"a b\\\"c\"@example.com".parse().unwrap();
+    assert_eq!(address.local_part(), "a b\"c");
+    assert_eq!(address.to_string(), "\"a b\\\"c\"@example.com");
+    assert_eq!(
+        EmailAddress::parse("\"abc\"@example.com")
+            .unwrap()
+            .to_string(),
+        "abc@example.com"
+    );
+    assert_eq!(
+        EmailAddress::parse("\"a@b,c\"@example.com")
+            .unwrap()
+            .local_part(),
+        "a@b,c"
+    );
+
+    // RFC 6531 local parts
+    let address = EmailAddress::parse("θσερ@εχαμπλε.ψομ").unwrap();
+    assert_eq!(address.local_part(), "θσερ");
+    assert_eq!(address.to_ascii(), None);
+    assert_eq!(address.to_string(), "θσερ@xn--mxahbxey0c.xn--xxaf0a");
+    assert_eq!(address.to_unicode(), "θσερ@εχαμπλε.ψομ");
+
+    // Address literals
+    let address = EmailAddress::parse("postmaster@[192.0.2.1]").unwrap();
+    assert_eq!(
+        address.host(),
+        Host::<&str>::Ipv4(net::Ipv4Addr::new(192, 0, 2, 1))
+    );
+    assert_eq!(address.to_string(), "postmaster@[192.0.2.1]");
+    // Leading zeros are decimal, not octal as in URL hosts
+    let address = EmailAddress::parse("a@[010.0.0.1]").unwrap();
+    assert_eq!(
+        address.host(),
+        Host::<&str>::Ipv4(net::Ipv4Addr::new(10, 0, 0, 1))
+    );
+    assert_eq!(address.to_string(), "a@[10.0.0.1]");
+    let address = EmailAddress::parse("postmaster@[ipv6:2001:DB8::1]").unwrap();
+    assert_eq!(address.to_string(), "postmaster@[IPv6:2001:db8::1]");
+    assert_eq!(address.to_unicode(), "postmaster@[IPv6:2001:db8::1]");
+
+    for (input, error) in [
+        ("example.com", EmailError::MissingAt),
+        ("\"abc\"example.com", EmailError::MissingAt),
+        ("@example.com", EmailError::InvalidLocalPart),
+        ("\"\"@example.com", EmailError::InvalidLocalPart),
+        ("a..b@example.com", EmailError::InvalidLocalPart),
+        (".a@example.com", EmailError::InvalidLocalPart),
+        ("a b@example.com", EmailError::InvalidLocalPart),
+        ("a@b@example.com", EmailError::InvalidDomain),
+        ("\"a\\\u{1}\"@example.com", EmailError::InvalidLocalPart),
+        ("a@", EmailError::InvalidDomain),
+        ("a@exa_mple.com", EmailError::InvalidDomain),
+        ("a@example..com", EmailError::InvalidDomain),
+        ("a@192.0.2.1", EmailError::InvalidDomain),
+        ("a@[192.0.2.256]", EmailError::InvalidAddressLiteral),
+        ("a@[0x7f.0.0.1]", EmailError::InvalidAddressLiteral),
+        ("a@[2001:db8::1]", EmailError::InvalidAddressLiteral),
+        ("a@[IPv6:fe80::1%25eth0]", EmailError::InvalidAddressLiteral),
+        ("a@[x-tag:abc]", EmailError::InvalidAddressLiteral),
+        ("a@[IPv6é1]", EmailError::InvalidAddressLiteral),
+        ("a@[é]", EmailError::InvalidAddressLiteral),
+        ("a@[1.2.3.4.5]", EmailError::InvalidAddressLiteral),
+        ("a@[192.0.2.1", EmailError::InvalidAddressLiteral),
+    ] {
+        assert_eq!(EmailAddress::parse(input), Err(error), "{}", input);
+    }
+    let local_part = "a".repeat(65);
+    assert_eq!(
+        EmailAddress::parse(&format!("{}@example.com", local_part)),
+        Err(EmailError::LocalPartTooLong)
+    );
+    let domain = [
+        "a".repeat(63),
+        "a".repeat(63),
+        "a".repeat(63),
+        "a".repeat(60),
+    ]
+    .join(".");
+    assert_eq!(
+        EmailAddress::parse(&format!("abc@{}", domain)),
+        Err(EmailError::TooLong)
+    );
+}
+
+#[test]
+fn mailto_urls() {
+    use url::{EmailAddress, EmailError};
+
+    let to_strings = |url: &str| -> Vec<String> {
+        Url::parse(url)
+            .unwrap()
+            .mailto_addresses()
+            .unwrap()
+            .unwrap()
+            .iter()
+            .map(|address| address.to_string())
+            .collect()
+    };
+    assert_eq!(to_strings("mailto:"), Vec::<String>::new());
+    assert_eq!(to_strings("mailto:?subject=hi"), Vec::<String>::new());
+    assert_eq!(
+        to_strings("mailto:a@example.com,%22b,c%22@example.com"),
+        ["a@example.com", "\"b,c\"@example.com"]
+    );
+    assert_eq!(
+        to_strings("mailto:?To=a+b@example.com&cc=c@example.com&to=d@b%C3%BCcher.example"),
+        ["a+b@example.com", "d@xn--bcher-kva.example"]
+    );
+    assert_eq!(
+        Url::parse("mailto:a@%FF").unwrap().mailto_addresses(),
+        Some(Err(EmailError::InvalidEncoding))
+    );
+    assert_eq!(
+        Url::parse("mailto:a@example.com,b")
+            .unwrap()
+            .mailto_addresses(),
+        Some(Err(EmailError::MissingAt))
+    );
+    assert!(Url::parse("http://a@example.com/")
+        .unwrap()
+        .mailto_addresses()
+        .is_none());
+
+    for input in [
+        "Jo.Smith@Bücher.Example",
+        "θσερ@εχαμπλε.ψομ",
+        "\"a b\\\"c,d%\"@example.com",
+        "user+tag@[IPv6:2001:db8::1]",
+        "!#$%&'*+-/=?^_`{|}~@example.com",
+    ] {
+        let address = EmailAddress::parse(input).unwrap();
+        let url = address.to_mailto_url();
+        assert_eq!(url.scheme(), "mailto");
+        assert_eq!(url.mailto_addresses(), Some(Ok(vec![address])), "{}", url);
+        assert_eq!(Url::parse(url.as_str()).unwrap(), url);
+    }
+}
+
+#[cfg(feature = "uts39")]
+#[test]
+fn host_display_string() {
+    let display = |input: &str| {
+        Url::parse(input)
+            .unwrap()
+            .host()
+            .unwrap()
+            .to_display_string()
+    };
+    assert_eq!(display("https://xn--bcher-kva.example/"), "bücher.example");
+    assert_eq!(display("https://[::1]/"), "[::1]");
+    // Homographs of Latin names
+    assert_eq!(display("https://p\u{430}ypal.com/"), "xn--pypal-4ve.com");
+    assert_eq!(
+        display("https://\u{430}\u{440}\u{440}\u{4CF}\u{435}.com/"),
+        "xn--80ak6aa92e.com"
+    );
+    // but not names that are just in another script
+    assert_eq!(
+        display("https://\u{43F}\u{440}\u{438}\u{43C}\u{435}\u{440}.\u{440}\u{444}/"),
+        "\u{43F}\u{440}\u{438}\u{43C}\u{435}\u{440}.\u{440}\u{444}"
+    );
+    assert_eq!(display("https://παράδειγμα.com/"), "παράδειγμα.com");
+}